@@ -45,6 +45,7 @@ const sidebars = {
       collapsed: false,
       items: [
         'monitoring/elasticsearch-exporter',
+        'monitoring/notifications',
       ],
     },
     {
@@ -1,5 +1,6 @@
 import axios, { AxiosInstance, AxiosError, Method } from 'axios';
 import { getTraceparent } from '../utils/traceparent';
+import { withBasePath, currentAppPath } from '../utils/basePath';
 import {
   PaginatedResponse,
   ClusterInfo,
@@ -126,7 +127,7 @@ export class ApiClient {
   private client: AxiosInstance;
   private retryConfig: RetryConfig;
 
-  constructor(baseURL: string = '/api', retryConfig?: Partial<RetryConfig>) {
+  constructor(baseURL: string = withBasePath('/api'), retryConfig?: Partial<RetryConfig>) {
     this.retryConfig = { ...DEFAULT_RETRY_CONFIG, ...retryConfig };
 
     this.client = axios.create({
@@ -254,7 +255,7 @@ export class ApiClient {
       // Handle authentication errors (401)
       // Session expired or invalid - redirect to login
       if (status === 401) {
-        window.location.href = `/login?redirect_to=${encodeURIComponent(window.location.pathname + window.location.search)}`;
+        window.location.href = withBasePath(`/login?redirect_to=${encodeURIComponent(currentAppPath())}`);
         return Promise.reject(new ApiClientError('Authentication required', 401, data));
       }
 
@@ -270,7 +271,7 @@ export class ApiClient {
         const redirectPath = clusterName
           ? `/access-denied/${encodeURIComponent(clusterName)}`
           : '/access-denied';
-        window.location.href = redirectPath;
+        window.location.href = withBasePath(redirectPath);
 
         return Promise.reject(new ApiClientError('Access forbidden', 403, data));
       }
@@ -1933,7 +1934,7 @@ export class ApiClient {
     params?: { start?: number; end?: number }
   ): Promise<ClusterMetricsHistoryResponse> {
     return this.executeWithRetry(async () => {
-      const url = new URL(`/api/clusters/${clusterId}/metrics`, window.location.origin);
+      const url = new URL(withBasePath(`/api/clusters/${clusterId}/metrics`), window.location.origin);
       if (params?.start) url.searchParams.append('start', String(params.start));
       if (params?.end) url.searchParams.append('end', String(params.end));
 
@@ -1972,7 +1973,7 @@ export class ApiClient {
     params?: { start?: number; end?: number }
   ): Promise<ClusterMetricsHistoryResponse> {
     return this.executeWithRetry(async () => {
-      const url = new URL(`/api/clusters/${clusterId}/metrics/history`, window.location.origin);
+      const url = new URL(withBasePath(`/api/clusters/${clusterId}/metrics/history`), window.location.origin);
       if (params?.start) url.searchParams.append('start', String(params.start));
       if (params?.end) url.searchParams.append('end', String(params.end));
 
@@ -2011,7 +2012,7 @@ export class ApiClient {
    params?: { start?: number; end?: number }
   ): Promise<NodeMetricsHistoryResponse> {
    return this.executeWithRetry(async () => {
-      const url = new URL(`/api/clusters/${clusterId}/metrics/nodes/${nodeId}`, window.location.origin);
+      const url = new URL(withBasePath(`/api/clusters/${clusterId}/metrics/nodes/${nodeId}`), window.location.origin);
       if (params?.start) url.searchParams.append('start', String(params.start));
       if (params?.end) url.searchParams.append('end', String(params.end));
 
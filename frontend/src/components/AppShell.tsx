@@ -48,6 +48,7 @@ import { queryKeys } from '../utils/queryKeys';
 import { DURATIONS, EASINGS } from '../lib/transitions';
 import { defaultSection, isValidClusterSection, type ClusterSection, CLUSTER_NAV } from '../routes/clusterRoutes';
 import type { ClusterInfo, ClusterStats } from '../types/api';
+import { withBasePath } from '../utils/basePath';
 
 // CLUSTER_NAV is imported from routes and provides navigation metadata
 
@@ -807,7 +808,7 @@ function NavigationContent({ onNavigate }: { onNavigate?: () => void }) {
   return (
     <Stack gap="xs">
       <NavLink
-        href="/"
+        href={withBasePath('/')}
         label="Dashboard"
         leftSection={<IconDashboard size={20} aria-hidden="true" />}
         active={isActive('/')}
@@ -1,5 +1,6 @@
 import { createContext, useContext, useState, useEffect, ReactNode } from 'react';
 import { apiClient } from '../api/client';
+import { withBasePath } from '../utils/basePath';
 
 /**
  * User information interface
@@ -57,7 +58,7 @@ export function AuthProvider({ children }: AuthProviderProps) {
     const checkAuth = async () => {
       try {
         // Use dedicated auth endpoint
-        const response = await fetch('/api/auth/me', {
+        const response = await fetch(withBasePath('/api/auth/me'), {
           credentials: 'include',
           signal,
         });
@@ -96,7 +97,7 @@ export function AuthProvider({ children }: AuthProviderProps) {
    * Login with username and password
    */
   const login = async (username: string, password: string) => {
-    const response = await fetch('/api/auth/login', {
+    const response = await fetch(withBasePath('/api/auth/login'), {
       method: 'POST',
       headers: { 'Content-Type': 'application/json' },
       credentials: 'include',
@@ -116,7 +117,7 @@ export function AuthProvider({ children }: AuthProviderProps) {
     // fails it usually indicates the browser did not send the session cookie
     // (e.g. Secure/SameSite mismatch). Provide a helpful error message so the
     // user (or dev) can act instead of seeing a generic failure.
-    const meResponse = await fetch('/api/auth/me', { credentials: 'include' });
+    const meResponse = await fetch(withBasePath('/api/auth/me'), { credentials: 'include' });
     if (!meResponse.ok) {
       // Attempt to read a useful message from the /api/auth/me response body
     const meErr = await meResponse
@@ -160,7 +161,7 @@ export function AuthProvider({ children }: AuthProviderProps) {
     
     // Redirect to login page with logout flag to prevent OIDC auto-redirect
     // This ensures the user sees a manual login button without countdown
-    window.location.href = '/login?logged_out=true';
+    window.location.href = withBasePath('/login?logged_out=true');
   };
 
   // Check if authentication is actually enabled
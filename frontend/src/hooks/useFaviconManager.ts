@@ -1,4 +1,5 @@
 import { useEffect } from 'react';
+import { withBasePath } from '../utils/basePath';
 
 /**
  * Custom hook to manage favicon based on cluster health status
@@ -41,6 +42,6 @@ export function useFaviconManager(clusterHealth: string | null): void {
     faviconLink.removeAttribute('href');
     // Force reflow
     void faviconLink.offsetHeight;
-    faviconLink.href = faviconPath;
+    faviconLink.href = withBasePath(faviconPath);
   }, [clusterHealth]);
 }
@@ -52,7 +52,7 @@ export function parseError(error: unknown): ErrorDetails {
     }
 
     return {
-      error: 'api_error',
+      error: apiError.error?.code || 'api_error',
       message: apiError.message,
       statusCode: apiError.statusCode,
       details: apiError.error,
@@ -77,7 +77,7 @@ export function parseError(error: unknown): ErrorDetails {
       const esError = extractElasticsearchError(response.data);
 
       return {
-        error: response.data.error || 'api_error',
+        error: response.data.code || response.data.error || 'api_error',
         message: esError || response.data.message || 'An error occurred',
         details: response.data.details,
         statusCode: response.status,
@@ -10,12 +10,23 @@ import {
   Box,
   Alert,
   Loader,
+  Image,
 } from '@mantine/core';
 import { useNavigate, useLocation } from 'react-router-dom';
 import { useState, useEffect } from 'react';
 import { useAuth } from '../contexts/AuthContext';
 import { IconAlertCircle, IconExternalLink } from '@tabler/icons-react';
 import { APP_VERSION, getAppVersion } from '../utils/version';
+import { withBasePath } from '../utils/basePath';
+
+/**
+ * Login screen branding served by GET /api/branding (config `branding` block)
+ */
+interface Branding {
+  title?: string;
+  logo_url?: string;
+  primary_color?: string;
+}
 
 /**
  * Login component provides authentication interface.
@@ -32,6 +43,7 @@ export function Login() {
   const [appVersion, setAppVersion] = useState(APP_VERSION);
   const [oidcEnabled, setOidcEnabled] = useState(false);
   const [countdown, setCountdown] = useState(4);
+  const [branding, setBranding] = useState<Branding | null>(null);
 
   // Get redirect_to from query params, default to dashboard
   const searchParams = new URLSearchParams(location.search);
@@ -52,6 +64,18 @@ export function Login() {
   };
   const isLoggedOut = searchParams.has('logged_out');
 
+  // Fetch branding before showing the login screen; fall back to the built-in look
+  useEffect(() => {
+    const controller = new AbortController();
+    fetch(withBasePath('/api/branding'), { signal: controller.signal })
+      .then((response) => (response.ok ? response.json() : {}))
+      .then((data: Branding) => setBranding(data))
+      .catch((err) => {
+        if (!(err instanceof DOMException && err.name === 'AbortError')) setBranding({});
+      });
+    return () => controller.abort();
+  }, []);
+
   // Check auth status and handle redirects, also fetch version
   useEffect(() => {
     const controller = new AbortController();
@@ -66,7 +90,7 @@ export function Login() {
         // First check if already authenticated via /api/auth/me
         // This will catch cases where user was just redirected back from OIDC
         try {
-          const meResponse = await fetch('/api/auth/me', {
+          const meResponse = await fetch(withBasePath('/api/auth/me'), {
             credentials: 'include',
             signal,
           });
@@ -89,7 +113,7 @@ export function Login() {
         }
 
         // Fetch auth status
-        const response = await fetch('/api/auth/status', {
+        const response = await fetch(withBasePath('/api/auth/status'), {
           credentials: 'include',
           signal,
         });
@@ -125,7 +149,7 @@ export function Login() {
                 if (prev <= 1) {
                   clearInterval(intervalId!);
                   intervalId = null;
-                  window.location.href = `/api/auth/oidc/login?redirect_to=${encodeURIComponent(redirectPath)}`;
+                  window.location.href = withBasePath(`/api/auth/oidc/login?redirect_to=${encodeURIComponent(redirectPath)}`);
                   return 0;
                 }
                 return prev - 1;
@@ -176,7 +200,7 @@ export function Login() {
   };
 
   // Show loading spinner while checking auth status
-  if (authChecking) {
+  if (authChecking || branding === null) {
     return (
       <Box style={{ minHeight: '100vh', display: 'flex', alignItems: 'center', justifyContent: 'center' }}>
         <Loader />
@@ -191,6 +215,9 @@ export function Login() {
         <Container size="xs" py="xl">
           <Paper shadow="md" p="xl" radius="md" withBorder w="100%">
             <Stack gap={0} align="center" mb="xl">
+              {branding.logo_url && (
+                <Image src={branding.logo_url} alt="" h={48} w="auto" fit="contain" mb="sm" />
+              )}
               <button
                 onClick={(e) => {
                   e.preventDefault();
@@ -205,7 +232,7 @@ export function Login() {
                 }}
               >
                 <Title order={2} ta="center">
-                  Secan
+                  {branding.title ?? 'Secan'}
                 </Title>
               </button>
               <Text size="xs" c="dimmed">
@@ -229,7 +256,7 @@ export function Login() {
                   <Text ta="center" size="sm">
                     Redirecting to OIDC provider...
                   </Text>
-                  <Text ta="center" size="xl" fw={700} c="blue">
+                  <Text ta="center" size="xl" fw={700} c={branding.primary_color ?? 'blue'}>
                     {countdown}
                   </Text>
                   <Text ta="center" size="xs" c="dimmed">
@@ -246,8 +273,9 @@ export function Login() {
               </Text>
               <Button
                 component="a"
-                href={`/api/auth/oidc/login?redirect_to=${encodeURIComponent(redirectPath)}`}
+                href={withBasePath(`/api/auth/oidc/login?redirect_to=${encodeURIComponent(redirectPath)}`)}
                 variant="outline"
+                color={branding.primary_color}
                 leftSection={<IconExternalLink size={16} />}
                 fullWidth
               >
@@ -266,6 +294,9 @@ export function Login() {
       <Container size="xs" py="xl">
         <Paper shadow="md" p="xl" radius="md" withBorder w="100%">
           <Stack gap={0} align="center" mb="xl">
+            {branding.logo_url && (
+              <Image src={branding.logo_url} alt="" h={48} w="auto" fit="contain" mb="sm" />
+            )}
             <button
               onClick={(e) => {
                 e.preventDefault();
@@ -280,7 +311,7 @@ export function Login() {
               }}
             >
               <Title order={2} ta="center">
-                Secan
+                {branding.title ?? 'Secan'}
               </Title>
             </button>
             <Text size="xs" c="dimmed">
@@ -315,7 +346,7 @@ export function Login() {
                 required
               />
 
-              <Button type="submit" fullWidth loading={loading}>
+              <Button type="submit" fullWidth loading={loading} color={branding.primary_color}>
                 Sign In
               </Button>
             </Stack>
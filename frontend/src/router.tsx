@@ -3,6 +3,7 @@ import { createBrowserRouter, Navigate, useLocation } from 'react-router-dom';
 import { AppShell } from './components/AppShell';
 import { LazyRoute } from './components/LazyRoute';
 import { useAuth } from './contexts/AuthContext';
+import { BASE_PATH } from './utils/basePath';
 
 // Protected route component - redirects to login if not authenticated
 function ProtectedRoute({ children }: { children: React.ReactNode }) {
@@ -312,4 +313,4 @@ export const router = createBrowserRouter([
     path: '*',
     element: <Navigate to="/" replace />,
   },
-]);
+], { basename: BASE_PATH || '/' });
@@ -506,6 +506,10 @@ export interface DetailedShardStats extends ShardInfo {
 export interface ApiError {
   data?: { reason?: string; root_cause?: Array<{ reason: string }> };
   status?: number;
+  /** Stable error code from cluster routes, e.g. `cluster_not_found` */
+  code?: string;
+  /** Cluster the failed request was for */
+  cluster_id?: string;
   error: string;
   message: string;
   details?: unknown;
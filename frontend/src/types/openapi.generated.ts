@@ -13,7 +13,8 @@ export interface paths {
         };
         /**
          * Version endpoint
-         * @description Returns the current version and git information
+         * @description Returns the version, git commit, build time and compiler of the running
+         *     binary, all embedded at build time.
          *     This endpoint does not require authentication
          */
         get: operations["get_version"];
@@ -1633,8 +1634,18 @@ export interface components {
         };
         /** @description Version response */
         VersionResponse: {
-            /** @example v1.2.28-5-g1234567 */
-            git_info: string;
+            /**
+             * @description When the binary was built (RFC 3339)
+             * @example 2026-10-17T08:30:00Z
+             */
+            build_time: string;
+            /**
+             * @description Short commit hash the binary was built from ("unknown" if not known)
+             * @example 1a2b3c4
+             */
+            git_sha: string;
+            /** @example rustc 1.90.0 (1159e78c4 2025-09-14) */
+            rustc_version: string;
             /** @example 1.2.28 */
             version: string;
         };
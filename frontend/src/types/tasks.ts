@@ -147,7 +147,7 @@ export interface TasksQueryParams {
   action_filter?: string;
 }
 
-// Task API errors are described by the shared ClusterErrorResponse in the
+// Task API errors are described by the shared ApiError in the
 // generated API types (frontend/src/types/api.ts). We intentionally do not
 // duplicate the shape here to avoid divergence.
 
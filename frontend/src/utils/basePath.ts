@@ -0,0 +1,25 @@
+/**
+ * Base path the app is served under (`server.base_path`), e.g. '/secan'
+ * The backend puts it in a meta tag of index.html; empty when served from the root
+ */
+export const BASE_PATH: string =
+  document
+    .querySelector<HTMLMetaElement>('meta[name="secan-base-path"]')
+    ?.content.replace(/\/+$/, '') ?? '';
+
+/**
+ * Absolute URL path for a path relative to the base path ('/api/version' → '/secan/api/version')
+ */
+export function withBasePath(path: string): string {
+  return `${BASE_PATH}${path}`;
+}
+
+/**
+ * Current location (path and query) relative to the base path, as used for redirect_to
+ */
+export function currentAppPath(): string {
+  const { pathname, search } = window.location;
+  const path =
+    BASE_PATH && pathname.startsWith(BASE_PATH) ? pathname.slice(BASE_PATH.length) || '/' : pathname;
+  return path + search;
+}
@@ -1,3 +1,5 @@
+import { withBasePath } from './basePath';
+
 /**
  * Application version fetched from backend API
  * Falls back to 'v1.2.4' if API is unavailable
@@ -10,7 +12,7 @@ export async function getAppVersion(): Promise<string> {
   }
 
   try {
-    const response = await fetch('/api/version');
+    const response = await fetch(withBasePath('/api/version'));
     if (response.ok) {
       const data = await response.json();
       cachedVersion = `v${data.version}`;
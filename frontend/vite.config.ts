@@ -1,10 +1,52 @@
-import { defineConfig } from 'vite';
+import { defineConfig, type Plugin } from 'vite';
 import react from '@vitejs/plugin-react';
+import fs from 'fs';
 import path from 'path';
+import zlib from 'zlib';
+
+/**
+ * Write .br and .gz variants of the built JS, CSS and SVG files next to them.
+ * The backend embeds them and serves them to clients that accept the encoding,
+ * so assets are not compressed again on every request.
+ */
+function precompress(): Plugin {
+  let outDir = 'dist';
+  return {
+    name: 'secan-precompress',
+    apply: 'build',
+    configResolved(config) {
+      outDir = path.resolve(config.root, config.build.outDir);
+    },
+    closeBundle() {
+      const walk = (dir: string): string[] =>
+        fs.readdirSync(dir, { withFileTypes: true }).flatMap((entry) => {
+          const file = path.join(dir, entry.name);
+          return entry.isDirectory() ? walk(file) : [file];
+        });
+      for (const file of walk(outDir)) {
+        // Tiny files gain nothing from compression
+        if (!/\.(js|css|svg)$/.test(file) || fs.statSync(file).size < 1024) {
+          continue;
+        }
+        const content = fs.readFileSync(file);
+        fs.writeFileSync(`${file}.gz`, zlib.gzipSync(content, { level: 9 }));
+        fs.writeFileSync(
+          `${file}.br`,
+          zlib.brotliCompressSync(content, {
+            params: { [zlib.constants.BROTLI_PARAM_QUALITY]: zlib.constants.BROTLI_MAX_QUALITY },
+          })
+        );
+      }
+    },
+  };
+}
 
 // https://vitejs.dev/config/
 export default defineConfig({
-  plugins: [react()],
+  plugins: [react(), precompress()],
+  // Relative asset URLs, resolved against the <base href> the backend adds to
+  // index.html, so the same build works under any server.base_path
+  base: './',
   resolve: {
     alias: {
       '@': path.resolve(__dirname, './src'),
@@ -2,28 +2,44 @@
 //!
 //! Provides application-level metrics for Prometheus scraping.
 //! This module provides the infrastructure - the actual metric recording
-//! should be done at the call sites.
-
-use metrics_exporter_prometheus::PrometheusBuilder;
-use std::net::SocketAddr;
-
-/// Initialize the Prometheus metrics exporter
-/// This should be called once at application startup
-pub fn init_metrics() -> Result<(), Box<dyn std::error::Error>> {
-    let addr: SocketAddr = "0.0.0.0:9090".parse()?;
-    let builder = PrometheusBuilder::new().with_http_listener(addr);
-
-    builder.install()?;
+//! should be done at the call sites. Metrics are rendered in the Prometheus
+//! text exposition format by the `/metrics` route.
+
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use std::sync::OnceLock;
+
+static HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
+
+/// Initialize the Prometheus metrics recorder
+///
+/// Installs the global recorder on first call and returns the handle used to
+/// render metrics; later calls return the same handle. If another recorder is
+/// already installed, metrics are not recorded and the handle renders empty
+/// output.
+pub fn init_metrics() -> &'static PrometheusHandle {
+    HANDLE.get_or_init(|| match PrometheusBuilder::new().install_recorder() {
+        Ok(handle) => handle,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to install metrics recorder, /metrics will be empty");
+            PrometheusBuilder::new().build_recorder().handle()
+        }
+    })
+}
 
-    Ok(())
+/// Render all recorded metrics in the Prometheus text exposition format
+pub fn render() -> String {
+    init_metrics().render()
 }
 
 /// HTTP request metrics
 pub mod http {
-    /// Record an HTTP request with method, path, status code, and duration
-    pub fn record_request(method: &str, _path: &str, status: u16, duration_ms: f64) {
-        metrics::counter!("http.requests.total", "method" => method.to_string(), "status" => status.to_string()).increment(1);
-        metrics::histogram!("http.request.duration_ms", "method" => method.to_string())
+    /// Record an HTTP request with method, route, status code, and duration
+    ///
+    /// `route` should be the matched route template (e.g. `/api/clusters/{id}/health`),
+    /// not the raw path, to keep label cardinality bounded.
+    pub fn record_request(method: &str, route: &str, status: u16, duration_ms: f64) {
+        metrics::counter!("http.requests.total", "method" => method.to_string(), "route" => route.to_string(), "status" => status.to_string()).increment(1);
+        metrics::histogram!("http.request.duration_ms", "method" => method.to_string(), "route" => route.to_string())
             .record(duration_ms);
     }
 }
@@ -58,6 +74,38 @@ pub mod cluster {
         metrics::gauge!("cluster.total").set(count as f64);
     }
 
+    /// Record the number of clusters whose last health check succeeded
+    pub fn set_reachable_clusters(count: usize) {
+        metrics::gauge!("cluster.reachable").set(count as f64);
+    }
+
+    /// Record the health status of a cluster (0 = green, 1 = yellow, 2 = red)
+    pub fn set_health_status(cluster_id: &str, status: u8) {
+        metrics::gauge!("cluster.health.status", "cluster_id" => cluster_id.to_string())
+            .set(status as f64);
+    }
+
+    /// Record whether the last health check of a cluster succeeded
+    pub fn set_up(cluster_id: &str, up: bool) {
+        metrics::gauge!("cluster.up", "cluster_id" => cluster_id.to_string()).set(if up {
+            1.0
+        } else {
+            0.0
+        });
+    }
+
+    /// Record the number of requests currently sent to a cluster
+    pub fn set_requests_in_flight(cluster_id: &str, count: usize) {
+        metrics::gauge!("cluster.requests.in_flight", "cluster_id" => cluster_id.to_string())
+            .set(count as f64);
+    }
+
+    /// Record a request rejected after queueing on a busy cluster
+    pub fn record_request_rejected(cluster_id: &str) {
+        metrics::counter!("cluster.requests.rejected.total", "cluster_id" => cluster_id.to_string())
+            .increment(1);
+    }
+
     /// Record cluster request latency
     pub fn record_request_latency(cluster_id: &str, latency_ms: f64) {
         metrics::histogram!("cluster.request.latency_ms", "cluster_id" => cluster_id.to_string())
@@ -78,6 +126,40 @@ pub mod cache {
     }
 }
 
+/// Session metrics
+pub mod sessions {
+    /// Record a newly created session
+    pub fn record_created() {
+        metrics::counter!("sessions.created.total").increment(1);
+    }
+
+    /// Set the number of sessions seen within the idle window
+    pub fn set_active(count: usize) {
+        metrics::gauge!("sessions.active").set(count as f64);
+    }
+
+    /// Set the number of entries in the revocation list
+    pub fn set_revoked(count: usize) {
+        metrics::gauge!("sessions.revoked").set(count as f64);
+    }
+}
+
+/// TLS certificate metrics
+pub mod tls {
+    /// Record the days left until a cluster's CA or client certificate expires
+    ///
+    /// Negative once the certificate has expired.
+    pub fn set_certificate_expiry_days(cluster_id: &str, path: &str, subject: &str, days: i64) {
+        metrics::gauge!(
+            "tls.certificate.expiry_days",
+            "cluster_id" => cluster_id.to_string(),
+            "path" => path.to_string(),
+            "subject" => subject.to_string()
+        )
+        .set(days as f64);
+    }
+}
+
 /// Active connections gauge
 pub mod connections {
     /// Set active connection count
@@ -0,0 +1,180 @@
+use crate::auth::local::verify_password_async;
+use crate::auth::{AuthUser, RateLimiter};
+use crate::config::ApiToken;
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+/// How long a successfully verified token is remembered
+///
+/// bcrypt verification is deliberately slow, so scripts issuing many requests
+/// would otherwise pay for it on every call.
+const VERIFIED_TOKEN_TTL: Duration = Duration::from_secs(300);
+
+/// Outcome of an API token authentication attempt
+#[derive(Debug)]
+pub enum ApiTokenOutcome {
+    /// Token matched a configured API token
+    Authenticated(AuthUser),
+    /// Token did not match any configured API token
+    Invalid,
+    /// Caller is blocked by the rate limiter
+    RateLimited,
+}
+
+/// API token authentication provider
+///
+/// Verifies bearer tokens against the bcrypt hashes configured in
+/// `auth.api_tokens`. Failed attempts are recorded in the shared
+/// authentication rate limiter.
+#[derive(Debug, Clone)]
+pub struct ApiTokenAuthenticator {
+    tokens: Vec<ApiToken>,
+    rate_limiter: RateLimiter,
+    /// SHA-256 digest of recently verified tokens -> index into `tokens`
+    verified: moka::future::Cache<String, usize>,
+}
+
+impl ApiTokenAuthenticator {
+    /// Create a new API token authenticator
+    pub fn new(tokens: Vec<ApiToken>, rate_limiter: RateLimiter) -> Self {
+        Self {
+            tokens,
+            rate_limiter,
+            verified: moka::future::Cache::builder()
+                .time_to_live(VERIFIED_TOKEN_TTL)
+                .build(),
+        }
+    }
+
+    /// Authenticate a bearer token
+    ///
+    /// `client_id` identifies the caller (usually its IP address) for rate limiting.
+    pub async fn authenticate(&self, token: &str, client_id: &str) -> Result<ApiTokenOutcome> {
+        let limiter_key = format!("api_token:{}", client_id);
+        if self.rate_limiter.is_rate_limited(&limiter_key).await {
+            tracing::warn!(client = %client_id, "API token authentication blocked: rate limit exceeded");
+            return Ok(ApiTokenOutcome::RateLimited);
+        }
+
+        let digest = token_digest(token);
+        let matched = match self.verified.get(&digest).await {
+            Some(index) => Some(index),
+            None => self.verify(token).await?,
+        };
+
+        let Some(index) = matched else {
+            self.rate_limiter.record_failed_attempt(&limiter_key).await;
+            tracing::warn!(client = %client_id, "API token authentication failed: invalid token");
+            return Ok(ApiTokenOutcome::Invalid);
+        };
+
+        self.verified.insert(digest, index).await;
+        self.rate_limiter.record_success(&limiter_key).await;
+
+        let api_token = &self.tokens[index];
+        let user = AuthUser::new(
+            format!("api-token:{}", api_token.name),
+            api_token.name.clone(),
+            api_token.groups.clone(),
+        )
+        .with_auth_type("api_token");
+
+        tracing::debug!(token_name = %api_token.name, "API token authenticated successfully");
+
+        Ok(ApiTokenOutcome::Authenticated(user))
+    }
+
+    /// Find the configured token matching `token`, if any
+    async fn verify(&self, token: &str) -> Result<Option<usize>> {
+        for (index, api_token) in self.tokens.iter().enumerate() {
+            if verify_password_async(token, &api_token.token_hash).await? {
+                return Ok(Some(index));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Hex-encoded SHA-256 digest of a token, used as cache key so raw tokens are not retained
+fn token_digest(token: &str) -> String {
+    Sha256::digest(token.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{hash_password, RateLimitConfig};
+
+    fn authenticator(max_attempts: u32) -> ApiTokenAuthenticator {
+        let tokens = vec![ApiToken {
+            name: "ci".to_string(),
+            token_hash: hash_password("ci-secret-token").expect("hash token"),
+            groups: vec!["deployers".to_string()],
+        }];
+        ApiTokenAuthenticator::new(
+            tokens,
+            RateLimiter::new(RateLimitConfig::new(max_attempts, 300, 900)),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_valid_token_authenticates() {
+        let auth = authenticator(5);
+
+        let outcome = auth
+            .authenticate("ci-secret-token", "127.0.0.1")
+            .await
+            .expect("authenticate");
+
+        match outcome {
+            ApiTokenOutcome::Authenticated(user) => {
+                assert_eq!(user.username, "ci");
+                assert_eq!(user.roles, vec!["deployers".to_string()]);
+                assert_eq!(user.auth_type, "api_token");
+            }
+            other => panic!("expected authenticated, got {:?}", other),
+        }
+
+        // Second call is served from the verified-token cache
+        assert!(matches!(
+            auth.authenticate("ci-secret-token", "127.0.0.1")
+                .await
+                .expect("authenticate"),
+            ApiTokenOutcome::Authenticated(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_tokens_are_rate_limited() {
+        let auth = authenticator(2);
+
+        for _ in 0..2 {
+            assert!(matches!(
+                auth.authenticate("wrong", "10.0.0.1")
+                    .await
+                    .expect("authenticate"),
+                ApiTokenOutcome::Invalid
+            ));
+        }
+
+        // Blocked now, even with the correct token
+        assert!(matches!(
+            auth.authenticate("ci-secret-token", "10.0.0.1")
+                .await
+                .expect("authenticate"),
+            ApiTokenOutcome::RateLimited
+        ));
+
+        // Other clients are unaffected
+        assert!(matches!(
+            auth.authenticate("ci-secret-token", "10.0.0.2")
+                .await
+                .expect("authenticate"),
+            ApiTokenOutcome::Authenticated(_)
+        ));
+    }
+}
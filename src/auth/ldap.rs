@@ -853,6 +853,7 @@ impl LdapAuthProvider {
             roles: Vec::new(),               // Roles are not extracted from LDAP
             accessible_clusters: Vec::new(), // Clusters are not extracted from LDAP
             auth_type: "ldap".to_string(),
+            client_ip: None,
         }
     }
 
@@ -1,12 +1,18 @@
 use crate::auth::{AuthUser, RateLimiter, SessionManager};
 use crate::config::LocalUser;
 use anyhow::{Context, Result};
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
 use bcrypt::{hash, verify, DEFAULT_COST};
+use std::sync::{Arc, RwLock, RwLockReadGuard};
 
 /// Local user authentication provider
+///
+/// Clones share the same user list, so a [`LocalAuthProvider::reload`] on any
+/// clone is visible to all of them.
 #[derive(Debug, Clone)]
 pub struct LocalAuthProvider {
-    users: Vec<LocalUser>,
+    users: Arc<RwLock<Vec<LocalUser>>>,
     session_manager: SessionManager,
     rate_limiter: Option<RateLimiter>,
 }
@@ -15,7 +21,7 @@ impl LocalAuthProvider {
     /// Create a new local authentication provider
     pub fn new(users: Vec<LocalUser>, session_manager: SessionManager) -> Self {
         Self {
-            users,
+            users: Arc::new(RwLock::new(users)),
             session_manager,
             rate_limiter: None,
         }
@@ -28,95 +34,61 @@ impl LocalAuthProvider {
         rate_limiter: RateLimiter,
     ) -> Self {
         Self {
-            users,
+            users: Arc::new(RwLock::new(users)),
             session_manager,
             rate_limiter: Some(rate_limiter),
         }
     }
 
+    fn read_users(&self) -> RwLockReadGuard<'_, Vec<LocalUser>> {
+        // A panic while holding the lock cannot leave the user list inconsistent
+        self.users.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Replace the configured users, e.g. on config reload
+    ///
+    /// Returns the usernames whose password hash changed or who were removed,
+    /// so their existing sessions can be revoked.
+    pub fn reload(&self, users: Vec<LocalUser>) -> Vec<String> {
+        let mut current = self.users.write().unwrap_or_else(|e| e.into_inner());
+        let stale = current
+            .iter()
+            .filter(|old| {
+                !users.iter().any(|new| {
+                    new.username == old.username && new.password_hash == old.password_hash
+                })
+            })
+            .map(|old| old.username.clone())
+            .collect();
+        *current = users;
+        stale
+    }
+
     /// Authenticate a user with username and password
     ///
     /// Returns a session token if authentication succeeds
     /// Returns None if rate limited or authentication fails
+    ///
+    /// For callers without a client address; attempts are rate limited as
+    /// if they all came from one unknown client.
     pub async fn authenticate(&self, username: &str, password: &str) -> Result<Option<String>> {
-        // Check rate limiting by username
-        if let Some(rate_limiter) = &self.rate_limiter {
-            if rate_limiter.is_rate_limited(username).await {
-                tracing::warn!(
-                    username = %username,
-                    "Authentication blocked: rate limit exceeded"
-                );
-                return Ok(None);
-            }
-        }
-
-        // Find user by username
-        let user = self.users.iter().find(|u| u.username == username);
-
-        match user {
-            Some(user) => {
-                // Verify password against stored hash (async to avoid blocking runtime)
-                let password_valid = verify_password_async(password, &user.password_hash).await?;
-
-                if password_valid {
-                    // Record successful authentication (clears rate limit)
-                    if let Some(rate_limiter) = &self.rate_limiter {
-                        rate_limiter.record_success(username).await;
-                    }
-
-                    let auth_user = AuthUser::new(
-                        user.username.clone(),
-                        user.username.clone(),
-                        user.groups.clone(),
-                    );
-
-                    let token = self.session_manager.create_session(auth_user).await?;
-
-                    tracing::debug!(
-                        username = %username,
-                        groups = ?user.groups,
-                        "User authenticated successfully"
-                    );
-
-                    Ok(Some(token))
-                } else {
-                    // Record failed attempt
-                    if let Some(rate_limiter) = &self.rate_limiter {
-                        rate_limiter.record_failed_attempt(username).await;
-                    }
-
-                    tracing::warn!(
-                        username = %username,
-                        "Authentication failed: invalid password"
-                    );
-                    Ok(None)
-                }
-            }
-            None => {
-                // Record failed attempt even for non-existent users
-                // to prevent username enumeration attacks
-                if let Some(rate_limiter) = &self.rate_limiter {
-                    rate_limiter.record_failed_attempt(username).await;
-                }
-
-                tracing::warn!(
-                    username = %username,
-                    "Authentication failed: user not found"
-                );
-                Ok(None)
-            }
-        }
+        self.authenticate_with_ip(username, password, "unknown")
+            .await
     }
 
     /// Authenticate with IP-based rate limiting
     ///
-    /// Checks both username and IP address for rate limiting
+    /// Failed attempts count against the client IP and against the pair of
+    /// client IP and username, never the username alone, so nobody can lock
+    /// a user out by failing logins under their name from elsewhere.
     pub async fn authenticate_with_ip(
         &self,
         username: &str,
         password: &str,
         ip_address: &str,
     ) -> Result<Option<String>> {
+        let user_key = login_attempt_key(ip_address, username);
+
         // Check rate limiting by IP address
         if let Some(rate_limiter) = &self.rate_limiter {
             if rate_limiter.is_rate_limited(ip_address).await {
@@ -128,8 +100,8 @@ impl LocalAuthProvider {
                 return Ok(None);
             }
 
-            // Also check username-based rate limiting
-            if rate_limiter.is_rate_limited(username).await {
+            // Also check rate limiting of this user from this IP
+            if rate_limiter.is_rate_limited(&user_key).await {
                 tracing::warn!(
                     username = %username,
                     ip = %ip_address,
@@ -140,7 +112,7 @@ impl LocalAuthProvider {
         }
 
         // Find user by username
-        let user = self.users.iter().find(|u| u.username == username);
+        let user = self.get_user(username);
 
         match user {
             Some(user) => {
@@ -150,7 +122,7 @@ impl LocalAuthProvider {
                 if password_valid {
                     // Record successful authentication (clears rate limits)
                     if let Some(rate_limiter) = &self.rate_limiter {
-                        rate_limiter.record_success(username).await;
+                        rate_limiter.record_success(&user_key).await;
                         rate_limiter.record_success(ip_address).await;
                     }
 
@@ -171,9 +143,9 @@ impl LocalAuthProvider {
 
                     Ok(Some(token))
                 } else {
-                    // Record failed attempt for both username and IP
+                    // Record failed attempt for both the user from this IP and the IP
                     if let Some(rate_limiter) = &self.rate_limiter {
-                        rate_limiter.record_failed_attempt(username).await;
+                        rate_limiter.record_failed_attempt(&user_key).await;
                         rate_limiter.record_failed_attempt(ip_address).await;
                     }
 
@@ -186,9 +158,9 @@ impl LocalAuthProvider {
                 }
             }
             None => {
-                // Record failed attempt for both username and IP
+                // Record failed attempt for both the user from this IP and the IP
                 if let Some(rate_limiter) = &self.rate_limiter {
-                    rate_limiter.record_failed_attempt(username).await;
+                    rate_limiter.record_failed_attempt(&user_key).await;
                     rate_limiter.record_failed_attempt(ip_address).await;
                 }
 
@@ -206,7 +178,7 @@ impl LocalAuthProvider {
     ///
     /// Useful for testing or validation purposes
     pub fn validate_credentials(&self, username: &str, password: &str) -> Result<bool> {
-        let user = self.users.iter().find(|u| u.username == username);
+        let user = self.get_user(username);
 
         match user {
             Some(user) => verify_password(password, &user.password_hash),
@@ -215,26 +187,115 @@ impl LocalAuthProvider {
     }
 
     /// Get user information by username
-    pub fn get_user(&self, username: &str) -> Option<&LocalUser> {
-        self.users.iter().find(|u| u.username == username)
+    pub fn get_user(&self, username: &str) -> Option<LocalUser> {
+        self.read_users()
+            .iter()
+            .find(|u| u.username == username)
+            .cloned()
     }
 
     /// Get all configured users (without password hashes)
     pub fn list_users(&self) -> Vec<String> {
-        self.users.iter().map(|u| u.username.clone()).collect()
+        self.read_users()
+            .iter()
+            .map(|u| u.username.clone())
+            .collect()
     }
 }
 
+/// Lowest bcrypt cost accepted by [`hash_password_with_cost`]
+pub const BCRYPT_MIN_COST: u32 = 4;
+/// Highest bcrypt cost accepted by [`hash_password_with_cost`]
+pub const BCRYPT_MAX_COST: u32 = 31;
+
 /// Hash a password using bcrypt
 ///
 /// Uses DEFAULT_COST (12) for security
 pub fn hash_password(password: &str) -> Result<String> {
-    hash(password, DEFAULT_COST).context("Failed to hash password")
+    hash_password_with_cost(password, DEFAULT_COST)
 }
 
-/// Verify a password against a bcrypt hash
+/// Hash a password using bcrypt with an explicit cost (4-31)
+pub fn hash_password_with_cost(password: &str, cost: u32) -> Result<String> {
+    if !(BCRYPT_MIN_COST..=BCRYPT_MAX_COST).contains(&cost) {
+        anyhow::bail!(
+            "bcrypt cost must be between {} and {}, got {}",
+            BCRYPT_MIN_COST,
+            BCRYPT_MAX_COST,
+            cost
+        );
+    }
+    hash(password, cost).context("Failed to hash password")
+}
+
+/// Hash a password using Argon2id with the default parameters
+pub fn hash_password_argon2(password: &str) -> Result<String> {
+    let salt_bytes: [u8; 16] = rand::random();
+    let salt = SaltString::encode_b64(&salt_bytes)
+        .map_err(|e| anyhow::anyhow!("Failed to encode salt: {}", e))?;
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| anyhow::anyhow!("Failed to hash password: {}", e))
+}
+
+/// Password hashing scheme, identified by the hash prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HashAlgorithm {
+    #[default]
+    Bcrypt,
+    Argon2,
+}
+
+impl HashAlgorithm {
+    /// Detect the scheme of a stored hash (`$2b$` bcrypt, `$argon2id$` Argon2id)
+    pub fn detect(hash: &str) -> Option<Self> {
+        const BCRYPT_PREFIXES: &[&str] = &["$2a$", "$2b$", "$2x$", "$2y$"];
+
+        if BCRYPT_PREFIXES
+            .iter()
+            .any(|prefix| hash.starts_with(prefix))
+        {
+            Some(Self::Bcrypt)
+        } else if hash.starts_with("$argon2id$") {
+            Some(Self::Argon2)
+        } else {
+            None
+        }
+    }
+}
+
+impl std::str::FromStr for HashAlgorithm {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "bcrypt" => Ok(Self::Bcrypt),
+            "argon2" | "argon2id" => Ok(Self::Argon2),
+            other => anyhow::bail!("Unknown algorithm '{}' (expected bcrypt or argon2)", other),
+        }
+    }
+}
+
+/// Verify a password against a bcrypt or Argon2id hash
+///
+/// The scheme is detected from the hash prefix, and cost parameters are read
+/// from the hash itself, so users hashed with different schemes or costs can
+/// be mixed in one user list.
 pub fn verify_password(password: &str, hash: &str) -> Result<bool> {
-    verify(password, hash).context("Failed to verify password")
+    match HashAlgorithm::detect(hash) {
+        Some(HashAlgorithm::Bcrypt) => verify(password, hash).context("Failed to verify password"),
+        Some(HashAlgorithm::Argon2) => {
+            let parsed = PasswordHash::new(hash)
+                .map_err(|e| anyhow::anyhow!("Invalid Argon2 hash: {}", e))?;
+            match Argon2::default().verify_password(password.as_bytes(), &parsed) {
+                Ok(()) => Ok(true),
+                Err(argon2::password_hash::Error::Password) => Ok(false),
+                Err(e) => Err(anyhow::anyhow!("Failed to verify password: {}", e)),
+            }
+        }
+        None => anyhow::bail!("Unrecognized password hash format"),
+    }
 }
 
 /// Verify a password asynchronously using spawn_blocking
@@ -279,6 +340,11 @@ pub async fn verify_password_async(password: &str, hash: &str) -> Result<bool> {
     }
 }
 
+/// Rate limiter key of login attempts for a user from one client IP
+fn login_attempt_key(ip_address: &str, username: &str) -> String {
+    format!("{}/{}", ip_address, username)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -313,6 +379,71 @@ mod tests {
         assert_ne!(hash, hash2);
     }
 
+    #[test]
+    fn test_hash_password_with_cost() {
+        let password = "test_password";
+        let hash = hash_password_with_cost(password, 5).expect("hash password");
+
+        assert!(hash.starts_with("$2b$05$"), "{}", hash);
+        assert!(verify_password(password, &hash).expect("verify password"));
+        assert!(!verify_password("wrong_password", &hash).expect("verify password"));
+
+        // Hashes at the default cost still verify alongside it
+        let default_hash = hash_password(password).expect("hash password");
+        assert!(verify_password(password, &default_hash).expect("verify password"));
+    }
+
+    #[test]
+    fn test_hash_password_with_cost_out_of_range() {
+        assert!(hash_password_with_cost("pw", BCRYPT_MIN_COST - 1).is_err());
+        let err = hash_password_with_cost("pw", BCRYPT_MAX_COST + 1).expect_err("cost too high");
+        assert!(err.to_string().contains("between 4 and 31"), "{}", err);
+    }
+
+    #[test]
+    fn test_argon2_password_round_trip() {
+        let password = "test_password";
+        let hash = hash_password_argon2(password).expect("hash password");
+
+        assert!(hash.starts_with("$argon2id$"), "{}", hash);
+        assert_eq!(HashAlgorithm::detect(&hash), Some(HashAlgorithm::Argon2));
+        assert!(verify_password(password, &hash).expect("verify password"));
+        assert!(!verify_password("wrong_password", &hash).expect("verify password"));
+    }
+
+    #[test]
+    fn test_bcrypt_hash_is_detected() {
+        let hash = hash_password_with_cost("test_password", 4).expect("hash password");
+
+        assert_eq!(HashAlgorithm::detect(&hash), Some(HashAlgorithm::Bcrypt));
+        assert!(verify_password("test_password", &hash).expect("verify password"));
+    }
+
+    #[test]
+    fn test_unrecognized_hash_prefix() {
+        assert_eq!(
+            HashAlgorithm::detect("$argon2i$v=19$m=16,t=2,p=1$c2FsdA$aGFzaA"),
+            None
+        );
+        assert_eq!(HashAlgorithm::detect("plaintext"), None);
+
+        let err = verify_password("pw", "$1$md5crypt$hash").expect_err("unknown scheme");
+        assert!(err.to_string().contains("Unrecognized"), "{}", err);
+    }
+
+    #[test]
+    fn test_hash_algorithm_from_str() {
+        assert_eq!(
+            "bcrypt".parse::<HashAlgorithm>().ok(),
+            Some(HashAlgorithm::Bcrypt)
+        );
+        assert_eq!(
+            "Argon2".parse::<HashAlgorithm>().ok(),
+            Some(HashAlgorithm::Argon2)
+        );
+        assert!("md5".parse::<HashAlgorithm>().is_err());
+    }
+
     #[test]
     fn test_verify_password() {
         let password = "test_password";
@@ -454,6 +585,31 @@ mod tests {
         assert!(token.is_none());
     }
 
+    #[tokio::test]
+    async fn test_failed_logins_do_not_lock_out_other_ips() {
+        let users = create_test_users();
+        let session_config = SessionConfig::new(60, TEST_SECRET.to_string());
+        let session_manager = SessionManager::new(session_config);
+        let rate_limit_config = RateLimitConfig::new(3, 300, 900);
+        let rate_limiter = RateLimiter::new(rate_limit_config);
+        let provider = LocalAuthProvider::with_rate_limiter(users, session_manager, rate_limiter);
+
+        for _ in 0..3 {
+            let token = provider
+                .authenticate_with_ip("admin", "wrong_password", "203.0.113.7")
+                .await
+                .expect("authenticate_with_ip should succeed");
+            assert!(token.is_none());
+        }
+
+        // The user can still log in from their own address
+        let token = provider
+            .authenticate_with_ip("admin", "admin123", "192.168.1.100")
+            .await
+            .expect("authenticate_with_ip should succeed");
+        assert!(token.is_some());
+    }
+
     #[tokio::test]
     async fn test_local_auth_provider_success_clears_rate_limit() {
         let users = create_test_users();
@@ -1,11 +1,15 @@
-use crate::auth::{build_session_cookie_header, AuthUser, SessionManager};
+use crate::auth::api_token::{ApiTokenAuthenticator, ApiTokenOutcome};
+use crate::auth::{AuthUser, OidcAuthProvider, SessionManager, TokenRefreshOutcome};
 use crate::config::AuthMode;
+use crate::middleware::base_path::BasePath;
+use crate::middleware::client_ip::ClientIp;
 use axum::{
-    extract::{Request, State},
-    http::{header, StatusCode},
+    extract::{ConnectInfo, Request, State},
+    http::{header, HeaderMap, StatusCode},
     middleware::Next,
     response::{IntoResponse, Redirect, Response},
 };
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 
 /// Authentication state shared across the application
@@ -13,6 +17,10 @@ use std::sync::Arc;
 pub struct AuthState {
     pub session_manager: Arc<SessionManager>,
     pub auth_mode: AuthMode,
+    /// API token authenticator (set when `auth.api_tokens` is configured)
+    pub api_tokens: Option<Arc<ApiTokenAuthenticator>>,
+    /// OIDC provider used to refresh access tokens of OIDC sessions
+    pub oidc_provider: Option<Arc<OidcAuthProvider>>,
 }
 
 impl AuthState {
@@ -20,8 +28,22 @@ impl AuthState {
         Self {
             session_manager,
             auth_mode,
+            api_tokens: None,
+            oidc_provider: None,
         }
     }
+
+    /// Accept `Authorization: Bearer <token>` API tokens in addition to sessions
+    pub fn with_api_tokens(mut self, api_tokens: Arc<ApiTokenAuthenticator>) -> Self {
+        self.api_tokens = Some(api_tokens);
+        self
+    }
+
+    /// Refresh OIDC access tokens (and re-evaluate groups) as sessions age
+    pub fn with_oidc_provider(mut self, oidc_provider: Arc<OidcAuthProvider>) -> Self {
+        self.oidc_provider = Some(oidc_provider);
+        self
+    }
 }
 
 /// Extension type to attach authenticated user to request
@@ -31,6 +53,7 @@ pub struct AuthenticatedUser(pub AuthUser);
 /// Authentication middleware that validates session tokens from cookies
 ///
 /// This middleware:
+/// - Authenticates `Authorization: Bearer` API tokens when configured
 /// - Extracts session tokens from cookies
 /// - Validates tokens using SessionManager
 /// - Supports Open mode (no authentication required)
@@ -42,6 +65,8 @@ pub async fn auth_middleware(
     mut request: Request,
     next: Next,
 ) -> Result<Response, AuthError> {
+    let client_ip = resolved_client_ip(&request);
+
     // In Open mode, allow all requests without authentication
     if auth_state.auth_mode == AuthMode::Open {
         tracing::debug!("Open mode: allowing request without authentication");
@@ -53,7 +78,8 @@ pub async fn auth_middleware(
             vec!["*".to_string()], // Full access in Open mode
             vec!["*".to_string()], // Full cluster access in Open mode
         )
-        .with_auth_type("open");
+        .with_auth_type("open")
+        .with_client_ip(client_ip);
 
         request
             .extensions_mut()
@@ -68,8 +94,35 @@ pub async fn auth_middleware(
         return Ok(next.run(request).await);
     }
 
+    // API tokens take precedence over session cookies for programmatic access
+    if let Some(api_tokens) = &auth_state.api_tokens {
+        if let Some(token) = extract_bearer_token(request.headers()) {
+            let client_id = client_identifier(&request);
+            let outcome = api_tokens
+                .authenticate(&token, &client_id)
+                .await
+                .map_err(|e| {
+                    tracing::error!(error = %e, "Failed to verify API token");
+                    AuthError::InternalError
+                })?;
+
+            return match outcome {
+                ApiTokenOutcome::Authenticated(user) => {
+                    let user = user.with_client_ip(client_ip);
+                    request.extensions_mut().insert(AuthenticatedUser(user));
+                    Ok(next.run(request).await)
+                }
+                ApiTokenOutcome::Invalid => Err(AuthError::InvalidApiToken),
+                ApiTokenOutcome::RateLimited => Err(AuthError::RateLimited),
+            };
+        }
+    }
+
     // Extract session token from cookies
-    let token = match crate::auth::session::extract_session_token(request.headers()) {
+    let token = match auth_state
+        .session_manager
+        .extract_session_token(request.headers())
+    {
         Some(token) => token,
         None => {
             // No session token
@@ -114,8 +167,53 @@ pub async fn auth_middleware(
         }
     };
 
-    let renewed_token = validation.renewed_token;
-    let session = validation.session;
+    let mut renewed_token = validation.renewed_token;
+    let mut session = validation.session;
+
+    // Restart the idle window for this session
+    auth_state
+        .session_manager
+        .touch_session(&session, client_ip)
+        .await;
+
+    // Keep the OIDC access token fresh and pick up group changes. A refresh
+    // the provider rejects ends the session so the user logs in again.
+    if let Some(oidc) = auth_state
+        .oidc_provider
+        .as_ref()
+        .filter(|_| session.auth_type == "oidc")
+    {
+        match oidc
+            .refresh_session_tokens(&session.session_id, &session.user_id)
+            .await
+        {
+            TokenRefreshOutcome::Current => {}
+            TokenRefreshOutcome::Refreshed { roles } if roles != session.roles => {
+                match auth_state
+                    .session_manager
+                    .reissue_with_roles(&session, roles.clone())
+                {
+                    Ok(new_token) => {
+                        renewed_token = Some(new_token);
+                        session.roles = roles;
+                    }
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Failed to re-issue session with refreshed roles")
+                    }
+                }
+            }
+            TokenRefreshOutcome::Refreshed { .. } => {}
+            TokenRefreshOutcome::Failed => {
+                if let Err(e) = auth_state.session_manager.invalidate_session(&token).await {
+                    tracing::error!(error = %e, "Failed to invalidate session");
+                }
+                return Ok(ended_session_response(
+                    &auth_state.session_manager,
+                    &request,
+                ));
+            }
+        }
+    }
 
     // Create AuthUser from session with accessible clusters
     let user = AuthUser::new_with_clusters(
@@ -124,7 +222,8 @@ pub async fn auth_middleware(
         session.roles,
         session.accessible_clusters,
     )
-    .with_auth_type(session.auth_type);
+    .with_auth_type(session.auth_type)
+    .with_client_ip(client_ip);
 
     tracing::debug!(
         user_id = %user.id,
@@ -144,7 +243,9 @@ pub async fn auth_middleware(
         let max_age = auth_state.session_manager.timeout_minutes() * 60;
         response.headers_mut().insert(
             header::SET_COOKIE,
-            build_session_cookie_header(&new_token, max_age),
+            auth_state
+                .session_manager
+                .session_cookie_header(&new_token, max_age),
         );
         tracing::debug!("Sliding-expiry: renewed session cookie appended to response");
     }
@@ -152,6 +253,53 @@ pub async fn auth_middleware(
     Ok(response)
 }
 
+/// Response for a session ended server-side: clear the cookie and send the
+/// user to login (401 for API requests, which the frontend redirects)
+fn ended_session_response(session_manager: &SessionManager, request: &Request) -> Response {
+    let mut response = if is_api_request(request.uri().path()) {
+        AuthError::InvalidSession.into_response()
+    } else {
+        Redirect::to(&build_login_redirect_url(request)).into_response()
+    };
+    response.headers_mut().insert(
+        header::SET_COOKIE,
+        session_manager.clear_session_cookie_header(),
+    );
+    response
+}
+
+/// Extract the token from an `Authorization: Bearer <token>` header
+fn extract_bearer_token(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+}
+
+/// Client IP resolved by the client IP middleware, falling back to the peer
+fn resolved_client_ip(request: &Request) -> Option<IpAddr> {
+    request
+        .extensions()
+        .get::<ClientIp>()
+        .map(|ClientIp(ip)| *ip)
+        .or_else(|| {
+            request
+                .extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip())
+        })
+}
+
+/// Identify the calling client for rate limiting (resolved client IP when available)
+fn client_identifier(request: &Request) -> String {
+    resolved_client_ip(request)
+        .map(|ip| ip.to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
 /// Check if a path is an API endpoint
 fn is_api_request(path: &str) -> bool {
     path.starts_with("/api/")
@@ -160,18 +308,30 @@ fn is_api_request(path: &str) -> bool {
 /// Check if a path is public and doesn't require authentication
 fn is_public_path(path: &str) -> bool {
     // Health and readiness endpoints
-    if path == "/health" || path == "/ready" {
+    if matches!(
+        path,
+        "/health" | "/ready" | "/health/live" | "/health/ready"
+    ) {
         return true;
     }
 
-    // API version endpoint
-    if path == "/api/version" {
+    // Prometheus scrape endpoint
+    if path == "/metrics" {
+        return true;
+    }
+
+    // API version and login branding endpoints
+    if path == "/api/version" || path == "/api/branding" {
         return true;
     }
 
     // Authentication endpoints (login, logout, OIDC callbacks)
-    // Note: /api/auth/me requires authentication
-    if path.starts_with("/api/auth/") && path != "/api/auth/me" {
+    // Note: /api/auth/me and session administration require authentication
+    if path.starts_with("/api/auth/")
+        && path != "/api/auth/me"
+        && path != "/api/auth/sessions"
+        && !path.starts_with("/api/auth/sessions/")
+    {
         return true;
     }
 
@@ -215,8 +375,13 @@ fn build_login_redirect_url(request: &Request) -> String {
     // URL encode the original path
     let redirect_to = urlencoding::encode(&full_path);
 
-    // Redirect to frontend login page (not API endpoint)
-    format!("/login?redirect_to={}", redirect_to)
+    // Redirect to frontend login page (not API endpoint); `redirect_to`
+    // stays relative to the base path, like the frontend's routes
+    format!(
+        "{}/login?redirect_to={}",
+        BasePath::of(request.extensions()),
+        redirect_to
+    )
 }
 
 /// Authentication errors
@@ -228,6 +393,10 @@ pub enum AuthError {
     InvalidCookie,
     /// Session is invalid or expired
     InvalidSession,
+    /// Bearer API token does not match any configured token
+    InvalidApiToken,
+    /// Too many failed authentication attempts from this client
+    RateLimited,
     /// Internal error during authentication
     InternalError,
 }
@@ -238,6 +407,11 @@ impl IntoResponse for AuthError {
             AuthError::MissingSessionToken => (StatusCode::UNAUTHORIZED, "Missing session token"),
             AuthError::InvalidCookie => (StatusCode::BAD_REQUEST, "Invalid cookie format"),
             AuthError::InvalidSession => (StatusCode::UNAUTHORIZED, "Invalid or expired session"),
+            AuthError::InvalidApiToken => (StatusCode::UNAUTHORIZED, "Invalid API token"),
+            AuthError::RateLimited => (
+                StatusCode::TOO_MANY_REQUESTS,
+                "Too many failed authentication attempts",
+            ),
             AuthError::InternalError => {
                 (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error")
             }
@@ -250,7 +424,7 @@ impl IntoResponse for AuthError {
             AuthError::MissingSessionToken | AuthError::InvalidSession => {
                 tracing::debug!(status = %status, message = %message, "Authentication error");
             }
-            AuthError::InvalidCookie => {
+            AuthError::InvalidCookie | AuthError::InvalidApiToken | AuthError::RateLimited => {
                 tracing::warn!(status = %status, message = %message, "Authentication error");
             }
             AuthError::InternalError => {
@@ -313,6 +487,65 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_auth_middleware_attaches_resolved_client_ip() {
+        use crate::middleware::client_ip::{client_ip_middleware, ClientIpState};
+
+        async fn ip_handler(
+            axum::Extension(user): axum::Extension<AuthenticatedUser>,
+        ) -> impl IntoResponse {
+            user.0
+                .client_ip
+                .map(|ip| ip.to_string())
+                .unwrap_or_default()
+        }
+
+        let session_manager = Arc::new(SessionManager::new(SessionConfig::new(
+            60,
+            TEST_SECRET.to_string(),
+        )));
+        let auth_state = Arc::new(AuthState::new(session_manager, AuthMode::Open));
+        let ip_state = Arc::new(ClientIpState::new(vec!["10.0.0.0/8"
+            .parse()
+            .expect("valid CIDR")]));
+        let app = Router::new()
+            .route("/test", get(ip_handler))
+            .layer(middleware::from_fn_with_state(
+                auth_state.clone(),
+                auth_middleware,
+            ))
+            .layer(middleware::from_fn_with_state(
+                ip_state,
+                client_ip_middleware,
+            ))
+            .with_state(auth_state);
+
+        let mut request = Request::builder()
+            .uri("/test")
+            .header("x-forwarded-for", "203.0.113.7")
+            .body(Body::empty())
+            .expect("build request body");
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 5], 40000))));
+
+        let response = app.oneshot(request).await.expect("send request to app");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("read body");
+        assert_eq!(&body[..], b"203.0.113.7");
+    }
+
+    #[test]
+    fn test_session_administration_is_not_public() {
+        assert!(is_public_path("/api/auth/login"));
+        assert!(is_public_path("/api/auth/status"));
+        assert!(!is_public_path("/api/auth/me"));
+        assert!(!is_public_path("/api/auth/sessions"));
+        assert!(!is_public_path("/api/auth/sessions/revoke"));
+    }
+
     #[tokio::test]
     async fn test_auth_middleware_missing_token() {
         let app = create_test_app(AuthMode::LocalUsers);
@@ -424,4 +657,50 @@ mod tests {
         assert_eq!(auth_user.0.username, user.username);
         assert_eq!(auth_user.0.roles, user.roles);
     }
+
+    #[tokio::test]
+    async fn test_auth_middleware_api_token() {
+        let session_manager = Arc::new(SessionManager::new(SessionConfig::new(
+            60,
+            TEST_SECRET.to_string(),
+        )));
+        let api_tokens = Arc::new(ApiTokenAuthenticator::new(
+            vec![crate::config::ApiToken {
+                name: "ci".to_string(),
+                token_hash: crate::auth::hash_password("ci-token").expect("hash token"),
+                groups: vec!["admin".to_string()],
+            }],
+            crate::auth::RateLimiter::new(crate::auth::RateLimitConfig::default()),
+        ));
+        let auth_state = Arc::new(
+            AuthState::new(session_manager, AuthMode::LocalUsers).with_api_tokens(api_tokens),
+        );
+        let app = Router::new()
+            .route("/api/test", get(test_handler))
+            .layer(middleware::from_fn_with_state(
+                auth_state.clone(),
+                auth_middleware,
+            ))
+            .with_state(auth_state);
+
+        let request = Request::builder()
+            .uri("/api/test")
+            .header("Authorization", "Bearer ci-token")
+            .body(Body::empty())
+            .expect("build request body");
+        let response = app
+            .clone()
+            .oneshot(request)
+            .await
+            .expect("send request to app");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let request = Request::builder()
+            .uri("/api/test")
+            .header("Authorization", "Bearer wrong-token")
+            .body(Body::empty())
+            .expect("build request body");
+        let response = app.oneshot(request).await.expect("send request to app");
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
 }
@@ -1,4 +1,5 @@
 // Authentication module
+pub mod api_token;
 pub mod ldap;
 pub mod local;
 pub mod middleware;
@@ -7,13 +8,17 @@ pub mod rate_limiter;
 pub mod rbac;
 pub mod session;
 
+pub use api_token::{ApiTokenAuthenticator, ApiTokenOutcome};
 pub use ldap::{sanitize_ldap_input, LdapAuthProvider};
-pub use local::{hash_password, verify_password, LocalAuthProvider};
+pub use local::{
+    hash_password, hash_password_argon2, hash_password_with_cost, verify_password, HashAlgorithm,
+    LocalAuthProvider,
+};
 pub use middleware::{auth_middleware, AuthError, AuthState, AuthenticatedUser};
-pub use oidc::OidcAuthProvider;
-pub use rate_limiter::{RateLimitConfig, RateLimiter};
+pub use oidc::{OidcAuthProvider, PendingLogin, TokenRefreshOutcome};
+pub use rate_limiter::{RateLimitConfig, RateLimitExceeded, RateLimiter};
 pub use rbac::{RbacManager, Role};
 pub use session::{
-    build_clear_session_cookie_header, build_session_cookie_header, generate_token, AuthUser,
-    Session, SessionConfig, SessionManager, SessionValidation,
+    generate_token, ActiveSession, AuthUser, Session, SessionConfig, SessionManager,
+    SessionValidation,
 };
@@ -1,8 +1,12 @@
 use chrono::{DateTime, Duration, Utc};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+/// Number of bucket checks between sweeps of idle bucket records
+const BUCKET_PRUNE_INTERVAL: u64 = 1024;
+
 /// Configuration for rate limiting
 #[derive(Debug, Clone)]
 pub struct RateLimitConfig {
@@ -89,11 +93,27 @@ impl AttemptRecord {
     }
 }
 
-/// Rate limiter for authentication attempts
+/// A request rejected by a rate limit bucket
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitExceeded {
+    /// Seconds until the identifier may retry (at least 1)
+    pub retry_after_seconds: u64,
+}
+
+/// Rate limiter for authentication attempts and request buckets
+///
+/// The default configuration tracks failed attempts (see
+/// [`RateLimiter::record_failed_attempt`]). Named buckets added with
+/// [`RateLimiter::with_buckets`] count every request instead, each with its
+/// own limit and window (see [`RateLimiter::check_request`]).
 #[derive(Debug, Clone)]
 pub struct RateLimiter {
     config: RateLimitConfig,
     records: Arc<RwLock<HashMap<String, AttemptRecord>>>,
+    buckets: Arc<HashMap<String, RateLimitConfig>>,
+    /// Request records per bucket, keyed by bucket name then identifier
+    bucket_records: Arc<RwLock<HashMap<String, HashMap<String, AttemptRecord>>>>,
+    bucket_checks: Arc<AtomicU64>,
 }
 
 impl RateLimiter {
@@ -102,7 +122,96 @@ impl RateLimiter {
         Self {
             config,
             records: Arc::new(RwLock::new(HashMap::new())),
+            buckets: Arc::new(HashMap::new()),
+            bucket_records: Arc::new(RwLock::new(HashMap::new())),
+            bucket_checks: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Add named request buckets, replacing any configured before
+    ///
+    /// For a bucket, `max_attempts` is the number of requests allowed per
+    /// `window_seconds`. With a `block_duration_seconds` of 0 a rejected
+    /// identifier may retry as soon as its oldest request leaves the window;
+    /// otherwise it is blocked for that long.
+    pub fn with_buckets(
+        mut self,
+        buckets: impl IntoIterator<Item = (String, RateLimitConfig)>,
+    ) -> Self {
+        self.buckets = Arc::new(buckets.into_iter().collect());
+        self
+    }
+
+    /// Whether a bucket with this name is configured
+    pub fn has_bucket(&self, bucket: &str) -> bool {
+        self.buckets.contains_key(bucket)
+    }
+
+    /// Count a request against a named bucket
+    ///
+    /// Returns an error with the retry delay when the identifier has used up
+    /// the bucket's budget. Rejected requests are not counted, so a client
+    /// that backs off for the advertised delay is let through again.
+    /// Requests against an unknown bucket are always allowed.
+    pub async fn check_request(
+        &self,
+        bucket: &str,
+        identifier: &str,
+    ) -> Result<(), RateLimitExceeded> {
+        let Some(config) = self.buckets.get(bucket) else {
+            return Ok(());
+        };
+
+        let mut bucket_records = self.bucket_records.write().await;
+        let records = bucket_records.entry(bucket.to_string()).or_default();
+        let window = Duration::seconds(config.window_seconds as i64);
+
+        if self
+            .bucket_checks
+            .fetch_add(1, Ordering::Relaxed)
+            .is_multiple_of(BUCKET_PRUNE_INTERVAL)
+        {
+            prune_records(records, window);
+        }
+
+        let record = records
+            .entry(identifier.to_string())
+            .or_insert_with(AttemptRecord::new);
+        let now = Utc::now();
+
+        if let Some(blocked_until) = record.blocked_until.filter(|until| *until > now) {
+            return Err(RateLimitExceeded::until(blocked_until, now));
         }
+
+        record.cleanup_old_attempts(window);
+
+        if record.attempts.len() >= config.max_attempts as usize {
+            let retry_at = if config.block_duration_seconds > 0 {
+                let until = now + Duration::seconds(config.block_duration_seconds as i64);
+                record.blocked_until = Some(until);
+                until
+            } else {
+                // Attempts are appended in order, so the first one leaves the
+                // window first
+                record
+                    .attempts
+                    .first()
+                    .map_or(now, |oldest| *oldest + window)
+            };
+
+            tracing::debug!(
+                bucket = %bucket,
+                identifier = %identifier,
+                max_requests = config.max_attempts,
+                window_seconds = config.window_seconds,
+                "Request rate limit exceeded"
+            );
+
+            return Err(RateLimitExceeded::until(retry_at, now));
+        }
+
+        record.add_attempt(now);
+        Ok(())
     }
 
     /// Check if an identifier is currently rate limited
@@ -244,6 +353,14 @@ impl RateLimiter {
             remaining_records = records.len(),
             "Cleaned up rate limiter records"
         );
+        drop(records);
+
+        let mut bucket_records = self.bucket_records.write().await;
+        for (bucket, records) in bucket_records.iter_mut() {
+            if let Some(config) = self.buckets.get(bucket) {
+                prune_records(records, Duration::seconds(config.window_seconds as i64));
+            }
+        }
     }
 
     /// Start a background task to periodically clean up expired records
@@ -258,6 +375,26 @@ impl RateLimiter {
     }
 }
 
+impl RateLimitExceeded {
+    fn until(retry_at: DateTime<Utc>, now: DateTime<Utc>) -> Self {
+        // Round up so clients never retry a moment too early
+        let millis = (retry_at - now).num_milliseconds().max(0) as u64;
+        Self {
+            retry_after_seconds: millis.div_ceil(1000).max(1),
+        }
+    }
+}
+
+/// Drop records that are neither blocked nor have attempts within the window
+fn prune_records(records: &mut HashMap<String, AttemptRecord>, window: Duration) {
+    let now = Utc::now();
+    let cutoff = now - window;
+    records.retain(|_, record| {
+        record.blocked_until.is_some_and(|until| until > now)
+            || record.attempts.iter().any(|&t| t > cutoff)
+    });
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -495,4 +632,83 @@ mod tests {
 
         assert!(limiter.is_rate_limited(username).await);
     }
+
+    fn bucket_limiter(max_requests: u32, window_seconds: u64, block_seconds: u64) -> RateLimiter {
+        RateLimiter::new(RateLimitConfig::default()).with_buckets([(
+            "clusters".to_string(),
+            RateLimitConfig::new(max_requests, window_seconds, block_seconds),
+        )])
+    }
+
+    #[tokio::test]
+    async fn test_bucket_rejects_burst_over_limit() {
+        let limiter = bucket_limiter(3, 60, 0);
+
+        for _ in 0..3 {
+            assert!(limiter.check_request("clusters", "alice").await.is_ok());
+        }
+
+        let err = limiter
+            .check_request("clusters", "alice")
+            .await
+            .expect_err("fourth request in the window should be rejected");
+        assert!(err.retry_after_seconds > 0);
+        assert!(err.retry_after_seconds <= 60);
+
+        // Other identifiers have their own budget
+        assert!(limiter.check_request("clusters", "bob").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_bucket_allows_sustained_traffic_under_limit() {
+        let limiter = bucket_limiter(3, 1, 0);
+
+        // One request every 400ms never puts more than 3 in a 1s window
+        for _ in 0..8 {
+            assert!(limiter.check_request("clusters", "alice").await.is_ok());
+            tokio::time::sleep(tokio::time::Duration::from_millis(400)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bucket_recovers_after_window() {
+        let limiter = bucket_limiter(2, 1, 0);
+
+        assert!(limiter.check_request("clusters", "alice").await.is_ok());
+        assert!(limiter.check_request("clusters", "alice").await.is_ok());
+        let err = limiter
+            .check_request("clusters", "alice")
+            .await
+            .unwrap_err();
+        assert_eq!(err.retry_after_seconds, 1);
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(1100)).await;
+        assert!(limiter.check_request("clusters", "alice").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_bucket_block_duration() {
+        let limiter = bucket_limiter(1, 1, 120);
+
+        assert!(limiter.check_request("clusters", "alice").await.is_ok());
+        let err = limiter
+            .check_request("clusters", "alice")
+            .await
+            .unwrap_err();
+        assert!(err.retry_after_seconds > 60);
+
+        // Still blocked after the window has passed
+        tokio::time::sleep(tokio::time::Duration::from_millis(1100)).await;
+        assert!(limiter.check_request("clusters", "alice").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_bucket_is_not_limited() {
+        let limiter = bucket_limiter(1, 60, 0);
+
+        assert!(!limiter.has_bucket("proxy"));
+        for _ in 0..5 {
+            assert!(limiter.check_request("proxy", "alice").await.is_ok());
+        }
+    }
 }
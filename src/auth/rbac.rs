@@ -1,29 +1,78 @@
 use crate::auth::AuthUser;
-use crate::config::RoleConfig;
+use crate::config::{Permission, ProxyAllowConfig, RoleConfig};
+use regex::Regex;
 use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard};
+
+/// Prefix marking a cluster pattern as a regular expression (e.g. "regex:^prod-(eu|us)-\d+$")
+pub const REGEX_PATTERN_PREFIX: &str = "regex:";
 
 /// Role for RBAC with cluster access patterns
 #[derive(Debug, Clone)]
 pub struct Role {
     /// Role name
     pub name: String,
-    /// Glob patterns for cluster access (e.g., "prod-*", "dev-*", "*")
+    /// Glob patterns for cluster access (e.g., "prod-*", "dev-*", "*"), or
+    /// regular expressions prefixed with `regex:`
     pub cluster_patterns: Vec<String>,
+    /// Permission level granted on matching clusters
+    pub permission: Permission,
+    /// Allowlist for this role's generic proxy requests (unrestricted if None)
+    pub proxy_allow: Option<ProxyAllowConfig>,
+    /// Compiled `regex:` entries of `cluster_patterns`
+    cluster_regexes: Vec<Regex>,
 }
 
 impl Role {
-    /// Create a new role
+    /// Create a new read-only role
     pub fn new(name: String, cluster_patterns: Vec<String>) -> Self {
+        Self::build(name, cluster_patterns, Permission::Read, None)
+    }
+
+    fn build(
+        name: String,
+        cluster_patterns: Vec<String>,
+        permission: Permission,
+        proxy_allow: Option<ProxyAllowConfig>,
+    ) -> Self {
+        // Invalid expressions are rejected by RoleConfig::validate; any that
+        // slip through simply match nothing
+        let cluster_regexes = cluster_patterns
+            .iter()
+            .filter_map(|pattern| pattern.strip_prefix(REGEX_PATTERN_PREFIX))
+            .filter_map(|expr| match compile_cluster_regex(expr) {
+                Ok(regex) => Some(regex),
+                Err(e) => {
+                    tracing::warn!(role = %name, pattern = %expr, error = %e, "Ignoring invalid cluster regex");
+                    None
+                }
+            })
+            .collect();
+
         Self {
             name,
             cluster_patterns,
+            permission,
+            proxy_allow,
+            cluster_regexes,
         }
     }
 
     /// Check if this role grants access to a specific cluster
     pub fn matches_cluster(&self, cluster_id: &str) -> bool {
+        if self
+            .cluster_regexes
+            .iter()
+            .any(|regex| regex.is_match(cluster_id))
+        {
+            return true;
+        }
+
         self.cluster_patterns.iter().any(|pattern| {
-            if pattern == "*" {
+            if pattern.starts_with(REGEX_PATTERN_PREFIX) {
+                // Matched above
+                false
+            } else if pattern == "*" {
                 // Wildcard matches everything
                 true
             } else if pattern.contains('*') {
@@ -39,16 +88,23 @@ impl Role {
 
 impl From<RoleConfig> for Role {
     fn from(config: RoleConfig) -> Self {
-        Self {
-            name: config.name,
-            cluster_patterns: config.cluster_patterns,
-        }
+        Self::build(
+            config.name,
+            config.cluster_patterns,
+            config.permission,
+            config.proxy_allow,
+        )
     }
 }
 
+/// Compile a `regex:` cluster pattern; it must match the whole cluster ID
+pub(crate) fn compile_cluster_regex(expr: &str) -> Result<Regex, regex::Error> {
+    Regex::new(&format!("^(?:{})$", expr))
+}
+
 /// Simple glob pattern matching for cluster patterns
 /// Supports * wildcard (matches any sequence of characters)
-fn glob_match(pattern: &str, text: &str) -> bool {
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
     // If no wildcard, do exact match
     if !pattern.contains('*') {
         return pattern == text;
@@ -103,24 +159,43 @@ fn glob_match(pattern: &str, text: &str) -> bool {
 }
 
 /// RBAC Manager for access control
+///
+/// Clones share the same role table, so a [`RbacManager::reload`] on any clone is
+/// visible to every component holding one (middleware, route states, etc.).
 #[derive(Debug, Clone)]
 pub struct RbacManager {
     /// Map of role name to Role
-    roles: HashMap<String, Role>,
+    roles: Arc<RwLock<HashMap<String, Role>>>,
 }
 
 impl RbacManager {
     /// Create a new RBAC manager from role configurations
     pub fn new(role_configs: Vec<RoleConfig>) -> Self {
-        let roles = role_configs
+        Self {
+            roles: Arc::new(RwLock::new(Self::build_roles(role_configs))),
+        }
+    }
+
+    fn build_roles(role_configs: Vec<RoleConfig>) -> HashMap<String, Role> {
+        role_configs
             .into_iter()
             .map(|config| {
                 let role = Role::from(config);
                 (role.name.clone(), role)
             })
-            .collect();
+            .collect()
+    }
+
+    fn read_roles(&self) -> RwLockReadGuard<'_, HashMap<String, Role>> {
+        self.roles.read().expect("RBAC roles RwLock poisoned")
+    }
 
-        Self { roles }
+    /// Replace all roles with a new set of role configurations
+    ///
+    /// Used by configuration hot reload; the swap is atomic for readers.
+    pub fn reload(&self, role_configs: Vec<RoleConfig>) {
+        let roles = Self::build_roles(role_configs);
+        *self.roles.write().expect("RBAC roles RwLock poisoned") = roles;
     }
 
     /// Get all accessible clusters for a user based on their groups
@@ -138,24 +213,101 @@ impl RbacManager {
 
     /// Check if a user can access a specific cluster
     pub fn can_access_cluster(&self, user: &AuthUser, cluster_id: &str) -> bool {
-        for role_name in &user.roles {
-            if let Some(role) = self.roles.get(role_name) {
-                if role.matches_cluster(cluster_id) {
-                    return true;
-                }
-            }
+        self.permission_for(user, cluster_id).is_some()
+    }
+
+    /// Highest permission level the user's roles grant on a cluster
+    ///
+    /// Returns `None` when no role matches, i.e. the user cannot access the cluster.
+    pub fn permission_for(&self, user: &AuthUser, cluster_id: &str) -> Option<Permission> {
+        let roles = self.read_roles();
+        user.roles
+            .iter()
+            .filter_map(|role_name| roles.get(role_name))
+            .filter(|role| role.matches_cluster(cluster_id))
+            .map(|role| role.permission)
+            .max()
+    }
+
+    /// Check whether a user holds at least `required` on a cluster
+    ///
+    /// The anonymous open-mode user has no roles to grant levels, so it never
+    /// passes a write or admin check.
+    pub fn has_permission(&self, user: &AuthUser, cluster_id: &str, required: Permission) -> bool {
+        if user.auth_type == "open" {
+            return required == Permission::Read;
+        }
+        self.permission_for(user, cluster_id)
+            .is_some_and(|granted| granted >= required)
+    }
+
+    /// Check whether a user may modify a cluster (settings, shard relocation, writes)
+    pub fn can_write(&self, user: &AuthUser, cluster_id: &str) -> bool {
+        self.has_permission(user, cluster_id, Permission::Write)
+    }
+
+    /// Check whether a user may run destructive operations on a cluster
+    pub fn can_admin(&self, user: &AuthUser, cluster_id: &str) -> bool {
+        self.has_permission(user, cluster_id, Permission::Admin)
+    }
+
+    /// Check whether a user administers Secan itself (e.g. other users' sessions)
+    ///
+    /// Requires a role granting `admin` on every cluster, i.e. with the `*`
+    /// cluster pattern.
+    pub fn is_global_admin(&self, user: &AuthUser) -> bool {
+        if user.auth_type == "open" {
+            return false;
+        }
+        let roles = self.read_roles();
+        user.roles
+            .iter()
+            .filter_map(|role_name| roles.get(role_name))
+            .any(|role| {
+                role.permission == Permission::Admin
+                    && role.cluster_patterns.iter().any(|pattern| pattern == "*")
+            })
+    }
+
+    /// Check a generic proxy request against the user's role allowlists
+    ///
+    /// Only roles matching the cluster count. Roles are additive: if any of
+    /// them has no `proxy_allow`, the request is not restricted; otherwise it
+    /// must match at least one role's allowlist.
+    pub fn proxy_request_allowed(
+        &self,
+        user: &AuthUser,
+        cluster_id: &str,
+        method: &str,
+        path: &str,
+    ) -> bool {
+        let roles = self.read_roles();
+        let mut matching = user
+            .roles
+            .iter()
+            .filter_map(|role_name| roles.get(role_name))
+            .filter(|role| role.matches_cluster(cluster_id))
+            .peekable();
+
+        if matching.peek().is_none() {
+            return true;
         }
-        false
+
+        matching.any(|role| {
+            role.proxy_allow
+                .as_ref()
+                .is_none_or(|policy| policy.allows(method, path))
+        })
     }
 
     /// Get a role by name
-    pub fn get_role(&self, role_name: &str) -> Option<&Role> {
-        self.roles.get(role_name)
+    pub fn get_role(&self, role_name: &str) -> Option<Role> {
+        self.read_roles().get(role_name).cloned()
     }
 
     /// Get all roles
-    pub fn get_all_roles(&self) -> Vec<&Role> {
-        self.roles.values().collect()
+    pub fn get_all_roles(&self) -> Vec<Role> {
+        self.read_roles().values().cloned().collect()
     }
 }
 
@@ -249,14 +401,20 @@ mod tests {
             RoleConfig {
                 name: "admin".to_string(),
                 cluster_patterns: vec!["*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
             },
             RoleConfig {
                 name: "prod-viewer".to_string(),
                 cluster_patterns: vec!["prod-*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
             },
             RoleConfig {
                 name: "dev-admin".to_string(),
                 cluster_patterns: vec!["dev-*".to_string(), "staging-*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
             },
         ];
 
@@ -297,16 +455,165 @@ mod tests {
         assert!(!rbac.can_access_cluster(&dev_admin, "prod-cluster-1"));
     }
 
+    #[test]
+    fn test_rbac_manager_permission_levels() {
+        let rbac = RbacManager::new(vec![
+            RoleConfig {
+                name: "viewer".to_string(),
+                cluster_patterns: vec!["*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
+            },
+            RoleConfig {
+                name: "operator".to_string(),
+                cluster_patterns: vec!["dev-*".to_string()],
+                permission: Permission::Write,
+                proxy_allow: None,
+            },
+            RoleConfig {
+                name: "prod-admin".to_string(),
+                cluster_patterns: vec!["prod-*".to_string()],
+                permission: Permission::Admin,
+                proxy_allow: None,
+            },
+        ]);
+
+        let viewer = AuthUser::new(
+            "u1".to_string(),
+            "alice".to_string(),
+            vec!["viewer".to_string()],
+        );
+        assert!(rbac.can_access_cluster(&viewer, "prod-1"));
+        assert!(!rbac.can_write(&viewer, "prod-1"));
+        assert!(!rbac.can_admin(&viewer, "prod-1"));
+
+        // The highest level across matching roles wins
+        let operator = AuthUser::new(
+            "u2".to_string(),
+            "bob".to_string(),
+            vec!["viewer".to_string(), "operator".to_string()],
+        );
+        assert_eq!(
+            rbac.permission_for(&operator, "dev-1"),
+            Some(Permission::Write)
+        );
+        assert!(rbac.can_write(&operator, "dev-1"));
+        assert!(!rbac.can_admin(&operator, "dev-1"));
+        assert!(!rbac.can_write(&operator, "prod-1"));
+
+        let admin = AuthUser::new(
+            "u3".to_string(),
+            "carol".to_string(),
+            vec!["prod-admin".to_string()],
+        );
+        assert!(rbac.can_write(&admin, "prod-1"));
+        assert!(rbac.can_admin(&admin, "prod-1"));
+        assert!(!rbac.can_write(&admin, "dev-1"));
+        assert_eq!(rbac.permission_for(&admin, "dev-1"), None);
+
+        // The anonymous open-mode user never gets write access
+        let open_user = AuthUser::new_with_clusters(
+            "open".to_string(),
+            "open".to_string(),
+            vec!["prod-admin".to_string()],
+            vec!["*".to_string()],
+        )
+        .with_auth_type("open");
+        assert!(!rbac.can_write(&open_user, "prod-1"));
+        assert!(!rbac.can_admin(&open_user, "prod-1"));
+    }
+
+    #[test]
+    fn test_global_admin_needs_admin_on_every_cluster() {
+        let rbac = RbacManager::new(vec![
+            RoleConfig {
+                name: "superuser".to_string(),
+                cluster_patterns: vec!["*".to_string()],
+                permission: Permission::Admin,
+                proxy_allow: None,
+            },
+            RoleConfig {
+                name: "prod-admin".to_string(),
+                cluster_patterns: vec!["prod-*".to_string()],
+                permission: Permission::Admin,
+                proxy_allow: None,
+            },
+            RoleConfig {
+                name: "viewer".to_string(),
+                cluster_patterns: vec!["*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
+            },
+        ]);
+        let user = |role: &str| {
+            AuthUser::new(
+                "u1".to_string(),
+                "alice".to_string(),
+                vec![role.to_string()],
+            )
+        };
+
+        assert!(rbac.is_global_admin(&user("superuser")));
+        assert!(!rbac.is_global_admin(&user("prod-admin")));
+        assert!(!rbac.is_global_admin(&user("viewer")));
+        assert!(!rbac.is_global_admin(&user("superuser").with_auth_type("open")));
+    }
+
+    #[test]
+    fn test_rbac_manager_proxy_request_allowed() {
+        let read_only = ProxyAllowConfig {
+            methods: vec!["GET".to_string()],
+            path_patterns: vec!["/_cat/*".to_string(), "/_cluster/health".to_string()],
+        };
+        let rbac = RbacManager::new(vec![
+            RoleConfig {
+                name: "viewer".to_string(),
+                cluster_patterns: vec!["*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: Some(read_only),
+            },
+            RoleConfig {
+                name: "operator".to_string(),
+                cluster_patterns: vec!["dev-*".to_string()],
+                permission: Permission::Write,
+                proxy_allow: None,
+            },
+        ]);
+
+        let viewer = AuthUser::new(
+            "u1".to_string(),
+            "alice".to_string(),
+            vec!["viewer".to_string()],
+        );
+        assert!(rbac.proxy_request_allowed(&viewer, "prod-1", "GET", "/_cat/indices"));
+        assert!(rbac.proxy_request_allowed(&viewer, "prod-1", "get", "/_cluster/health"));
+        assert!(!rbac.proxy_request_allowed(&viewer, "prod-1", "POST", "/_cat/indices"));
+        assert!(!rbac.proxy_request_allowed(&viewer, "prod-1", "GET", "/_nodes"));
+
+        // An unrestricted role matching the cluster lifts the restriction
+        let operator = AuthUser::new(
+            "u2".to_string(),
+            "bob".to_string(),
+            vec!["viewer".to_string(), "operator".to_string()],
+        );
+        assert!(rbac.proxy_request_allowed(&operator, "dev-1", "DELETE", "/logs"));
+        assert!(!rbac.proxy_request_allowed(&operator, "prod-1", "DELETE", "/logs"));
+    }
+
     #[test]
     fn test_rbac_manager_user_with_multiple_roles() {
         let role_configs = vec![
             RoleConfig {
                 name: "prod-viewer".to_string(),
                 cluster_patterns: vec!["prod-*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
             },
             RoleConfig {
                 name: "dev-admin".to_string(),
                 cluster_patterns: vec!["dev-*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
             },
         ];
 
@@ -329,6 +636,8 @@ mod tests {
         let role_configs = vec![RoleConfig {
             name: "admin".to_string(),
             cluster_patterns: vec!["*".to_string()],
+            permission: Permission::Read,
+            proxy_allow: None,
         }];
 
         let rbac = RbacManager::new(role_configs);
@@ -345,6 +654,8 @@ mod tests {
         let role_configs = vec![RoleConfig {
             name: "admin".to_string(),
             cluster_patterns: vec!["*".to_string()],
+            permission: Permission::Read,
+            proxy_allow: None,
         }];
 
         let rbac = RbacManager::new(role_configs);
@@ -365,10 +676,14 @@ mod tests {
             RoleConfig {
                 name: "prod-viewer".to_string(),
                 cluster_patterns: vec!["prod-*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
             },
             RoleConfig {
                 name: "dev-admin".to_string(),
                 cluster_patterns: vec!["dev-*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
             },
         ];
 
@@ -418,11 +733,95 @@ mod tests {
         assert!(accessible.contains(&"dev-cluster-1".to_string()));
     }
 
+    #[test]
+    fn test_role_matches_cluster_regex() {
+        let role = Role::new(
+            "regional".to_string(),
+            vec![r"regex:prod-(eu|us)-\d+".to_string()],
+        );
+
+        assert!(role.matches_cluster("prod-eu-1"));
+        assert!(role.matches_cluster("prod-us-42"));
+        assert!(!role.matches_cluster("prod-ap-1"));
+        // Anchored: partial matches do not count
+        assert!(!role.matches_cluster("prod-eu-1-old"));
+        assert!(!role.matches_cluster("x-prod-eu-1"));
+
+        // A literal "regex:" entry is never compared as an exact cluster ID
+        let invalid = Role::new("broken".to_string(), vec!["regex:(".to_string()]);
+        assert!(!invalid.matches_cluster("regex:("));
+    }
+
+    #[test]
+    fn test_rbac_manager_overlapping_patterns_across_roles() {
+        let rbac = RbacManager::new(vec![
+            RoleConfig {
+                name: "eu-viewer".to_string(),
+                cluster_patterns: vec!["prod-eu-*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
+            },
+            RoleConfig {
+                name: "primary-operator".to_string(),
+                cluster_patterns: vec![r"regex:prod-(eu|us)-1".to_string()],
+                permission: Permission::Write,
+                proxy_allow: None,
+            },
+            RoleConfig {
+                name: "staging".to_string(),
+                cluster_patterns: vec!["staging".to_string()],
+                permission: Permission::Admin,
+                proxy_allow: None,
+            },
+        ]);
+
+        let all_clusters: Vec<String> = [
+            "prod-eu-1",
+            "prod-eu-2",
+            "prod-us-1",
+            "prod-us-2",
+            "staging",
+        ]
+        .iter()
+        .map(|id| id.to_string())
+        .collect();
+
+        let user = AuthUser::new(
+            "user1".to_string(),
+            "user".to_string(),
+            vec!["eu-viewer".to_string(), "primary-operator".to_string()],
+        );
+
+        // Each cluster appears once even when several roles match it
+        assert_eq!(
+            rbac.get_accessible_clusters(&user, &all_clusters),
+            vec!["prod-eu-1", "prod-eu-2", "prod-us-1"]
+        );
+
+        // The highest level among overlapping roles applies
+        assert_eq!(
+            rbac.permission_for(&user, "prod-eu-1"),
+            Some(Permission::Write)
+        );
+        assert_eq!(
+            rbac.permission_for(&user, "prod-eu-2"),
+            Some(Permission::Read)
+        );
+        assert_eq!(
+            rbac.permission_for(&user, "prod-us-1"),
+            Some(Permission::Write)
+        );
+        assert_eq!(rbac.permission_for(&user, "prod-us-2"), None);
+        assert_eq!(rbac.permission_for(&user, "staging"), None);
+    }
+
     #[test]
     fn test_rbac_manager_get_role() {
         let role_configs = vec![RoleConfig {
             name: "admin".to_string(),
             cluster_patterns: vec!["*".to_string()],
+            permission: Permission::Read,
+            proxy_allow: None,
         }];
 
         let rbac = RbacManager::new(role_configs);
@@ -442,10 +841,14 @@ mod tests {
             RoleConfig {
                 name: "admin".to_string(),
                 cluster_patterns: vec!["*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
             },
             RoleConfig {
                 name: "viewer".to_string(),
                 cluster_patterns: vec!["prod-*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
             },
         ];
 
@@ -454,4 +857,33 @@ mod tests {
         let roles = rbac.get_all_roles();
         assert_eq!(roles.len(), 2);
     }
+
+    #[test]
+    fn test_rbac_manager_reload_shared_across_clones() {
+        let rbac = RbacManager::new(vec![RoleConfig {
+            name: "viewer".to_string(),
+            cluster_patterns: vec!["dev-*".to_string()],
+            permission: Permission::Read,
+            proxy_allow: None,
+        }]);
+        let shared = rbac.clone();
+
+        let user = AuthUser::new(
+            "user1".to_string(),
+            "testuser".to_string(),
+            vec!["viewer".to_string()],
+        );
+        assert!(shared.can_access_cluster(&user, "dev-1"));
+        assert!(!shared.can_access_cluster(&user, "prod-1"));
+
+        rbac.reload(vec![RoleConfig {
+            name: "viewer".to_string(),
+            cluster_patterns: vec!["prod-*".to_string()],
+            permission: Permission::Read,
+            proxy_allow: None,
+        }]);
+
+        assert!(!shared.can_access_cluster(&user, "dev-1"));
+        assert!(shared.can_access_cluster(&user, "prod-1"));
+    }
 }
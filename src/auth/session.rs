@@ -1,9 +1,14 @@
+use crate::config::{LocalUser, SessionCookieConfig};
 use chrono::{DateTime, Duration, Utc};
 use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 // Keep a cookie size threshold for diagnostics. We will not use a server-side
@@ -18,17 +23,36 @@ const COOKIE_SIZE_LIMIT: usize = 3800;
 pub struct SessionConfig {
     /// Session timeout in minutes (used for JWT `exp` and renewal threshold)
     pub timeout_minutes: u64,
+    /// Idle timeout in minutes; sessions without activity for this long are
+    /// rejected even if their JWT has not expired. `None` disables idle expiry.
+    pub idle_timeout_minutes: Option<u64>,
     /// HMAC-SHA256 signing secret loaded from `SECAN_SESSION_SECRET`
     pub secret: String,
+    /// Name and attributes of the session cookie
+    pub cookie: SessionCookieConfig,
 }
 
 impl SessionConfig {
     pub fn new(timeout_minutes: u64, secret: String) -> Self {
         Self {
             timeout_minutes,
+            idle_timeout_minutes: None,
             secret,
+            cookie: SessionCookieConfig::default(),
         }
     }
+
+    /// Set the idle timeout in minutes
+    pub fn with_idle_timeout(mut self, idle_timeout_minutes: Option<u64>) -> Self {
+        self.idle_timeout_minutes = idle_timeout_minutes;
+        self
+    }
+
+    /// Set the session cookie name and attributes
+    pub fn with_cookie(mut self, cookie: SessionCookieConfig) -> Self {
+        self.cookie = cookie;
+        self
+    }
 }
 
 // ── JWT claims (embedded in every session cookie) ─────────────────────────────
@@ -65,6 +89,25 @@ pub struct SessionClaims {
     pub iat: u64,
     /// JWT ID — used to revoke individual sessions on logout
     pub jti: String,
+    /// Session ID — stable across renewals, used for idle tracking.
+    /// Empty for tokens issued before it was introduced (the `jti` is used instead).
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub sid: String,
+    /// Fingerprint of the local user's password hash at login; empty for
+    /// other auth types
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub cred: String,
+}
+
+impl SessionClaims {
+    /// Session ID used for idle tracking
+    fn session_id(&self) -> &str {
+        if self.sid.is_empty() {
+            &self.jti
+        } else {
+            &self.sid
+        }
+    }
 }
 
 // ── Session (view after decoding) ─────────────────────────────────────────────
@@ -95,6 +138,10 @@ pub struct Session {
     pub last_activity: DateTime<Utc>,
     /// JWT ID — forwarded for logging / revocation
     pub jti: String,
+    /// Session ID — stable across renewals, used for idle tracking
+    pub session_id: String,
+    /// Password hash fingerprint carried over on renewal (`cred` claim)
+    pub credential: String,
 }
 
 // ── Validation result ─────────────────────────────────────────────────────────
@@ -128,6 +175,9 @@ pub struct AuthUser {
     /// Authentication method: "local", "ldap", "oidc", or "open"
     #[serde(default = "default_auth_type")]
     pub auth_type: String,
+    /// Client IP of the current request, resolved through trusted proxies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_ip: Option<IpAddr>,
 }
 
 impl AuthUser {
@@ -138,6 +188,7 @@ impl AuthUser {
             roles,
             accessible_clusters: Vec::new(),
             auth_type: default_auth_type(),
+            client_ip: None,
         }
     }
 
@@ -154,6 +205,7 @@ impl AuthUser {
             roles,
             accessible_clusters,
             auth_type: default_auth_type(),
+            client_ip: None,
         }
     }
 
@@ -162,85 +214,12 @@ impl AuthUser {
         self.auth_type = auth_type.into();
         self
     }
-}
-
-// ── Cookie helper (shared between middleware and route handlers) ───────────────
-
-/// Returns `true` if the `Secure` cookie attribute should be included.
-///
-/// Defaults to **`true`** (secure by default for HTTPS production deployments).
-/// Set `SECAN_SECURE_COOKIES=false` to opt out when running over plain HTTP
-/// (e.g. local development via `task backend:run`).
-fn secure_cookies_enabled() -> bool {
-    std::env::var("SECAN_SECURE_COOKIES")
-        .map(|v| v.to_lowercase() != "false")
-        .unwrap_or(true)
-}
-
-/// Build a `Set-Cookie` header value for the `session_token` cookie.
-///
-/// The `Secure` flag defaults to **on**.  Set `SECAN_SECURE_COOKIES=false` only
-/// when serving over plain HTTP (local development).  HTTPS deployments should
-/// never disable `Secure` — modern browsers enforce "Schemeful SameSite" and
-/// will refuse to send a non-Secure cookie from an HTTPS origin.
-pub fn build_session_cookie_header(token: &str, max_age_seconds: u64) -> http::HeaderValue {
-    // If the JWT exceeds common cookie size limits, log a warning for
-    // diagnostics. We intentionally do not fall back to a server-side
-    // reference store: sessions must remain stateless for horizontal
-    // scalability. The full JWT will still be set in the cookie; admins can
-    // use logs to find offending token sizes and reduce embedded data.
-    if token.len() > COOKIE_SIZE_LIMIT {
-        tracing::warn!(
-            token_len = token.len(),
-            limit = COOKIE_SIZE_LIMIT,
-            "JWT size exceeds typical browser cookie limits — Set-Cookie may be ignored by some browsers"
-        );
-    }
 
-    let value = if secure_cookies_enabled() {
-        format!(
-            "session_token={}; Path=/; HttpOnly; SameSite=Lax; Secure; Max-Age={}",
-            token, max_age_seconds
-        )
-    } else {
-        format!(
-            "session_token={}; Path=/; HttpOnly; SameSite=Lax; Max-Age={}",
-            token, max_age_seconds
-        )
-    };
-
-    http::HeaderValue::from_str(&value).unwrap_or_else(|e| {
-        tracing::error!(error = %e, "Failed to build session cookie header");
-        http::HeaderValue::from_static("session_token=invalid")
-    })
-}
-
-/// Build a `Set-Cookie` header that clears the `session_token` cookie.
-///
-/// Uses the same `Secure` flag as [`build_session_cookie_header`] so the
-/// browser matches and removes the correct cookie on logout.
-pub fn build_clear_session_cookie_header() -> http::HeaderValue {
-    if secure_cookies_enabled() {
-        http::HeaderValue::from_static(
-            "session_token=; Path=/; HttpOnly; SameSite=Lax; Secure; Max-Age=0",
-        )
-    } else {
-        http::HeaderValue::from_static("session_token=; Path=/; HttpOnly; SameSite=Lax; Max-Age=0")
-    }
-}
-
-/// Extract the `session_token` cookie value from request headers.
-///
-/// Returns `None` when the header is absent or the cookie is not present.
-pub fn extract_session_token(headers: &http::HeaderMap) -> Option<String> {
-    let cookie_str = headers.get(http::header::COOKIE)?.to_str().ok()?;
-    for cookie in cookie_str.split(';') {
-        let cookie = cookie.trim();
-        if let Some(value) = cookie.strip_prefix("session_token=") {
-            return Some(value.to_string());
-        }
+    /// Builder: attach the client IP of the current request.
+    pub fn with_client_ip(mut self, client_ip: Option<IpAddr>) -> Self {
+        self.client_ip = client_ip;
+        self
     }
-    None
 }
 
 // ── Opaque token generator (still used by OIDC state parameter) ───────────────
@@ -258,6 +237,27 @@ pub fn generate_token() -> String {
 
 // ── Session manager ───────────────────────────────────────────────────────────
 
+/// A session seen by this instance, as listed for administrators
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ActiveSession {
+    #[schema(example = "user123")]
+    pub user_id: String,
+    #[schema(example = "alice")]
+    pub username: String,
+    /// When this instance first saw the session
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Utc>,
+    /// Time of the last authenticated request
+    #[schema(value_type = String, format = DateTime)]
+    pub last_accessed: DateTime<Utc>,
+    /// Client IP of the last authenticated request
+    #[schema(value_type = Option<String>, example = "10.0.0.5")]
+    pub client_ip: Option<IpAddr>,
+    /// Expiry of the session's newest token
+    #[schema(value_type = String, format = DateTime)]
+    pub expires_at: DateTime<Utc>,
+}
+
 /// Stateless JWT-based session manager.
 ///
 /// Sessions are encoded as signed JWTs and stored **entirely in the client cookie**;
@@ -272,6 +272,13 @@ pub fn generate_token() -> String {
 /// recently-logged-out JWTs could be accepted for their remaining TTL — an
 /// acceptable trade-off for an internal tool.
 ///
+/// # Active sessions
+///
+/// Session IDs seen at creation or validation are tracked with the expiry of
+/// their newest token so the number of active sessions can be reported. Like
+/// the revocation set this is per-instance; sessions created before a restart
+/// are counted again on their next request.
+///
 /// # Key management
 ///
 /// All replicas must share the same `SECAN_SESSION_SECRET`.  Rotating the secret
@@ -282,8 +289,22 @@ pub struct SessionManager {
     /// Wrapped in Arc so Clone is O(1) and the keys are never copied
     encoding_key: Arc<EncodingKey>,
     decoding_key: Arc<DecodingKey>,
-    /// jti → expiry timestamp of the revoked token
+    /// jti (or session ID for idle-evicted sessions) → expiry timestamp of the revoked entry
     revocation_list: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
+    /// Username or user ID → tokens issued before this time are rejected
+    revoked_before: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
+    /// Local username → fingerprint of the current password hash; `None`
+    /// unless local users are configured
+    local_credentials: Arc<std::sync::RwLock<Option<HashMap<String, String>>>>,
+    /// Session ID → time of the last authenticated request (only used with an idle timeout)
+    last_accessed: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
+    /// Session ID → sessions not logged out, for counting, listing and revoking them
+    active_sessions: Arc<std::sync::RwLock<HashMap<String, ActiveSession>>>,
+    /// Number of entries in `active_sessions`, readable without locking
+    active_count: Arc<AtomicUsize>,
+    /// Session ID → roles set by [`SessionManager::reissue_with_roles`]; they
+    /// replace the roles in any token of the session, including older ones
+    session_roles: Arc<std::sync::RwLock<HashMap<String, Vec<String>>>>,
 }
 
 impl std::fmt::Debug for SessionManager {
@@ -306,6 +327,12 @@ impl SessionManager {
             encoding_key,
             decoding_key,
             revocation_list: Arc::new(RwLock::new(HashMap::new())),
+            revoked_before: Arc::new(RwLock::new(HashMap::new())),
+            local_credentials: Arc::new(std::sync::RwLock::new(None)),
+            last_accessed: Arc::new(RwLock::new(HashMap::new())),
+            active_sessions: Arc::new(std::sync::RwLock::new(HashMap::new())),
+            active_count: Arc::new(AtomicUsize::new(0)),
+            session_roles: Arc::new(std::sync::RwLock::new(HashMap::new())),
         }
     }
 
@@ -314,8 +341,227 @@ impl SessionManager {
         self.config.timeout_minutes
     }
 
+    /// Build a `Set-Cookie` header value carrying a session token.
+    ///
+    /// Name and attributes come from `auth.session_cookie`; `Secure` is on
+    /// unless disabled there or with `SECAN_SECURE_COOKIES=false` (plain HTTP
+    /// only: browsers enforce "Schemeful SameSite" and will not send a
+    /// non-Secure cookie from an HTTPS origin).
+    pub fn session_cookie_header(&self, token: &str, max_age_seconds: u64) -> http::HeaderValue {
+        // If the JWT exceeds common cookie size limits, log a warning for
+        // diagnostics. We intentionally do not fall back to a server-side
+        // reference store: sessions must remain stateless for horizontal
+        // scalability. The full JWT will still be set in the cookie; admins can
+        // use logs to find offending token sizes and reduce embedded data.
+        if token.len() > COOKIE_SIZE_LIMIT {
+            tracing::warn!(
+                token_len = token.len(),
+                limit = COOKIE_SIZE_LIMIT,
+                "JWT size exceeds typical browser cookie limits — Set-Cookie may be ignored by some browsers"
+            );
+        }
+
+        let value = self.cookie_value(token, max_age_seconds);
+        http::HeaderValue::from_str(&value).unwrap_or_else(|e| {
+            tracing::error!(error = %e, "Failed to build session cookie header");
+            http::HeaderValue::from_static("session_token=invalid")
+        })
+    }
+
+    /// Build a `Set-Cookie` header that clears the session cookie.
+    ///
+    /// Uses the same attributes as [`Self::session_cookie_header`] so the
+    /// browser matches and removes the correct cookie on logout.
+    pub fn clear_session_cookie_header(&self) -> http::HeaderValue {
+        http::HeaderValue::from_str(&self.cookie_value("", 0)).unwrap_or_else(|e| {
+            tracing::error!(error = %e, "Failed to build session cookie header");
+            http::HeaderValue::from_static("session_token=; Path=/; Max-Age=0")
+        })
+    }
+
+    fn cookie_value(&self, token: &str, max_age_seconds: u64) -> String {
+        let cookie = &self.config.cookie;
+        let mut value = format!("{}={}; Path={}", cookie.name, token, cookie.path);
+        if let Some(domain) = &cookie.domain {
+            value.push_str(&format!("; Domain={}", domain));
+        }
+        if cookie.http_only {
+            value.push_str("; HttpOnly");
+        }
+        value.push_str(&format!("; SameSite={}", cookie.same_site.as_str()));
+        if cookie.is_secure() {
+            value.push_str("; Secure");
+        }
+        value.push_str(&format!("; Max-Age={}", max_age_seconds));
+        value
+    }
+
+    /// Extract the session cookie value from request headers.
+    ///
+    /// Returns `None` when the header is absent or the cookie is not present.
+    pub fn extract_session_token(&self, headers: &http::HeaderMap) -> Option<String> {
+        let cookie_str = headers.get(http::header::COOKIE)?.to_str().ok()?;
+        cookie_str.split(';').find_map(|cookie| {
+            let (name, value) = cookie.trim().split_once('=')?;
+            (name == self.config.cookie.name).then(|| value.to_string())
+        })
+    }
+
+    fn idle_timeout(&self) -> Option<Duration> {
+        self.config
+            .idle_timeout_minutes
+            .map(|minutes| Duration::minutes(minutes as i64))
+    }
+
     // ── Internal helpers ──────────────────────────────────────────────────────
 
+    /// Count a session as active until its token in `claims` expires
+    ///
+    /// Called on every validation, so the common case (already tracked with
+    /// this expiry) only takes the read lock.
+    fn track_session(&self, claims: &SessionClaims, client_ip: Option<IpAddr>) {
+        let Some(expires_at) = DateTime::from_timestamp(claims.exp as i64, 0) else {
+            return;
+        };
+        let session_id = claims.session_id();
+        let tracked = self
+            .read_active_sessions()
+            .get(session_id)
+            .is_some_and(|session| session.expires_at >= expires_at);
+        if tracked {
+            return;
+        }
+
+        let mut sessions = self.write_active_sessions();
+        if let Some(session) = sessions.get_mut(session_id) {
+            session.expires_at = session.expires_at.max(expires_at);
+            return;
+        }
+        let now = Utc::now();
+        sessions.insert(
+            session_id.to_string(),
+            ActiveSession {
+                user_id: claims.sub.clone(),
+                username: claims.username.clone(),
+                created_at: now,
+                last_accessed: now,
+                client_ip,
+                expires_at,
+            },
+        );
+        self.set_active_count(sessions.len());
+    }
+
+    /// Bind local users' sessions to their current password hash
+    ///
+    /// Tokens of local users carry a fingerprint of the password hash they
+    /// logged in with. Once this is set, a token whose fingerprint does not
+    /// match the user's current hash (password changed, user removed) is
+    /// rejected, including tokens issued before a restart or by another
+    /// replica. Called at startup and on every config reload.
+    pub fn set_local_credentials(&self, users: &[LocalUser]) {
+        let credentials = users
+            .iter()
+            .map(|user| {
+                (
+                    user.username.clone(),
+                    self.credential_fingerprint(&user.password_hash),
+                )
+            })
+            .collect();
+        *self
+            .local_credentials
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = Some(credentials);
+    }
+
+    /// Keyed digest of a password hash, so the token does not reveal the hash
+    fn credential_fingerprint(&self, password_hash: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.config.secret.as_bytes());
+        hasher.update([0]);
+        hasher.update(password_hash.as_bytes());
+        hasher.finalize()[..16]
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    /// `cred` claim for a new token: the user's current fingerprint for
+    /// local sessions, empty otherwise
+    fn credential_claim(&self, claims: &SessionClaims) -> String {
+        if claims.auth_type != "local" {
+            return String::new();
+        }
+        self.local_credentials
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .and_then(|credentials| credentials.get(&claims.username).cloned())
+            .unwrap_or_default()
+    }
+
+    /// Whether a local session's fingerprint matches the user's current one
+    ///
+    /// Always true for other auth types and when no local users are set.
+    fn credential_is_current(&self, claims: &SessionClaims) -> bool {
+        if claims.auth_type != "local" {
+            return true;
+        }
+        let credentials = self
+            .local_credentials
+            .read()
+            .unwrap_or_else(|e| e.into_inner());
+        match credentials.as_ref() {
+            Some(credentials) => credentials
+                .get(&claims.username)
+                .is_some_and(|current| !claims.cred.is_empty() && *current == claims.cred),
+            None => true,
+        }
+    }
+
+    /// Stop counting sessions, e.g. after logout or idle eviction
+    fn untrack_sessions<'a>(&self, session_ids: impl IntoIterator<Item = &'a str>) {
+        let mut sessions = self.write_active_sessions();
+        let mut session_roles = self.write_session_roles();
+        let before = sessions.len();
+        for session_id in session_ids {
+            sessions.remove(session_id);
+            session_roles.remove(session_id);
+        }
+        if sessions.len() != before {
+            self.set_active_count(sessions.len());
+        }
+    }
+
+    fn read_active_sessions(
+        &self,
+    ) -> std::sync::RwLockReadGuard<'_, HashMap<String, ActiveSession>> {
+        // A panic while holding the lock cannot leave the map inconsistent
+        self.active_sessions
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write_active_sessions(
+        &self,
+    ) -> std::sync::RwLockWriteGuard<'_, HashMap<String, ActiveSession>> {
+        self.active_sessions
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write_session_roles(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<String, Vec<String>>> {
+        self.session_roles
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn set_active_count(&self, count: usize) {
+        self.active_count.store(count, Ordering::Relaxed);
+        crate::app_metrics::sessions::set_active(count);
+    }
+
     fn encode_jwt(&self, claims: &SessionClaims) -> anyhow::Result<String> {
         encode(&Header::default(), claims, &self.encoding_key)
             .map_err(|e| anyhow::anyhow!("Failed to encode JWT: {}", e))
@@ -356,9 +602,24 @@ impl SessionManager {
             exp,
             iat: now.timestamp() as u64,
             jti: Uuid::new_v4().to_string(),
+            sid: Uuid::new_v4().to_string(),
+            cred: String::new(),
+        };
+        let claims = SessionClaims {
+            cred: self.credential_claim(&claims),
+            ..claims
         };
 
         let token = self.encode_jwt(&claims)?;
+        crate::app_metrics::sessions::record_created();
+        self.track_session(&claims, user.client_ip);
+
+        if self.config.idle_timeout_minutes.is_some() {
+            self.last_accessed
+                .write()
+                .await
+                .insert(claims.sid.clone(), now);
+        }
 
         // Diagnostic: log the encoded token length and cluster count so administrators
         // can identify cases where tokens grow too large (helps debugging ignored
@@ -376,14 +637,15 @@ impl SessionManager {
 
     /// Validate a session token.
     ///
-    /// Returns `None` when the token is invalid, expired, or revoked.
+    /// Returns `None` when the token is invalid, expired, revoked, or idle for
+    /// longer than the configured idle timeout.
     ///
     /// When `SessionValidation::renewed_token` is `Some`, the caller should
     /// set a new `session_token` cookie in the HTTP response to slide the
     /// expiry window forward.
     pub async fn validate_session(&self, token: &str) -> anyhow::Result<Option<SessionValidation>> {
         // Decode and verify signature + expiry
-        let claims = match self.decode_jwt(token) {
+        let mut claims = match self.decode_jwt(token) {
             Ok(c) => c,
             Err(e) => {
                 tracing::debug!(error = %e, "JWT validation failed");
@@ -391,20 +653,64 @@ impl SessionManager {
             }
         };
 
-        // Check revocation list
+        let session_id = claims.session_id().to_string();
+
+        // Roles re-evaluated mid-session apply to every token of the session
+        if let Some(roles) = self
+            .session_roles
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&session_id)
+        {
+            claims.roles = roles.clone();
+        }
+
+        // Check revocation list (individual tokens and idle-evicted sessions)
         {
             let revoked = self.revocation_list.read().await;
-            if revoked.contains_key(&claims.jti) {
+            if revoked.contains_key(&claims.jti) || revoked.contains_key(&session_id) {
                 tracing::debug!(jti = %claims.jti, "Session is revoked");
                 return Ok(None);
             }
         }
 
+        if !self.credential_is_current(&claims) {
+            tracing::debug!(jti = %claims.jti, "Session was issued for a password that is no longer current");
+            return Ok(None);
+        }
+
+        // Per-user cutoff, which also covers tokens this instance never saw
+        {
+            let revoked_before = self.revoked_before.read().await;
+            let issued_before = |user: &str| {
+                revoked_before
+                    .get(user)
+                    .is_some_and(|cutoff| (claims.iat as i64) < cutoff.timestamp())
+            };
+            if issued_before(&claims.username) || issued_before(&claims.sub) {
+                tracing::debug!(jti = %claims.jti, "Session was issued before the user's sessions were revoked");
+                return Ok(None);
+            }
+        }
+
         let now = Utc::now();
+
+        // Idle expiry. Sessions with no recorded activity (e.g. after a restart)
+        // start a fresh idle window rather than being rejected.
+        if let Some(idle_timeout) = self.idle_timeout() {
+            let last_accessed = self.last_accessed.read().await.get(&session_id).copied();
+            if last_accessed.is_some_and(|last| now - last > idle_timeout) {
+                tracing::debug!(session_id = %session_id, "Session idle timeout elapsed");
+                return Ok(None);
+            }
+        }
+
         let expires_at = DateTime::from_timestamp(claims.exp as i64, 0)
             .unwrap_or_else(|| now + Duration::minutes(self.config.timeout_minutes as i64));
         let created_at = DateTime::from_timestamp(claims.iat as i64, 0).unwrap_or(now);
 
+        self.track_session(&claims, None);
+
         let session = Session {
             token: token.to_string(),
             user_id: claims.sub.clone(),
@@ -416,6 +722,8 @@ impl SessionManager {
             expires_at,
             last_activity: now,
             jti: claims.jti.clone(),
+            session_id: session_id.clone(),
+            credential: claims.cred.clone(),
         };
 
         // Sliding expiry: re-issue when remaining TTL < 50 % of configured timeout
@@ -433,10 +741,13 @@ impl SessionManager {
                 iat: now.timestamp() as u64,
                 // Fresh jti for the renewed token
                 jti: Uuid::new_v4().to_string(),
+                sid: session_id,
+                cred: claims.cred,
             };
             match self.encode_jwt(&new_claims) {
                 Ok(new_token) => {
                     tracing::debug!("Session renewed (TTL below 50 % threshold)");
+                    self.track_session(&new_claims, None);
                     Some(new_token)
                 }
                 Err(e) => {
@@ -454,6 +765,104 @@ impl SessionManager {
         }))
     }
 
+    /// Session ID of a valid token, or `None` if it does not validate
+    pub fn session_id(&self, token: &str) -> Option<String> {
+        self.decode_jwt(token)
+            .ok()
+            .map(|claims| claims.session_id().to_string())
+    }
+
+    /// Re-issue a session's token with updated roles, keeping its session ID.
+    ///
+    /// Used when roles are re-evaluated mid-session (e.g. after an OIDC token
+    /// refresh). The new token gets a fresh expiry like a sliding renewal.
+    /// The roles are also kept for the session ID, so earlier tokens of the
+    /// session no longer carry the roles they were issued with.
+    pub fn reissue_with_roles(
+        &self,
+        session: &Session,
+        roles: Vec<String>,
+    ) -> anyhow::Result<String> {
+        let now = Utc::now();
+        let claims = SessionClaims {
+            sub: session.user_id.clone(),
+            username: session.username.clone(),
+            roles,
+            accessible_clusters: session.accessible_clusters.clone(),
+            auth_type: session.auth_type.clone(),
+            exp: (now + Duration::minutes(self.config.timeout_minutes as i64)).timestamp() as u64,
+            iat: now.timestamp() as u64,
+            jti: Uuid::new_v4().to_string(),
+            sid: session.session_id.clone(),
+            cred: session.credential.clone(),
+        };
+        let token = self.encode_jwt(&claims)?;
+        self.track_session(&claims, None);
+        self.write_session_roles()
+            .insert(claims.sid.clone(), claims.roles.clone());
+        Ok(token)
+    }
+
+    /// Record activity for a validated session, restarting its idle window.
+    ///
+    /// Called by the authentication middleware on every authenticated request.
+    /// Updates the last access time and client IP listed for the session;
+    /// the idle window is only tracked when an idle timeout is configured.
+    pub async fn touch_session(&self, session: &Session, client_ip: Option<IpAddr>) {
+        let now = Utc::now();
+        if let Some(active) = self.write_active_sessions().get_mut(&session.session_id) {
+            active.last_accessed = now;
+            if client_ip.is_some() {
+                active.client_ip = client_ip;
+            }
+        }
+
+        if self.config.idle_timeout_minutes.is_none() {
+            return;
+        }
+        self.last_accessed
+            .write()
+            .await
+            .insert(session.session_id.clone(), now);
+    }
+
+    /// Evict sessions whose idle window has elapsed.
+    ///
+    /// Evicted sessions are added to the revocation list for the longest
+    /// remaining lifetime of any of their tokens, so they stay rejected even
+    /// though their activity record is gone. Returns the number evicted.
+    pub async fn evict_idle_sessions(&self) -> usize {
+        let Some(idle_timeout) = self.idle_timeout() else {
+            return 0;
+        };
+
+        let now = Utc::now();
+        let idle: Vec<String> = {
+            let mut last_accessed = self.last_accessed.write().await;
+            let idle: Vec<String> = last_accessed
+                .iter()
+                .filter(|(_, last)| now - **last > idle_timeout)
+                .map(|(sid, _)| sid.clone())
+                .collect();
+            for sid in &idle {
+                last_accessed.remove(sid);
+            }
+            idle
+        };
+
+        if !idle.is_empty() {
+            self.untrack_sessions(idle.iter().map(String::as_str));
+            let revoke_until = now + Duration::minutes(self.config.timeout_minutes as i64);
+            let mut revoked = self.revocation_list.write().await;
+            for sid in &idle {
+                revoked.insert(sid.clone(), revoke_until);
+            }
+            tracing::debug!(evicted_count = idle.len(), "Evicted idle sessions");
+        }
+
+        idle.len()
+    }
+
     /// Revoke a session by adding its `jti` to the revocation list.
     ///
     /// If the token cannot be decoded (e.g. already expired) the call succeeds
@@ -464,6 +873,7 @@ impl SessionManager {
                 let exp = DateTime::from_timestamp(claims.exp as i64, 0).unwrap_or_else(Utc::now);
                 let mut revoked = self.revocation_list.write().await;
                 revoked.insert(claims.jti.clone(), exp);
+                self.untrack_sessions([claims.session_id()]);
                 tracing::debug!(jti = %claims.jti, username = %claims.username, "Session revoked");
             }
             Err(e) => {
@@ -484,6 +894,13 @@ impl SessionManager {
     /// revocation list is consulted, so stale entries are purely dead weight.
     pub async fn cleanup_expired(&self) -> usize {
         let now = Utc::now();
+        // Tokens older than a cutoff have all expired one timeout after it
+        let timeout = Duration::minutes(self.config.timeout_minutes as i64);
+        self.revoked_before
+            .write()
+            .await
+            .retain(|_, cutoff| *cutoff + timeout > now);
+
         let mut revoked = self.revocation_list.write().await;
         let initial = revoked.len();
         revoked.retain(|_, exp| *exp > now);
@@ -497,15 +914,92 @@ impl SessionManager {
         removed
     }
 
+    /// Stop counting sessions whose newest token has expired.
+    ///
+    /// Returns the number of sessions removed.
+    pub fn expire_sessions(&self) -> usize {
+        let now = Utc::now();
+        let mut sessions = self.write_active_sessions();
+        let initial = sessions.len();
+        sessions.retain(|_, session| session.expires_at > now);
+        let removed = initial - sessions.len();
+        if removed > 0 {
+            self.set_active_count(sessions.len());
+            self.write_session_roles()
+                .retain(|session_id, _| sessions.contains_key(session_id));
+        }
+        removed
+    }
+
+    /// Sessions that have not expired or been logged out, most recently
+    /// active first.
+    pub fn list_sessions(&self) -> Vec<ActiveSession> {
+        let now = Utc::now();
+        let mut sessions: Vec<ActiveSession> = self
+            .read_active_sessions()
+            .values()
+            .filter(|session| session.expires_at > now)
+            .cloned()
+            .collect();
+        sessions.sort_by_key(|session| std::cmp::Reverse(session.last_accessed));
+        sessions
+    }
+
+    /// Revoke every session of a user, matched by username or user ID.
+    ///
+    /// Tokens of the user issued before now are rejected from now on, including
+    /// ones this instance has not seen (issued before a restart or by another
+    /// replica). Tracked sessions are also added to the revocation list until
+    /// their newest token expires. Returns the IDs of the tracked sessions
+    /// that were revoked.
+    pub async fn revoke_user_sessions(&self, user: &str) -> Vec<String> {
+        self.revoked_before
+            .write()
+            .await
+            .insert(user.to_string(), Utc::now());
+
+        let revoked_sessions: Vec<(String, DateTime<Utc>)> = self
+            .read_active_sessions()
+            .iter()
+            .filter(|(_, session)| session.username == user || session.user_id == user)
+            .map(|(session_id, session)| (session_id.clone(), session.expires_at))
+            .collect();
+        if revoked_sessions.is_empty() {
+            return Vec::new();
+        }
+
+        {
+            let mut revoked = self.revocation_list.write().await;
+            for (session_id, expires_at) in &revoked_sessions {
+                revoked.insert(session_id.clone(), *expires_at);
+            }
+        }
+        self.untrack_sessions(revoked_sessions.iter().map(|(id, _)| id.as_str()));
+        tracing::info!(
+            user = %user,
+            revoked_count = revoked_sessions.len(),
+            "Revoked all sessions of user"
+        );
+        revoked_sessions.into_iter().map(|(id, _)| id).collect()
+    }
+
+    /// Number of sessions that have not expired or been logged out.
+    ///
+    /// Kept up to date as sessions are created and ended, so this does not
+    /// lock or scan the session map.
+    pub fn active_session_count(&self) -> usize {
+        self.active_count.load(Ordering::Relaxed)
+    }
+
     /// Number of entries currently in the revocation list.
     ///
-    /// This is a proxy metric used by the health endpoint; it counts **revoked**
-    /// (but not yet cleaned up) sessions, not active ones.
-    pub async fn active_session_count(&self) -> usize {
+    /// Counts **revoked** (but not yet cleaned up) tokens, not active sessions.
+    pub async fn revoked_session_count(&self) -> usize {
         self.revocation_list.read().await.len()
     }
 
-    /// Spawn a background task that periodically removes stale revocation entries.
+    /// Spawn a background task that periodically evicts idle sessions and
+    /// removes stale revocation entries.
     ///
     /// Each cleanup iteration runs inside its own `tokio::task::spawn` so that a panic
     /// in `cleanup_expired` is caught as a `JoinError` rather than killing the loop.
@@ -518,6 +1012,7 @@ impl SessionManager {
                 interval.tick().await;
 
                 let current_size = self.revocation_list.read().await.len();
+                crate::app_metrics::sessions::set_revoked(current_size);
                 if current_size > 10_000 {
                     tracing::warn!(
                         revocation_list_size = current_size,
@@ -526,11 +1021,17 @@ impl SessionManager {
                 }
 
                 let cleanup_self = self.clone();
-                match tokio::task::spawn(async move { cleanup_self.cleanup_expired().await }).await
+                match tokio::task::spawn(async move {
+                    let evicted = cleanup_self.evict_idle_sessions().await;
+                    cleanup_self.expire_sessions();
+                    (cleanup_self.cleanup_expired().await, evicted)
+                })
+                .await
                 {
-                    Ok(removed) => {
+                    Ok((removed, evicted)) => {
                         tracing::debug!(
                             removed = removed,
+                            evicted_idle = evicted,
                             remaining = current_size.saturating_sub(removed),
                             "Session revocation-list cleanup completed"
                         );
@@ -565,6 +1066,12 @@ impl SessionManager {
             exp: (now + Duration::seconds(seconds_from_now)).timestamp() as u64,
             iat: now.timestamp() as u64,
             jti: Uuid::new_v4().to_string(),
+            sid: Uuid::new_v4().to_string(),
+            cred: String::new(),
+        };
+        let claims = SessionClaims {
+            cred: self.credential_claim(&claims),
+            ..claims
         };
         self.encode_jwt(&claims)
     }
@@ -754,11 +1261,135 @@ mod tests {
             revoked.insert("jti-valid".to_string(), Utc::now() + Duration::hours(1));
         }
 
-        assert_eq!(manager.active_session_count().await, 6);
+        assert_eq!(manager.revoked_session_count().await, 6);
 
         let removed = manager.cleanup_expired().await;
         assert_eq!(removed, 5);
-        assert_eq!(manager.active_session_count().await, 1);
+        assert_eq!(manager.revoked_session_count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_revoke_user_sessions_rejects_all_their_tokens() {
+        let manager = SessionManager::new(test_config(60));
+        let other = AuthUser::new("u2".to_string(), "bob".to_string(), Vec::new());
+        let ip: IpAddr = "10.0.0.5".parse().unwrap();
+
+        let first = manager
+            .create_session(test_user().with_client_ip(Some(ip)))
+            .await
+            .unwrap();
+        let second = manager.create_session(test_user()).await.unwrap();
+        let kept = manager.create_session(other).await.unwrap();
+
+        let listed = manager.list_sessions();
+        assert_eq!(listed.len(), 3);
+        assert!(listed
+            .iter()
+            .any(|s| s.username == "testuser" && s.client_ip == Some(ip)));
+
+        // Matched by username or user ID
+        assert_eq!(manager.revoke_user_sessions("user123").await.len(), 2);
+        assert!(manager.validate_session(&first).await.unwrap().is_none());
+        assert!(manager.validate_session(&second).await.unwrap().is_none());
+        assert!(manager.validate_session(&kept).await.unwrap().is_some());
+        assert_eq!(manager.active_session_count(), 1);
+        assert!(manager.revoke_user_sessions("testuser").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_local_sessions_are_bound_to_the_password_hash() {
+        let local_user = |password_hash: &str| LocalUser {
+            username: "testuser".to_string(),
+            password_hash: password_hash.to_string(),
+            groups: Vec::new(),
+        };
+        let manager = SessionManager::new(test_config(60));
+        manager.set_local_credentials(&[local_user("$2b$04$old")]);
+        let token = manager.create_session(test_user()).await.unwrap();
+        let validation = manager.validate_session(&token).await.unwrap().unwrap();
+        assert!(!validation.session.credential.is_empty());
+
+        // Another replica, or this one after a restart, with the same config
+        let replica = SessionManager::new(test_config(60));
+        replica.set_local_credentials(&[local_user("$2b$04$old")]);
+        assert!(replica.validate_session(&token).await.unwrap().is_some());
+
+        // Password rotated, or the user removed
+        replica.set_local_credentials(&[local_user("$2b$04$new")]);
+        assert!(replica.validate_session(&token).await.unwrap().is_none());
+        replica.set_local_credentials(&[]);
+        assert!(replica.validate_session(&token).await.unwrap().is_none());
+
+        // Other auth types are not affected
+        let oidc = manager
+            .create_session(test_user().with_auth_type("oidc"))
+            .await
+            .unwrap();
+        assert!(replica.validate_session(&oidc).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_user_sessions_rejects_untracked_tokens() {
+        let manager = SessionManager::new(test_config(60));
+
+        // Issued an hour ago by another replica, or before a restart
+        let now = Utc::now();
+        let claims = |username: &str| SessionClaims {
+            sub: format!("id-{}", username),
+            username: username.to_string(),
+            roles: Vec::new(),
+            accessible_clusters: Vec::new(),
+            auth_type: "local".to_string(),
+            exp: (now + Duration::minutes(30)).timestamp() as u64,
+            iat: (now - Duration::hours(1)).timestamp() as u64,
+            jti: Uuid::new_v4().to_string(),
+            sid: Uuid::new_v4().to_string(),
+            cred: String::new(),
+        };
+        let untracked = manager.encode_jwt(&claims("testuser")).unwrap();
+        let other = manager.encode_jwt(&claims("bob")).unwrap();
+        assert_eq!(manager.active_session_count(), 0);
+
+        assert!(manager.revoke_user_sessions("testuser").await.is_empty());
+        assert!(manager
+            .validate_session(&untracked)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(manager.validate_session(&other).await.unwrap().is_some());
+
+        // Logging in again after the revocation works
+        let fresh = manager.create_session(test_user()).await.unwrap();
+        assert!(manager.validate_session(&fresh).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_active_session_count_follows_logins_and_logouts() {
+        let manager = SessionManager::new(test_config(60));
+        assert_eq!(manager.active_session_count(), 0);
+
+        let first = manager.create_session(test_user()).await.unwrap();
+        let second = manager.create_session(test_user()).await.unwrap();
+        assert_eq!(manager.active_session_count(), 2);
+
+        // Validating an already counted session does not count it again
+        manager.validate_session(&first).await.unwrap().unwrap();
+        assert_eq!(manager.active_session_count(), 2);
+
+        manager.invalidate_session(&first).await.unwrap();
+        assert_eq!(manager.active_session_count(), 1);
+
+        // Sessions past their expiry are dropped by the cleanup
+        let sid = manager.session_id(&second).unwrap();
+        manager
+            .active_sessions
+            .write()
+            .unwrap()
+            .get_mut(&sid)
+            .unwrap()
+            .expires_at = Utc::now() - Duration::minutes(1);
+        assert_eq!(manager.expire_sessions(), 1);
+        assert_eq!(manager.active_session_count(), 0);
     }
 
     // ── Sliding expiry / renewal ──────────────────────────────────────────────
@@ -864,4 +1495,203 @@ mod tests {
         assert!(manager.validate_session(&token_a).await.unwrap().is_none());
         assert!(manager.validate_session(&token_b).await.unwrap().is_some());
     }
+
+    // ── Idle timeout ──────────────────────────────────────────────────────────
+
+    /// Pretend the session behind `token` was last used `minutes` ago
+    async fn backdate_activity(manager: &SessionManager, token: &str, minutes: i64) {
+        // Decode without expiry validation so expired tokens can be backdated too
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.validate_exp = false;
+        let claims = decode::<SessionClaims>(token, &manager.decoding_key, &validation)
+            .unwrap()
+            .claims;
+        manager.last_accessed.write().await.insert(
+            claims.session_id().to_string(),
+            Utc::now() - Duration::minutes(minutes),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_idle_timeout_expires_session_before_absolute_timeout() {
+        let manager = SessionManager::new(test_config(480).with_idle_timeout(Some(15)));
+        let token = manager.create_session(test_user()).await.unwrap();
+
+        assert!(manager.validate_session(&token).await.unwrap().is_some());
+
+        // Idle for 20 minutes — JWT still valid for hours, but idle window elapsed
+        backdate_activity(&manager, &token, 20).await;
+        assert!(manager.validate_session(&token).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_touch_session_restarts_idle_window() {
+        let manager = SessionManager::new(test_config(480).with_idle_timeout(Some(15)));
+        let token = manager.create_session(test_user()).await.unwrap();
+
+        backdate_activity(&manager, &token, 10).await;
+        let v = manager.validate_session(&token).await.unwrap().unwrap();
+        manager.touch_session(&v.session, None).await;
+
+        // 10 more idle minutes would exceed 15 without the touch
+        let last = manager.last_accessed.read().await[&v.session.session_id];
+        assert!(Utc::now() - last < Duration::minutes(1));
+        assert!(manager.validate_session(&token).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_absolute_timeout_applies_with_recent_activity() {
+        let manager = SessionManager::new(test_config(60).with_idle_timeout(Some(15)));
+        let expired = manager
+            .create_session_expiring_in(test_user(), -60)
+            .await
+            .unwrap();
+
+        // Activity just now does not extend an expired token
+        backdate_activity(&manager, &expired, 0).await;
+        assert!(manager.validate_session(&expired).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_no_idle_expiry_without_idle_timeout() {
+        let manager = SessionManager::new(test_config(480));
+        let token = manager.create_session(test_user()).await.unwrap();
+
+        backdate_activity(&manager, &token, 300).await;
+        assert!(manager.validate_session(&token).await.unwrap().is_some());
+        assert_eq!(manager.evict_idle_sessions().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_evict_idle_sessions_keeps_them_rejected() {
+        let manager = SessionManager::new(test_config(480).with_idle_timeout(Some(15)));
+        let idle = manager.create_session(test_user()).await.unwrap();
+        let active = manager.create_session(test_user()).await.unwrap();
+
+        backdate_activity(&manager, &idle, 30).await;
+
+        assert_eq!(manager.evict_idle_sessions().await, 1);
+        assert_eq!(manager.last_accessed.read().await.len(), 1);
+
+        // Activity record is gone, but the session must not get a fresh window
+        assert!(manager.validate_session(&idle).await.unwrap().is_none());
+        assert!(manager.validate_session(&active).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_reissue_with_roles_keeps_session_id() {
+        let manager = SessionManager::new(test_config(60));
+        let token = manager.create_session(test_user()).await.unwrap();
+        let session = manager
+            .validate_session(&token)
+            .await
+            .unwrap()
+            .unwrap()
+            .session;
+
+        let reissued = manager
+            .reissue_with_roles(&session, vec!["ops".to_string()])
+            .unwrap();
+        let renewed = manager
+            .validate_session(&reissued)
+            .await
+            .unwrap()
+            .unwrap()
+            .session;
+
+        assert_eq!(renewed.roles, vec!["ops".to_string()]);
+        assert_eq!(renewed.session_id, session.session_id);
+        assert_ne!(renewed.jti, session.jti);
+        assert_eq!(manager.session_id(&reissued), Some(session.session_id));
+    }
+
+    #[tokio::test]
+    async fn test_reissue_with_roles_applies_to_earlier_tokens() {
+        let manager = SessionManager::new(test_config(60));
+        let token = manager.create_session(test_user()).await.unwrap();
+        let session = manager
+            .validate_session(&token)
+            .await
+            .unwrap()
+            .unwrap()
+            .session;
+        assert!(session.roles.contains(&"admin".to_string()));
+
+        manager
+            .reissue_with_roles(&session, vec!["ops".to_string()])
+            .unwrap();
+
+        // The old token stays valid but loses the removed role
+        let old = manager
+            .validate_session(&token)
+            .await
+            .unwrap()
+            .unwrap()
+            .session;
+        assert_eq!(old.roles, vec!["ops".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_renewed_token_keeps_session_id() {
+        let manager = SessionManager::new(test_config(60).with_idle_timeout(Some(15)));
+        let short_lived = manager
+            .create_session_expiring_in(test_user(), 10)
+            .await
+            .unwrap();
+
+        let v = manager
+            .validate_session(&short_lived)
+            .await
+            .unwrap()
+            .unwrap();
+        let renewed = v.renewed_token.unwrap();
+        let v2 = manager.validate_session(&renewed).await.unwrap().unwrap();
+
+        assert_eq!(v.session.session_id, v2.session.session_id);
+        assert_ne!(v.session.jti, v2.session.jti);
+    }
+
+    #[test]
+    fn test_session_cookie_uses_configured_attributes() {
+        let manager = SessionManager::new(test_config(60).with_cookie(SessionCookieConfig {
+            name: "secan_session".to_string(),
+            path: "/secan".to_string(),
+            domain: Some("example.com".to_string()),
+            same_site: crate::config::SameSite::Strict,
+            secure: Some(true),
+            http_only: false,
+        }));
+
+        assert_eq!(
+            manager.session_cookie_header("abc", 3600),
+            "secan_session=abc; Path=/secan; Domain=example.com; SameSite=Strict; Secure; Max-Age=3600"
+        );
+        assert_eq!(
+            manager.clear_session_cookie_header(),
+            "secan_session=; Path=/secan; Domain=example.com; SameSite=Strict; Secure; Max-Age=0"
+        );
+
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            http::header::COOKIE,
+            http::HeaderValue::from_static("session_token=old; secan_session=abc"),
+        );
+        assert_eq!(
+            manager.extract_session_token(&headers).as_deref(),
+            Some("abc")
+        );
+    }
+
+    #[test]
+    fn test_default_session_cookie() {
+        let manager = SessionManager::new(test_config(60).with_cookie(SessionCookieConfig {
+            secure: Some(true),
+            ..Default::default()
+        }));
+
+        assert_eq!(
+            manager.session_cookie_header("abc", 60),
+            "session_token=abc; Path=/; HttpOnly; SameSite=Lax; Secure; Max-Age=60"
+        );
+    }
 }
@@ -0,0 +1,18 @@
+//! Version and build details embedded at compile time by `build.rs`
+
+/// Crate version (`CARGO_PKG_VERSION`)
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Short git commit hash the binary was built from, or "unknown"
+pub const GIT_SHA: &str = env!("GIT_SHA");
+
+/// When the binary was built (RFC 3339)
+pub const BUILD_TIME: &str = env!("BUILD_TIME");
+
+/// Compiler used for the build (`rustc --version`)
+pub const RUSTC_VERSION: &str = env!("RUSTC_VERSION");
+
+/// Version line printed by `secan --version`
+pub fn version_line() -> String {
+    format!("Secan {} ({}, built {})", VERSION, GIT_SHA, BUILD_TIME)
+}
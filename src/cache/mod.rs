@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 /// TTL-based cache backed by moka::future::Cache
 ///
 /// Uses moka for automatic expiry, concurrent access, and bounded capacity.
@@ -6,3 +8,72 @@
 ///
 /// Validates: Requirement 31.2 - Cache cluster metadata for configurable duration
 pub type MetadataCache<T> = moka::future::Cache<String, T>;
+
+/// Create a TTL-based metadata cache bounded to `max_entries`
+///
+/// Once the bound is exceeded the least-recently-used entry is evicted, so
+/// frequently read keys survive while stale ones are dropped first. `get` and
+/// `insert` behave exactly as on an unbounded [`MetadataCache`].
+///
+/// # Arguments
+///
+/// * `ttl` - Time to live for each entry
+/// * `max_entries` - Maximum number of entries kept in the cache
+pub fn with_capacity<T>(ttl: Duration, max_entries: u64) -> MetadataCache<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    moka::future::Cache::builder()
+        .time_to_live(ttl)
+        .max_capacity(max_entries)
+        .eviction_policy(moka::policy::EvictionPolicy::lru())
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_with_capacity_evicts_least_recently_used() {
+        let cache: MetadataCache<u32> = with_capacity(Duration::from_secs(60), 3);
+
+        cache.insert("a".to_string(), 1).await;
+        cache.insert("b".to_string(), 2).await;
+        cache.insert("c".to_string(), 3).await;
+        cache.run_pending_tasks().await;
+
+        // Touch "a" so "b" becomes the least recently used entry
+        assert_eq!(cache.get("a").await, Some(1));
+        cache.run_pending_tasks().await;
+
+        cache.insert("d".to_string(), 4).await;
+        cache.run_pending_tasks().await;
+
+        assert_eq!(cache.entry_count(), 3);
+        assert_eq!(cache.get("b").await, None);
+        assert_eq!(cache.get("a").await, Some(1));
+        assert_eq!(cache.get("c").await, Some(3));
+        assert_eq!(cache.get("d").await, Some(4));
+    }
+
+    #[tokio::test]
+    async fn test_with_capacity_keeps_recently_touched_keys() {
+        let cache: MetadataCache<u32> = with_capacity(Duration::from_secs(60), 2);
+
+        cache.insert("hot".to_string(), 1).await;
+        cache.run_pending_tasks().await;
+
+        for i in 0..10u32 {
+            assert_eq!(cache.get("hot").await, Some(1));
+            cache.run_pending_tasks().await;
+            cache.insert(format!("cold-{}", i), i).await;
+            cache.run_pending_tasks().await;
+        }
+
+        assert_eq!(cache.entry_count(), 2);
+        assert_eq!(cache.get("hot").await, Some(1));
+        assert_eq!(cache.get("cold-9").await, Some(9));
+        assert_eq!(cache.get("cold-0").await, None);
+    }
+}
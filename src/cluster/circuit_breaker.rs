@@ -0,0 +1,228 @@
+use crate::config::CircuitBreakerConfig;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use utoipa::ToSchema;
+
+/// State of a cluster circuit breaker
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Requests flow normally
+    Closed,
+    /// Requests fail fast until the cooldown elapses
+    Open,
+    /// Cooldown elapsed; a single trial request decides the next state
+    HalfOpen,
+}
+
+/// Returned instead of contacting a cluster while its breaker is open
+#[derive(Error, Debug, Clone)]
+#[error("circuit open for cluster '{cluster_id}': temporarily unavailable, retry in {retry_after_secs}s")]
+pub struct CircuitOpenError {
+    pub cluster_id: String,
+    pub retry_after_secs: u64,
+}
+
+/// How long a half-open trial may run before another caller may retry it
+///
+/// Guards against a trial whose caller went away without recording a result.
+const TRIAL_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+struct BreakerInner {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    trial_started: Option<Instant>,
+}
+
+/// Per-cluster circuit breaker
+///
+/// Counts consecutive connection failures. Once `failure_threshold` is
+/// reached the breaker opens and [`CircuitBreaker::try_acquire`] rejects
+/// calls until `cooldown` has passed. The first call after that is let
+/// through as a trial: success closes the breaker, failure re-opens it for
+/// another cooldown.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    cluster_id: String,
+    failure_threshold: u32,
+    cooldown: Duration,
+    inner: Mutex<BreakerInner>,
+}
+
+impl CircuitBreaker {
+    /// Create a closed breaker for a cluster
+    pub fn new(cluster_id: impl Into<String>, config: &CircuitBreakerConfig) -> Self {
+        Self {
+            cluster_id: cluster_id.into(),
+            failure_threshold: config.failure_threshold.max(1),
+            cooldown: Duration::from_secs(config.cooldown_seconds),
+            inner: Mutex::new(BreakerInner {
+                consecutive_failures: 0,
+                opened_at: None,
+                trial_started: None,
+            }),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerInner> {
+        // A poisoned lock only means another thread panicked mid-update; the
+        // counters are still usable.
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Current breaker state
+    pub fn state(&self) -> CircuitState {
+        let inner = self.lock();
+        match inner.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if opened_at.elapsed() >= self.cooldown => CircuitState::HalfOpen,
+            Some(_) => CircuitState::Open,
+        }
+    }
+
+    /// Ask permission to send a request
+    ///
+    /// Returns an error while the breaker is open, or while it is half-open
+    /// and the single trial request is still in flight.
+    pub fn try_acquire(&self) -> Result<(), CircuitOpenError> {
+        let mut inner = self.lock();
+        let Some(opened_at) = inner.opened_at else {
+            return Ok(());
+        };
+
+        let elapsed = opened_at.elapsed();
+        let trial_pending = inner
+            .trial_started
+            .is_some_and(|started| started.elapsed() < TRIAL_TIMEOUT);
+        if elapsed >= self.cooldown && !trial_pending {
+            inner.trial_started = Some(Instant::now());
+            tracing::info!(cluster_id = %self.cluster_id, "Circuit half-open, allowing trial request");
+            return Ok(());
+        }
+
+        Err(CircuitOpenError {
+            cluster_id: self.cluster_id.clone(),
+            retry_after_secs: self.cooldown.saturating_sub(elapsed).as_secs().max(1),
+        })
+    }
+
+    /// Record a successful request, closing the breaker
+    pub fn record_success(&self) {
+        let mut inner = self.lock();
+        if inner.opened_at.is_some() {
+            tracing::info!(cluster_id = %self.cluster_id, "Circuit closed after successful trial request");
+        }
+        inner.consecutive_failures = 0;
+        inner.opened_at = None;
+        inner.trial_started = None;
+    }
+
+    /// Record a failed request, opening the breaker once the threshold is hit
+    pub fn record_failure(&self) {
+        let mut inner = self.lock();
+        inner.consecutive_failures = inner.consecutive_failures.saturating_add(1);
+
+        let was_trial = inner.trial_started.is_some();
+        if was_trial || inner.consecutive_failures >= self.failure_threshold {
+            if inner.opened_at.is_none() || was_trial {
+                tracing::warn!(
+                    cluster_id = %self.cluster_id,
+                    consecutive_failures = inner.consecutive_failures,
+                    cooldown_secs = self.cooldown.as_secs(),
+                    "Circuit opened"
+                );
+            }
+            inner.opened_at = Some(Instant::now());
+            inner.trial_started = None;
+        }
+    }
+}
+
+/// Whether an error means the cluster could not be reached
+///
+/// Only connection failures and timeouts count towards opening the breaker;
+/// Elasticsearch answering with an error status does not.
+pub fn is_connection_failure(err: &anyhow::Error) -> bool {
+    matches!(
+        crate::cluster::error::classify_anyhow(err),
+        crate::cluster::ProxyRequestError::ProxyTimeout
+            | crate::cluster::ProxyRequestError::RequestFailed(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(threshold: u32, cooldown_seconds: u64) -> CircuitBreaker {
+        CircuitBreaker::new(
+            "test",
+            &CircuitBreakerConfig {
+                failure_threshold: threshold,
+                cooldown_seconds,
+            },
+        )
+    }
+
+    #[test]
+    fn test_opens_after_threshold() {
+        let cb = breaker(3, 60);
+        cb.record_failure();
+        cb.record_failure();
+        assert_eq!(cb.state(), CircuitState::Closed);
+        assert!(cb.try_acquire().is_ok());
+
+        cb.record_failure();
+        assert_eq!(cb.state(), CircuitState::Open);
+        let err = cb.try_acquire().expect_err("breaker should be open");
+        assert_eq!(err.cluster_id, "test");
+        assert!(err.to_string().contains("circuit open"));
+    }
+
+    #[test]
+    fn test_success_resets_failure_count() {
+        let cb = breaker(2, 60);
+        cb.record_failure();
+        cb.record_success();
+        cb.record_failure();
+        assert_eq!(cb.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_half_open_allows_single_trial() {
+        let cb = breaker(1, 0);
+        cb.record_failure();
+        assert_eq!(cb.state(), CircuitState::HalfOpen);
+
+        assert!(cb.try_acquire().is_ok());
+        // Second caller is rejected while the trial is in flight
+        assert!(cb.try_acquire().is_err());
+
+        cb.record_success();
+        assert_eq!(cb.state(), CircuitState::Closed);
+        assert!(cb.try_acquire().is_ok());
+    }
+
+    #[test]
+    fn test_failed_trial_reopens() {
+        let cb = breaker(1, 60);
+        cb.record_failure();
+        // Simulate the cooldown having elapsed
+        cb.lock().opened_at = Some(Instant::now() - Duration::from_secs(61));
+        assert_eq!(cb.state(), CircuitState::HalfOpen);
+        assert!(cb.try_acquire().is_ok());
+
+        cb.record_failure();
+        assert_eq!(cb.state(), CircuitState::Open);
+        assert!(cb.try_acquire().is_err());
+    }
+
+    #[test]
+    fn test_status_errors_do_not_count_as_connection_failures() {
+        let err = anyhow::anyhow!("Cluster health request failed with status: 500");
+        assert!(!is_connection_failure(&err));
+    }
+}
@@ -8,6 +8,8 @@ use thiserror::Error;
 pub enum ProxyRequestError {
     #[error("access_denied")]
     AccessDenied,
+    #[error("cluster_not_found: {0}")]
+    ClusterNotFound(String),
     #[error("proxy_timeout")]
     ProxyTimeout,
     #[error("request_failed: {0}")]
@@ -16,6 +18,10 @@ pub enum ProxyRequestError {
     ResponseReadTimeout,
     #[error("response_read_failed: {0}")]
     ResponseReadFailed(String),
+    #[error("response_too_large: over {limit} bytes")]
+    ResponseTooLarge { limit: usize },
+    #[error("cluster_busy: {0}")]
+    ClusterBusy(#[from] crate::cluster::ConcurrencyLimitError),
     #[error("other: {0}")]
     Other(String),
 }
@@ -62,6 +68,80 @@ impl From<tokio::time::error::Elapsed> for ProxyRequestError {
     }
 }
 
+/// Why a call to a cluster failed, as far as the operator is concerned
+///
+/// Rejected credentials and missing privileges need a config fix, while an
+/// unreachable or slow cluster needs the network or the cluster looked at.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ClusterRequestError {
+    /// The cluster answered 401: the configured credentials were rejected
+    #[error("authentication failed")]
+    AuthenticationFailed,
+    /// The cluster answered 403: the credentials lack a privilege
+    #[error("permission denied")]
+    PermissionDenied,
+    /// No node could be connected to
+    #[error("unreachable: {0}")]
+    Unreachable(String),
+    /// The cluster did not answer in time
+    #[error("timed out")]
+    Timeout,
+}
+
+impl ClusterRequestError {
+    /// Classify an HTTP status returned by the cluster
+    ///
+    /// Only 401 and 403 are classified; other statuses are request specific.
+    pub fn from_status(status: reqwest::StatusCode) -> Option<Self> {
+        match status {
+            reqwest::StatusCode::UNAUTHORIZED => Some(Self::AuthenticationFailed),
+            reqwest::StatusCode::FORBIDDEN => Some(Self::PermissionDenied),
+            _ => None,
+        }
+    }
+
+    /// What went wrong, for messages shown to the operator
+    pub fn summary(&self) -> &'static str {
+        match self {
+            Self::AuthenticationFailed => "authentication failed",
+            Self::PermissionDenied => "permission denied",
+            Self::Unreachable(_) => "unreachable",
+            Self::Timeout => "timed out",
+        }
+    }
+
+    /// Classify a failed cluster call
+    ///
+    /// Status errors built with [`status_error`] are found anywhere in the
+    /// chain; otherwise connection failures and timeouts are recognized from
+    /// the underlying transport error. Returns `None` for anything else.
+    pub fn classify(err: &anyhow::Error) -> Option<Self> {
+        if let Some(typed) = err.downcast_ref::<ClusterRequestError>() {
+            return Some(typed.clone());
+        }
+        match classify_anyhow(err) {
+            ProxyRequestError::ProxyTimeout | ProxyRequestError::ResponseReadTimeout => {
+                Some(Self::Timeout)
+            }
+            ProxyRequestError::RequestFailed(_) => {
+                Some(Self::Unreachable(err.root_cause().to_string()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Error for a non-success response from the cluster
+///
+/// 401 and 403 carry a [`ClusterRequestError`] that
+/// [`ClusterRequestError::classify`] finds; `message` is what is displayed.
+pub fn status_error(status: reqwest::StatusCode, message: String) -> anyhow::Error {
+    match ClusterRequestError::from_status(status) {
+        Some(typed) => anyhow::Error::new(typed).context(message),
+        None => anyhow::anyhow!(message),
+    }
+}
+
 /// Classify an anyhow::Error into a ProxyRequestError by attempting to
 /// downcast into more specific error types (reqwest::Error, io::Error)
 /// before falling back to the generic Anyhow->Other conversion.
@@ -120,6 +200,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn status_error_classifies_auth_failures() {
+        let err = status_error(
+            reqwest::StatusCode::UNAUTHORIZED,
+            "Cluster health failed with status: 401".to_string(),
+        )
+        .context("Health request failed");
+        assert_eq!(
+            ClusterRequestError::classify(&err),
+            Some(ClusterRequestError::AuthenticationFailed)
+        );
+        assert_eq!(
+            err.to_string(),
+            "Health request failed",
+            "the typed error must not replace the message"
+        );
+
+        let err = status_error(reqwest::StatusCode::FORBIDDEN, "forbidden".to_string());
+        assert_eq!(
+            ClusterRequestError::classify(&err),
+            Some(ClusterRequestError::PermissionDenied)
+        );
+
+        let err = status_error(reqwest::StatusCode::NOT_FOUND, "missing".to_string());
+        assert_eq!(ClusterRequestError::classify(&err), None);
+    }
+
+    #[tokio::test]
+    async fn classify_connection_refused_as_unreachable() {
+        let client = reqwest::Client::new();
+        let err = anyhow::Error::new(client.get("http://127.0.0.1:9").send().await.unwrap_err())
+            .context("All Elasticsearch nodes failed");
+        assert!(matches!(
+            ClusterRequestError::classify(&err),
+            Some(ClusterRequestError::Unreachable(_))
+        ));
+    }
+
     #[tokio::test]
     async fn from_reqwest_connection_error_maps_to_request_failed() {
         // Attempt to connect to a likely-closed port to produce a connection error
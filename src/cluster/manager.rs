@@ -1,8 +1,13 @@
 use crate::auth::{AuthUser, RbacManager};
 use crate::cache::MetadataCache;
-use crate::cluster::client::{Client, ElasticsearchClient};
+use crate::cluster::circuit_breaker::{is_connection_failure, CircuitBreaker, CircuitState};
+use crate::cluster::client::{major_version, Client, ElasticsearchClient};
+use crate::cluster::error::{status_error, ClusterRequestError};
+use crate::cluster::request_limiter::{RequestLimiter, RequestPermit};
+use crate::cluster::retry::{self, RetryPolicy};
 use crate::config::{
-    ClusterConfig, ClusterWarning, MetricsSource, PrometheusConfig as ClusterPrometheusConfig,
+    ClusterConfig, ClusterWarning, Distribution, MetricsSource,
+    PrometheusConfig as ClusterPrometheusConfig,
 };
 use crate::telemetry::client::InstrumentedElasticsearchClient;
 use anyhow::{Context, Result};
@@ -10,12 +15,12 @@ use indexmap::IndexMap;
 use reqwest::{header::HeaderMap, Method, Response, StatusCode};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 use tokio::sync::RwLock;
-use tracing::instrument;
+use tracing::{instrument, Instrument};
 use utoipa::ToSchema;
 
 /// Cluster connection structure maintaining client and metadata
@@ -32,7 +37,7 @@ pub struct ClusterConnection {
     /// For backwards compatibility we keep `client` pointing to the first
     /// pre-created role-specific client (if any). New code should prefer
     /// `role_clients` and Manager::get_client_for_user for per-request selection.
-    pub client: Option<Arc<Client>>,
+    pub client: Option<Arc<dyn ElasticsearchClient>>,
     /// Per-role pre-created clients for this cluster. Entries are created at
     /// startup in the same order as configured RoleCredential entries.
     pub role_clients: Vec<RoleClient>,
@@ -42,10 +47,24 @@ pub struct ClusterConnection {
     pub metrics_source: MetricsSource,
     /// Prometheus configuration (if metrics_source is Prometheus)
     pub prometheus: Option<ClusterPrometheusConfig>,
+    /// Index pattern the indices list is limited to when none is requested
+    pub default_index_pattern: Option<String>,
     /// Whether this cluster is considered accessible at runtime
     pub accessible: bool,
     /// Optional human-friendly reason why cluster is inaccessible
     pub accessible_reason: Option<String>,
+    /// Circuit breaker guarding requests to this cluster
+    pub circuit_breaker: CircuitBreaker,
+    /// Limit on requests sent to this cluster at the same time
+    pub request_limiter: RequestLimiter,
+    /// Retry policy for transient errors in [`ClusterConnection::request`]
+    pub retry_policy: RetryPolicy,
+    /// Whether the cluster is enabled in configuration
+    pub enabled: bool,
+    /// Major version, detected at startup or taken from `es_version` in config
+    pub es_version: Option<u32>,
+    /// Whether `es_version` was detected from the cluster rather than configured
+    pub es_version_detected: bool,
 }
 
 /// Pre-created client bound to a set of roles
@@ -54,13 +73,13 @@ pub struct RoleClient {
     /// Roles this client applies to (may contain "*" for wildcard)
     pub roles: Vec<String>,
     /// The HTTP client configured with the matching credential
-    pub client: Arc<Client>,
+    pub client: Arc<dyn ElasticsearchClient>,
     /// Human-readable label for the matched role (joined roles)
     pub label: String,
 }
 
 /// Cluster health status
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema, schemars::JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum HealthStatus {
     Green,
@@ -69,7 +88,7 @@ pub enum HealthStatus {
 }
 
 /// Cluster health information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 pub struct ClusterHealth {
     pub status: HealthStatus,
     pub cluster_name: String,
@@ -82,6 +101,135 @@ pub struct ClusterHealth {
     pub unassigned_shards: u32,
 }
 
+/// How long cached health is kept, by the status it reports
+///
+/// Unhealthy clusters can be given a shorter TTL so they are re-polled more
+/// often than stable ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthCacheTtl {
+    pub green: Duration,
+    pub yellow: Duration,
+    pub red: Duration,
+}
+
+impl HealthCacheTtl {
+    /// The same TTL for every status
+    pub fn uniform(ttl: Duration) -> Self {
+        Self {
+            green: ttl,
+            yellow: ttl,
+            red: ttl,
+        }
+    }
+
+    /// TTL for health reporting `status`
+    pub fn for_status(&self, status: &HealthStatus) -> Duration {
+        match status {
+            HealthStatus::Green => self.green,
+            HealthStatus::Yellow => self.yellow,
+            HealthStatus::Red => self.red,
+        }
+    }
+
+    fn build_cache(self) -> MetadataCache<ClusterHealth> {
+        moka::future::Cache::builder().expire_after(self).build()
+    }
+
+    /// Cache of failed health checks, kept as long as red health
+    fn build_failure_cache(self) -> MetadataCache<String> {
+        moka::future::Cache::builder()
+            .time_to_live(self.red)
+            .build()
+    }
+}
+
+impl moka::Expiry<String, ClusterHealth> for HealthCacheTtl {
+    fn expire_after_create(
+        &self,
+        _key: &String,
+        health: &ClusterHealth,
+        _created_at: Instant,
+    ) -> Option<Duration> {
+        Some(self.for_status(&health.status))
+    }
+
+    // A refresh restarts the TTL for the new status
+    fn expire_after_update(
+        &self,
+        _key: &String,
+        health: &ClusterHealth,
+        _updated_at: Instant,
+        _duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        Some(self.for_status(&health.status))
+    }
+}
+
+/// Cluster metadata cached per resource type, each with its own TTL
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CachedResource {
+    /// `_cat/indices` listings
+    Indices,
+    /// Node info (roles, attributes, versions)
+    Nodes,
+    /// Index templates
+    Templates,
+}
+
+impl CachedResource {
+    const ALL: [CachedResource; 3] = [Self::Indices, Self::Nodes, Self::Templates];
+
+    /// Cache name used in spans and cache metrics
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Indices => "indices",
+            Self::Nodes => "nodes",
+            Self::Templates => "templates",
+        }
+    }
+}
+
+/// How long each [`CachedResource`] is kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceCacheTtl {
+    pub indices: Duration,
+    pub nodes: Duration,
+    pub templates: Duration,
+}
+
+impl ResourceCacheTtl {
+    /// The same TTL for every resource
+    pub fn uniform(ttl: Duration) -> Self {
+        Self {
+            indices: ttl,
+            nodes: ttl,
+            templates: ttl,
+        }
+    }
+
+    /// TTL for `resource`
+    pub fn for_resource(&self, resource: CachedResource) -> Duration {
+        match resource {
+            CachedResource::Indices => self.indices,
+            CachedResource::Nodes => self.nodes,
+            CachedResource::Templates => self.templates,
+        }
+    }
+
+    fn build_caches(self) -> HashMap<CachedResource, MetadataCache<Value>> {
+        CachedResource::ALL
+            .into_iter()
+            .map(|resource| {
+                let cache = moka::future::Cache::builder()
+                    .time_to_live(self.for_resource(resource))
+                    .support_invalidation_closures()
+                    .build();
+                (resource, cache)
+            })
+            .collect()
+    }
+}
+
 /// Cluster information for API responses
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ClusterInfo {
@@ -93,14 +241,187 @@ pub struct ClusterInfo {
     pub accessible_reason: Option<String>,
     /// Metrics source (internal or prometheus)
     pub metrics_source: MetricsSource,
+    /// Whether the cluster is enabled in configuration
+    pub enabled: bool,
+    /// Major version of the cluster, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub es_version: Option<u32>,
+    /// Whether `es_version` was detected from the cluster at startup
+    pub es_version_detected: bool,
+}
+
+/// Maximum number of clusters initialised at the same time
+const CLUSTER_INIT_CONCURRENCY: usize = 8;
+
+/// How long startup waits for a cluster to report its version
+const VERSION_DETECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often a request may retry connecting to a cluster that was unreachable
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Maximum number of health stream subscribers across all clusters
+pub const HEALTH_STREAM_MAX_SUBSCRIBERS: usize = 256;
+
+/// Refreshed health events buffered per subscriber before older ones are dropped
+const HEALTH_EVENT_CAPACITY: usize = 64;
+
+/// Health fetched from a cluster, published to health stream subscribers
+#[derive(Debug, Clone)]
+pub struct HealthEvent {
+    pub cluster_id: String,
+    pub health: ClusterHealth,
+}
+
+/// A health stream subscriber holding one of the
+/// [`HEALTH_STREAM_MAX_SUBSCRIBERS`] slots until dropped
+#[derive(Debug)]
+pub struct HealthSubscription {
+    pub events: tokio::sync::broadcast::Receiver<HealthEvent>,
+    _slot: tokio::sync::OwnedSemaphorePermit,
+}
+
+/// What to do with clusters that do not answer at startup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnreachableClusterPolicy {
+    /// Don't check reachability; failures surface on the first request
+    #[default]
+    Ignore,
+    /// Fail initialisation, naming the unreachable clusters
+    Fail,
+    /// Mark them inaccessible and reconnect when they are next requested
+    Defer,
+}
+
+/// An unreachable cluster waiting to be reconnected
+#[derive(Debug)]
+struct PendingReconnect {
+    config: ClusterConfig,
+    last_attempt: Instant,
+}
+
+/// What startup learned about a cluster from `GET /`
+struct StartupProbe {
+    es_version: Option<u32>,
+    es_version_detected: bool,
+    /// Major version the cluster reported, even with detection disabled
+    reported_version: Option<u32>,
+    /// Why the cluster did not answer, if it was asked
+    unreachable: Option<String>,
+}
+
+/// Why the startup probe failed
+///
+/// Rejected credentials are called out separately so they are not mistaken
+/// for a network problem.
+fn startup_failure_reason(error: &anyhow::Error) -> String {
+    match ClusterRequestError::classify(error) {
+        Some(
+            kind @ (ClusterRequestError::AuthenticationFailed
+            | ClusterRequestError::PermissionDenied),
+        ) => {
+            format!("Cluster {}: {}", kind.summary(), error)
+        }
+        _ => format!("Cluster unreachable: {}", error),
+    }
+}
+
+/// Ask a cluster for its major version at startup
+///
+/// The cluster is asked via `GET /` when `detect_version` is on or
+/// `check_reachable` is set. The configured `es_version` is used when
+/// detection is disabled or the cluster cannot be reached.
+async fn probe_cluster(
+    client: &dyn ElasticsearchClient,
+    config: &ClusterConfig,
+    check_reachable: bool,
+) -> StartupProbe {
+    if !config.detect_version && !check_reachable {
+        return StartupProbe {
+            es_version: config.es_version,
+            es_version_detected: false,
+            reported_version: None,
+            unreachable: None,
+        };
+    }
+
+    let (info, unreachable) =
+        match tokio::time::timeout(VERSION_DETECTION_TIMEOUT, client.info()).await {
+            Ok(Ok(info)) => (Some(info), None),
+            Ok(Err(e)) => (None, Some(startup_failure_reason(&e))),
+            Err(_) => (
+                None,
+                Some(format!(
+                    "Cluster unreachable: no response within {}s",
+                    VERSION_DETECTION_TIMEOUT.as_secs()
+                )),
+            ),
+        };
+    let reported_version = info.as_ref().and_then(major_version);
+
+    if !config.detect_version {
+        return StartupProbe {
+            es_version: config.es_version,
+            es_version_detected: false,
+            reported_version,
+            unreachable,
+        };
+    }
+
+    if let Some(reason) = &unreachable {
+        tracing::warn!(cluster_id = %config.id, error = %reason, configured = ?config.es_version, "Version detection failed, using configured es_version");
+    }
+
+    match reported_version {
+        Some(version) => {
+            tracing::info!(cluster_id = %config.id, es_version = version, "Detected cluster version");
+            StartupProbe {
+                es_version: Some(version),
+                es_version_detected: true,
+                reported_version,
+                unreachable,
+            }
+        }
+        None => StartupProbe {
+            es_version: config.es_version,
+            es_version_detected: false,
+            reported_version,
+            unreachable,
+        },
+    }
 }
 
 impl ClusterConnection {
-    fn client_ref(&self) -> Result<&Client> {
-        self.client
-            .as_ref()
-            .map(|c| c.as_ref())
-            .ok_or_else(|| anyhow::anyhow!("Cluster '{}' is inaccessible", self.id))
+    fn client_ref(&self) -> Result<&dyn ElasticsearchClient> {
+        self.client.as_ref().map(|c| c.as_ref()).ok_or_else(|| {
+            if self.enabled {
+                anyhow::anyhow!("Cluster '{}' is inaccessible", self.id)
+            } else {
+                anyhow::anyhow!("Cluster '{}' is disabled", self.id)
+            }
+        })
+    }
+
+    /// Run a client call through the circuit breaker and request limiter
+    ///
+    /// Fails fast with [`CircuitOpenError`] while the breaker is open, waits
+    /// for a request slot (failing with
+    /// [`ConcurrencyLimitError`](crate::cluster::ConcurrencyLimitError) after
+    /// the queue timeout) and records connection failures and successes.
+    async fn guarded<'a, T, F, Fut>(&'a self, call: F) -> Result<T>
+    where
+        F: FnOnce(&'a dyn ElasticsearchClient) -> Fut,
+        Fut: std::future::Future<Output = Result<T>> + 'a,
+    {
+        let client = self.client_ref()?;
+        self.circuit_breaker.try_acquire()?;
+        let _permit = self.request_limiter.acquire().await?;
+
+        let result = call(client).await;
+        match &result {
+            Err(e) if is_connection_failure(e) => self.circuit_breaker.record_failure(),
+            _ => self.circuit_breaker.record_success(),
+        }
+        result
     }
 
     /// Create a new cluster connection from configuration
@@ -120,6 +441,7 @@ impl ClusterConnection {
         let c = Client::new(config)
             .await
             .with_context(|| format!("Failed to create client for cluster '{}'", config.id))?;
+        let probe = probe_cluster(&c, config, false).await;
 
         Ok(Self {
             id: config.id.clone(),
@@ -132,11 +454,45 @@ impl ClusterConnection {
             tls_config: config.tls.clone(),
             metrics_source: config.metrics_source.clone(),
             prometheus: config.prometheus.clone(),
+            default_index_pattern: config.default_index_pattern.clone(),
+            es_version: probe.es_version,
+            es_version_detected: probe.es_version_detected,
             accessible: true,
             accessible_reason: None,
+            circuit_breaker: CircuitBreaker::new(config.id.clone(), &config.circuit_breaker),
+            request_limiter: RequestLimiter::new(config),
+            retry_policy: RetryPolicy::new(&config.retry),
+            enabled: true,
         })
     }
 
+    /// Create a connection using an existing client
+    ///
+    /// The cluster is not contacted; `es_version` is taken from the config.
+    /// Used to run clusters against a `MockClient`
+    /// in tests.
+    pub fn with_client(config: &ClusterConfig, client: Arc<dyn ElasticsearchClient>) -> Self {
+        Self {
+            id: config.id.clone(),
+            name: config.name.clone(),
+            nodes: config.nodes.clone(),
+            client: Some(client),
+            role_clients: Vec::new(),
+            tls_config: config.tls.clone(),
+            metrics_source: config.metrics_source.clone(),
+            prometheus: config.prometheus.clone(),
+            default_index_pattern: config.default_index_pattern.clone(),
+            es_version: config.es_version,
+            es_version_detected: false,
+            accessible: true,
+            accessible_reason: None,
+            circuit_breaker: CircuitBreaker::new(config.id.clone(), &config.circuit_breaker),
+            request_limiter: RequestLimiter::new(config),
+            retry_policy: RetryPolicy::new(&config.retry),
+            enabled: config.enabled,
+        }
+    }
+
     /// Check cluster health
     ///
     /// # Returns
@@ -148,9 +504,8 @@ impl ClusterConnection {
     /// Validates: Requirements 2.10, 2.14
     #[instrument(skip(self), fields(cluster_id = %self.id))]
     pub async fn check_health(&self) -> Result<ClusterHealth> {
-        let client = self.client_ref()?;
-        let health_json = client
-            .health()
+        let health_json = self
+            .guarded(|client| client.health())
             .await
             .context("Failed to fetch cluster health")?;
 
@@ -206,6 +561,11 @@ impl ClusterConnection {
 
     /// Execute a request against this cluster
     ///
+    /// GET and HEAD requests answered with 429 or 503, or failing with a
+    /// connection error, are retried according to the cluster's
+    /// [`RetryPolicy`]. When every attempt fails the last response or error
+    /// is returned.
+    ///
     /// # Arguments
     ///
     /// * `method` - HTTP method
@@ -222,136 +582,292 @@ impl ClusterConnection {
         path: &str,
         body: Option<Value>,
     ) -> Result<Response> {
-        // Use instrumented request for tracing
-        let client = self.client_ref()?;
-        client
-            .instrumented_request(method, path, body, &self.id)
-            .await
+        if !self.retry_policy.retries_method(&method) {
+            // Use instrumented request for tracing
+            return self
+                .guarded(|client| client.instrumented_request(method, path, body, &self.id, None))
+                .await;
+        }
+
+        let start = Instant::now();
+        let mut retries = 0;
+        loop {
+            let result = self
+                .guarded(|client| {
+                    client.instrumented_request(method.clone(), path, body.clone(), &self.id, None)
+                })
+                .await;
+            let (reason, retry_after) = match &result {
+                Ok(response) if retry::is_retryable_status(response.status()) => (
+                    format!("status {}", response.status()),
+                    retry::retry_after(response),
+                ),
+                Err(e) if retry::is_retryable_error(e) => (e.to_string(), None),
+                _ => return result,
+            };
+            let Some(delay) = self
+                .retry_policy
+                .delay(retries, start.elapsed(), retry_after)
+            else {
+                if retries > 0 {
+                    tracing::warn!(retries, reason = %reason, "Giving up on request after retries");
+                }
+                return result;
+            };
+            drop(result);
+
+            retries += 1;
+            tracing::debug!(
+                retry = retries,
+                delay_ms = delay.as_millis() as u64,
+                reason = %reason,
+                "Retrying request after transient error"
+            );
+            tokio::time::sleep(delay).await;
+        }
     }
 
     /// Get cluster health using SDK typed method
     pub async fn health(&self) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.health().await
+        self.guarded(|client| client.health()).await
     }
 
     /// Get cluster info (root endpoint) using SDK typed method
     pub async fn info(&self) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.info().await
+        self.guarded(|client| client.info()).await
     }
 
     /// Get cluster settings using SDK typed method
     pub async fn cluster_settings(&self, include_defaults: bool) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.cluster_settings(include_defaults).await
+        self.guarded(|client| client.cluster_settings(include_defaults))
+            .await
     }
 
     /// Get cluster stats using SDK typed method
     #[instrument(skip(self), fields(cluster_id = %self.id))]
     pub async fn cluster_stats(&self) -> Result<Value> {
         // Use instrumented request for tracing
-        let client = self.client_ref()?;
-        let response = client
-            .instrumented_request(Method::GET, "_cluster/stats", None::<Value>, &self.id)
+        let response = self
+            .guarded(|client| {
+                client.instrumented_request(
+                    Method::GET,
+                    "_cluster/stats",
+                    None::<Value>,
+                    &self.id,
+                    None,
+                )
+            })
             .await?;
+        if !response.status().is_success() {
+            return Err(status_error(
+                response.status(),
+                format!("Cluster stats failed with status: {}", response.status()),
+            ));
+        }
         Ok(response.json().await?)
     }
 
     /// Get nodes info using SDK typed method
     pub async fn nodes_info(&self) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.nodes_info().await
+        self.guarded(|client| client.nodes_info()).await
     }
 
     /// Get nodes stats using SDK typed method
     pub async fn nodes_stats(&self) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.nodes_stats().await
+        self.guarded(|client| client.nodes_stats()).await
     }
 
     /// Get stats for a specific node using SDK typed method
     pub async fn node_stats(&self, node_id: &str) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.node_stats(node_id).await
+        self.guarded(|client| client.node_stats(node_id)).await
     }
 
     /// Get indices using SDK typed method
     pub async fn indices_get(&self, index: &str) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.indices_get(index).await
+        self.guarded(|client| client.indices_get(index)).await
     }
 
     /// Get indices stats using SDK typed method
     pub async fn indices_stats(&self) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.indices_stats().await
+        self.guarded(|client| client.indices_stats()).await
     }
 
     /// Merge cluster health status into indices stats (non-critical operation)
     pub async fn merge_indices_health(&self, stats: &mut Value) -> Result<()> {
-        let client = self.client_ref()?;
-        client.merge_indices_health(stats).await
+        self.guarded(|client| client.merge_indices_health(stats))
+            .await
     }
 
     /// Get cluster state using SDK typed method
     pub async fn cluster_state(&self) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.cluster_state().await
+        self.guarded(|client| client.cluster_state()).await
     }
 
     /// Get indices stats with shard-level details using SDK typed method
     pub async fn indices_stats_with_shards(&self, index: &str) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.indices_stats_with_shards(index).await
+        self.guarded(|client| client.indices_stats_with_shards(index))
+            .await
     }
 
     /// Get shard information using _cat/shards API (memory-efficient)
     pub async fn cat_shards(&self) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.cat_shards().await
+        self.guarded(|client| client.cat_shards()).await
     }
 
     /// Get indices information using _cat/indices API (lightweight)
-    pub async fn cat_indices(&self) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.cat_indices().await
+    pub async fn cat_indices(&self, pattern: Option<&str>) -> Result<Value> {
+        self.guarded(|client| client.cat_indices(pattern)).await
     }
 
     /// Get shard information for a specific node (memory-efficient)
     pub async fn cat_shards_for_node(&self, node_id: &str) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.cat_shards_for_node(node_id).await
+        self.guarded(|client| client.cat_shards_for_node(node_id))
+            .await
     }
 
     /// Get shard information for a specific index
     /// Returns full shard details including docs and store
     pub async fn cat_shards_for_index(&self, index: &str) -> Result<Value> {
-        let client = self.client_ref()?;
-        client.cat_shards_for_index(index).await
+        self.guarded(|client| client.cat_shards_for_index(index))
+            .await
     }
 
     /// Get cluster state with routing_nodes metric for paginated shard listing
     #[instrument(skip(self), fields(cluster_id = %self.id))]
     pub async fn cluster_state_routing_nodes(&self, indices: Option<&[String]>) -> Result<Value> {
-        let client = self.client_ref()?;
-        client
-            .cluster_state_routing_nodes(indices)
+        self.guarded(|client| client.cluster_state_routing_nodes(indices))
             .await
             .context("Failed to get cluster state with routing nodes")
     }
 
+    /// Get cluster-level changes queued on the master
+    pub async fn pending_tasks(&self) -> Result<Value> {
+        self.guarded(|client| client.pending_tasks()).await
+    }
+
+    /// Get shard recoveries still in progress, optionally for an index expression
+    pub async fn active_recoveries(&self, index: Option<&str>) -> Result<Value> {
+        self.guarded(|client| client.active_recoveries(index)).await
+    }
+
+    /// Get deprecation warnings for the next major upgrade
+    pub async fn migration_deprecations(&self) -> Result<Value> {
+        self.guarded(|client| client.migration_deprecations()).await
+    }
+
     /// Get master node ID using _cat/master API (memory-efficient)
     #[instrument(skip(self), fields(cluster_id = %self.id))]
     pub async fn cat_master(&self) -> Result<String> {
-        let client = self.client_ref()?;
-        let response = client
-            .cat_master()
+        let response = self
+            .guarded(|client| client.cat_master())
             .await
             .context("Failed to get master node info")?;
 
         Ok(response)
     }
+
+    /// Get all registered snapshot repositories
+    pub async fn snapshot_repositories(&self) -> Result<Value> {
+        self.guarded(|client| client.snapshot_repositories()).await
+    }
+
+    /// Get all snapshots in a repository
+    pub async fn snapshots(&self, repository: &str) -> Result<Value> {
+        self.guarded(|client| client.snapshots(repository)).await
+    }
+
+    /// Get an ILM policy by name
+    pub async fn ilm_policy(&self, policy: &str) -> Result<Value> {
+        self.guarded(|client| client.ilm_policy(policy)).await
+    }
+
+    /// Explain the ILM state of an index
+    pub async fn ilm_explain(&self, index: &str) -> Result<Value> {
+        self.guarded(|client| client.ilm_explain(index)).await
+    }
+
+    /// Get all composable index templates
+    pub async fn index_templates(&self) -> Result<Value> {
+        self.guarded(|client| client.get_index_templates()).await
+    }
+
+    /// Get all legacy (`/_template`) index templates
+    pub async fn legacy_index_templates(&self) -> Result<Value> {
+        self.guarded(|client| client.get_legacy_index_templates())
+            .await
+    }
+
+    /// Get all component templates
+    pub async fn component_templates(&self) -> Result<Value> {
+        self.guarded(|client| client.get_component_templates())
+            .await
+    }
+
+    /// Why the deprecation info API can't be used on this cluster, if it can't
+    ///
+    /// `_migration/deprecations` is Elasticsearch only, and its response is
+    /// only understood for the supported major versions (per the detected or
+    /// configured `es_version`).
+    pub fn deprecation_info_unavailable(&self) -> Option<String> {
+        let distribution = self.client.as_ref()?.distribution();
+        if distribution != Distribution::Elasticsearch {
+            return Some(format!(
+                "The deprecation info API is not available on {}",
+                distribution.product_name()
+            ));
+        }
+        match self.es_version {
+            None => Some(
+                "The cluster version is unknown; set es_version to enable deprecation checks"
+                    .to_string(),
+            ),
+            Some(version) if !distribution.supported_major_versions().contains(&version) => {
+                Some(format!(
+                    "The deprecation info API is not supported on Elasticsearch {}",
+                    version
+                ))
+            }
+            Some(_) => None,
+        }
+    }
+
+    /// Why synced flush can't be used on this cluster, if it can't
+    ///
+    /// `_flush/synced` exists up to Elasticsearch 7 and OpenSearch 1; it was
+    /// removed in Elasticsearch 8 and OpenSearch 2, where a regular flush
+    /// gives the same recovery benefit. Clusters of unknown version get the
+    /// regular flush too, since it works everywhere.
+    pub fn synced_flush_unavailable(&self) -> Option<String> {
+        let distribution = self.client.as_ref()?.distribution();
+        let last_version = match distribution {
+            Distribution::Elasticsearch => 7,
+            Distribution::Opensearch => 1,
+        };
+        match self.es_version {
+            None => Some(
+                "The cluster version is unknown, so a regular flush was run; set es_version to use synced flush"
+                    .to_string(),
+            ),
+            Some(version) if version > last_version => Some(format!(
+                "Synced flush was removed in {} {}; a regular flush was run instead",
+                distribution.product_name(),
+                last_version + 1
+            )),
+            Some(_) => None,
+        }
+    }
+
+    /// Whether legacy index templates should be listed for this cluster
+    ///
+    /// Only Elasticsearch 7 clusters (per the detected or configured
+    /// `es_version`) are expected to still carry legacy templates.
+    pub fn has_legacy_templates(&self) -> bool {
+        self.es_version == Some(7)
+            && self
+                .client
+                .as_ref()
+                .is_some_and(|client| client.distribution() == Distribution::Elasticsearch)
+    }
 }
 
 #[cfg(test)]
@@ -446,6 +962,9 @@ mod tests {
             accessible_reason: None,
 
             metrics_source: MetricsSource::Internal,
+            enabled: true,
+            es_version: Some(8),
+            es_version_detected: true,
         };
 
         let json = serde_json::to_string(&info).expect("serialize cluster info to JSON");
@@ -464,6 +983,9 @@ pub struct ProxyAuditRequest {
     pub user_roles: Vec<String>,
     pub request_id: String,
     pub audit_enabled: bool,
+    /// Fail with [`ProxyRequestError::ResponseTooLarge`] when the response
+    /// body is larger than this
+    pub max_response_bytes: Option<usize>,
 }
 
 /// Cluster manager for managing multiple Elasticsearch/OpenSearch clusters
@@ -476,11 +998,28 @@ pub struct Manager {
     clusters: Arc<RwLock<IndexMap<String, Arc<ClusterConnection>>>>,
     /// RBAC manager for access control
     rbac: Option<Arc<RbacManager>>,
-    /// Cache for cluster health metadata
+    /// Cache for cluster health metadata, expiring per [`HealthCacheTtl`]
     health_cache: MetadataCache<ClusterHealth>,
+    /// Errors of failed health checks, for [`Manager::check_health_for_probe`]
+    health_failures: MetadataCache<String>,
+    /// Caches for other metadata, one per resource, expiring per [`ResourceCacheTtl`]
+    resource_caches: HashMap<CachedResource, MetadataCache<Value>>,
+    /// How clusters that do not answer at startup or reload are handled
+    unreachable_policy: UnreachableClusterPolicy,
+    /// Unreachable clusters to reconnect on their next request
+    pending_reconnects: std::sync::Mutex<HashMap<String, PendingReconnect>>,
+    /// Webhook notifier told about every refreshed health status
+    notifier: Option<Arc<HealthNotifier>>,
+    /// Every refreshed health, for health stream subscribers
+    health_events: tokio::sync::broadcast::Sender<HealthEvent>,
+    /// Free health stream subscriber slots
+    health_stream_slots: Arc<tokio::sync::Semaphore>,
+    /// Clusters whose last health refresh succeeded
+    reachable_clusters: std::sync::Mutex<HashSet<String>>,
 }
 
 use crate::cluster::ProxyRequestError;
+use crate::notifications::HealthNotifier;
 
 impl Manager {
     /// Create a new cluster manager from configuration
@@ -503,22 +1042,194 @@ impl Manager {
         cache_duration: Duration,
         cluster_warnings: Option<Vec<ClusterWarning>>,
     ) -> Result<Self> {
-        let mut clusters = IndexMap::new();
-        let total = cluster_configs.len();
+        Self::new_with_policy(
+            cluster_configs,
+            cache_duration,
+            cluster_warnings,
+            UnreachableClusterPolicy::default(),
+        )
+        .await
+    }
+
+    /// Create a new cluster manager that checks clusters are reachable
+    ///
+    /// With [`UnreachableClusterPolicy::Fail`] an unreachable cluster is an
+    /// error; with [`UnreachableClusterPolicy::Defer`] it is marked
+    /// inaccessible and reconnected lazily by [`Manager::get_cluster`]. The
+    /// policy also applies to [`Manager::reload_clusters`].
+    pub async fn new_with_policy(
+        cluster_configs: Vec<ClusterConfig>,
+        cache_duration: Duration,
+        cluster_warnings: Option<Vec<ClusterWarning>>,
+        unreachable_policy: UnreachableClusterPolicy,
+    ) -> Result<Self> {
+        let (clusters, pending) =
+            Self::build_connections(cluster_configs, cluster_warnings, unreachable_policy).await?;
+
+        tracing::debug!(cluster_count = clusters.len(), "Clusters initialized");
+        crate::app_metrics::cluster::set_total_clusters(clusters.len() as i64);
+        tracing::debug!(
+            cache_duration_secs = cache_duration.as_secs(),
+            "Cache configured"
+        );
+
+        Ok(Self {
+            clusters: Arc::new(RwLock::new(clusters)),
+            rbac: None,
+            health_cache: HealthCacheTtl::uniform(cache_duration).build_cache(),
+            health_failures: HealthCacheTtl::uniform(cache_duration).build_failure_cache(),
+            resource_caches: ResourceCacheTtl::uniform(cache_duration).build_caches(),
+            unreachable_policy,
+            pending_reconnects: std::sync::Mutex::new(pending),
+            notifier: None,
+            health_events: tokio::sync::broadcast::channel(HEALTH_EVENT_CAPACITY).0,
+            health_stream_slots: Arc::new(tokio::sync::Semaphore::new(
+                HEALTH_STREAM_MAX_SUBSCRIBERS,
+            )),
+            reachable_clusters: std::sync::Mutex::new(HashSet::new()),
+        })
+    }
+
+    /// Cache health for a TTL depending on its status instead of `cache_duration`
+    pub fn with_health_cache_ttl(mut self, ttl: HealthCacheTtl) -> Self {
+        self.health_cache = ttl.build_cache();
+        self.health_failures = ttl.build_failure_cache();
+        self
+    }
+
+    /// Cache indices, nodes and templates for their own TTLs instead of `cache_duration`
+    pub fn with_resource_cache_ttl(mut self, ttl: ResourceCacheTtl) -> Self {
+        self.resource_caches = ttl.build_caches();
+        self
+    }
+
+    /// Create a manager over already built connections
+    ///
+    /// Connections keep the given order. Nothing is contacted at creation,
+    /// which makes this the entry point for tests using
+    /// [`ClusterConnection::with_client`].
+    pub fn with_connections(connections: Vec<ClusterConnection>, cache_duration: Duration) -> Self {
+        let clusters = connections
+            .into_iter()
+            .map(|connection| (connection.id.clone(), Arc::new(connection)))
+            .collect::<IndexMap<_, _>>();
+        crate::app_metrics::cluster::set_total_clusters(clusters.len() as i64);
+
+        Self {
+            clusters: Arc::new(RwLock::new(clusters)),
+            rbac: None,
+            health_cache: HealthCacheTtl::uniform(cache_duration).build_cache(),
+            health_failures: HealthCacheTtl::uniform(cache_duration).build_failure_cache(),
+            resource_caches: ResourceCacheTtl::uniform(cache_duration).build_caches(),
+            unreachable_policy: UnreachableClusterPolicy::default(),
+            pending_reconnects: std::sync::Mutex::new(HashMap::new()),
+            notifier: None,
+            health_events: tokio::sync::broadcast::channel(HEALTH_EVENT_CAPACITY).0,
+            health_stream_slots: Arc::new(tokio::sync::Semaphore::new(
+                HEALTH_STREAM_MAX_SUBSCRIBERS,
+            )),
+            reachable_clusters: std::sync::Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Notify webhooks when a refreshed health status differs from the last one
+    pub fn with_notifier(mut self, notifier: HealthNotifier) -> Self {
+        self.notifier = Some(Arc::new(notifier));
+        self
+    }
+
+    /// Build cluster connections from configuration
+    ///
+    /// Connections are built concurrently, at most
+    /// [`CLUSTER_INIT_CONCURRENCY`] at a time, so one slow cluster does not
+    /// hold up the others. Clusters keep their configuration order.
+    /// Clusters that fail to initialise (or carry a load-time warning) are kept
+    /// as inaccessible placeholders so they still show up in listings.
+    async fn build_connections(
+        mut cluster_configs: Vec<ClusterConfig>,
+        cluster_warnings: Option<Vec<ClusterWarning>>,
+        unreachable_policy: UnreachableClusterPolicy,
+    ) -> Result<(
+        IndexMap<String, Arc<ClusterConnection>>,
+        HashMap<String, PendingReconnect>,
+    )> {
+        if cluster_configs.is_empty() {
+            anyhow::bail!("No clusters configured");
+        }
 
-        let warnings_map: std::collections::HashMap<String, String> = cluster_warnings
+        let mut warnings_map: std::collections::HashMap<String, String> = cluster_warnings
             .unwrap_or_default()
             .into_iter()
             .map(|w| (w.id, w.reason))
             .collect();
 
-        for config in cluster_configs {
-            let display_name = config.name.as_deref().unwrap_or(&config.id);
-            tracing::debug!(cluster_id = %config.id, cluster_name = %display_name, "Initializing cluster");
+        // Clusters are listed in insertion order: by `order`, then config order
+        cluster_configs.sort_by_key(|config| config.order.map_or((1, 0), |order| (0, order)));
+
+        let check_reachable = unreachable_policy != UnreachableClusterPolicy::Ignore;
+        let permits = tokio::sync::Semaphore::new(CLUSTER_INIT_CONCURRENCY);
+        let builds = cluster_configs.into_iter().map(|config| {
+            let warning = warnings_map.remove(&config.id);
+            let retry_config =
+                (unreachable_policy == UnreachableClusterPolicy::Defer).then(|| config.clone());
+            let permits = &permits;
+            async move {
+                let _permit = permits
+                    .acquire()
+                    .await
+                    .expect("cluster init semaphore is never closed");
+                let (connection, unreachable) =
+                    Self::build_connection(config, warning, check_reachable).await;
+                (connection, unreachable.map(|reason| (reason, retry_config)))
+            }
+        });
+
+        let mut clusters = IndexMap::new();
+        let mut pending = HashMap::new();
+        let mut unreachable_ids = Vec::new();
+        for (connection, unreachable) in futures::future::join_all(builds).await {
+            if let Some((reason, retry_config)) = unreachable {
+                tracing::warn!(cluster_id = %connection.id, reason = %reason, "Cluster is unreachable");
+                unreachable_ids.push(connection.id.clone());
+                if let Some(config) = retry_config {
+                    pending.insert(
+                        connection.id.clone(),
+                        PendingReconnect {
+                            config,
+                            last_attempt: Instant::now(),
+                        },
+                    );
+                }
+            }
+            clusters.insert(connection.id.clone(), Arc::new(connection));
+        }
+
+        if unreachable_policy == UnreachableClusterPolicy::Fail && !unreachable_ids.is_empty() {
+            anyhow::bail!(
+                "Cannot reach cluster(s): {}. Set server.fail_on_unreachable_cluster: false to start without them",
+                unreachable_ids.join(", ")
+            );
+        }
+
+        Ok((clusters, pending))
+    }
 
-            if let Some(reason) = warnings_map.get(&config.id) {
-                tracing::warn!(cluster_id = %config.id, reason = %reason, "Cluster has config warning and will be marked inaccessible");
-                let placeholder = ClusterConnection {
+    /// Build the connection for a single cluster
+    ///
+    /// With `check_reachable` set the cluster is asked for its version, and a
+    /// cluster that does not answer is marked inaccessible. The second value
+    /// is then the reason it could not be reached.
+    async fn build_connection(
+        config: ClusterConfig,
+        warning: Option<String>,
+        check_reachable: bool,
+    ) -> (ClusterConnection, Option<String>) {
+        let display_name = config.name.as_deref().unwrap_or(&config.id);
+
+        if !config.enabled {
+            tracing::info!(cluster_id = %config.id, cluster_name = %display_name, "Cluster is disabled, skipping connection");
+            return (
+                ClusterConnection {
                     id: config.id.clone(),
                     name: config.name.clone(),
                     nodes: config.nodes.clone(),
@@ -527,141 +1238,265 @@ impl Manager {
                     tls_config: config.tls.clone(),
                     metrics_source: config.metrics_source.clone(),
                     prometheus: config.prometheus.clone(),
+                    default_index_pattern: config.default_index_pattern.clone(),
+                    es_version: config.es_version,
+                    es_version_detected: false,
                     accessible: false,
-                    accessible_reason: Some(reason.clone()),
-                };
-                clusters.insert(config.id.clone(), Arc::new(placeholder));
-                continue;
-            }
+                    accessible_reason: Some("Cluster is disabled in configuration".to_string()),
+                    circuit_breaker: CircuitBreaker::new(
+                        config.id.clone(),
+                        &config.circuit_breaker,
+                    ),
+                    request_limiter: RequestLimiter::new(&config),
+                    retry_policy: RetryPolicy::new(&config.retry),
+                    enabled: false,
+                },
+                None,
+            );
+        }
 
-            // Initialize cluster connections. Pre-create one HTTP client per
-            // configured RoleCredential to allow per-role credential selection
-            // at runtime. If no RoleCredential entries are configured we fall
-            // back to legacy behaviour and create a single client.
-            if config.auth.is_empty() {
-                match Client::new(&config).await {
-                    Ok(c) => {
-                        let connection = ClusterConnection {
-                            id: config.id.clone(),
-                            name: config.name.clone(),
-                            nodes: config.nodes.clone(),
-                            client: Some(Arc::new(c)),
-                            role_clients: Vec::new(),
-                            tls_config: config.tls.clone(),
-                            metrics_source: config.metrics_source.clone(),
-                            prometheus: config.prometheus.clone(),
-                            accessible: true,
-                            accessible_reason: None,
-                        };
-                        clusters.insert(config.id.clone(), Arc::new(connection));
-                    }
-                    Err(e) => {
-                        tracing::warn!(
-                            cluster_id = %config.id,
-                            error = %e,
-                            "Failed to initialise cluster — it will be marked inaccessible"
-                        );
-                        let placeholder = ClusterConnection {
-                            id: config.id.clone(),
-                            name: config.name.clone(),
-                            nodes: config.nodes.clone(),
-                            client: None,
-                            role_clients: Vec::new(),
-                            tls_config: config.tls.clone(),
-                            metrics_source: config.metrics_source.clone(),
-                            prometheus: config.prometheus.clone(),
-                            accessible: false,
-                            accessible_reason: Some(e.to_string()),
-                        };
-                        clusters.insert(config.id.clone(), Arc::new(placeholder));
-                    }
-                }
-            } else {
-                // Create one client per RoleCredential in configuration order
-                let mut role_clients: Vec<RoleClient> = Vec::new();
-                let mut init_error: Option<anyhow::Error> = None;
-
-                for rc in &config.auth {
-                    match Client::new_with_auth(&config, Some(&rc.auth)).await {
-                        Ok(c) => {
-                            let arc = Arc::new(c);
-                            let label = rc.roles.join(",");
-                            role_clients.push(RoleClient {
-                                roles: rc.roles.clone(),
-                                client: arc,
-                                label,
-                            });
-                        }
-                        Err(e) => {
-                            init_error = Some(e);
-                            break;
-                        }
-                    }
-                }
+        tracing::debug!(cluster_id = %config.id, cluster_name = %display_name, "Initializing cluster");
 
-                if init_error.is_some() {
-                    tracing::warn!(
-                        cluster_id = %config.id,
-                        error = %init_error.as_ref().map(|e| e.to_string()).unwrap_or_default(),
-                        "Failed to initialise role-specific clients — cluster will be marked inaccessible"
-                    );
-                    let placeholder = ClusterConnection {
-                        id: config.id.clone(),
-                        name: config.name.clone(),
-                        nodes: config.nodes.clone(),
-                        client: None,
-                        role_clients: Vec::new(),
-                        tls_config: config.tls.clone(),
-                        metrics_source: config.metrics_source.clone(),
-                        prometheus: config.prometheus.clone(),
-                        accessible: false,
-                        accessible_reason: init_error.as_ref().map(|e| e.to_string()),
-                    };
-                    clusters.insert(config.id.clone(), Arc::new(placeholder));
-                    continue;
-                }
-
-                // For backwards compatibility keep `client` pointing at the first role client
-                // Use `first()` to satisfy clippy::get_first
-                let primary = role_clients.first().map(|rc| rc.client.clone());
-
-                let connection = ClusterConnection {
+        if let Some(reason) = warning {
+            tracing::warn!(cluster_id = %config.id, reason = %reason, "Cluster has config warning and will be marked inaccessible");
+            return (
+                ClusterConnection {
                     id: config.id.clone(),
                     name: config.name.clone(),
                     nodes: config.nodes.clone(),
-                    client: primary,
-                    role_clients,
+                    client: None,
+                    role_clients: Vec::new(),
                     tls_config: config.tls.clone(),
                     metrics_source: config.metrics_source.clone(),
                     prometheus: config.prometheus.clone(),
-                    accessible: true,
-                    accessible_reason: None,
-                };
+                    default_index_pattern: config.default_index_pattern.clone(),
+                    es_version: config.es_version,
+                    es_version_detected: false,
+                    accessible: false,
+                    accessible_reason: Some(reason),
+                    circuit_breaker: CircuitBreaker::new(
+                        config.id.clone(),
+                        &config.circuit_breaker,
+                    ),
+                    request_limiter: RequestLimiter::new(&config),
+                    retry_policy: RetryPolicy::new(&config.retry),
+                    enabled: true,
+                },
+                None,
+            );
+        }
+
+        // Initialize cluster connections. Pre-create one HTTP client per
+        // configured RoleCredential to allow per-role credential selection
+        // at runtime. If no RoleCredential entries are configured we fall
+        // back to legacy behaviour and create a single client.
+        if config.auth.is_empty() {
+            match Client::new(&config).await {
+                Ok(c) => {
+                    let probe = probe_cluster(&c, &config, check_reachable).await;
+                    let unreachable = probe.unreachable.filter(|_| check_reachable);
+                    let connection = ClusterConnection {
+                        id: config.id.clone(),
+                        name: config.name.clone(),
+                        nodes: config.nodes.clone(),
+                        client: Some(Arc::new(c)),
+                        role_clients: Vec::new(),
+                        tls_config: config.tls.clone(),
+                        metrics_source: config.metrics_source.clone(),
+                        prometheus: config.prometheus.clone(),
+                        default_index_pattern: config.default_index_pattern.clone(),
+                        es_version: probe.es_version,
+                        es_version_detected: probe.es_version_detected,
+                        accessible: unreachable.is_none(),
+                        accessible_reason: unreachable.clone(),
+                        circuit_breaker: CircuitBreaker::new(
+                            config.id.clone(),
+                            &config.circuit_breaker,
+                        ),
+                        request_limiter: RequestLimiter::new(&config),
+                        retry_policy: RetryPolicy::new(&config.retry),
+                        enabled: true,
+                    };
+                    (connection, unreachable)
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        cluster_id = %config.id,
+                        error = %e,
+                        "Failed to initialise cluster — it will be marked inaccessible"
+                    );
+                    let connection = ClusterConnection {
+                        id: config.id.clone(),
+                        name: config.name.clone(),
+                        nodes: config.nodes.clone(),
+                        client: None,
+                        role_clients: Vec::new(),
+                        tls_config: config.tls.clone(),
+                        metrics_source: config.metrics_source.clone(),
+                        prometheus: config.prometheus.clone(),
+                        default_index_pattern: config.default_index_pattern.clone(),
+                        es_version: config.es_version,
+                        es_version_detected: false,
+                        accessible: false,
+                        accessible_reason: Some(e.to_string()),
+                        circuit_breaker: CircuitBreaker::new(
+                            config.id.clone(),
+                            &config.circuit_breaker,
+                        ),
+                        request_limiter: RequestLimiter::new(&config),
+                        retry_policy: RetryPolicy::new(&config.retry),
+                        enabled: true,
+                    };
+                    (connection, None)
+                }
+            }
+        } else {
+            // Create one client per RoleCredential in configuration order
+            let mut role_clients: Vec<RoleClient> = Vec::new();
+            let mut init_error: Option<anyhow::Error> = None;
+
+            for rc in &config.auth {
+                match Client::new_with_auth(&config, Some(&rc.auth)).await {
+                    Ok(c) => {
+                        let arc = Arc::new(c);
+                        let label = rc.roles.join(",");
+                        role_clients.push(RoleClient {
+                            roles: rc.roles.clone(),
+                            client: arc,
+                            label,
+                        });
+                    }
+                    Err(e) => {
+                        init_error = Some(e);
+                        break;
+                    }
+                }
+            }
+
+            if init_error.is_some() {
+                tracing::warn!(
+                    cluster_id = %config.id,
+                    error = %init_error.as_ref().map(|e| e.to_string()).unwrap_or_default(),
+                    "Failed to initialise role-specific clients — cluster will be marked inaccessible"
+                );
+                return (
+                    ClusterConnection {
+                        id: config.id.clone(),
+                        name: config.name.clone(),
+                        nodes: config.nodes.clone(),
+                        client: None,
+                        role_clients: Vec::new(),
+                        tls_config: config.tls.clone(),
+                        metrics_source: config.metrics_source.clone(),
+                        prometheus: config.prometheus.clone(),
+                        default_index_pattern: config.default_index_pattern.clone(),
+                        es_version: config.es_version,
+                        es_version_detected: false,
+                        accessible: false,
+                        accessible_reason: init_error.as_ref().map(|e| e.to_string()),
+                        circuit_breaker: CircuitBreaker::new(
+                            config.id.clone(),
+                            &config.circuit_breaker,
+                        ),
+                        request_limiter: RequestLimiter::new(&config),
+                        retry_policy: RetryPolicy::new(&config.retry),
+                        enabled: true,
+                    },
+                    None,
+                );
+            }
 
-                clusters.insert(config.id.clone(), Arc::new(connection));
+            // For backwards compatibility keep `client` pointing at the first role client
+            // Use `first()` to satisfy clippy::get_first
+            let primary = role_clients.first().map(|rc| rc.client.clone());
+            let probe = match &primary {
+                Some(client) => probe_cluster(client.as_ref(), &config, check_reachable).await,
+                None => StartupProbe {
+                    es_version: config.es_version,
+                    es_version_detected: false,
+                    reported_version: None,
+                    unreachable: None,
+                },
+            };
+            let unreachable = probe.unreachable.filter(|_| check_reachable);
+            // Only the primary was asked; the other role clients talk to the same cluster
+            if let Some(major) = probe.reported_version {
+                for role_client in &role_clients {
+                    role_client.client.set_detected_version(major);
+                }
             }
+
+            let connection = ClusterConnection {
+                id: config.id.clone(),
+                name: config.name.clone(),
+                nodes: config.nodes.clone(),
+                client: primary,
+                role_clients,
+                tls_config: config.tls.clone(),
+                metrics_source: config.metrics_source.clone(),
+                prometheus: config.prometheus.clone(),
+                default_index_pattern: config.default_index_pattern.clone(),
+                es_version: probe.es_version,
+                es_version_detected: probe.es_version_detected,
+                accessible: unreachable.is_none(),
+                accessible_reason: unreachable.clone(),
+                circuit_breaker: CircuitBreaker::new(config.id.clone(), &config.circuit_breaker),
+                request_limiter: RequestLimiter::new(&config),
+                retry_policy: RetryPolicy::new(&config.retry),
+                enabled: true,
+            };
+            (connection, unreachable)
         }
+    }
 
-        if total == 0 {
-            anyhow::bail!("No clusters configured");
+    /// Replace the configured cluster set
+    ///
+    /// New connections are fully built before the swap so in-flight requests keep
+    /// using the previous connections and readers never observe a partial set.
+    /// Cached health entries are dropped since they may belong to removed clusters.
+    /// A cluster that is down never fails a reload: with
+    /// [`UnreachableClusterPolicy::Fail`] it is deferred instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `cluster_configs` - New cluster configurations
+    /// * `cluster_warnings` - Load-time warnings for clusters to mark inaccessible
+    pub async fn reload_clusters(
+        &self,
+        cluster_configs: Vec<ClusterConfig>,
+        cluster_warnings: Option<Vec<ClusterWarning>>,
+    ) -> Result<()> {
+        let unreachable_policy = match self.unreachable_policy {
+            UnreachableClusterPolicy::Fail => UnreachableClusterPolicy::Defer,
+            policy => policy,
+        };
+        let (clusters, pending) =
+            Self::build_connections(cluster_configs, cluster_warnings, unreachable_policy).await?;
+        let cluster_count = clusters.len();
+
+        {
+            let mut current = self.clusters.write().await;
+            *current = clusters;
+            *self.lock_pending_reconnects() = pending;
         }
+        self.health_cache.invalidate_all();
+        self.health_failures.invalidate_all();
+        for cache in self.resource_caches.values() {
+            cache.invalidate_all();
+        }
+        crate::app_metrics::cluster::set_total_clusters(cluster_count as i64);
+        self.lock_reachable_clusters().clear();
+        crate::app_metrics::cluster::set_reachable_clusters(0);
 
-        tracing::debug!(cluster_count = clusters.len(), "Clusters initialized");
-        tracing::debug!(
-            cache_duration_secs = cache_duration.as_secs(),
-            "Cache configured"
-        );
+        tracing::debug!(cluster_count, "Clusters reloaded");
 
-        Ok(Self {
-            clusters: Arc::new(RwLock::new(clusters)),
-            rbac: None,
-            health_cache: moka::future::Cache::builder()
-                .time_to_live(cache_duration)
-                .build(),
-        })
+        Ok(())
     }
 
     /// Backwards-compatible constructor that doesn't accept load-time warnings
+    ///
+    /// No background tasks are started; see
+    /// [`Manager::start_health_refresh_task`].
     pub async fn new(
         cluster_configs: Vec<ClusterConfig>,
         cache_duration: Duration,
@@ -709,12 +1544,91 @@ impl Manager {
     /// Validates: Requirements 2.15, 2.16
     #[instrument(skip(self), fields(cluster_id = %cluster_id))]
     pub async fn get_cluster(&self, cluster_id: &str) -> Result<Arc<ClusterConnection>> {
-        let clusters = self.clusters.read().await;
-
-        clusters
+        let conn = self
+            .clusters
+            .read()
+            .await
             .get(cluster_id)
             .cloned()
-            .ok_or_else(|| anyhow::anyhow!("Cluster '{}' not found", cluster_id))
+            .ok_or_else(|| anyhow::anyhow!("Cluster '{}' not found", cluster_id))?;
+
+        if conn.accessible {
+            return Ok(conn);
+        }
+        Ok(self.try_reconnect(&conn).await.unwrap_or(conn))
+    }
+
+    /// Number of clusters whose last health refresh succeeded
+    pub fn reachable_cluster_count(&self) -> usize {
+        self.lock_reachable_clusters().len()
+    }
+
+    /// Track the outcome of a health refresh and publish the reachable count
+    fn record_reachable(&self, cluster_id: &str, reachable: bool) {
+        let mut clusters = self.lock_reachable_clusters();
+        let changed = if reachable {
+            clusters.insert(cluster_id.to_string())
+        } else {
+            clusters.remove(cluster_id)
+        };
+        if changed {
+            crate::app_metrics::cluster::set_reachable_clusters(clusters.len());
+        }
+    }
+
+    fn lock_reachable_clusters(&self) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        // A panic while holding the lock cannot leave the set inconsistent
+        self.reachable_clusters
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn lock_pending_reconnects(
+        &self,
+    ) -> std::sync::MutexGuard<'_, HashMap<String, PendingReconnect>> {
+        // A panic while holding the lock cannot leave the map inconsistent
+        self.pending_reconnects
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Try to connect a cluster that was unreachable at startup or reload
+    ///
+    /// Attempts are spaced at least [`RECONNECT_INTERVAL`] apart. On success
+    /// the new connection replaces `conn`, unless the cluster set was
+    /// reloaded in the meantime.
+    async fn try_reconnect(&self, conn: &Arc<ClusterConnection>) -> Option<Arc<ClusterConnection>> {
+        let config = {
+            let mut pending = self.lock_pending_reconnects();
+            let entry = pending.get_mut(&conn.id)?;
+            if entry.last_attempt.elapsed() < RECONNECT_INTERVAL {
+                return None;
+            }
+            entry.last_attempt = Instant::now();
+            entry.config.clone()
+        };
+
+        let (connection, unreachable) = Self::build_connection(config, None, true).await;
+        if !connection.accessible {
+            tracing::debug!(
+                cluster_id = %conn.id,
+                reason = ?unreachable.or(connection.accessible_reason),
+                "Cluster is still unreachable"
+            );
+            return None;
+        }
+
+        let connection = Arc::new(connection);
+        let mut clusters = self.clusters.write().await;
+        match clusters.get_mut(&conn.id) {
+            Some(current) if Arc::ptr_eq(current, conn) => *current = connection.clone(),
+            _ => return None,
+        }
+        self.lock_pending_reconnects().remove(&conn.id);
+        drop(clusters);
+
+        tracing::info!(cluster_id = %conn.id, "Cluster reconnected");
+        Some(connection)
     }
 
     /// List all configured clusters
@@ -739,6 +1653,9 @@ impl Manager {
                 accessible: conn.accessible, // reflect initialization result
                 accessible_reason: conn.accessible_reason.clone(),
                 metrics_source: conn.metrics_source.clone(),
+                enabled: conn.enabled,
+                es_version: conn.es_version,
+                es_version_detected: conn.es_version_detected,
             })
             .collect()
     }
@@ -883,31 +1800,204 @@ impl Manager {
     #[instrument(skip(self), fields(cluster_id = %cluster_id))]
     pub async fn check_health(&self, cluster_id: &str) -> Result<ClusterHealth> {
         // Try to get from cache first
-        if let Some(cached_health) = self.health_cache.get(cluster_id).await {
+        if let Some(cached_health) = self
+            .health_cache
+            .get(cluster_id)
+            .instrument(tracing::info_span!(
+                "cache.get",
+                cache = "health",
+                cluster_id
+            ))
+            .await
+        {
             tracing::debug!("Returning cached health");
+            crate::app_metrics::cache::record_hit("health");
             return Ok(cached_health);
         }
+        crate::app_metrics::cache::record_miss("health");
 
         // Cache miss - fetch fresh data
         tracing::debug!(
             "Cache miss - fetching fresh health for cluster '{}'",
             cluster_id
         );
+        self.refresh_health(cluster_id).await
+    }
+
+    /// Get cached metadata for a cluster, fetching it on a miss
+    ///
+    /// `key` identifies the entry within the cluster (e.g. an index
+    /// expression) and may be empty. Failed fetches are not cached.
+    pub async fn cached_resource<F, Fut>(
+        &self,
+        resource: CachedResource,
+        cluster_id: &str,
+        key: &str,
+        fetch: F,
+    ) -> Result<Value>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<Value>>,
+    {
+        let cache = &self.resource_caches[&resource];
+        let cache_key = format!("{}/{}", cluster_id, key);
+        if let Some(cached) = cache
+            .get(&cache_key)
+            .instrument(tracing::info_span!(
+                "cache.get",
+                cache = resource.label(),
+                cluster_id
+            ))
+            .await
+        {
+            crate::app_metrics::cache::record_hit(resource.label());
+            return Ok(cached);
+        }
+        crate::app_metrics::cache::record_miss(resource.label());
+
+        let value = fetch().await?;
+        cache
+            .insert(cache_key, value.clone())
+            .instrument(tracing::info_span!(
+                "cache.insert",
+                cache = resource.label(),
+                cluster_id
+            ))
+            .await;
+        Ok(value)
+    }
+
+    /// Drop cached metadata of one resource for a cluster after it changed
+    pub fn invalidate_resource(&self, resource: CachedResource, cluster_id: &str) {
+        let prefix = format!("{}/", cluster_id);
+        if let Err(e) = self.resource_caches[&resource]
+            .invalidate_entries_if(move |key, _| key.starts_with(&prefix))
+        {
+            tracing::warn!(
+                cluster_id,
+                cache = resource.label(),
+                error = %e,
+                "Failed to invalidate cache"
+            );
+        }
+    }
+
+    /// Fetch fresh health for a cluster, bypassing and repopulating the cache
+    ///
+    /// # Arguments
+    ///
+    /// * `cluster_id` - The cluster identifier
+    ///
+    /// # Returns
+    ///
+    /// ClusterHealth information or an error
+    #[instrument(skip(self), fields(cluster_id = %cluster_id))]
+    pub async fn refresh_health(&self, cluster_id: &str) -> Result<ClusterHealth> {
         let cluster = self.get_cluster(cluster_id).await?;
 
         let health = cluster
             .check_health()
             .await
-            .with_context(|| format!("Failed to check health for cluster '{}'", cluster_id))?;
+            .with_context(|| format!("Failed to check health for cluster '{}'", cluster_id));
+        crate::app_metrics::cluster::set_up(cluster_id, health.is_ok());
+        self.record_reachable(cluster_id, health.is_ok());
+        let health = health?;
+        crate::app_metrics::cluster::set_health_status(
+            cluster_id,
+            match health.status {
+                HealthStatus::Green => 0,
+                HealthStatus::Yellow => 1,
+                HealthStatus::Red => 2,
+            },
+        );
+
+        if let Some(notifier) = &self.notifier {
+            notifier.observe(cluster_id, cluster.name.as_deref(), &health.status);
+        }
 
         // Cache the result
         self.health_cache
             .insert(cluster_id.to_string(), health.clone())
+            .instrument(tracing::info_span!(
+                "cache.insert",
+                cache = "health",
+                cluster_id
+            ))
             .await;
 
+        // Sending only fails when nobody is subscribed
+        let _ = self.health_events.send(HealthEvent {
+            cluster_id: cluster_id.to_string(),
+            health: health.clone(),
+        });
+
         Ok(health)
     }
 
+    /// Subscribe to every health refresh, of all clusters
+    ///
+    /// Returns `None` when [`HEALTH_STREAM_MAX_SUBSCRIBERS`] subscriptions
+    /// are already open. The slot is released when the subscription is dropped.
+    pub fn subscribe_health(&self) -> Option<HealthSubscription> {
+        let slot = self.health_stream_slots.clone().try_acquire_owned().ok()?;
+        Some(HealthSubscription {
+            events: self.health_events.subscribe(),
+            _slot: slot,
+        })
+    }
+
+    /// Spawn a background task that keeps the health cache warm
+    ///
+    /// Every `interval` the health of each accessible cluster is refreshed.
+    /// Refreshes are staggered evenly across the interval so clusters do not
+    /// all hit their backends at the same instant, and each runs in its own
+    /// task so a slow cluster does not delay the others. Failures are logged
+    /// and the loop continues. Abort the returned handle to stop the task.
+    ///
+    /// The constructors do not start this task, since it needs the shared
+    /// `Arc<Manager>`. The caller starts it once the manager is shared, as
+    /// `main` does when `cache.background_health_refresh` is enabled.
+    pub fn start_health_refresh_task(
+        self: Arc<Self>,
+        interval: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        let interval = interval.max(Duration::from_secs(1));
+        tokio::spawn(async move {
+            loop {
+                let cycle_start = tokio::time::Instant::now();
+
+                // Re-read the cluster list each cycle so config reloads are picked up
+                let cluster_ids: Vec<String> = {
+                    let clusters = self.clusters.read().await;
+                    clusters
+                        .iter()
+                        .filter(|(_, conn)| conn.accessible)
+                        .map(|(id, _)| id.clone())
+                        .collect()
+                };
+                let slot = interval / cluster_ids.len().max(1) as u32;
+
+                for (i, cluster_id) in cluster_ids.into_iter().enumerate() {
+                    tokio::time::sleep_until(cycle_start + slot * i as u32).await;
+
+                    let manager = self.clone();
+                    tokio::spawn(async move {
+                        match manager.refresh_health(&cluster_id).await {
+                            Ok(_) => {
+                                tracing::debug!(cluster_id = %cluster_id, "Background health refresh completed");
+                            }
+                            Err(e) => {
+                                tracing::warn!(cluster_id = %cluster_id, error = %e, "Background health refresh failed");
+                            }
+                        }
+                    });
+                }
+
+                tokio::time::sleep_until(cycle_start + interval).await;
+            }
+        })
+    }
+
     /// Check health of all clusters concurrently
     ///
     /// Uses join_all to run health checks in parallel, reducing latency
@@ -938,6 +2028,72 @@ impl Manager {
         results.into_iter().collect()
     }
 
+    /// Check health of the given clusters concurrently
+    ///
+    /// Like [`Manager::check_all_health`] but limited to `cluster_ids` and
+    /// served from the health cache where possible. Unknown or unreachable
+    /// clusters yield an error entry instead of failing the whole call.
+    ///
+    /// # Arguments
+    ///
+    /// * `cluster_ids` - The cluster identifiers to check
+    #[instrument(skip(self))]
+    pub async fn check_health_for(
+        &self,
+        cluster_ids: &[String],
+    ) -> HashMap<String, Result<ClusterHealth>> {
+        let health_futures: Vec<_> = cluster_ids
+            .iter()
+            .map(|id| async move { (id.clone(), self.check_health(id).await) })
+            .collect();
+
+        futures::future::join_all(health_futures)
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// Check health of the given clusters for a frequent probe
+    ///
+    /// Like [`Manager::check_health_for`], but a failed check is remembered
+    /// for the red health TTL, like a red health would be, so probes do not
+    /// wait on an unreachable cluster every time.
+    #[instrument(skip(self))]
+    pub async fn check_health_for_probe(
+        &self,
+        cluster_ids: &[String],
+    ) -> HashMap<String, Result<ClusterHealth>> {
+        let health_futures: Vec<_> = cluster_ids
+            .iter()
+            .map(|id| async move {
+                if let Some(error) = self.health_failures.get(id).await {
+                    return (id.clone(), Err(anyhow::anyhow!(error)));
+                }
+                let health = self.check_health(id).await;
+                match &health {
+                    Ok(_) => self.health_failures.invalidate(id).await,
+                    Err(e) => self.health_failures.insert(id.clone(), e.to_string()).await,
+                }
+                (id.clone(), health)
+            })
+            .collect();
+
+        futures::future::join_all(health_futures)
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// Get the circuit breaker state of a cluster
+    ///
+    /// Returns `None` if the cluster is not configured.
+    pub async fn circuit_state(&self, cluster_id: &str) -> Option<CircuitState> {
+        let clusters = self.clusters.read().await;
+        clusters
+            .get(cluster_id)
+            .map(|conn| conn.circuit_breaker.state())
+    }
+
     /// Get the number of configured clusters
     ///
     /// # Returns
@@ -967,7 +2123,7 @@ impl Manager {
     /// Select a pre-created client for a user based on their roles.
     ///
     /// Iterates configured RoleClient entries in order (first-match-wins).
-    /// Returns the Arc<Client> and a matched role label on success. If the
+    /// Returns the client and a matched role label on success. If the
     /// cluster has no role-specific clients (legacy/unauthenticated) the
     /// legacy `client` is returned and the matched role label is "*".
     #[instrument(skip(self), fields(cluster_id = %cluster_id))]
@@ -975,13 +2131,12 @@ impl Manager {
         &self,
         cluster_id: &str,
         user_roles: &[String],
-    ) -> Result<(Arc<Client>, String)> {
-        let clusters = self.clusters.read().await;
+    ) -> Result<(Arc<dyn ElasticsearchClient>, String)> {
+        let conn = self.get_cluster(cluster_id).await?;
 
-        let conn = clusters
-            .get(cluster_id)
-            .cloned()
-            .ok_or_else(|| anyhow::anyhow!("Cluster '{}' not found", cluster_id))?;
+        if !conn.enabled {
+            anyhow::bail!("Cluster '{}' is disabled", cluster_id);
+        }
 
         if !conn.accessible {
             anyhow::bail!("Cluster '{}' is inaccessible", cluster_id);
@@ -1034,8 +2189,63 @@ impl Manager {
     #[instrument(skip(self, req), fields(cluster_id = %req.cluster_id, http_method = %req.method, path = %req.path))]
     pub async fn proxy_request_with_audit(
         &self,
-        req: ProxyAuditRequest,
+        mut req: ProxyAuditRequest,
     ) -> std::result::Result<(StatusCode, HeaderMap, Vec<u8>, String), ProxyRequestError> {
+        let start = Instant::now();
+        let (resp, matched_role_label, _permit) = self.send_proxy_request(&mut req).await?;
+
+        let status = resp.status();
+        // Clone headers before consuming the response body
+        let headers = resp.headers().clone();
+        let bytes = match tokio::time::timeout(
+            std::time::Duration::from_secs(10),
+            read_body_limited(resp, req.max_response_bytes),
+        )
+        .await
+        {
+            Err(_) => return Err(ProxyRequestError::ResponseReadTimeout),
+            Ok(inner) => inner?,
+        };
+
+        emit_proxy_audit(req, &matched_role_label, status, start);
+
+        Ok((status, headers, bytes, matched_role_label))
+    }
+
+    /// Proxy a request like [`Manager::proxy_request_with_audit`], but return
+    /// the response as soon as its headers arrive
+    ///
+    /// The body is left unread so callers can stream it; reading it is not
+    /// covered by a timeout or by `max_response_bytes`. The audit entry is
+    /// emitted when the headers arrive. The cluster's request slot is held
+    /// until the returned permit is dropped, so keep it until the body is read.
+    #[instrument(skip(self, req), fields(cluster_id = %req.cluster_id, http_method = %req.method, path = %req.path))]
+    pub async fn proxy_request_streaming(
+        &self,
+        mut req: ProxyAuditRequest,
+    ) -> std::result::Result<(Response, String, RequestPermit), ProxyRequestError> {
+        let start = Instant::now();
+        let (resp, matched_role_label, permit) = self.send_proxy_request(&mut req).await?;
+
+        emit_proxy_audit(req, &matched_role_label, resp.status(), start);
+
+        Ok((resp, matched_role_label, permit))
+    }
+
+    /// Send a proxied request and wait for the response headers
+    ///
+    /// Returns the response, the label of the role whose client was used and
+    /// the cluster request slot the request holds.
+    async fn send_proxy_request(
+        &self,
+        req: &mut ProxyAuditRequest,
+    ) -> std::result::Result<(Response, String, RequestPermit), ProxyRequestError> {
+        // An unknown cluster is not found rather than denied
+        let breaker_cluster = self
+            .get_cluster(&req.cluster_id)
+            .await
+            .map_err(|_| ProxyRequestError::ClusterNotFound(req.cluster_id.clone()))?;
+
         // Select client for this user/cluster. If no match, return an error
         // that callers should map to a local access_denied response. Do NOT
         // emit audit for local access_denied.
@@ -1047,10 +2257,14 @@ impl Manager {
             Err(_) => return Err(ProxyRequestError::AccessDenied),
         };
 
-        // Perform the request with a 30s timeout and 10s read timeout for
-        // response body (matches existing handler behaviour).
-        let start = Instant::now();
-        // Perform the instrumented request with a timeout. Convert tokio::time::Elapsed
+        // Fail fast while the cluster's circuit breaker is open
+        breaker_cluster
+            .circuit_breaker
+            .try_acquire()
+            .map_err(|e| ProxyRequestError::RequestFailed(e.to_string()))?;
+        let permit = breaker_cluster.request_limiter.acquire().await?;
+
+        // Perform the instrumented request with a 30s timeout. Convert tokio::time::Elapsed
         // into ProxyRequestError::ProxyTimeout via From, and attempt to downcast
         // anyhow::Error returned by the instrumented_request into more specific
         // errors (reqwest::Error, io::Error) before falling back to Other.
@@ -1059,44 +2273,75 @@ impl Manager {
             client.as_ref().instrumented_request(
                 req.method.clone(),
                 &req.path,
-                req.body,
+                req.body.take(),
                 &req.cluster_id,
+                Some(req.request_id.as_str()).filter(|id| !id.is_empty()),
             ),
         )
         .await
-        .map_err(Into::<ProxyRequestError>::into)?; // Elapsed -> ProxyTimeout
+        .map_err(|elapsed| {
+            breaker_cluster.circuit_breaker.record_failure();
+            Into::<ProxyRequestError>::into(elapsed)
+        })?; // Elapsed -> ProxyTimeout
 
         // Classify any error returned by the instrumented_request into a
         // ProxyRequestError using the helper in src/cluster/error.rs.
-        let resp = inner_res.map_err(|e| crate::cluster::error::classify_anyhow(&e))?;
+        let resp = inner_res.map_err(|e| {
+            if is_connection_failure(&e) {
+                breaker_cluster.circuit_breaker.record_failure();
+            }
+            crate::cluster::error::classify_anyhow(&e)
+        })?;
+        breaker_cluster.circuit_breaker.record_success();
 
-        let status = resp.status();
-        // Clone headers before consuming the response body
-        let headers = resp.headers().clone();
-        let bytes =
-            match tokio::time::timeout(std::time::Duration::from_secs(10), resp.bytes()).await {
-                Err(_) => return Err(ProxyRequestError::ResponseReadTimeout),
-                Ok(inner) => inner.map_err(ProxyRequestError::from)?,
-            };
+        Ok((resp, matched_role_label, permit))
+    }
+}
 
-        let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
-
-        // Emit audit entry for forwarded requests (only if request reached ES)
-        let entry = crate::audit::AuditEntry::now(
-            req.request_id,
-            req.user_id.unwrap_or_default(),
-            req.user_roles,
-            req.cluster_id,
-            matched_role_label.clone(),
-            req.method.to_string(),
-            req.path,
-            status.as_u16(),
-            duration_ms,
-        );
-        crate::audit::emit_if_enabled(req.audit_enabled, &entry);
+/// Emit the audit entry for a request that reached Elasticsearch
+fn emit_proxy_audit(
+    req: ProxyAuditRequest,
+    matched_role_label: &str,
+    status: StatusCode,
+    start: Instant,
+) {
+    let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
+    let entry = crate::audit::AuditEntry::now(
+        req.request_id,
+        req.user_id.unwrap_or_default(),
+        req.user_roles,
+        req.cluster_id,
+        matched_role_label.to_string(),
+        req.method.to_string(),
+        req.path,
+        status.as_u16(),
+        duration_ms,
+    );
+    crate::audit::emit_if_enabled(req.audit_enabled, &entry);
+}
+
+/// Read a response body, failing once it grows past `limit`
+///
+/// A `Content-Length` over the limit fails before anything is read.
+pub(crate) async fn read_body_limited(
+    mut resp: Response,
+    limit: Option<usize>,
+) -> std::result::Result<Vec<u8>, ProxyRequestError> {
+    let Some(limit) = limit else {
+        return Ok(resp.bytes().await?.to_vec());
+    };
+    if resp.content_length().is_some_and(|len| len > limit as u64) {
+        return Err(ProxyRequestError::ResponseTooLarge { limit });
+    }
 
-        Ok((status, headers, bytes.to_vec(), matched_role_label))
+    let mut body = Vec::new();
+    while let Some(chunk) = resp.chunk().await? {
+        if body.len() + chunk.len() > limit {
+            return Err(ProxyRequestError::ResponseTooLarge { limit });
+        }
+        body.extend_from_slice(&chunk);
     }
+    Ok(body)
 }
 
 #[cfg(test)]
@@ -1144,14 +2389,10 @@ mod manager_tests {
     }
 
     #[tokio::test]
-    async fn test_get_cluster() {
+    async fn test_reload_clusters() {
         let configs = vec![ClusterConfig {
-            id: "test".to_string(),
-            name: Some("Test".to_string()),
+            id: "old".to_string(),
             nodes: vec!["http://localhost:9200".to_string()],
-            auth: Vec::new(),
-            tls: TlsConfig::default(),
-
             ..Default::default()
         }];
 
@@ -1159,11 +2400,755 @@ mod manager_tests {
             .await
             .expect("create manager");
 
-        let cluster = manager.get_cluster("test").await;
-        assert!(cluster.is_ok());
+        let new_configs = vec![
+            ClusterConfig {
+                id: "new1".to_string(),
+                nodes: vec!["http://localhost:9200".to_string()],
+                ..Default::default()
+            },
+            ClusterConfig {
+                id: "new2".to_string(),
+                nodes: vec!["http://localhost:9201".to_string()],
+                ..Default::default()
+            },
+        ];
 
-        let conn = cluster.expect("get_cluster should succeed");
-        assert_eq!(conn.id, "test");
+        manager
+            .reload_clusters(new_configs, None)
+            .await
+            .expect("reload clusters");
+
+        assert_eq!(manager.cluster_count().await, 2);
+        assert!(!manager.has_cluster("old").await);
+        assert!(manager.has_cluster("new1").await);
+
+        // An empty cluster set is rejected and the current set is kept
+        assert!(manager.reload_clusters(vec![], None).await.is_err());
+        assert_eq!(manager.cluster_count().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_health_bypasses_cache() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/_cluster/health"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "yellow",
+                "cluster_name": "mock",
+                "number_of_nodes": 1
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let configs = vec![ClusterConfig {
+            id: "mock".to_string(),
+            nodes: vec![server.uri()],
+            ..Default::default()
+        }];
+        let manager = Manager::new(configs, Duration::from_secs(60))
+            .await
+            .expect("create manager");
+
+        // First call populates the cache, second is served from it
+        let health = manager.check_health("mock").await.expect("health");
+        assert_eq!(health.status, HealthStatus::Yellow);
+        manager.check_health("mock").await.expect("cached health");
+
+        // Refresh always goes to the cluster
+        let refreshed = manager.refresh_health("mock").await.expect("refresh");
+        assert_eq!(refreshed.cluster_name, "mock");
+        manager.check_health("mock").await.expect("cached health");
+    }
+
+    fn health_with_status(status: HealthStatus) -> ClusterHealth {
+        ClusterHealth {
+            status,
+            cluster_name: "test".to_string(),
+            number_of_nodes: 1,
+            number_of_data_nodes: 1,
+            active_primary_shards: 0,
+            active_shards: 0,
+            relocating_shards: 0,
+            initializing_shards: 0,
+            unassigned_shards: 0,
+        }
+    }
+
+    #[test]
+    fn test_health_cache_ttl_per_status() {
+        use moka::Expiry;
+
+        let ttl = HealthCacheTtl {
+            green: Duration::from_secs(120),
+            yellow: Duration::from_secs(20),
+            red: Duration::from_secs(5),
+        };
+        let key = "cluster".to_string();
+
+        for (status, expected) in [
+            (HealthStatus::Green, 120),
+            (HealthStatus::Yellow, 20),
+            (HealthStatus::Red, 5),
+        ] {
+            let health = health_with_status(status);
+            let expected = Some(Duration::from_secs(expected));
+            assert_eq!(
+                ttl.expire_after_create(&key, &health, Instant::now()),
+                expected
+            );
+            // An update restarts the TTL for the new status
+            assert_eq!(
+                ttl.expire_after_update(&key, &health, Instant::now(), Some(Duration::ZERO)),
+                expected
+            );
+        }
+
+        let uniform = HealthCacheTtl::uniform(Duration::from_secs(30));
+        assert_eq!(
+            uniform.for_status(&HealthStatus::Red),
+            Duration::from_secs(30)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resource_caches_honor_their_own_ttl() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let manager = Manager::with_connections(Vec::new(), Duration::from_secs(60))
+            .with_resource_cache_ttl(ResourceCacheTtl {
+                indices: Duration::from_millis(200),
+                nodes: Duration::from_secs(60),
+                templates: Duration::from_secs(60),
+            });
+        let fetches = AtomicUsize::new(0);
+        let fetch = |resource: CachedResource| {
+            let manager = &manager;
+            let fetches = &fetches;
+            async move {
+                manager
+                    .cached_resource(resource, "prod", "", || async {
+                        fetches.fetch_add(1, Ordering::SeqCst);
+                        Ok(serde_json::json!({"resource": resource.label()}))
+                    })
+                    .await
+                    .expect("cached resource")
+            }
+        };
+
+        assert_eq!(fetch(CachedResource::Indices).await["resource"], "indices");
+        assert_eq!(fetch(CachedResource::Nodes).await["resource"], "nodes");
+        fetch(CachedResource::Indices).await;
+        fetch(CachedResource::Nodes).await;
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+
+        tokio::time::sleep(Duration::from_millis(400)).await;
+
+        // Indices have expired and are fetched again; nodes are still cached
+        fetch(CachedResource::Indices).await;
+        fetch(CachedResource::Nodes).await;
+        assert_eq!(fetches.load(Ordering::SeqCst), 3);
+
+        // Invalidation only drops the resource of the given cluster
+        manager.invalidate_resource(CachedResource::Nodes, "staging");
+        fetch(CachedResource::Nodes).await;
+        assert_eq!(fetches.load(Ordering::SeqCst), 3);
+        manager.invalidate_resource(CachedResource::Nodes, "prod");
+        fetch(CachedResource::Nodes).await;
+        assert_eq!(fetches.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_failed_fetches_are_not_cached() {
+        let manager = Manager::with_connections(Vec::new(), Duration::from_secs(60));
+
+        let result = manager
+            .cached_resource(CachedResource::Templates, "prod", "", || async {
+                Err(anyhow::anyhow!("cluster unavailable"))
+            })
+            .await;
+        assert!(result.is_err());
+
+        let value = manager
+            .cached_resource(CachedResource::Templates, "prod", "", || async {
+                Ok(serde_json::json!({"index_templates": []}))
+            })
+            .await
+            .expect("fetched after failure");
+        assert_eq!(value, serde_json::json!({"index_templates": []}));
+    }
+
+    #[tokio::test]
+    async fn test_unhealthy_clusters_are_repolled_sooner() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let green = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/_cluster/health"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "green",
+                "cluster_name": "stable"
+            })))
+            .expect(1)
+            .mount(&green)
+            .await;
+        let red = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/_cluster/health"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "red",
+                "cluster_name": "failing"
+            })))
+            .expect(2)
+            .mount(&red)
+            .await;
+
+        let configs = vec![
+            ClusterConfig {
+                id: "stable".to_string(),
+                nodes: vec![green.uri()],
+                ..Default::default()
+            },
+            ClusterConfig {
+                id: "failing".to_string(),
+                nodes: vec![red.uri()],
+                ..Default::default()
+            },
+        ];
+        let manager = Manager::new(configs, Duration::from_secs(60))
+            .await
+            .expect("create manager")
+            .with_health_cache_ttl(HealthCacheTtl {
+                green: Duration::from_secs(60),
+                yellow: Duration::from_millis(200),
+                red: Duration::from_millis(200),
+            });
+
+        manager.check_health("stable").await.expect("green health");
+        manager.check_health("failing").await.expect("red health");
+        tokio::time::sleep(Duration::from_millis(400)).await;
+
+        // Red health has expired and is fetched again; green is still cached
+        manager.check_health("stable").await.expect("cached green");
+        manager
+            .check_health("failing")
+            .await
+            .expect("refetched red");
+    }
+
+    #[tokio::test]
+    async fn test_clusters_are_initialised_concurrently() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let delay = Duration::from_millis(500);
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"version": {"number": "8.15.0"}}))
+                    .set_delay(delay),
+            )
+            .mount(&server)
+            .await;
+
+        let configs: Vec<ClusterConfig> = (0..6)
+            .map(|i| ClusterConfig {
+                id: format!("slow-{}", i),
+                nodes: vec![server.uri()],
+                ..Default::default()
+            })
+            .collect();
+
+        let started = Instant::now();
+        let manager = Manager::new(configs, Duration::from_secs(60))
+            .await
+            .expect("create manager");
+        let elapsed = started.elapsed();
+
+        // Sequential init would take 6 x 500ms
+        assert!(elapsed < delay * 3, "init took {:?}", elapsed);
+        let ids: Vec<String> = manager
+            .list_clusters()
+            .await
+            .into_iter()
+            .map(|info| info.id)
+            .collect();
+        assert_eq!(
+            ids,
+            vec!["slow-0", "slow-1", "slow-2", "slow-3", "slow-4", "slow-5"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_cluster_fails_startup_with_fail_policy() {
+        let configs = vec![ClusterConfig {
+            id: "down".to_string(),
+            nodes: vec!["http://127.0.0.1:9".to_string()],
+            ..Default::default()
+        }];
+
+        let err = Manager::new_with_policy(
+            configs,
+            Duration::from_secs(60),
+            None,
+            UnreachableClusterPolicy::Fail,
+        )
+        .await
+        .expect_err("unreachable cluster");
+        assert!(err.to_string().contains("down"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_cluster_does_not_fail_reload_with_fail_policy() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"version": {"number": "8.15.0"}})),
+            )
+            .mount(&server)
+            .await;
+        let up = ClusterConfig {
+            id: "up".to_string(),
+            nodes: vec![server.uri()],
+            ..Default::default()
+        };
+        let manager = Manager::new_with_policy(
+            vec![up.clone()],
+            Duration::from_secs(60),
+            None,
+            UnreachableClusterPolicy::Fail,
+        )
+        .await
+        .expect("create manager");
+
+        let down = ClusterConfig {
+            id: "down".to_string(),
+            nodes: vec!["http://127.0.0.1:9".to_string()],
+            ..Default::default()
+        };
+        manager
+            .reload_clusters(vec![up, down], None)
+            .await
+            .expect("reload with an unreachable cluster");
+
+        let clusters = manager.list_clusters().await;
+        assert_eq!(clusters.len(), 2);
+        assert!(clusters[0].accessible);
+        assert!(!clusters[1].accessible);
+        assert!(manager.lock_pending_reconnects().contains_key("down"));
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_cluster_reconnects_with_defer_policy() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let configs = vec![ClusterConfig {
+            id: "flaky".to_string(),
+            nodes: vec![server.uri()],
+            ..Default::default()
+        }];
+        let manager = Manager::new_with_policy(
+            configs,
+            Duration::from_secs(60),
+            None,
+            UnreachableClusterPolicy::Defer,
+        )
+        .await
+        .expect("create manager");
+
+        let info = &manager.list_clusters().await[0];
+        assert!(!info.accessible);
+        assert!(info
+            .accessible_reason
+            .as_deref()
+            .is_some_and(|reason| reason.starts_with("Cluster unreachable")));
+
+        server.reset().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"version": {"number": "8.15.0"}})),
+            )
+            .mount(&server)
+            .await;
+
+        // Within the retry interval the previous attempt is not repeated
+        let conn = manager.get_cluster("flaky").await.expect("cluster");
+        assert!(!conn.accessible);
+
+        if let Some(entry) = manager.lock_pending_reconnects().get_mut("flaky") {
+            entry.last_attempt = Instant::now()
+                .checked_sub(RECONNECT_INTERVAL)
+                .expect("instant in the past");
+        }
+        let conn = manager.get_cluster("flaky").await.expect("cluster");
+        assert!(conn.accessible);
+        assert_eq!(conn.es_version, Some(8));
+        assert!(manager.list_clusters().await[0].accessible);
+        assert!(manager.lock_pending_reconnects().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_es_version_is_detected_at_startup() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "version": {"number": "7.17.22"}
+            })))
+            .mount(&server)
+            .await;
+
+        let configs = vec![ClusterConfig {
+            id: "mock".to_string(),
+            nodes: vec![server.uri()],
+            es_version: Some(8),
+            ..Default::default()
+        }];
+        let manager = Manager::new(configs, Duration::from_secs(60))
+            .await
+            .expect("create manager");
+
+        let cluster = manager.get_cluster("mock").await.expect("cluster");
+        assert_eq!(cluster.es_version, Some(7));
+        assert!(cluster.es_version_detected);
+        assert!(cluster.has_legacy_templates());
+
+        let info = &manager.list_clusters().await[0];
+        assert_eq!(info.es_version, Some(7));
+        assert!(info.es_version_detected);
+    }
+
+    #[tokio::test]
+    async fn test_es_version_falls_back_to_config_when_unreachable() {
+        let config = ClusterConfig {
+            id: "down".to_string(),
+            nodes: vec!["http://127.0.0.1:9".to_string()],
+            es_version: Some(8),
+            ..Default::default()
+        };
+
+        let conn = ClusterConnection::new(&config)
+            .await
+            .expect("create connection");
+        assert_eq!(conn.es_version, Some(8));
+        assert!(!conn.es_version_detected);
+
+        let config = ClusterConfig {
+            detect_version: false,
+            ..config
+        };
+        let conn = ClusterConnection::new(&config)
+            .await
+            .expect("create connection");
+        assert_eq!(conn.es_version, Some(8));
+        assert!(!conn.es_version_detected);
+    }
+
+    #[tokio::test]
+    async fn test_check_health_for_reports_errors_per_cluster() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/_cluster/health"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "green"})),
+            )
+            .mount(&server)
+            .await;
+
+        let configs = vec![
+            ClusterConfig {
+                id: "up".to_string(),
+                nodes: vec![server.uri()],
+                ..Default::default()
+            },
+            ClusterConfig {
+                id: "down".to_string(),
+                nodes: vec!["http://127.0.0.1:9".to_string()],
+                node_timeout_seconds: Some(2),
+                ..Default::default()
+            },
+        ];
+        let manager = Manager::new(configs, Duration::from_secs(60))
+            .await
+            .expect("create manager");
+
+        let results = manager
+            .check_health_for(&["up".to_string(), "down".to_string()])
+            .await;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results["up"].as_ref().expect("up is healthy").status,
+            HealthStatus::Green
+        );
+        assert!(results["down"].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_check_health_for_probe_remembers_failures() {
+        use crate::cluster::mock::MockClient;
+
+        let client = Arc::new(MockClient::new());
+        let config = ClusterConfig {
+            id: "down".to_string(),
+            ..Default::default()
+        };
+        let manager = Manager::with_connections(
+            vec![ClusterConnection::with_client(&config, client.clone())],
+            Duration::from_secs(60),
+        );
+        let ids = vec!["down".to_string()];
+
+        for _ in 0..2 {
+            let results = manager.check_health_for_probe(&ids).await;
+            assert!(results["down"].is_err());
+        }
+        let checks = client
+            .requests()
+            .iter()
+            .filter(|r| r.starts_with("GET _cluster/health"))
+            .count();
+        assert_eq!(checks, 1, "the failure is served from the cache");
+    }
+
+    #[tokio::test]
+    async fn test_health_refresh_task_warms_cache() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/_cluster/health"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "yellow"})),
+            )
+            .mount(&server)
+            .await;
+
+        let configs = vec![
+            ClusterConfig {
+                id: "a".to_string(),
+                nodes: vec![server.uri()],
+                ..Default::default()
+            },
+            ClusterConfig {
+                id: "b".to_string(),
+                nodes: vec![server.uri()],
+                ..Default::default()
+            },
+        ];
+        let manager = Arc::new(
+            Manager::new(configs, Duration::from_secs(60))
+                .await
+                .expect("create manager"),
+        );
+
+        let handle = manager
+            .clone()
+            .start_health_refresh_task(Duration::from_secs(1));
+        tokio::time::sleep(Duration::from_millis(900)).await;
+
+        // Both clusters were refreshed within one interval without a request
+        assert!(manager.health_cache.get("a").await.is_some());
+        assert!(manager.health_cache.get("b").await.is_some());
+
+        handle.abort();
+        assert!(handle.await.expect_err("task aborted").is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_fails_fast_after_connection_failures() {
+        let configs = vec![ClusterConfig {
+            id: "down".to_string(),
+            nodes: vec!["http://127.0.0.1:9".to_string()],
+            node_timeout_seconds: Some(2),
+            circuit_breaker: crate::config::CircuitBreakerConfig {
+                failure_threshold: 1,
+                cooldown_seconds: 60,
+            },
+            ..Default::default()
+        }];
+        let manager = Manager::new(configs, Duration::from_secs(60))
+            .await
+            .expect("create manager");
+
+        assert_eq!(
+            manager.circuit_state("down").await,
+            Some(CircuitState::Closed)
+        );
+
+        let err = manager
+            .refresh_health("down")
+            .await
+            .expect_err("cluster is unreachable");
+        assert!(err
+            .downcast_ref::<crate::cluster::CircuitOpenError>()
+            .is_none());
+        assert_eq!(
+            manager.circuit_state("down").await,
+            Some(CircuitState::Open)
+        );
+
+        let err = manager
+            .refresh_health("down")
+            .await
+            .expect_err("breaker is open");
+        assert!(err
+            .downcast_ref::<crate::cluster::CircuitOpenError>()
+            .is_some());
+        assert_eq!(manager.circuit_state("missing").await, None);
+    }
+
+    #[tokio::test]
+    async fn test_request_retries_idempotent_requests_on_503() {
+        let mock = Arc::new(
+            crate::cluster::mock::MockClient::new()
+                .with_response(
+                    Method::GET,
+                    "_cluster/health",
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    serde_json::json!({}),
+                )
+                .with_response(
+                    Method::POST,
+                    "_refresh",
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    serde_json::json!({}),
+                ),
+        );
+        let config = ClusterConfig {
+            id: "busy".to_string(),
+            retry: crate::config::RetryConfig {
+                max_retries: 2,
+                base_backoff_ms: 1,
+                max_retry_time_ms: 1000,
+            },
+            ..Default::default()
+        };
+        let cluster = ClusterConnection::with_client(&config, mock.clone());
+
+        // The last response is returned once the retries are used up
+        let response = cluster
+            .request(Method::GET, "_cluster/health", None)
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(mock.requests().len(), 3);
+
+        // Non-idempotent requests are sent once
+        cluster
+            .request(Method::POST, "_refresh", None)
+            .await
+            .expect("response");
+        assert_eq!(mock.requests().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_reachable_cluster_count_follows_health_refreshes() {
+        let config = |id: &str| ClusterConfig {
+            id: id.to_string(),
+            ..Default::default()
+        };
+        let up = crate::cluster::mock::MockClient::new()
+            .with_json("_cluster/health", serde_json::json!({"status": "green"}));
+        let manager = Manager::with_connections(
+            vec![
+                ClusterConnection::with_client(&config("up"), Arc::new(up)),
+                ClusterConnection::with_client(
+                    &config("down"),
+                    Arc::new(crate::cluster::mock::MockClient::new()),
+                ),
+            ],
+            Duration::from_secs(60),
+        );
+        assert_eq!(manager.reachable_cluster_count(), 0);
+
+        manager.refresh_health("up").await.expect("healthy cluster");
+        manager
+            .refresh_health("down")
+            .await
+            .expect_err("health is not mocked");
+        assert_eq!(manager.reachable_cluster_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_cluster_is_listed_without_connection() {
+        let configs = vec![ClusterConfig {
+            id: "off".to_string(),
+            nodes: vec!["http://127.0.0.1:9".to_string()],
+            enabled: false,
+            ..Default::default()
+        }];
+        let manager = Manager::new(configs, Duration::from_secs(60))
+            .await
+            .expect("create manager");
+
+        let clusters = manager.list_clusters().await;
+        assert_eq!(clusters.len(), 1);
+        assert!(!clusters[0].enabled);
+        assert!(!clusters[0].accessible);
+
+        let conn = manager.get_cluster("off").await.expect("cluster is listed");
+        assert!(conn.client.is_none());
+
+        let err = manager
+            .get_client_for_user("off", &[])
+            .await
+            .expect_err("disabled cluster has no client");
+        assert!(err.to_string().contains("disabled"));
+
+        let err = conn.check_health().await.expect_err("disabled cluster");
+        assert!(format!("{:#}", err).contains("disabled"));
+    }
+
+    #[tokio::test]
+    async fn test_get_cluster() {
+        let configs = vec![ClusterConfig {
+            id: "test".to_string(),
+            name: Some("Test".to_string()),
+            nodes: vec!["http://localhost:9200".to_string()],
+            auth: Vec::new(),
+            tls: TlsConfig::default(),
+
+            ..Default::default()
+        }];
+
+        let manager = Manager::new(configs, Duration::from_secs(30))
+            .await
+            .expect("create manager");
+
+        let cluster = manager.get_cluster("test").await;
+        assert!(cluster.is_ok());
+
+        let conn = cluster.expect("get_cluster should succeed");
+        assert_eq!(conn.id, "test");
     }
 
     #[tokio::test]
@@ -1228,6 +3213,7 @@ mod manager_tests {
                 user_roles: vec![],
                 request_id: "req-1".to_string(),
                 audit_enabled: true,
+                max_response_bytes: None,
             })
             .await
             .expect("proxy should succeed");
@@ -1240,6 +3226,53 @@ mod manager_tests {
         assert_eq!(matched_role_label, "*");
     }
 
+    #[tokio::test]
+    async fn test_proxy_request_with_audit_response_size_limit() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("x".repeat(2048)))
+            .mount(&mock_server)
+            .await;
+
+        let cfg = crate::config::ClusterConfig {
+            id: "big".to_string(),
+            nodes: vec![mock_server.uri()],
+            ..Default::default()
+        };
+        let manager = Manager::new(vec![cfg], std::time::Duration::from_secs(30))
+            .await
+            .expect("create manager");
+        let request = |limit| ProxyAuditRequest {
+            cluster_id: "big".to_string(),
+            method: Method::GET,
+            path: "/_cluster/state".to_string(),
+            body: None,
+            user_id: None,
+            user_roles: vec![],
+            request_id: "req-1".to_string(),
+            audit_enabled: false,
+            max_response_bytes: Some(limit),
+        };
+
+        let err = manager
+            .proxy_request_with_audit(request(1024))
+            .await
+            .expect_err("response over the limit");
+        assert!(matches!(
+            err,
+            ProxyRequestError::ResponseTooLarge { limit: 1024 }
+        ));
+
+        let (_, _, body, _) = manager
+            .proxy_request_with_audit(request(2048))
+            .await
+            .expect("response within the limit");
+        assert_eq!(body.len(), 2048);
+    }
+
     #[tokio::test]
     async fn test_proxy_request_with_audit_access_denied() {
         // Create cluster config with a role-specific credential (so role_clients is non-empty)
@@ -1271,6 +3304,7 @@ mod manager_tests {
                 user_roles: vec![],
                 request_id: "req-2".to_string(),
                 audit_enabled: false,
+                max_response_bytes: None,
             })
             .await
             .expect_err("should be access denied");
@@ -1281,6 +3315,38 @@ mod manager_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_proxy_request_with_audit_unknown_cluster() {
+        let cfg = crate::config::ClusterConfig {
+            id: "known".to_string(),
+            nodes: vec!["http://localhost:9200".to_string()],
+            ..Default::default()
+        };
+        let manager = Manager::new(vec![cfg], std::time::Duration::from_secs(30))
+            .await
+            .expect("create manager");
+
+        let err = manager
+            .proxy_request_with_audit(ProxyAuditRequest {
+                cluster_id: "missing".to_string(),
+                method: Method::GET,
+                path: "/_tasks".to_string(),
+                body: None,
+                user_id: Some("user-1".to_string()),
+                user_roles: vec!["admin".to_string()],
+                request_id: "req-3".to_string(),
+                audit_enabled: false,
+                max_response_bytes: None,
+            })
+            .await
+            .expect_err("should be not found");
+
+        match err {
+            ProxyRequestError::ClusterNotFound(id) => assert_eq!(id, "missing"),
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_get_client_for_user_ordering_and_wildcard() {
         use crate::config::{ClusterAuth, ClusterConfig, RoleCredential, TlsConfig};
@@ -1395,6 +3461,7 @@ mod manager_tests {
                 user_roles: vec!["guest".to_string()], // No matching role
                 request_id: "req-denied".to_string(),
                 audit_enabled: true,
+                max_response_bytes: None,
             })
             .await
             .expect_err("should be access denied");
@@ -1438,6 +3505,35 @@ mod manager_tests {
         assert!(clusters.iter().any(|c| c.id == "cluster2"));
     }
 
+    #[tokio::test]
+    async fn test_list_clusters_is_ordered() {
+        let cluster = |id: &str, order: Option<i32>| ClusterConfig {
+            id: id.to_string(),
+            nodes: vec!["http://localhost:9200".to_string()],
+            order,
+            ..Default::default()
+        };
+        let configs = vec![
+            cluster("zeta", None),
+            cluster("staging", Some(2)),
+            cluster("alpha", None),
+            cluster("production", Some(1)),
+        ];
+
+        let manager = Manager::new(configs, Duration::from_secs(30))
+            .await
+            .expect("create manager");
+        let ids: Vec<String> = manager
+            .list_clusters()
+            .await
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+
+        // Ordered clusters first, the rest keep their config order
+        assert_eq!(ids, vec!["production", "staging", "zeta", "alpha"]);
+    }
+
     #[tokio::test]
     async fn test_has_cluster() {
         let configs = vec![ClusterConfig {
@@ -1540,7 +3636,7 @@ mod manager_tests {
     #[tokio::test]
     async fn test_manager_with_rbac() {
         use crate::auth::{AuthUser, RbacManager};
-        use crate::config::RoleConfig;
+        use crate::config::{Permission, RoleConfig};
 
         let configs = vec![
             ClusterConfig {
@@ -1567,10 +3663,14 @@ mod manager_tests {
             RoleConfig {
                 name: "prod-admin".to_string(),
                 cluster_patterns: vec!["prod-*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
             },
             RoleConfig {
                 name: "dev-admin".to_string(),
                 cluster_patterns: vec!["dev-*".to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
             },
         ];
 
@@ -1603,7 +3703,7 @@ mod manager_tests {
     #[tokio::test]
     async fn test_list_accessible_clusters() {
         use crate::auth::{AuthUser, RbacManager};
-        use crate::config::RoleConfig;
+        use crate::config::{Permission, RoleConfig};
 
         let configs = vec![
             ClusterConfig {
@@ -1638,6 +3738,8 @@ mod manager_tests {
         let role_configs = vec![RoleConfig {
             name: "prod-viewer".to_string(),
             cluster_patterns: vec!["prod-*".to_string()],
+            permission: Permission::Read,
+            proxy_allow: None,
         }];
 
         let rbac = RbacManager::new(role_configs);
@@ -1661,7 +3763,7 @@ mod manager_tests {
     #[tokio::test]
     async fn test_get_cluster_with_auth_authorized() {
         use crate::auth::{AuthUser, RbacManager};
-        use crate::config::RoleConfig;
+        use crate::config::{Permission, RoleConfig};
 
         let configs = vec![ClusterConfig {
             id: "prod-cluster-1".to_string(),
@@ -1676,6 +3778,8 @@ mod manager_tests {
         let role_configs = vec![RoleConfig {
             name: "admin".to_string(),
             cluster_patterns: vec!["*".to_string()],
+            permission: Permission::Read,
+            proxy_allow: None,
         }];
 
         let rbac = RbacManager::new(role_configs);
@@ -1698,7 +3802,7 @@ mod manager_tests {
     #[tokio::test]
     async fn test_get_cluster_with_auth_unauthorized() {
         use crate::auth::{AuthUser, RbacManager};
-        use crate::config::RoleConfig;
+        use crate::config::{Permission, RoleConfig};
 
         let configs = vec![ClusterConfig {
             id: "prod-cluster-1".to_string(),
@@ -1713,6 +3817,8 @@ mod manager_tests {
         let role_configs = vec![RoleConfig {
             name: "dev-viewer".to_string(),
             cluster_patterns: vec!["dev-*".to_string()],
+            permission: Permission::Read,
+            proxy_allow: None,
         }];
 
         let rbac = RbacManager::new(role_configs);
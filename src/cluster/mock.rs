@@ -0,0 +1,343 @@
+//! In-memory [`ElasticsearchClient`] for tests
+//!
+//! [`MockClient`] answers requests from canned JSON bodies keyed by method and
+//! path, so clusters, the manager and route handlers can be exercised without
+//! a live cluster. Available in unit tests and behind the `test-util` feature.
+
+use super::client::ElasticsearchClient;
+use super::error::status_error;
+use crate::config::Distribution;
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::{Method, Response, StatusCode};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Client serving canned responses
+///
+/// Paths are matched without the leading slash and query string, so
+/// `/_cat/indices?format=json` is answered by a response registered for
+/// `_cat/indices`. Unregistered requests get a 404.
+///
+/// Typed methods return the canned body of the endpoint they call as-is,
+/// without the post-processing [`Client`](super::Client) applies (for
+/// example `indices_stats` does not add closed indices).
+#[derive(Debug, Default)]
+pub struct MockClient {
+    responses: HashMap<(Method, String), (StatusCode, Value)>,
+    distribution: Distribution,
+    requests: Mutex<Vec<String>>,
+}
+
+impl MockClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer `GET path` with `body` and status 200
+    pub fn with_json(self, path: &str, body: Value) -> Self {
+        self.with_response(Method::GET, path, StatusCode::OK, body)
+    }
+
+    /// Answer `method path` with `status` and `body`
+    pub fn with_response(
+        mut self,
+        method: Method,
+        path: &str,
+        status: StatusCode,
+        body: Value,
+    ) -> Self {
+        self.responses
+            .insert((method, normalize(path).to_string()), (status, body));
+        self
+    }
+
+    /// Report a distribution other than Elasticsearch
+    pub fn with_distribution(mut self, distribution: Distribution) -> Self {
+        self.distribution = distribution;
+        self
+    }
+
+    /// Requests received so far, as `METHOD path` with the query string
+    pub fn requests(&self) -> Vec<String> {
+        self.requests
+            .lock()
+            .map(|requests| requests.clone())
+            .unwrap_or_default()
+    }
+
+    fn respond(&self, method: &Method, path: &str) -> (StatusCode, Value) {
+        if let Ok(mut requests) = self.requests.lock() {
+            requests.push(format!("{} {}", method, path.trim_start_matches('/')));
+        }
+
+        self.responses
+            .get(&(method.clone(), normalize(path).to_string()))
+            .cloned()
+            .unwrap_or_else(|| {
+                (
+                    StatusCode::NOT_FOUND,
+                    serde_json::json!({
+                        "error": format!("no mock response for {} {}", method, normalize(path))
+                    }),
+                )
+            })
+    }
+
+    /// Canned body for a typed method, failing on non-2xx like [`Client`](super::Client)
+    fn json(&self, method: Method, path: &str) -> Result<Value> {
+        let (status, body) = self.respond(&method, path);
+        if !status.is_success() {
+            return Err(status_error(
+                status,
+                format!("{} {} failed with status: {}", method, path, status),
+            ));
+        }
+        Ok(body)
+    }
+}
+
+/// Path without leading slash or query string
+fn normalize(path: &str) -> &str {
+    let path = path.trim_start_matches('/');
+    path.split_once('?').map_or(path, |(path, _)| path)
+}
+
+#[async_trait]
+impl ElasticsearchClient for MockClient {
+    async fn request(&self, method: Method, path: &str, _body: Option<Value>) -> Result<Response> {
+        let (status, body) = self.respond(&method, path);
+        let response = axum::http::Response::builder()
+            .status(status)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body.to_string())?;
+        Ok(Response::from(response))
+    }
+
+    fn base_url(&self) -> &str {
+        "http://mock"
+    }
+
+    fn distribution(&self) -> Distribution {
+        self.distribution
+    }
+
+    async fn health(&self) -> Result<Value> {
+        self.json(Method::GET, "_cluster/health")
+    }
+
+    async fn info(&self) -> Result<Value> {
+        self.json(Method::GET, "/")
+    }
+
+    async fn cluster_stats(&self) -> Result<Value> {
+        self.json(Method::GET, "_cluster/stats")
+    }
+
+    async fn nodes_info(&self) -> Result<Value> {
+        self.json(Method::GET, "_nodes")
+    }
+
+    async fn nodes_stats(&self) -> Result<Value> {
+        self.json(Method::GET, "_nodes/stats")
+    }
+
+    async fn node_stats(&self, node_id: &str) -> Result<Value> {
+        self.json(Method::GET, &format!("_nodes/{}/stats", node_id))
+    }
+
+    async fn indices_get(&self, index: &str) -> Result<Value> {
+        self.json(Method::GET, index)
+    }
+
+    async fn indices_stats(&self) -> Result<Value> {
+        self.json(Method::GET, "_stats")
+    }
+
+    async fn indices_stats_with_shards(&self, index: &str) -> Result<Value> {
+        self.json(Method::GET, &format!("{}/_stats", index))
+    }
+
+    async fn cluster_state(&self) -> Result<Value> {
+        self.json(Method::GET, "_cluster/state")
+    }
+
+    async fn merge_indices_health(&self, _stats: &mut Value) -> Result<()> {
+        Ok(())
+    }
+
+    async fn cluster_settings(&self, include_defaults: bool) -> Result<Value> {
+        let path = if include_defaults {
+            "_cluster/settings?include_defaults=true"
+        } else {
+            "_cluster/settings"
+        };
+        self.json(Method::GET, path)
+    }
+
+    async fn cat_shards(&self) -> Result<Value> {
+        self.json(Method::GET, "_cat/shards")
+    }
+
+    async fn cat_indices(&self, pattern: Option<&str>) -> Result<Value> {
+        match pattern {
+            Some(pattern) => self.json(Method::GET, &format!("_cat/indices/{}", pattern)),
+            None => self.json(Method::GET, "_cat/indices"),
+        }
+    }
+
+    async fn cat_shards_for_node(&self, _node_id: &str) -> Result<Value> {
+        self.json(Method::GET, "_cat/shards")
+    }
+
+    async fn cat_shards_for_index(&self, index: &str) -> Result<Value> {
+        self.json(Method::GET, &format!("_cat/shards/{}", index))
+    }
+
+    async fn cat_master(&self) -> Result<String> {
+        let master = self.json(Method::GET, "_cat/master")?;
+        master[0]["id"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| anyhow::anyhow!("No master node found in response"))
+    }
+
+    async fn cluster_state_routing_nodes(&self, indices: Option<&[String]>) -> Result<Value> {
+        match indices {
+            Some(indices) if !indices.is_empty() => self.json(
+                Method::GET,
+                &format!("_cluster/state/routing_nodes/{}", indices.join(",")),
+            ),
+            _ => self.json(Method::GET, "_cluster/state/routing_nodes"),
+        }
+    }
+
+    async fn pending_tasks(&self) -> Result<Value> {
+        self.json(Method::GET, "_cluster/pending_tasks")
+    }
+
+    async fn active_recoveries(&self, index: Option<&str>) -> Result<Value> {
+        match index {
+            Some(index) => self.json(Method::GET, &format!("{}/_recovery", index)),
+            None => self.json(Method::GET, "_recovery"),
+        }
+    }
+
+    async fn migration_deprecations(&self) -> Result<Value> {
+        self.json(Method::GET, "_migration/deprecations")
+    }
+
+    async fn get_index_templates(&self) -> Result<Value> {
+        self.json(Method::GET, "_index_template")
+    }
+
+    async fn get_legacy_index_templates(&self) -> Result<Value> {
+        self.json(Method::GET, "_template")
+    }
+
+    async fn get_index_template(&self, name: &str) -> Result<Value> {
+        self.json(Method::GET, &format!("_index_template/{}", name))
+    }
+
+    async fn put_index_template(&self, name: &str, _body: Value) -> Result<Value> {
+        self.json(Method::PUT, &format!("_index_template/{}", name))
+    }
+
+    async fn delete_index_template(&self, name: &str) -> Result<Value> {
+        self.json(Method::DELETE, &format!("_index_template/{}", name))
+    }
+
+    async fn simulate_index_template(&self, _body: Value) -> Result<Value> {
+        self.json(Method::POST, "_index_template/_simulate")
+    }
+
+    async fn get_component_templates(&self) -> Result<Value> {
+        self.json(Method::GET, "_component_template")
+    }
+
+    async fn get_component_template(&self, name: &str) -> Result<Value> {
+        self.json(Method::GET, &format!("_component_template/{}", name))
+    }
+
+    async fn put_component_template(&self, name: &str, _body: Value) -> Result<Value> {
+        self.json(Method::PUT, &format!("_component_template/{}", name))
+    }
+
+    async fn delete_component_template(&self, name: &str) -> Result<Value> {
+        self.json(Method::DELETE, &format!("_component_template/{}", name))
+    }
+
+    async fn get_aliases(&self) -> Result<Value> {
+        self.json(Method::GET, "_alias")
+    }
+
+    async fn get_alias(&self, name: &str) -> Result<Value> {
+        self.json(Method::GET, &format!("{}/_alias", name))
+    }
+
+    async fn put_alias(&self, _name: &str, _body: Value) -> Result<Value> {
+        self.json(Method::POST, "_aliases")
+    }
+
+    async fn delete_alias(&self, name: &str) -> Result<Value> {
+        self.json(Method::DELETE, &format!("_alias/{}", name))
+    }
+
+    async fn snapshot_repositories(&self) -> Result<Value> {
+        self.json(Method::GET, "_snapshot")
+    }
+
+    async fn snapshots(&self, repository: &str) -> Result<Value> {
+        self.json(Method::GET, &format!("_snapshot/{}/_all", repository))
+    }
+
+    async fn ilm_policy(&self, policy: &str) -> Result<Value> {
+        self.json(Method::GET, &format!("_ilm/policy/{}", policy))
+    }
+
+    async fn ilm_explain(&self, index: &str) -> Result<Value> {
+        self.json(Method::GET, &format!("{}/_ilm/explain", index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mock_client_serves_canned_responses() {
+        let client = MockClient::new()
+            .with_json("_cluster/health", serde_json::json!({"status": "green"}))
+            .with_json("/_cat/master", serde_json::json!([{"id": "node-1"}]));
+
+        assert_eq!(client.health().await.expect("health")["status"], "green");
+        assert_eq!(client.cat_master().await.expect("master"), "node-1");
+        assert!(client.nodes_info().await.is_err());
+
+        let response = client
+            .request(Method::GET, "/_cluster/health?level=indices", None)
+            .await
+            .expect("raw request");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Value = response.json().await.expect("json body");
+        assert_eq!(body["status"], "green");
+
+        let missing = client
+            .request(Method::GET, "/_cat/nodes", None)
+            .await
+            .expect("raw request");
+        assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+
+        assert_eq!(
+            client.requests(),
+            vec![
+                "GET _cluster/health",
+                "GET _cat/master",
+                "GET _nodes",
+                "GET _cluster/health?level=indices",
+                "GET _cat/nodes",
+            ]
+        );
+    }
+}
@@ -1,7 +1,20 @@
+pub mod circuit_breaker;
 pub mod client;
 pub mod error;
 pub mod manager;
+#[cfg(any(test, feature = "test-util"))]
+pub mod mock;
+pub mod request_limiter;
+pub mod retry;
+pub mod verify;
 
+pub use circuit_breaker::{CircuitOpenError, CircuitState};
 pub use client::{Client, ElasticsearchClient};
-pub use error::ProxyRequestError;
-pub use manager::{ClusterConnection, ClusterHealth, ClusterInfo, HealthStatus, Manager};
+pub use error::{ClusterRequestError, ProxyRequestError};
+pub use manager::{
+    CachedResource, ClusterConnection, ClusterHealth, ClusterInfo, HealthCacheTtl, HealthEvent,
+    HealthStatus, HealthSubscription, Manager, ResourceCacheTtl, UnreachableClusterPolicy,
+    HEALTH_STREAM_MAX_SUBSCRIBERS,
+};
+pub use request_limiter::{ConcurrencyLimitError, RequestLimiter, RequestPermit};
+pub use retry::RetryPolicy;
@@ -0,0 +1,166 @@
+use crate::config::ClusterConfig;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// How long a request waits for a slot when `request_queue_timeout_seconds`
+/// is not configured
+pub const DEFAULT_REQUEST_QUEUE_TIMEOUT_SECS: u64 = 10;
+
+/// Returned when a request waited too long for a slot on a busy cluster
+#[derive(Error, Debug, Clone)]
+#[error("cluster '{cluster_id}' is busy: {limit} requests already in flight, retry in {retry_after_secs}s")]
+pub struct ConcurrencyLimitError {
+    pub cluster_id: String,
+    pub limit: usize,
+    pub retry_after_secs: u64,
+}
+
+/// Per-cluster limit on requests in flight
+///
+/// With `max_concurrent_requests` configured, requests beyond the limit
+/// queue for a free slot and fail with [`ConcurrencyLimitError`] once they
+/// waited `request_queue_timeout_seconds`. Without it every request gets a
+/// slot at once. Requests in flight are counted either way and published as
+/// the `cluster_requests_in_flight` gauge.
+#[derive(Debug)]
+pub struct RequestLimiter {
+    cluster_id: String,
+    limit: Option<(usize, Arc<Semaphore>)>,
+    queue_timeout: Duration,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl RequestLimiter {
+    /// Create the limiter configured for a cluster
+    pub fn new(config: &ClusterConfig) -> Self {
+        Self {
+            cluster_id: config.id.clone(),
+            limit: config
+                .max_concurrent_requests
+                .map(|limit| (limit, Arc::new(Semaphore::new(limit)))),
+            queue_timeout: Duration::from_secs(
+                config
+                    .request_queue_timeout_seconds
+                    .unwrap_or(DEFAULT_REQUEST_QUEUE_TIMEOUT_SECS),
+            ),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Wait for a slot to send a request
+    ///
+    /// The slot is held until the returned permit is dropped.
+    pub async fn acquire(&self) -> Result<RequestPermit, ConcurrencyLimitError> {
+        let slot = match &self.limit {
+            None => None,
+            Some((limit, semaphore)) => {
+                match tokio::time::timeout(self.queue_timeout, semaphore.clone().acquire_owned())
+                    .await
+                {
+                    Ok(Ok(permit)) => Some(permit),
+                    // The semaphore is never closed; treat it like a timeout
+                    Ok(Err(_)) | Err(_) => {
+                        tracing::warn!(
+                            cluster_id = %self.cluster_id,
+                            limit,
+                            "Request rejected: cluster concurrency limit reached"
+                        );
+                        crate::app_metrics::cluster::record_request_rejected(&self.cluster_id);
+                        return Err(ConcurrencyLimitError {
+                            cluster_id: self.cluster_id.clone(),
+                            limit: *limit,
+                            retry_after_secs: self.queue_timeout.as_secs().max(1),
+                        });
+                    }
+                }
+            }
+        };
+
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        crate::app_metrics::cluster::set_requests_in_flight(&self.cluster_id, in_flight);
+        Ok(RequestPermit {
+            cluster_id: self.cluster_id.clone(),
+            in_flight: self.in_flight.clone(),
+            _slot: slot,
+        })
+    }
+
+    /// Number of requests currently in flight
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+}
+
+/// A request slot on a cluster, released when dropped
+#[derive(Debug)]
+pub struct RequestPermit {
+    cluster_id: String,
+    in_flight: Arc<AtomicUsize>,
+    _slot: Option<OwnedSemaphorePermit>,
+}
+
+impl Drop for RequestPermit {
+    fn drop(&mut self) {
+        let in_flight = self
+            .in_flight
+            .fetch_sub(1, Ordering::SeqCst)
+            .saturating_sub(1);
+        crate::app_metrics::cluster::set_requests_in_flight(&self.cluster_id, in_flight);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(max_concurrent_requests: Option<usize>, queue_timeout_secs: u64) -> RequestLimiter {
+        RequestLimiter::new(&ClusterConfig {
+            id: "prod".to_string(),
+            max_concurrent_requests,
+            request_queue_timeout_seconds: Some(queue_timeout_secs),
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_unbounded_limiter_counts_in_flight() {
+        let limiter = limiter(None, 1);
+
+        let permits: Vec<_> = futures::future::join_all((0..20).map(|_| limiter.acquire()))
+            .await
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .expect("unbounded limiter never rejects");
+        assert_eq!(limiter.in_flight(), 20);
+
+        drop(permits);
+        assert_eq!(limiter.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_requests_over_limit_queue_then_time_out() {
+        let limiter = limiter(Some(2), 1);
+        let first = limiter.acquire().await.expect("first slot");
+        let _second = limiter.acquire().await.expect("second slot");
+
+        // A queued request gets the slot as soon as one is released
+        let queued = limiter.acquire();
+        tokio::pin!(queued);
+        assert!(futures::poll!(&mut queued).is_pending());
+        drop(first);
+        let third = queued.await.expect("slot released");
+        assert_eq!(limiter.in_flight(), 2);
+
+        // Nothing is released now, so the next request times out
+        let err = limiter.acquire().await.expect_err("queue timeout");
+        assert_eq!(err.limit, 2);
+        assert_eq!(err.retry_after_secs, 1);
+        assert_eq!(limiter.in_flight(), 2);
+
+        drop(third);
+        assert_eq!(limiter.in_flight(), 1);
+    }
+}
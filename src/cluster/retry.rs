@@ -0,0 +1,153 @@
+use crate::cluster::error::classify_anyhow;
+use crate::cluster::ProxyRequestError;
+use crate::config::RetryConfig;
+use reqwest::{header, Method, Response, StatusCode};
+use std::time::Duration;
+
+/// Retry policy for transient errors from a cluster
+///
+/// Only idempotent requests (GET and HEAD) are retried, and only on 429, 503
+/// or a connection error. Each retry waits for the `Retry-After` the cluster
+/// asked for, or else a jittered exponential backoff, and retrying stops
+/// once the request as a whole would take longer than the configured budget.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_backoff: Duration,
+    max_retry_time: Duration,
+}
+
+impl RetryPolicy {
+    /// Create the policy configured for a cluster
+    pub fn new(config: &RetryConfig) -> Self {
+        Self {
+            max_retries: config.max_retries,
+            base_backoff: Duration::from_millis(config.base_backoff_ms),
+            max_retry_time: Duration::from_millis(config.max_retry_time_ms),
+        }
+    }
+
+    /// Whether requests with `method` may be retried at all
+    pub fn retries_method(&self, method: &Method) -> bool {
+        self.max_retries > 0 && (*method == Method::GET || *method == Method::HEAD)
+    }
+
+    /// How long to wait before retry number `retry` (starting at 0), or
+    /// `None` when the request should not be retried again
+    ///
+    /// `elapsed` is the time already spent on the request, including
+    /// earlier attempts.
+    pub fn delay(
+        &self,
+        retry: u32,
+        elapsed: Duration,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if retry >= self.max_retries {
+            return None;
+        }
+        let delay = retry_after.unwrap_or_else(|| self.backoff(retry));
+        (elapsed + delay <= self.max_retry_time).then_some(delay)
+    }
+
+    /// Exponential backoff with jitter: a random delay between half and all
+    /// of `base_backoff * 2^retry`
+    fn backoff(&self, retry: u32) -> Duration {
+        let ceiling = self
+            .base_backoff
+            .saturating_mul(1 << retry.min(16))
+            .as_millis() as u64;
+        Duration::from_millis(rand::random_range(ceiling / 2..=ceiling))
+    }
+}
+
+/// Whether a response status is worth retrying
+pub fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::SERVICE_UNAVAILABLE
+}
+
+/// Whether a failed request is worth retrying
+///
+/// Connection errors (refused or reset connections) are; timeouts are not,
+/// since every node already had the full node timeout to answer.
+pub fn is_retryable_error(err: &anyhow::Error) -> bool {
+    matches!(classify_anyhow(err), ProxyRequestError::RequestFailed(_))
+}
+
+/// Delay asked for by a `Retry-After` header given in seconds
+pub fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(max_retries: u32, base_backoff_ms: u64, max_retry_time_ms: u64) -> RetryPolicy {
+        RetryPolicy::new(&RetryConfig {
+            max_retries,
+            base_backoff_ms,
+            max_retry_time_ms,
+        })
+    }
+
+    #[test]
+    fn test_only_idempotent_methods_are_retried() {
+        let policy = policy(2, 100, 5000);
+        assert!(policy.retries_method(&Method::GET));
+        assert!(policy.retries_method(&Method::HEAD));
+        assert!(!policy.retries_method(&Method::POST));
+        assert!(!policy.retries_method(&Method::PUT));
+        assert!(!policy.retries_method(&Method::DELETE));
+
+        assert!(!self::policy(0, 100, 5000).retries_method(&Method::GET));
+    }
+
+    #[test]
+    fn test_delay_backs_off_and_respects_limits() {
+        let policy = policy(3, 100, 1000);
+
+        for retry in 0..3 {
+            let ceiling = Duration::from_millis(100 << retry);
+            let delay = policy
+                .delay(retry, Duration::ZERO, None)
+                .expect("retry allowed");
+            assert!(delay >= ceiling / 2 && delay <= ceiling, "{:?}", delay);
+        }
+        assert_eq!(policy.delay(3, Duration::ZERO, None), None);
+
+        // Retry-After wins over the backoff, within the time budget
+        assert_eq!(
+            policy.delay(0, Duration::ZERO, Some(Duration::from_secs(1))),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            policy.delay(0, Duration::ZERO, Some(Duration::from_secs(2))),
+            None
+        );
+        assert_eq!(
+            policy.delay(
+                1,
+                Duration::from_millis(900),
+                Some(Duration::from_millis(200))
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_retryable_statuses() {
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_retryable_status(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+    }
+}
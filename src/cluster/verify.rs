@@ -0,0 +1,210 @@
+//! Reachability and authentication checks for `secan verify-clusters`
+//! Each configured credential is tried against `_cluster/health` so a
+//! config can be checked against the real clusters before it is deployed
+
+use super::client::{Client, ElasticsearchClient};
+use super::error::ClusterRequestError;
+use super::HealthStatus;
+use crate::config::{ClusterConfig, ClusterWarning};
+use anyhow::Result;
+use std::time::Duration;
+
+/// Outcome of checking one cluster credential
+#[derive(Debug, Clone, PartialEq)]
+pub enum VerifyOutcome {
+    /// The health call succeeded with this status
+    Pass(HealthStatus),
+    /// The cluster could not be reached or rejected the credential
+    Fail(String),
+    /// The cluster was not checked
+    Skip(String),
+}
+
+/// Result for one cluster and credential
+#[derive(Debug, Clone)]
+pub struct VerifyResult {
+    pub cluster_id: String,
+    /// Roles of the credential that was used, or "default" without per-role auth
+    pub credential: String,
+    pub outcome: VerifyOutcome,
+}
+
+impl VerifyResult {
+    pub fn failed(&self) -> bool {
+        matches!(self.outcome, VerifyOutcome::Fail(_))
+    }
+}
+
+/// Check every configured cluster credential, giving each call `timeout`
+///
+/// Clusters with a load-time warning fail with that warning; disabled
+/// clusters are skipped. Clusters are checked concurrently and results keep
+/// the configuration order.
+pub async fn verify_clusters(
+    configs: &[ClusterConfig],
+    warnings: &[ClusterWarning],
+    timeout: Duration,
+) -> Vec<VerifyResult> {
+    let checks = configs.iter().map(|config| async move {
+        let result = |credential: &str, outcome| VerifyResult {
+            cluster_id: config.id.clone(),
+            credential: credential.to_string(),
+            outcome,
+        };
+
+        if !config.enabled {
+            return vec![result(
+                "-",
+                VerifyOutcome::Skip("disabled in configuration".to_string()),
+            )];
+        }
+        if let Some(warning) = warnings.iter().find(|w| w.id == config.id) {
+            return vec![result("-", VerifyOutcome::Fail(warning.reason.clone()))];
+        }
+
+        if config.auth.is_empty() {
+            let outcome = check_client(Client::new(config).await, timeout).await;
+            return vec![result("default", outcome)];
+        }
+
+        let mut results = Vec::new();
+        for credential in &config.auth {
+            let client = Client::new_with_auth(config, Some(&credential.auth)).await;
+            let outcome = check_client(client, timeout).await;
+            results.push(result(&credential.roles.join(","), outcome));
+        }
+        results
+    });
+
+    futures::future::join_all(checks)
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+async fn check_client(client: Result<Client>, timeout: Duration) -> VerifyOutcome {
+    let client = match client {
+        Ok(client) => client,
+        Err(e) => return VerifyOutcome::Fail(failure_reason(&e)),
+    };
+
+    match tokio::time::timeout(timeout, client.health()).await {
+        Err(_) => VerifyOutcome::Fail(format!(
+            "{}: no response within {}s",
+            ClusterRequestError::Timeout.summary(),
+            timeout.as_secs_f64()
+        )),
+        Ok(Err(e)) => VerifyOutcome::Fail(failure_reason(&e)),
+        Ok(Ok(health)) => match health["status"].as_str() {
+            Some("green") => VerifyOutcome::Pass(HealthStatus::Green),
+            Some("yellow") => VerifyOutcome::Pass(HealthStatus::Yellow),
+            Some("red") => VerifyOutcome::Pass(HealthStatus::Red),
+            _ => VerifyOutcome::Fail("health response has no status".to_string()),
+        },
+    }
+}
+
+/// The kind of failure, then the outermost and innermost error messages
+///
+/// The full chain repeats the URL at every level, which is unreadable in a
+/// table. Leading with "authentication failed" or "unreachable" tells the
+/// operator whether to fix credentials or the network.
+fn failure_reason(error: &anyhow::Error) -> String {
+    let root = error.root_cause().to_string();
+    let top = error.to_string();
+    let detail = if top == root {
+        top
+    } else {
+        format!("{}: {}", top, root)
+    };
+    match ClusterRequestError::classify(error) {
+        Some(kind) => format!("{}: {}", kind.summary(), detail),
+        None => detail,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ClusterAuth, RoleCredential};
+    use wiremock::matchers::{header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_verify_clusters_reports_each_credential() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/_cluster/health"))
+            .and(header_exists("authorization"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "cluster_name": "test",
+                "status": "yellow"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/_cluster/health"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let mut authenticated = ClusterConfig::new("auth".to_string(), vec![server.uri()]);
+        authenticated.auth = vec![RoleCredential {
+            roles: vec!["admin".to_string()],
+            auth: ClusterAuth::Basic {
+                username: "elastic".to_string(),
+                password: "secret".to_string(),
+            },
+        }];
+        let anonymous = ClusterConfig::new("anon".to_string(), vec![server.uri()]);
+        let mut disabled = ClusterConfig::new("off".to_string(), vec![server.uri()]);
+        disabled.enabled = false;
+
+        let results = verify_clusters(
+            &[authenticated, anonymous, disabled],
+            &[],
+            Duration::from_secs(5),
+        )
+        .await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].cluster_id, "auth");
+        assert_eq!(results[0].credential, "admin");
+        assert_eq!(
+            results[0].outcome,
+            VerifyOutcome::Pass(HealthStatus::Yellow)
+        );
+        assert!(results[1].failed());
+        assert!(
+            matches!(&results[1].outcome, VerifyOutcome::Fail(reason) if reason.starts_with("authentication failed: ")),
+            "{:?}",
+            results[1].outcome
+        );
+        assert!(matches!(results[2].outcome, VerifyOutcome::Skip(_)));
+    }
+
+    #[tokio::test]
+    async fn test_verify_clusters_fails_unreachable_and_warned_clusters() {
+        let unreachable = ClusterConfig::new("down".to_string(), vec!["http://127.0.0.1:9".into()]);
+        let warned = ClusterConfig::new("broken".to_string(), vec!["http://127.0.0.1:9".into()]);
+        let warnings = [ClusterWarning {
+            id: "broken".to_string(),
+            reason: "missing credentials".to_string(),
+        }];
+
+        let results =
+            verify_clusters(&[unreachable, warned], &warnings, Duration::from_secs(5)).await;
+
+        assert!(results.iter().all(VerifyResult::failed));
+        assert!(
+            matches!(&results[0].outcome, VerifyOutcome::Fail(reason) if reason.starts_with("unreachable: ")),
+            "{:?}",
+            results[0].outcome
+        );
+        assert_eq!(
+            results[1].outcome,
+            VerifyOutcome::Fail("missing credentials".to_string())
+        );
+    }
+}
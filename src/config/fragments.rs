@@ -0,0 +1,191 @@
+//! Config fragments read from the directory in `SECAN_CONFIG_DIR`
+//! Fragments are merged after the config files and before `SECAN_*`
+//! environment overrides. Unlike other config files they add to the
+//! `clusters` and `auth.roles` lists instead of replacing them, so each
+//! fragment can define its own clusters.
+
+use super::includes;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming the fragment directory (e.g. `/etc/secan/conf.d`)
+pub(super) const CONFIG_DIR_ENV_VAR: &str = "SECAN_CONFIG_DIR";
+
+/// Lists concatenated across fragments, as paths from the config root
+const APPENDED_LISTS: &[&[&str]] = &[&["clusters"], &["auth", "roles"]];
+
+/// Fragment directory selected through `SECAN_CONFIG_DIR`, if any
+pub(super) fn config_dir() -> Option<PathBuf> {
+    std::env::var(CONFIG_DIR_ENV_VAR)
+        .ok()
+        .map(|dir| dir.trim().to_string())
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// `*.yaml` and `*.yml` files in `dir`, sorted by name
+///
+/// Hidden files are skipped, which also skips the `..data` entries of
+/// Kubernetes ConfigMap mounts. A missing directory yields no fragments; at
+/// startup this runs before logging is set up, so it is also reported as a
+/// config warning.
+pub(super) fn fragment_files(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        tracing::warn!(
+            dir = %dir.display(),
+            "{} is not a directory, no config fragments loaded",
+            CONFIG_DIR_ENV_VAR
+        );
+        return Ok(Vec::new());
+    }
+
+    let entries = std::fs::read_dir(dir).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to read configuration directory {}: {}",
+            dir.display(),
+            e
+        )
+    })?;
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Failed to read configuration directory {}: {}",
+                    dir.display(),
+                    e
+                )
+            })?
+            .path();
+        let is_fragment = path
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .is_some_and(|name| {
+                !name.starts_with('.') && (name.ends_with(".yaml") || name.ends_with(".yml"))
+            });
+        if is_fragment && path.is_file() {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Read a fragment, with its `includes` and `${VAR}` substitution, as JSON
+pub(super) fn load_fragment(path: &Path) -> anyhow::Result<Value> {
+    let mut builder = config::Config::builder();
+    for source in includes::resolve(&[path.to_path_buf()])? {
+        builder = builder.add_source(config::File::from_str(
+            &source.content,
+            config::FileFormat::Yaml,
+        ));
+    }
+    builder
+        .build()
+        .and_then(|fragment| fragment.try_deserialize())
+        .map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to parse configuration fragment {}: {}",
+                path.display(),
+                e
+            )
+        })
+}
+
+/// Deep-merge `fragment` into `base`
+///
+/// Maps are merged key by key and the fragment wins on other values, except
+/// for the lists in [`APPENDED_LISTS`], which are concatenated.
+pub(super) fn merge(base: &mut Value, fragment: Value) {
+    merge_at(base, fragment, &mut Vec::new());
+}
+
+fn merge_at(base: &mut Value, fragment: Value, path: &mut Vec<String>) {
+    match (base, fragment) {
+        (Value::Object(base), Value::Object(fragment)) => {
+            for (key, value) in fragment {
+                path.push(key.clone());
+                match base.get_mut(&key) {
+                    Some(existing) => merge_at(existing, value, path),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+                path.pop();
+            }
+        }
+        (Value::Array(base), Value::Array(fragment)) if is_appended_list(path) => {
+            base.extend(fragment);
+        }
+        (base, fragment) => *base = fragment,
+    }
+}
+
+fn is_appended_list(path: &[String]) -> bool {
+    APPENDED_LISTS
+        .iter()
+        .any(|list| list.iter().copied().eq(path.iter().map(String::as_str)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_fragment_files_are_sorted_yaml_files() {
+        let dir = TempDir::new().expect("temp dir");
+        for name in [
+            "20-staging.yaml",
+            "10-prod.yml",
+            "README.md",
+            ".hidden.yaml",
+        ] {
+            fs::write(dir.path().join(name), "clusters: []\n").expect("write file");
+        }
+        fs::create_dir(dir.path().join("30-dir.yaml")).expect("create dir");
+
+        let names: Vec<String> = fragment_files(dir.path())
+            .expect("list fragments")
+            .iter()
+            .filter_map(|path| path.file_name())
+            .map(|name| name.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, vec!["10-prod.yml", "20-staging.yaml"]);
+
+        let empty = TempDir::new().expect("temp dir");
+        assert!(fragment_files(empty.path()).expect("empty dir").is_empty());
+        assert!(fragment_files(&dir.path().join("missing"))
+            .expect("missing dir")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_merge_appends_clusters_and_roles() {
+        let mut base = json!({
+            "server": {"port": 9000, "host": "0.0.0.0", "cors_allowed_origins": ["https://a"]},
+            "auth": {"mode": "local_users", "roles": [{"name": "admin"}]},
+            "clusters": [{"id": "prod"}]
+        });
+
+        merge(
+            &mut base,
+            json!({
+                "server": {"port": 9443, "cors_allowed_origins": ["https://b"]},
+                "auth": {"roles": [{"name": "viewer"}]},
+                "clusters": [{"id": "staging"}]
+            }),
+        );
+
+        assert_eq!(
+            base,
+            json!({
+                "server": {"port": 9443, "host": "0.0.0.0", "cors_allowed_origins": ["https://b"]},
+                "auth": {"mode": "local_users", "roles": [{"name": "admin"}, {"name": "viewer"}]},
+                "clusters": [{"id": "prod"}, {"id": "staging"}]
+            })
+        );
+    }
+}
@@ -0,0 +1,201 @@
+//! `includes:` directive for splitting configuration across files
+//! Included files are merged before the file that includes them, so the
+//! including file wins on conflicting keys
+
+use super::Config;
+use std::path::{Path, PathBuf};
+use yaml_rust2::{Yaml, YamlLoader};
+
+/// Top-level key listing the files a config file includes
+const INCLUDES_KEY: &str = "includes";
+
+/// A config file's contents after `${VAR}` substitution
+pub(super) struct ConfigSource {
+    pub path: PathBuf,
+    pub content: String,
+}
+
+/// Expand `includes` for the given files, in merge order (lowest priority first)
+///
+/// Include paths are relative to the including file. A file included from
+/// several places is merged each time it is reached; a file that includes
+/// itself, directly or indirectly, is an error.
+pub(super) fn resolve(files: &[PathBuf]) -> anyhow::Result<Vec<ConfigSource>> {
+    let mut sources = Vec::new();
+    for path in files {
+        resolve_file(path, &mut Vec::new(), &mut sources)?;
+    }
+    Ok(sources)
+}
+
+fn resolve_file(
+    path: &Path,
+    chain: &mut Vec<PathBuf>,
+    sources: &mut Vec<ConfigSource>,
+) -> anyhow::Result<()> {
+    let canonical = path.canonicalize().map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to read configuration file {}: {}",
+            path.display(),
+            e
+        )
+    })?;
+    if let Some(start) = chain.iter().position(|seen| *seen == canonical) {
+        let cycle: Vec<String> = chain[start..]
+            .iter()
+            .chain(std::iter::once(&canonical))
+            .map(|p| p.display().to_string())
+            .collect();
+        anyhow::bail!("Configuration include cycle: {}", cycle.join(" -> "));
+    }
+
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to read configuration file {}: {}",
+            path.display(),
+            e
+        )
+    })?;
+    let content = Config::substitute_env_vars(&content);
+
+    let base = canonical
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    chain.push(canonical);
+    for include in include_paths(&content) {
+        let included = base.join(&include);
+        if !included.is_file() {
+            anyhow::bail!(
+                "Configuration file {} includes {}, which does not exist",
+                path.display(),
+                included.display()
+            );
+        }
+        resolve_file(&included, chain, sources)?;
+    }
+    chain.pop();
+
+    sources.push(ConfigSource {
+        path: path.to_path_buf(),
+        content,
+    });
+    Ok(())
+}
+
+/// Read the `includes` list from a file's YAML
+///
+/// Content that does not parse is left for the config loader to report.
+fn include_paths(content: &str) -> Vec<String> {
+    let Ok(docs) = YamlLoader::load_from_str(content) else {
+        return Vec::new();
+    };
+    match docs.first().map(|doc| &doc[INCLUDES_KEY]) {
+        Some(Yaml::Array(items)) => items
+            .iter()
+            .filter_map(|item| item.as_str().map(str::to_string))
+            .collect(),
+        Some(Yaml::String(single)) => vec![single.clone()],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write(dir: &Path, name: &str, content: &str) -> PathBuf {
+        let path = dir.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("create dir");
+        }
+        fs::write(&path, content).expect("write file");
+        path
+    }
+
+    fn names(sources: &[ConfigSource]) -> Vec<String> {
+        sources
+            .iter()
+            .map(|s| {
+                s.path
+                    .file_name()
+                    .expect("file name")
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_includes_are_merged_first_and_relative_to_includer() {
+        let dir = TempDir::new().expect("temp dir");
+        write(dir.path(), "shared/roles.yaml", "auth:\n  roles: []\n");
+        write(
+            dir.path(),
+            "shared/clusters.yaml",
+            "includes: [roles.yaml]\nclusters: []\n",
+        );
+        let main = write(
+            dir.path(),
+            "config.yaml",
+            "includes:\n  - shared/clusters.yaml\n",
+        );
+
+        let sources = resolve(&[main]).expect("resolve");
+
+        assert_eq!(
+            names(&sources),
+            vec!["roles.yaml", "clusters.yaml", "config.yaml"]
+        );
+    }
+
+    #[test]
+    fn test_missing_include_is_an_error() {
+        let dir = TempDir::new().expect("temp dir");
+        let main = write(dir.path(), "config.yaml", "includes: [nope.yaml]\n");
+
+        let err = resolve(&[main]).err().expect("missing include");
+        assert!(err.to_string().contains("nope.yaml"), "{}", err);
+        assert!(err.to_string().contains("does not exist"), "{}", err);
+    }
+
+    #[test]
+    fn test_include_cycle_is_an_error() {
+        let dir = TempDir::new().expect("temp dir");
+        write(dir.path(), "a.yaml", "includes: [b.yaml]\n");
+        write(dir.path(), "b.yaml", "includes: [a.yaml]\n");
+        let main = write(dir.path(), "config.yaml", "includes: [a.yaml]\n");
+
+        let err = resolve(&[main]).err().expect("cycle");
+        let message = err.to_string();
+        assert!(message.contains("include cycle"), "{}", message);
+        assert!(message.contains("a.yaml -> "), "{}", message);
+    }
+
+    #[test]
+    fn test_same_file_from_two_includers_is_not_a_cycle() {
+        let dir = TempDir::new().expect("temp dir");
+        write(dir.path(), "common.yaml", "clusters: []\n");
+        write(dir.path(), "a.yaml", "includes: [common.yaml]\n");
+        write(dir.path(), "b.yaml", "includes: [common.yaml]\n");
+        let main = write(dir.path(), "config.yaml", "includes: [a.yaml, b.yaml]\n");
+
+        assert!(resolve(&[main]).is_ok());
+    }
+
+    #[test]
+    fn test_include_paths_substitute_env_vars() {
+        let dir = TempDir::new().expect("temp dir");
+        write(dir.path(), "staging.yaml", "clusters: []\n");
+        let main = write(
+            dir.path(),
+            "config.yaml",
+            "includes: [\"${SECAN_TEST_INCLUDE_ENV:-staging}.yaml\"]\n",
+        );
+
+        let sources = resolve(&[main]).expect("resolve");
+        assert_eq!(names(&sources), vec!["staging.yaml", "config.yaml"]);
+    }
+}
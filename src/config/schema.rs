@@ -0,0 +1,47 @@
+//! JSON Schema of the config file format for `secan config schema`
+//! Generated from the config types, so it always matches what the loader accepts
+
+use super::Config;
+use serde_json::Value;
+
+impl Config {
+    /// JSON Schema describing the config file, for editor validation and
+    /// autocompletion
+    pub fn json_schema() -> Value {
+        let mut schema = schemars::schema_for!(Config);
+        schema.insert("title".to_string(), "Secan configuration".into());
+        schema.to_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_describes_enums_and_tagged_auth() {
+        let schema = Config::json_schema();
+        let defs = &schema["$defs"];
+
+        let modes: Vec<&str> = defs["AuthMode"]["enum"]
+            .as_array()
+            .expect("auth modes")
+            .iter()
+            .filter_map(Value::as_str)
+            .collect();
+        assert_eq!(modes, vec!["local_users", "oidc", "ldap", "open"]);
+
+        let auth_types: Vec<&str> = defs["ClusterAuth"]["oneOf"]
+            .as_array()
+            .expect("cluster auth variants")
+            .iter()
+            .filter_map(|variant| variant["properties"]["type"]["const"].as_str())
+            .collect();
+        assert!(auth_types.contains(&"basic"), "{:?}", auth_types);
+        assert!(auth_types.contains(&"api_key"), "{:?}", auth_types);
+
+        // Cluster IDs and nodes are required, everything with a default is not
+        let required = &defs["ClusterConfig"]["required"];
+        assert_eq!(required, &serde_json::json!(["id", "nodes"]));
+    }
+}
@@ -0,0 +1,218 @@
+//! Startup warnings for configurations that are valid but risky
+//! These never stop the server; they are logged so insecure settings are
+//! noticed before a deployment reaches production
+
+use super::{fragments, AuthMode, Config};
+use std::fmt;
+use std::path::Path;
+
+/// Session lifetimes above this many minutes (7 days) are flagged
+const LONG_SESSION_TIMEOUT_MINUTES: u64 = 7 * 24 * 60;
+
+/// A valid but risky configuration setting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigWarning {
+    /// Path of the setting, e.g. `clusters[prod].tls.verify`
+    pub field: String,
+    pub message: String,
+}
+
+impl ConfigWarning {
+    fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+impl Config {
+    /// Validate the configuration and collect warnings for risky settings
+    ///
+    /// Warnings are reported even when validation fails, so callers can show
+    /// both at once.
+    pub fn validate_with_warnings(&self) -> (anyhow::Result<()>, Vec<ConfigWarning>) {
+        (self.validate(), self.warnings())
+    }
+
+    fn warnings(&self) -> Vec<ConfigWarning> {
+        let mut warnings = Vec::new();
+
+        for cluster in &self.clusters {
+            if !cluster.tls.verify {
+                warnings.push(ConfigWarning::new(
+                    format!("clusters[{}].tls.verify", cluster.id),
+                    "TLS certificate verification is disabled",
+                ));
+            }
+        }
+
+        if self.auth.mode == AuthMode::Open {
+            for cluster in &self.clusters {
+                if cluster.nodes.iter().any(|node| node.starts_with("http://")) {
+                    warnings.push(ConfigWarning::new(
+                        format!("clusters[{}].nodes", cluster.id),
+                        "open auth mode with a cluster reachable over plain http; anyone who can reach Secan can use this cluster unencrypted",
+                    ));
+                }
+            }
+        }
+
+        if self.auth.mode != AuthMode::Open
+            && self.auth.session_timeout_minutes > LONG_SESSION_TIMEOUT_MINUTES
+        {
+            warnings.push(ConfigWarning::new(
+                "auth.session_timeout_minutes",
+                format!(
+                    "sessions last {} minutes (more than {} days)",
+                    self.auth.session_timeout_minutes,
+                    LONG_SESSION_TIMEOUT_MINUTES / (24 * 60)
+                ),
+            ));
+        }
+
+        if self
+            .auth
+            .ldap
+            .as_ref()
+            .is_some_and(|ldap| ldap.tls_skip_verify)
+        {
+            warnings.push(ConfigWarning::new(
+                "auth.ldap.tls_skip_verify",
+                "LDAP server certificate verification is disabled",
+            ));
+        }
+
+        if !self.notifications.webhooks.is_empty() && !self.cache.background_health_refresh {
+            warnings.push(ConfigWarning::new(
+                "notifications.webhooks",
+                "webhooks only fire when cache.background_health_refresh is enabled; status changes are otherwise noticed only when someone views the cluster",
+            ));
+        }
+
+        warnings.extend(config_dir_warning(fragments::config_dir().as_deref()));
+
+        warnings
+    }
+}
+
+/// Warning for a `SECAN_CONFIG_DIR` that is not a directory
+///
+/// Fragments are loaded before logging is set up, so a missing directory is
+/// reported here to be logged with the other startup warnings.
+fn config_dir_warning(dir: Option<&Path>) -> Option<ConfigWarning> {
+    let dir = dir.filter(|dir| !dir.is_dir())?;
+    Some(ConfigWarning::new(
+        fragments::CONFIG_DIR_ENV_VAR,
+        format!(
+            "{} is not a directory, no config fragments loaded",
+            dir.display()
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ClusterConfig;
+
+    fn config(nodes: &[&str]) -> Config {
+        Config {
+            clusters: vec![ClusterConfig::new(
+                "prod".to_string(),
+                nodes.iter().map(|n| n.to_string()).collect(),
+            )],
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn test_secure_config_has_no_warnings() {
+        let config = config(&["https://localhost:9200"]);
+
+        let (result, warnings) = config.validate_with_warnings();
+        assert!(result.is_ok());
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_tls_verify_disabled_warns() {
+        let mut config = config(&["https://localhost:9200"]);
+        config.clusters[0].tls.verify = false;
+
+        let (_, warnings) = config.validate_with_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].field, "clusters[prod].tls.verify");
+    }
+
+    #[test]
+    fn test_open_mode_with_http_cluster_warns() {
+        let mut config = config(&["http://localhost:9200"]);
+
+        let (_, warnings) = config.validate_with_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].field, "clusters[prod].nodes");
+
+        config.auth.mode = AuthMode::LocalUsers;
+        let (_, warnings) = config.validate_with_warnings();
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_long_session_timeout_warns() {
+        let mut config = config(&["https://localhost:9200"]);
+        config.auth.mode = AuthMode::LocalUsers;
+        config.auth.session_timeout_minutes = 30 * 24 * 60;
+
+        let (_, warnings) = config.validate_with_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].field, "auth.session_timeout_minutes");
+    }
+
+    #[test]
+    fn test_webhooks_without_background_refresh_warn() {
+        let mut config = config(&["https://localhost:9200"]);
+        config.notifications.webhooks = vec![crate::config::WebhookConfig {
+            url: "https://hooks.example.com/secan".to_string(),
+            on_status: vec![crate::cluster::HealthStatus::Red],
+            format: crate::config::WebhookFormat::Generic,
+        }];
+
+        let (_, warnings) = config.validate_with_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].field, "notifications.webhooks");
+
+        config.cache.background_health_refresh = true;
+        let (_, warnings) = config.validate_with_warnings();
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_missing_config_dir_warns() {
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        assert!(config_dir_warning(None).is_none());
+        assert!(config_dir_warning(Some(dir.path())).is_none());
+
+        let warning =
+            config_dir_warning(Some(&dir.path().join("missing"))).expect("missing dir warns");
+        assert_eq!(warning.field, "SECAN_CONFIG_DIR");
+        assert!(warning.message.contains("not a directory"));
+    }
+
+    #[test]
+    fn test_warnings_are_reported_alongside_errors() {
+        let mut config = config(&["http://localhost:9200"]);
+        config.clusters[0].tls.verify = false;
+        config.clusters[0].node_timeout_seconds = Some(0);
+
+        let (result, warnings) = config.validate_with_warnings();
+        assert!(result.is_err());
+        assert_eq!(warnings.len(), 2);
+    }
+}
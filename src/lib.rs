@@ -13,6 +13,12 @@ pub mod assets;
 /// Configured via standard OTEL_* environment variables.
 pub mod telemetry;
 
+/// Log format and output
+///
+/// Builds the tracing subscriber's formatting layer from the `logging` config
+/// section: JSON or pretty lines, written to stdout, stderr or a rotated file.
+pub mod logging;
+
 /// Authentication and authorization system
 ///
 /// Provides multiple authentication modes including:
@@ -50,6 +56,9 @@ pub mod middleware;
 /// Audit logging for proxied Elasticsearch calls
 pub mod audit;
 
+/// Webhook notifications for cluster health status changes
+pub mod notifications;
+
 /// Error types and handling
 ///
 /// Provides centralized error types using thiserror for domain-specific errors
@@ -89,6 +98,11 @@ pub mod tls;
 /// Provides application-level metrics for observability.
 pub mod app_metrics;
 
+/// Build information
+///
+/// Version, git commit, build time and compiler embedded by the build script.
+pub mod build_info;
+
 // Re-export commonly used types
 pub use config::Config;
 pub use server::Server;
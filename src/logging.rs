@@ -0,0 +1,205 @@
+//! Log formatting and output for the tracing subscriber
+//!
+//! Builds the `fmt` layer from the `logging` config section. The level filter
+//! is still taken from `RUST_LOG`.
+
+use crate::config::{LogFormat, LogOutput, LogRotation, LoggingConfig};
+use anyhow::{Context, Result};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::Subscriber;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+/// Install the global tracing subscriber without OpenTelemetry
+pub fn init(config: &LoggingConfig) -> Result<()> {
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(fmt_layer(config)?)
+        .init();
+
+    Ok(())
+}
+
+/// Formatting layer writing log lines as configured
+///
+/// Fails if the log file cannot be opened.
+pub fn fmt_layer<S>(config: &LoggingConfig) -> Result<Box<dyn Layer<S> + Send + Sync>>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let writer = match config.output {
+        LogOutput::Stdout => BoxMakeWriter::new(io::stdout),
+        LogOutput::Stderr => BoxMakeWriter::new(io::stderr),
+        LogOutput::File => {
+            let path = config
+                .file_path
+                .as_deref()
+                .context("logging.file_path is required when logging.output is file")?;
+            BoxMakeWriter::new(Mutex::new(RollingFile::open(path, &config.rotation)?))
+        }
+    };
+
+    let layer = tracing_subscriber::fmt::layer()
+        .with_writer(writer)
+        .with_ansi(config.output != LogOutput::File)
+        .with_target(true)
+        .with_thread_ids(true)
+        .with_line_number(true);
+
+    Ok(match config.format {
+        LogFormat::Json => layer.json().boxed(),
+        LogFormat::Pretty => layer.pretty().boxed(),
+    })
+}
+
+/// Log file that is rotated once it reaches a maximum size
+///
+/// Each log line is written in a single `write` call, so lines are never
+/// split across files.
+#[derive(Debug)]
+pub struct RollingFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_bytes: u64,
+    max_files: usize,
+}
+
+impl RollingFile {
+    /// Open `path` for appending, creating it and its directory if needed
+    pub fn open(path: &Path, rotation: &LogRotation) -> Result<Self> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create log directory {}", dir.display()))?;
+        }
+        let file = open_append(path)
+            .with_context(|| format!("Failed to open log file {}", path.display()))?;
+        let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            size,
+            max_bytes: rotation.max_size_mb.saturating_mul(1024 * 1024),
+            max_files: rotation.max_files,
+        })
+    }
+
+    /// Shift `<file>.N` to `<file>.N+1`, move the current file to `<file>.1`
+    /// and start a new one
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        if self.max_files == 0 {
+            self.file = File::create(&self.path)?;
+            self.size = 0;
+            return Ok(());
+        }
+
+        let _ = fs::remove_file(self.rotated_path(self.max_files));
+        for n in (1..self.max_files).rev() {
+            let from = self.rotated_path(n);
+            if from.exists() {
+                fs::rename(&from, self.rotated_path(n + 1))?;
+            }
+        }
+        fs::rename(&self.path, self.rotated_path(1))?;
+
+        self.file = open_append(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+
+    fn rotated_path(&self, n: usize) -> PathBuf {
+        let mut name = self.path.clone().into_os_string();
+        name.push(format!(".{}", n));
+        PathBuf::from(name)
+    }
+}
+
+impl Write for RollingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        self.file.write_all(buf)?;
+        self.size += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rolling_file(path: &Path, max_bytes: u64, max_files: usize) -> RollingFile {
+        let mut file = RollingFile::open(path, &LogRotation::default()).expect("open log file");
+        file.max_bytes = max_bytes;
+        file.max_files = max_files;
+        file
+    }
+
+    #[test]
+    fn test_rolling_file_rotates_and_keeps_max_files() {
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let path = dir.path().join("logs").join("secan.log");
+        let mut file = rolling_file(&path, 10, 2);
+
+        for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+            file.write_all(line.as_bytes()).expect("write line");
+        }
+
+        let read = |p: PathBuf| fs::read_to_string(p).expect("read log file");
+        assert_eq!(read(path.clone()), "fourth\n");
+        assert_eq!(read(dir.path().join("logs/secan.log.1")), "third\n");
+        assert_eq!(read(dir.path().join("logs/secan.log.2")), "second\n");
+        assert!(!dir.path().join("logs/secan.log.3").exists());
+    }
+
+    #[test]
+    fn test_rolling_file_appends_to_existing_file() {
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let path = dir.path().join("secan.log");
+        fs::write(&path, "old\n").expect("write existing log");
+
+        let mut file = rolling_file(&path, 1024, 1);
+        file.write_all(b"new\n").expect("write line");
+
+        assert_eq!(
+            fs::read_to_string(&path).expect("read log file"),
+            "old\nnew\n"
+        );
+    }
+
+    #[test]
+    fn test_rolling_file_without_backups_truncates() {
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let path = dir.path().join("secan.log");
+        let mut file = rolling_file(&path, 8, 0);
+
+        file.write_all(b"first\n").expect("write line");
+        file.write_all(b"second\n").expect("write line");
+
+        assert_eq!(
+            fs::read_to_string(&path).expect("read log file"),
+            "second\n"
+        );
+        assert!(!dir.path().join("secan.log.1").exists());
+    }
+}
@@ -1,18 +1,283 @@
 use anyhow::Context;
-use secan::auth::{SessionConfig, SessionManager};
-use secan::cluster::Manager as ClusterManager;
-use secan::config::Config;
+use secan::auth::local::{BCRYPT_MAX_COST, BCRYPT_MIN_COST};
+use secan::auth::{
+    hash_password_argon2, hash_password_with_cost, HashAlgorithm, SessionConfig, SessionManager,
+};
+use secan::cluster::verify::{verify_clusters, VerifyOutcome};
+use secan::cluster::{
+    HealthCacheTtl, HealthStatus, Manager as ClusterManager, ResourceCacheTtl,
+    UnreachableClusterPolicy,
+};
+use secan::config::{ClusterWarning, Config, DumpFormat};
+use secan::notifications::HealthNotifier;
 use secan::telemetry;
+use secan::tls::TlsManager;
 use secan::Server;
 use std::env;
 use std::sync::Arc;
 use tracing::info;
 
-/// Generate bcrypt password hash
-fn generate_password_hash(password: &str) -> Result<String, anyhow::Error> {
-    use bcrypt::{hash, DEFAULT_COST};
-    hash(password, DEFAULT_COST)
-        .map_err(|e| anyhow::anyhow!("Failed to generate password hash: {}", e))
+/// Seconds each `verify-clusters` health call may take unless `--timeout` is given
+const DEFAULT_VERIFY_TIMEOUT_SECS: u64 = 10;
+
+/// Parse `[--algorithm bcrypt|argon2] [--cost <4-31>] <value>` for the hash subcommands
+///
+/// Returns the value to hash, the algorithm (bcrypt by default) and the
+/// bcrypt cost if one was given.
+fn parse_hash_args(args: &[String]) -> Result<(&str, HashAlgorithm, Option<u32>), String> {
+    let mut algorithm = HashAlgorithm::default();
+    let mut cost = None;
+    let mut value = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, raw)) if flag.starts_with("--") => (flag, Some(raw)),
+            _ => (arg.as_str(), None),
+        };
+        if !matches!(flag, "--cost" | "--algorithm") {
+            if value.is_some() {
+                return Err(format!("Unexpected argument '{arg}'"));
+            }
+            value = Some(arg.as_str());
+            continue;
+        }
+
+        let raw = match inline {
+            Some(raw) => raw,
+            None => args
+                .next()
+                .ok_or_else(|| format!("{flag} requires a value"))?
+                .as_str(),
+        };
+        if flag == "--algorithm" {
+            algorithm = raw.parse().map_err(|e| format!("{e}"))?;
+        } else {
+            let parsed = raw
+                .parse()
+                .ok()
+                .filter(|cost| (BCRYPT_MIN_COST..=BCRYPT_MAX_COST).contains(cost))
+                .ok_or_else(|| {
+                    format!(
+                        "Invalid cost '{raw}': must be between {BCRYPT_MIN_COST} and {BCRYPT_MAX_COST}"
+                    )
+                })?;
+            cost = Some(parsed);
+        }
+    }
+
+    if algorithm == HashAlgorithm::Argon2 && cost.is_some() {
+        return Err("--cost only applies to bcrypt".to_string());
+    }
+    let value = value.ok_or_else(|| "missing value to hash".to_string())?;
+    Ok((value, algorithm, cost))
+}
+
+/// Hash a password or token with the chosen algorithm
+fn hash_secret(value: &str, algorithm: HashAlgorithm, cost: Option<u32>) -> anyhow::Result<String> {
+    match algorithm {
+        HashAlgorithm::Bcrypt => {
+            hash_password_with_cost(value, cost.unwrap_or(bcrypt::DEFAULT_COST))
+        }
+        HashAlgorithm::Argon2 => hash_password_argon2(value),
+    }
+}
+
+/// Load and validate the configuration without starting the server
+///
+/// Uses the default config file lookup unless `path` is given. No cluster
+/// connections are made. Returns the process exit code.
+fn validate_config(path: Option<&str>) -> i32 {
+    match load_config(path) {
+        Ok((config, warnings)) => {
+            println!("configuration valid");
+            println!("  auth mode: {}", auth_mode_name(&config.auth.mode));
+            println!("  clusters:  {}", config.clusters.len());
+            for warning in &warnings {
+                println!("  warning:   cluster '{}': {}", warning.id, warning.reason);
+            }
+            let (_, config_warnings) = config.validate_with_warnings();
+            for warning in &config_warnings {
+                println!("  warning:   {}", warning);
+            }
+            0
+        }
+        Err(e) => {
+            eprintln!("configuration invalid: {e:#}");
+            1
+        }
+    }
+}
+
+/// Print the effective configuration with secrets redacted
+///
+/// Accepts `[--format yaml|json|toml] [path]`. Returns the process exit code.
+fn config_dump(args: &[String]) -> i32 {
+    let mut format = DumpFormat::default();
+    let mut path = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = match arg.as_str() {
+            "--format" => match args.next() {
+                Some(value) => value.as_str(),
+                None => {
+                    eprintln!("--format requires a value (yaml, json or toml)");
+                    return 2;
+                }
+            },
+            other => match other.strip_prefix("--format=") {
+                Some(value) => value,
+                None => {
+                    path = Some(other);
+                    continue;
+                }
+            },
+        };
+        format = match value.parse() {
+            Ok(format) => format,
+            Err(e) => {
+                eprintln!("{e}");
+                return 2;
+            }
+        };
+    }
+
+    let (config, warnings) = match load_config(path) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("configuration invalid: {e:#}");
+            return 1;
+        }
+    };
+    for warning in &warnings {
+        eprintln!("warning: cluster '{}': {}", warning.id, warning.reason);
+    }
+
+    match config.dump(format) {
+        Ok(output) => {
+            print!("{output}");
+            0
+        }
+        Err(e) => {
+            eprintln!("Failed to render configuration: {e:#}");
+            1
+        }
+    }
+}
+
+/// Check that every configured cluster answers a health call
+///
+/// Accepts `[--timeout <seconds>] [path]` and prints a PASS/FAIL table.
+/// Returns the process exit code: non-zero if any cluster failed.
+async fn verify_clusters_command(args: &[String]) -> i32 {
+    let mut timeout_secs = DEFAULT_VERIFY_TIMEOUT_SECS;
+    let mut path = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = match arg.as_str() {
+            "--timeout" => match args.next() {
+                Some(value) => value.as_str(),
+                None => {
+                    eprintln!("--timeout requires a value in seconds");
+                    return 2;
+                }
+            },
+            other => match other.strip_prefix("--timeout=") {
+                Some(value) => value,
+                None => {
+                    path = Some(other);
+                    continue;
+                }
+            },
+        };
+        timeout_secs = match value.parse::<u64>() {
+            Ok(secs) if secs > 0 => secs,
+            _ => {
+                eprintln!("Invalid --timeout '{value}': expected a positive number of seconds");
+                return 2;
+            }
+        };
+    }
+
+    let (config, warnings) = match load_config(path) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("configuration invalid: {e:#}");
+            return 1;
+        }
+    };
+
+    let results = verify_clusters(
+        &config.clusters,
+        &warnings,
+        std::time::Duration::from_secs(timeout_secs),
+    )
+    .await;
+
+    let rows: Vec<[String; 4]> = results
+        .iter()
+        .map(|result| {
+            let (status, detail) = match &result.outcome {
+                VerifyOutcome::Pass(health) => ("PASS", format!("health {}", health_name(health))),
+                VerifyOutcome::Fail(reason) => ("FAIL", reason.clone()),
+                VerifyOutcome::Skip(reason) => ("SKIP", reason.clone()),
+            };
+            [
+                result.cluster_id.clone(),
+                result.credential.clone(),
+                status.to_string(),
+                detail,
+            ]
+        })
+        .collect();
+    let header = ["CLUSTER", "CREDENTIAL", "RESULT", "DETAIL"].map(str::to_string);
+    let id_width = rows
+        .iter()
+        .chain([&header])
+        .map(|r| r[0].len())
+        .max()
+        .unwrap_or(0);
+    let cred_width = rows
+        .iter()
+        .chain([&header])
+        .map(|r| r[1].len())
+        .max()
+        .unwrap_or(0);
+    for [id, credential, status, detail] in [&header].into_iter().chain(&rows) {
+        println!("{id:id_width$}  {credential:cred_width$}  {status:6}  {detail}");
+    }
+
+    let failed = results.iter().filter(|result| result.failed()).count();
+    if failed > 0 {
+        eprintln!("{failed} of {} check(s) failed", results.len());
+        1
+    } else {
+        0
+    }
+}
+
+/// Health status as Elasticsearch reports it (e.g. "yellow")
+fn health_name(status: &HealthStatus) -> &'static str {
+    match status {
+        HealthStatus::Green => "green",
+        HealthStatus::Yellow => "yellow",
+        HealthStatus::Red => "red",
+    }
+}
+
+/// Load the configuration from `path`, or from the default config files
+fn load_config(path: Option<&str>) -> anyhow::Result<(Config, Vec<ClusterWarning>)> {
+    match path {
+        Some(path) => Config::load_from_path(std::path::Path::new(path)),
+        None => Config::load_with_warnings(),
+    }
+}
+
+/// Auth mode as written in the config file (e.g. "local_users")
+fn auth_mode_name(mode: &secan::config::AuthMode) -> String {
+    serde_json::to_value(mode)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_else(|| format!("{mode:?}"))
 }
 
 /// Print usage information
@@ -21,7 +286,18 @@ fn print_usage() {
     eprintln!();
     eprintln!("Usage:");
     eprintln!("  secan [OPTIONS]              Start the server");
-    eprintln!("  secan hash-password <pass>   Generate bcrypt password hash");
+    eprintln!("  secan hash-password [--algorithm A] [--cost N] <pass>");
+    eprintln!("                               Generate password hash (A: bcrypt or argon2,");
+    eprintln!("                               bcrypt cost N: 4-31, default 12)");
+    eprintln!("  secan hash-token [--algorithm A] [--cost N] <token>");
+    eprintln!("                               Generate API token hash");
+    eprintln!("  secan validate-config [path] Validate configuration and exit");
+    eprintln!("  secan verify-clusters [--timeout N] [path]");
+    eprintln!("                               Check each cluster is reachable and accepts");
+    eprintln!("                               its credentials (N seconds per call, default 10)");
+    eprintln!("  secan config dump [--format yaml|json|toml] [path]");
+    eprintln!("                               Print effective configuration (secrets redacted)");
+    eprintln!("  secan config schema          Print the JSON Schema of the config file");
     eprintln!();
     eprintln!("Options:");
     eprintln!("  -h, --help    Print help");
@@ -29,6 +305,13 @@ fn print_usage() {
     eprintln!();
     eprintln!("Examples:");
     eprintln!("  secan hash-password mypassword");
+    eprintln!("  secan hash-password --cost 14 mypassword");
+    eprintln!("  secan hash-password --algorithm argon2 mypassword");
+    eprintln!("  secan hash-token \"$(openssl rand -hex 32)\"");
+    eprintln!("  secan validate-config config.yaml");
+    eprintln!("  secan verify-clusters --timeout 5 config.yaml");
+    eprintln!("  SECAN_CLUSTERS_0_ID=prod secan config dump --format json");
+    eprintln!("  secan config schema > secan.schema.json");
     eprintln!("  secan");
 }
 
@@ -40,14 +323,15 @@ async fn main() -> anyhow::Result<()> {
     if args.len() > 1 {
         match args[1].as_str() {
             "hash-password" => {
-                if args.len() < 3 {
-                    eprintln!("Error: Password required");
-                    eprintln!("Usage: secan hash-password <password>");
-                    std::process::exit(1);
-                }
-
-                let password = &args[2];
-                let hash = generate_password_hash(password)?;
+                let (password, algorithm, cost) = match parse_hash_args(&args[2..]) {
+                    Ok(parsed) => parsed,
+                    Err(e) => {
+                        eprintln!("Error: {e}");
+                        eprintln!("Usage: secan hash-password [--algorithm bcrypt|argon2] [--cost <4-31>] <password>");
+                        std::process::exit(1);
+                    }
+                };
+                let hash = hash_secret(password, algorithm, cost)?;
 
                 println!("Password hash for '{password}':");
                 println!("{hash}");
@@ -61,12 +345,54 @@ async fn main() -> anyhow::Result<()> {
 
                 return Ok(());
             }
+            "hash-token" => {
+                let (token, algorithm, cost) = match parse_hash_args(&args[2..]) {
+                    Ok(parsed) => parsed,
+                    Err(e) => {
+                        eprintln!("Error: {e}");
+                        eprintln!("Usage: secan hash-token [--algorithm bcrypt|argon2] [--cost <4-31>] <token>");
+                        std::process::exit(1);
+                    }
+                };
+                let hash = hash_secret(token, algorithm, cost)?;
+
+                println!("Token hash:");
+                println!("{hash}");
+                println!();
+                println!("Add to config.yaml:");
+                println!("  auth:");
+                println!("    api_tokens:");
+                println!("      - name: \"ci\"");
+                println!("        token_hash: \"{hash}\"");
+                println!("        groups:");
+                println!("          - \"admin\"");
+
+                return Ok(());
+            }
+            "validate-config" => {
+                std::process::exit(validate_config(args.get(2).map(String::as_str)));
+            }
+            "verify-clusters" => {
+                std::process::exit(verify_clusters_command(&args[2..]).await);
+            }
+            "config" => match args.get(2).map(String::as_str) {
+                Some("dump") => std::process::exit(config_dump(&args[3..])),
+                Some("schema") => {
+                    println!("{}", serde_json::to_string_pretty(&Config::json_schema())?);
+                    return Ok(());
+                }
+                _ => {
+                    eprintln!("Usage: secan config dump [--format yaml|json|toml] [path]");
+                    eprintln!("       secan config schema");
+                    std::process::exit(2);
+                }
+            },
             "-h" | "--help" => {
                 print_usage();
                 return Ok(());
             }
             "-V" | "--version" => {
-                println!("Secan {}", env!("CARGO_PKG_VERSION"));
+                println!("{}", secan::build_info::version_line());
                 return Ok(());
             }
             _ => {
@@ -77,30 +403,26 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
+    // Load configuration and collect any cluster-level warnings (e.g. mixed auth).
+    // Loaded before the tracing subscriber, which is built from `logging`.
+    let (config, cluster_warnings) =
+        Config::load_with_warnings().context("Failed to load application configuration")?;
+
     // Initialize OpenTelemetry telemetry and tracing subscriber
-    // This handles both OTel tracing and JSON logging configuration
-    let _telemetry_guard = telemetry::init_telemetry();
+    // This handles both OTel tracing and the configured log format/output
+    let _telemetry_guard = telemetry::init_telemetry_with_config(&config.logging, &config.tracing);
 
     // If telemetry is disabled, we still need to initialize the tracing subscriber
     if _telemetry_guard.is_none() {
-        let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
-            .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
-
-        tracing_subscriber::fmt()
-            .json()
-            .with_env_filter(env_filter)
-            .with_target(true)
-            .with_thread_ids(true)
-            .with_line_number(true)
-            .init();
+        secan::logging::init(&config.logging).context("Failed to initialize logging")?;
     }
 
     info!("Secan - Elasticsearch Cluster Management Tool");
     info!("Starting backend server...");
 
-    // Load configuration and collect any cluster-level warnings (e.g. mixed auth)
-    let (config, cluster_warnings) =
-        Config::load_with_warnings().context("Failed to load application configuration")?;
+    // Install the metrics recorder before anything records metrics
+    secan::app_metrics::init_metrics();
+
     info!("Configuration loaded successfully");
 
     // If there were cluster-level problems detected during config parsing, log a startup summary
@@ -111,6 +433,12 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
+    // Valid but risky settings (disabled TLS verification, open mode over http, ...)
+    let (_, config_warnings) = config.validate_with_warnings();
+    for warning in &config_warnings {
+        tracing::warn!(field = %warning.field, "Insecure configuration: {}", warning.message);
+    }
+
     // Log startup configuration (sanitized - no sensitive data)
     info!(
         server_host = %config.server.host,
@@ -118,6 +446,7 @@ async fn main() -> anyhow::Result<()> {
         auth_mode = ?config.auth.mode,
         cluster_count = config.clusters.len(),
         session_timeout_minutes = config.auth.session_timeout_minutes,
+        session_idle_timeout_minutes = ?config.auth.session_idle_timeout_minutes,
         "Startup configuration"
     );
 
@@ -137,12 +466,32 @@ async fn main() -> anyhow::Result<()> {
     // Initialize cluster manager
     tracing::debug!("Initializing cluster manager...");
     let cache_duration = std::time::Duration::from_secs(config.cache.get_duration_secs());
-    let cluster_manager = ClusterManager::new_with_warnings(
+    let background_health_refresh = config.cache.background_health_refresh;
+    let unreachable_policy = if config.server.fail_on_unreachable_cluster {
+        UnreachableClusterPolicy::Fail
+    } else {
+        UnreachableClusterPolicy::Defer
+    };
+    let mut cluster_manager = ClusterManager::new_with_policy(
         config.clusters.clone(),
         cache_duration,
         Some(cluster_warnings),
+        unreachable_policy,
     )
-    .await?;
+    .await?
+    .with_health_cache_ttl(HealthCacheTtl {
+        green: std::time::Duration::from_secs(config.cache.health_ttl_secs(&HealthStatus::Green)),
+        yellow: std::time::Duration::from_secs(config.cache.health_ttl_secs(&HealthStatus::Yellow)),
+        red: std::time::Duration::from_secs(config.cache.health_ttl_secs(&HealthStatus::Red)),
+    })
+    .with_resource_cache_ttl(ResourceCacheTtl {
+        indices: std::time::Duration::from_secs(config.cache.indices_secs()),
+        nodes: std::time::Duration::from_secs(config.cache.nodes_secs()),
+        templates: std::time::Duration::from_secs(config.cache.templates_secs()),
+    });
+    if !config.notifications.webhooks.is_empty() {
+        cluster_manager = cluster_manager.with_notifier(HealthNotifier::new(&config.notifications));
+    }
     tracing::debug!("Cluster manager initialized successfully");
 
     // Read and validate session secret — must be set and at least 32 characters.
@@ -163,7 +512,9 @@ async fn main() -> anyhow::Result<()> {
 
     // Initialize session manager
     tracing::debug!("Initializing session manager...");
-    let session_config = SessionConfig::new(config.auth.session_timeout_minutes, session_secret);
+    let session_config = SessionConfig::new(config.auth.session_timeout_minutes, session_secret)
+        .with_idle_timeout(config.auth.session_idle_timeout_minutes)
+        .with_cookie(config.auth.session_cookie.clone());
     let session_manager = SessionManager::new(session_config);
     tracing::debug!("Session manager initialized successfully");
 
@@ -171,12 +522,29 @@ async fn main() -> anyhow::Result<()> {
     let cleanup_handle = Arc::new(session_manager.clone()).start_cleanup_task();
     tracing::debug!("Session cleanup task started");
 
+    // The server takes ownership of the config
+    let cert_expiry_clusters = config.clusters.clone();
+    let cert_expiry_warning_days = config.server.cert_expiry_warning_days;
+
     // Create and start server (async to initialize OIDC provider if configured)
     tracing::debug!("Creating server...");
     let server = Server::new(config, cluster_manager, session_manager)
         .await
         .context("Failed to create server")?;
 
+    // Keep the health cache warm if enabled
+    let health_refresh_handle = background_health_refresh.then(|| {
+        tracing::debug!("Background health refresh task started");
+        server
+            .cluster_manager
+            .clone()
+            .start_health_refresh_task(cache_duration)
+    });
+
+    // Warn about CA and client certificates close to expiry
+    let cert_expiry_handle =
+        TlsManager::start_expiry_check_task(cert_expiry_clusters, cert_expiry_warning_days);
+
     info!("Starting server...");
 
     // Run server (this will block until shutdown)
@@ -191,5 +559,10 @@ async fn main() -> anyhow::Result<()> {
         cleanup_handle.abort();
     }
 
+    if let Some(handle) = health_refresh_handle {
+        handle.abort();
+    }
+    cert_expiry_handle.abort();
+
     server_result
 }
@@ -509,6 +509,36 @@ impl PrometheusMetricsService {
         PrometheusClient::build_query(metric_name, self.job_name.as_deref(), self.labels.as_ref())
     }
 
+    /// Run an arbitrary PromQL range query over a time range
+    ///
+    /// Uses `step` as the sample interval, or the range's recommended step
+    /// when `None`. Unlike the fixed cluster metrics, errors are returned to
+    /// the caller rather than degrading to an empty series.
+    pub async fn query_range(
+        &self,
+        query: &str,
+        time_range: &TimeRange,
+        step: Option<Duration>,
+    ) -> Result<Vec<MetricPoint>> {
+        let start = chrono::DateTime::from_timestamp(time_range.start, 0)
+            .ok_or_else(|| anyhow::anyhow!("Invalid start timestamp: {}", time_range.start))?;
+        let end = chrono::DateTime::from_timestamp(time_range.end, 0)
+            .ok_or_else(|| anyhow::anyhow!("Invalid end timestamp: {}", time_range.end))?;
+        let step =
+            step.unwrap_or_else(|| Duration::from_secs(time_range.recommended_step() as u64));
+
+        let values = self.client.query_range(query, start, end, step).await?;
+
+        Ok(values
+            .iter()
+            .filter_map(|value| {
+                PrometheusClient::parse_value(&value.1)
+                    .ok()
+                    .map(|parsed| MetricPoint::new(value.0, parsed))
+            })
+            .collect())
+    }
+
     /// Query a metric over a time range using a pre-built query string
     async fn query_metric_range_with_query(
         &self,
@@ -519,7 +549,7 @@ impl PrometheusMetricsService {
 
         match self
             .client
-            .query_range(query, time_range.start, time_range.end, step)
+            .query_range_series(query, time_range.start, time_range.end, step)
             .await
         {
             Ok(results) => {
@@ -940,4 +970,39 @@ mod tests {
         assert_eq!(point.timestamp, 1000);
         assert_eq!(point.value, 42.5);
     }
+
+    #[tokio::test]
+    async fn test_prometheus_service_query_range_uses_custom_step() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/query_range"))
+            .and(query_param("step", "15"))
+            .and(query_param("start", "1700000000"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "success",
+                "data": {
+                    "resultType": "matrix",
+                    "result": [{"metric": {}, "values": [[1700000000, "3"], [1700000015, "NaN?"]]}]
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let service = PrometheusMetricsService::new(&server.uri(), "test".to_string(), None, None)
+            .expect("create service");
+        let time_range = TimeRange::new(1_700_000_000, 1_700_000_060).expect("time range");
+
+        let points = service
+            .query_range("sum(up)", &time_range, Some(Duration::from_secs(15)))
+            .await
+            .expect("range query");
+
+        // Unparseable samples are skipped
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].timestamp, 1_700_000_000);
+        assert_eq!(points[0].value, 3.0);
+    }
 }
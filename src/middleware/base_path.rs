@@ -0,0 +1,20 @@
+use axum::http::Extensions;
+
+/// URL prefix Secan is served under (`server.base_path`)
+///
+/// Routes are nested under the prefix, so handlers and middleware see paths
+/// with it stripped. Anything that sends the browser to an absolute path
+/// (redirects, the URLs in `index.html`) adds it back from this request
+/// extension. It is only inserted when a base path is configured.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BasePath(pub String);
+
+impl BasePath {
+    /// Prefix of the request's routes, or empty when served from the root
+    pub fn of(extensions: &Extensions) -> &str {
+        extensions
+            .get::<BasePath>()
+            .map(|BasePath(prefix)| prefix.as_str())
+            .unwrap_or("")
+    }
+}
@@ -0,0 +1,228 @@
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{header::FORWARDED, HeaderMap},
+    middleware::Next,
+    response::Response,
+};
+use ipnet::IpNet;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+/// Header set by most load balancers with the chain of client and proxy IPs
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// Resolved IP address of the client that made the request
+///
+/// Inserted into request extensions by [`client_ip_middleware`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIp(pub IpAddr);
+
+/// Trusted proxy ranges shared with the middleware
+#[derive(Debug, Clone, Default)]
+pub struct ClientIpState {
+    pub trusted_proxies: Vec<IpNet>,
+}
+
+impl ClientIpState {
+    pub fn new(trusted_proxies: Vec<IpNet>) -> Self {
+        Self { trusted_proxies }
+    }
+
+    fn is_trusted(&self, ip: &IpAddr) -> bool {
+        self.trusted_proxies.iter().any(|net| net.contains(ip))
+    }
+}
+
+/// Middleware that resolves the client IP and stores it as [`ClientIp`]
+///
+/// Forwarding headers are only honoured when the socket peer is a trusted
+/// proxy; otherwise (or when no proxies are configured) the peer address is
+/// the client IP.
+pub async fn client_ip_middleware(
+    State(state): State<Arc<ClientIpState>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+
+    if let Some(ip) = peer.map(|peer| resolve_client_ip(peer, request.headers(), &state)) {
+        request.extensions_mut().insert(ClientIp(ip));
+    }
+
+    next.run(request).await
+}
+
+/// Resolve the client IP from the socket peer and forwarding headers
+///
+/// The `Forwarded` header is preferred over `X-Forwarded-For`. The address
+/// chain is walked from the nearest hop back, skipping trusted proxies, so a
+/// client cannot spoof its address by prepending entries.
+fn resolve_client_ip(peer: IpAddr, headers: &HeaderMap, state: &ClientIpState) -> IpAddr {
+    if !state.is_trusted(&peer) {
+        return peer;
+    }
+
+    let chain = forwarded_chain(headers);
+    if chain.is_empty() {
+        tracing::debug!(peer = %peer, "Trusted proxy sent no forwarding header");
+        return peer;
+    }
+
+    // Every hop after the client was appended by a proxy, so the first
+    // untrusted address from the right is the client. If all are trusted the
+    // leftmost one is the best we have.
+    let mut client = peer;
+    for ip in chain.iter().rev() {
+        client = *ip;
+        if !state.is_trusted(ip) {
+            break;
+        }
+    }
+    client
+}
+
+/// Collect the forwarding chain, oldest hop first
+fn forwarded_chain(headers: &HeaderMap) -> Vec<IpAddr> {
+    let forwarded: Vec<IpAddr> = headers
+        .get_all(FORWARDED)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(parse_forwarded_element)
+        .collect();
+    if !forwarded.is_empty() {
+        return forwarded;
+    }
+
+    headers
+        .get_all(X_FORWARDED_FOR)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|entry| parse_node(entry.trim()))
+        .collect()
+}
+
+/// Extract the `for=` address from one `Forwarded` element (RFC 7239)
+fn parse_forwarded_element(element: &str) -> Option<IpAddr> {
+    element.split(';').find_map(|pair| {
+        let (key, value) = pair.trim().split_once('=')?;
+        if key.trim().eq_ignore_ascii_case("for") {
+            parse_node(value.trim().trim_matches('"'))
+        } else {
+            None
+        }
+    })
+}
+
+/// Parse a node as an IP, ignoring an optional port
+///
+/// Accepts `1.2.3.4`, `1.2.3.4:5678`, `2001:db8::1` and `[2001:db8::1]:5678`.
+/// Obfuscated identifiers and `unknown` yield `None`.
+fn parse_node(node: &str) -> Option<IpAddr> {
+    if let Ok(ip) = node.parse::<IpAddr>() {
+        return Some(ip);
+    }
+    if let Some(rest) = node.strip_prefix('[') {
+        return rest.split_once(']')?.0.parse().ok();
+    }
+    node.parse::<SocketAddr>().ok().map(|addr| addr.ip())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().expect("valid IP")
+    }
+
+    fn state(trusted: &[&str]) -> ClientIpState {
+        ClientIpState::new(
+            trusted
+                .iter()
+                .map(|net| net.parse().expect("valid CIDR"))
+                .collect(),
+        )
+    }
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[test]
+    fn test_no_trusted_proxies_uses_peer() {
+        let h = headers(&[(X_FORWARDED_FOR, "203.0.113.7")]);
+        assert_eq!(
+            resolve_client_ip(ip("10.0.0.5"), &h, &state(&[])),
+            ip("10.0.0.5")
+        );
+    }
+
+    #[test]
+    fn test_untrusted_peer_headers_are_ignored() {
+        let h = headers(&[(X_FORWARDED_FOR, "203.0.113.7")]);
+        assert_eq!(
+            resolve_client_ip(ip("198.51.100.9"), &h, &state(&["10.0.0.0/8"])),
+            ip("198.51.100.9")
+        );
+    }
+
+    #[test]
+    fn test_x_forwarded_for_from_trusted_proxy() {
+        let h = headers(&[(X_FORWARDED_FOR, "203.0.113.7, 10.0.0.2")]);
+        assert_eq!(
+            resolve_client_ip(ip("10.0.0.5"), &h, &state(&["10.0.0.0/8"])),
+            ip("203.0.113.7")
+        );
+    }
+
+    #[test]
+    fn test_spoofed_leading_entries_are_skipped() {
+        // The client claimed to be 1.1.1.1; the LB appended the real address
+        let h = headers(&[(X_FORWARDED_FOR, "1.1.1.1, 203.0.113.7")]);
+        assert_eq!(
+            resolve_client_ip(ip("10.0.0.5"), &h, &state(&["10.0.0.0/8"])),
+            ip("203.0.113.7")
+        );
+    }
+
+    #[test]
+    fn test_forwarded_header_preferred() {
+        let h = headers(&[
+            (X_FORWARDED_FOR, "198.51.100.1"),
+            (
+                "forwarded",
+                "for=\"[2001:db8::1]:4711\";proto=https, for=10.0.0.2",
+            ),
+        ]);
+        assert_eq!(
+            resolve_client_ip(ip("10.0.0.5"), &h, &state(&["10.0.0.0/8"])),
+            ip("2001:db8::1")
+        );
+    }
+
+    #[test]
+    fn test_trusted_proxy_without_headers_uses_peer() {
+        assert_eq!(
+            resolve_client_ip(ip("10.0.0.5"), &HeaderMap::new(), &state(&["10.0.0.0/8"])),
+            ip("10.0.0.5")
+        );
+    }
+
+    #[test]
+    fn test_parse_node() {
+        assert_eq!(parse_node("192.0.2.1:8080"), Some(ip("192.0.2.1")));
+        assert_eq!(parse_node("2001:db8::2"), Some(ip("2001:db8::2")));
+        assert_eq!(parse_node("unknown"), None);
+        assert_eq!(parse_node("_hidden"), None);
+    }
+}
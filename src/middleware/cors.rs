@@ -0,0 +1,105 @@
+use super::logging::REQUEST_ID_HEADER;
+use axum::http::{header, HeaderName, HeaderValue, Method};
+use tower_http::cors::CorsLayer;
+
+/// Build the CORS layer for the configured origins
+///
+/// Returns `None` when no origins are configured, leaving the API same-origin
+/// only. Otherwise the listed origins may call the API with credentials
+/// (session cookies) using the methods secan's routes accept.
+pub fn cors_layer(allowed_origins: &[String]) -> Option<CorsLayer> {
+    if allowed_origins.is_empty() {
+        return None;
+    }
+
+    // Origins are checked in ServerConfig::validate, so parse failures here
+    // only drop the offending entry.
+    let origins: Vec<HeaderValue> = allowed_origins
+        .iter()
+        .filter_map(|origin| origin.parse().ok())
+        .collect();
+
+    Some(
+        CorsLayer::new()
+            .allow_origin(origins)
+            .allow_methods([
+                Method::GET,
+                Method::POST,
+                Method::PUT,
+                Method::DELETE,
+                Method::HEAD,
+                Method::OPTIONS,
+            ])
+            .allow_headers([
+                header::CONTENT_TYPE,
+                header::AUTHORIZATION,
+                header::ACCEPT,
+                HeaderName::from_static(REQUEST_ID_HEADER),
+            ])
+            .expose_headers([HeaderName::from_static(REQUEST_ID_HEADER)])
+            .allow_credentials(true),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use tower::ServiceExt;
+
+    fn app(origins: &[String]) -> Router {
+        let router = Router::new().route("/api/health", get(|| async { "ok" }));
+        match cors_layer(origins) {
+            Some(cors) => router.layer(cors),
+            None => router,
+        }
+    }
+
+    #[test]
+    fn test_empty_origins_disable_cors() {
+        assert!(cors_layer(&[]).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_allowed_origin_gets_credentialed_cors_headers() {
+        let response = app(&["http://localhost:5173".to_string()])
+            .oneshot(
+                Request::builder()
+                    .uri("/api/health")
+                    .header(header::ORIGIN, "http://localhost:5173")
+                    .body(Body::empty())
+                    .expect("build request"),
+            )
+            .await
+            .expect("request");
+
+        let headers = response.headers();
+        assert_eq!(
+            headers.get(header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some(&HeaderValue::from_static("http://localhost:5173"))
+        );
+        assert_eq!(
+            headers.get(header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+            Some(&HeaderValue::from_static("true"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_other_origin_gets_no_cors_headers() {
+        let response = app(&["http://localhost:5173".to_string()])
+            .oneshot(
+                Request::builder()
+                    .uri("/api/health")
+                    .header(header::ORIGIN, "http://evil.example")
+                    .body(Body::empty())
+                    .expect("build request"),
+            )
+            .await
+            .expect("request");
+
+        assert!(response
+            .headers()
+            .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .is_none());
+    }
+}
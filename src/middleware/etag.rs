@@ -0,0 +1,176 @@
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{header, HeaderMap, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+
+/// Middleware adding a weak ETag to successful GET responses
+///
+/// The ETag is a hash of the serialized response body, so it only changes
+/// when the underlying data does. A request whose `If-None-Match` matches
+/// gets an empty 304 instead of the full payload, which keeps frequent
+/// dashboard polling of stable clusters cheap. Responses are marked
+/// `no-cache` so browsers revalidate on every poll instead of guessing.
+pub async fn etag_middleware(request: Request, next: Next) -> Response {
+    if !matches!(*request.method(), Method::GET | Method::HEAD) {
+        return next.run(request).await;
+    }
+    let if_none_match = request.headers().get(header::IF_NONE_MATCH).cloned();
+
+    let response = next.run(request).await;
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to buffer response body for ETag");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let etag = weak_etag(&bytes);
+    parts.headers.insert(header::ETAG, etag.clone());
+    parts
+        .headers
+        .insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+
+    if if_none_match.is_some_and(|value| etag_matches(&value, &etag)) {
+        let mut headers = HeaderMap::new();
+        for name in [header::ETAG, header::CACHE_CONTROL, header::VARY] {
+            if let Some(value) = parts.headers.get(&name) {
+                headers.insert(name, value.clone());
+            }
+        }
+        return (StatusCode::NOT_MODIFIED, headers).into_response();
+    }
+
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+/// Weak ETag over a response body (`W/"<hash>"`)
+fn weak_etag(body: &[u8]) -> HeaderValue {
+    HeaderValue::from_str(&format!("W/\"{}\"", body_hash(body)))
+        .expect("hex ETag is a valid header value")
+}
+
+/// Strong ETag over content served byte for byte (`"<hash>"`)
+pub(crate) fn content_etag(content: &[u8]) -> HeaderValue {
+    HeaderValue::from_str(&format!("\"{}\"", body_hash(content)))
+        .expect("hex ETag is a valid header value")
+}
+
+/// First 16 bytes of the body's sha256, in hex
+fn body_hash(body: &[u8]) -> String {
+    Sha256::digest(body)[..16]
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Whether an `If-None-Match` header matches `etag`
+///
+/// Uses weak comparison: `W/` prefixes are ignored, and `*` matches anything.
+pub(crate) fn etag_matches(if_none_match: &HeaderValue, etag: &HeaderValue) -> bool {
+    let (Ok(candidates), Ok(etag)) = (if_none_match.to_str(), etag.to_str()) else {
+        return false;
+    };
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let etag = opaque(etag);
+    candidates
+        .split(',')
+        .any(|candidate| candidate.trim() == "*" || opaque(candidate) == etag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{routing::get, Router};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    fn app(counter: Arc<AtomicUsize>) -> Router {
+        Router::new()
+            .route(
+                "/stats",
+                get(move || {
+                    let counter = counter.clone();
+                    async move { format!("{{\"polls\":{}}}", counter.load(Ordering::SeqCst)) }
+                }),
+            )
+            .route(
+                "/missing",
+                get(|| async { (StatusCode::NOT_FOUND, "not found") }),
+            )
+            .layer(axum::middleware::from_fn(etag_middleware))
+    }
+
+    async fn get_with(app: &Router, uri: &str, if_none_match: Option<&str>) -> Response {
+        let mut request = Request::builder().uri(uri);
+        if let Some(etag) = if_none_match {
+            request = request.header(header::IF_NONE_MATCH, etag);
+        }
+        app.clone()
+            .oneshot(request.body(Body::empty()).expect("request"))
+            .await
+            .expect("response")
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_response_returns_not_modified() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let app = app(counter.clone());
+
+        let first = get_with(&app, "/stats", None).await;
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first.headers()[header::ETAG]
+            .to_str()
+            .expect("etag")
+            .to_string();
+        assert!(etag.starts_with("W/\""), "{etag}");
+        assert_eq!(first.headers()[header::CACHE_CONTROL], "no-cache");
+
+        let cached = get_with(&app, "/stats", Some(&etag)).await;
+        assert_eq!(cached.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(cached.headers()[header::ETAG], etag.as_str());
+        let body = axum::body::to_bytes(cached.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        assert!(body.is_empty());
+
+        // New data changes the ETag, so the full response is sent again
+        counter.fetch_add(1, Ordering::SeqCst);
+        let changed = get_with(&app, "/stats", Some(&etag)).await;
+        assert_eq!(changed.status(), StatusCode::OK);
+        assert_ne!(changed.headers()[header::ETAG], etag.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_error_responses_have_no_etag() {
+        let app = app(Arc::new(AtomicUsize::new(0)));
+
+        let response = get_with(&app, "/missing", Some("*")).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert!(response.headers().get(header::ETAG).is_none());
+    }
+
+    #[test]
+    fn test_etag_matches() {
+        let etag = HeaderValue::from_static("W/\"abc\"");
+
+        assert!(etag_matches(&HeaderValue::from_static("W/\"abc\""), &etag));
+        assert!(etag_matches(&HeaderValue::from_static("\"abc\""), &etag));
+        assert!(etag_matches(
+            &HeaderValue::from_static("W/\"xyz\", W/\"abc\""),
+            &etag
+        ));
+        assert!(etag_matches(&HeaderValue::from_static("*"), &etag));
+        assert!(!etag_matches(&HeaderValue::from_static("W/\"xyz\""), &etag));
+    }
+}
@@ -1,15 +1,21 @@
-use axum::{extract::Request, middleware::Next, response::Response};
+use axum::{extract::Request, http::HeaderMap, middleware::Next, response::Response};
 use std::time::Instant;
-use tracing::{debug, error, warn};
+use tracing::{debug, error, warn, Instrument};
 use uuid::Uuid;
 
 /// Request ID header name
 pub const REQUEST_ID_HEADER: &str = "x-request-id";
 
+/// Longest incoming request ID that is reused rather than replaced
+const MAX_REQUEST_ID_LEN: usize = 128;
+
 /// Logging middleware that logs all HTTP requests with request IDs
 ///
 /// This middleware:
-/// - Generates a unique request ID for each request
+/// - Reuses the caller's `X-Request-Id` or generates a unique request ID
+/// - Runs the request in a `request` span carrying the ID, so every log line
+///   emitted while handling it includes `request_id`
+/// - Echoes the ID back in the `X-Request-Id` response header
 /// - Logs request details (method, path, request ID)
 /// - Logs response details (status code, duration)
 /// - Sanitizes sensitive data from logs
@@ -21,12 +27,8 @@ pub async fn logging_middleware(mut request: Request, next: Next) -> Response {
     let start = Instant::now();
 
     // Generate or extract request ID
-    let request_id = request
-        .headers()
-        .get(REQUEST_ID_HEADER)
-        .and_then(|v| v.to_str().ok())
-        .map(|s| s.to_string())
-        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let request_id =
+        incoming_request_id(request.headers()).unwrap_or_else(|| Uuid::new_v4().to_string());
 
     let method = request.method().clone();
     let uri = request.uri().clone();
@@ -68,8 +70,9 @@ pub async fn logging_middleware(mut request: Request, next: Next) -> Response {
         .extensions_mut()
         .insert(RequestId(request_id.clone()));
 
-    // Process request
-    let response = next.run(request).await;
+    // Process request inside a span so downstream logs carry the request ID
+    let span = tracing::info_span!("request", request_id = %request_id);
+    let response = next.run(request).instrument(span).await;
 
     let status = response.status();
     let duration = start.elapsed();
@@ -148,6 +151,22 @@ pub async fn logging_middleware(mut request: Request, next: Next) -> Response {
     Response::from_parts(parts, body)
 }
 
+/// Request ID sent by the caller, if it is safe to log, echo and forward
+///
+/// IDs that are empty, longer than [`MAX_REQUEST_ID_LEN`] or contain anything
+/// but visible ASCII are ignored and a new ID is generated instead.
+fn incoming_request_id(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|id| {
+            !id.is_empty()
+                && id.len() <= MAX_REQUEST_ID_LEN
+                && id.bytes().all(|b| b.is_ascii_graphic())
+        })
+        .map(str::to_string)
+}
+
 /// Sanitize path to remove sensitive data from logs
 ///
 /// This function removes or masks sensitive information from request paths:
@@ -200,6 +219,25 @@ impl RequestId {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_incoming_request_id() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(incoming_request_id(&headers), None);
+
+        headers.insert(REQUEST_ID_HEADER, "abc-123".parse().expect("header value"));
+        assert_eq!(incoming_request_id(&headers).as_deref(), Some("abc-123"));
+
+        headers.insert(
+            REQUEST_ID_HEADER,
+            "has space".parse().expect("header value"),
+        );
+        assert_eq!(incoming_request_id(&headers), None);
+
+        let long = "a".repeat(MAX_REQUEST_ID_LEN + 1);
+        headers.insert(REQUEST_ID_HEADER, long.parse().expect("header value"));
+        assert_eq!(incoming_request_id(&headers), None);
+    }
+
     #[test]
     fn test_sanitize_path_with_token() {
         let path = "/api/clusters/test?token=secret123&other=value";
@@ -0,0 +1,34 @@
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use std::time::Instant;
+
+/// Route label used for requests that did not match any route (static assets)
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Metrics middleware that counts HTTP requests by route and status
+///
+/// The route label is the matched route template rather than the raw path so
+/// cluster IDs and index names do not create unbounded label sets.
+pub async fn metrics_middleware(request: Request, next: Next) -> Response {
+    let start = Instant::now();
+    let method = request.method().clone();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+
+    let response = next.run(request).await;
+
+    crate::app_metrics::http::record_request(
+        method.as_str(),
+        &route,
+        response.status().as_u16(),
+        start.elapsed().as_secs_f64() * 1000.0,
+    );
+
+    response
+}
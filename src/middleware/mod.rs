@@ -1,4 +1,11 @@
+pub mod base_path;
+pub mod client_ip;
+pub mod cors;
+pub mod etag;
 pub mod logging;
+pub mod metrics;
 pub mod permissions;
+pub mod rate_limit;
+pub mod read_only;
 pub mod security;
 pub mod validation;
@@ -2,7 +2,7 @@ use crate::auth::middleware::AuthenticatedUser;
 use crate::auth::RbacManager;
 use axum::{
     extract::{Request, State},
-    http::StatusCode,
+    http::{Method, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
 };
@@ -27,6 +27,8 @@ impl PermissionState {
 ///
 /// Skips checks in Open mode. For all other modes, resolves cluster access
 /// from the authenticated user's roles against the configured RBAC rules.
+/// Requests that modify a cluster additionally need a role with `write`, and
+/// destructive ones (deleting or closing indices) a role with `admin`.
 pub async fn permission_middleware(
     State(state): State<Arc<PermissionState>>,
     request: Request,
@@ -45,25 +47,118 @@ pub async fn permission_middleware(
         if !state.rbac.can_access_cluster(&user.0, &cluster_id) {
             return Err(PermissionError::Forbidden(cluster_id));
         }
+
+        if requires_admin(request.method(), request.uri().path())
+            && !state.rbac.can_admin(&user.0, &cluster_id)
+        {
+            return Err(PermissionError::AdminForbidden(cluster_id));
+        }
+
+        if requires_write(request.method(), request.uri().path())
+            && !state.rbac.can_write(&user.0, &cluster_id)
+        {
+            return Err(PermissionError::WriteForbidden(cluster_id));
+        }
     }
 
     Ok(next.run(request).await)
 }
 
+/// Routes directly under `/api/clusters` that are not a cluster ID
+const MULTI_CLUSTER_ROUTES: &[&str] = &["health", "compare", "search"];
+
 /// Extract cluster ID from paths like /api/clusters/{cluster_id}/...
+///
+/// `/api/clusters/health`, `/api/clusters/compare` and `/api/clusters/search`
+/// span several clusters and check access themselves, so they are not
+/// treated as cluster paths.
 fn extract_cluster_id_from_path(path: &str) -> Option<String> {
     let parts: Vec<&str> = path.split('/').collect();
+    if parts.len() == 4 && MULTI_CLUSTER_ROUTES.contains(&parts[3]) {
+        return None;
+    }
     if parts.len() >= 4 && parts[1] == "api" && parts[2] == "clusters" && !parts[3].is_empty() {
         return Some(parts[3].to_string());
     }
     None
 }
 
+/// Secan POST routes (relative to the cluster path) that only read data
+const READ_ONLY_POST_ROUTES: &[&str] = &["topology/tiles", "index-templates/_simulate"];
+
+/// Elasticsearch POST endpoints that only read data, matched on the last path segment
+const READ_ONLY_POST_ENDPOINTS: &[&str] = &[
+    "_search",
+    "_msearch",
+    "_count",
+    "_mget",
+    "_field_caps",
+    "_analyze",
+];
+
+/// Whether a request to a cluster path modifies the cluster
+///
+/// GET, HEAD and OPTIONS never do. POST is treated as a read only for search
+/// style endpoints that take their query in the body; everything else,
+/// including paths with dot segments that could be normalized elsewhere,
+/// needs write permission.
+fn requires_write(method: &Method, path: &str) -> bool {
+    if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+        return false;
+    }
+    if *method != Method::POST {
+        return true;
+    }
+
+    let lower = path.to_ascii_lowercase();
+    if lower.contains("..") || lower.contains("%2e") {
+        return true;
+    }
+
+    // Path is /api/clusters/{id}/...; look at what follows the cluster ID
+    let rest = path.splitn(5, '/').nth(4).unwrap_or_default();
+    if READ_ONLY_POST_ROUTES.contains(&rest) {
+        return false;
+    }
+    let last_segment = rest.rsplit('/').next().unwrap_or_default();
+    !READ_ONLY_POST_ENDPOINTS.contains(&last_segment)
+}
+
+/// Whether a request to a cluster path deletes or closes indices
+///
+/// Covers `DELETE` of a single path segment (an index, index list, pattern or
+/// `_all`), `DELETE indices/{index}` and any `_close` endpoint. The path is
+/// percent-decoded first, since the proxy forwards the decoded path, and paths
+/// with dot segments that could resolve to one of these upstream are treated
+/// as admin operations too.
+fn requires_admin(method: &Method, path: &str) -> bool {
+    if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+        return false;
+    }
+
+    // Path is /api/clusters/{id}/...; look at what follows the cluster ID
+    let rest = path.splitn(5, '/').nth(4).unwrap_or_default();
+    let Ok(rest) = urlencoding::decode(rest) else {
+        return true;
+    };
+    let segments: Vec<&str> = rest.split('/').filter(|s| !s.is_empty()).collect();
+    if segments.iter().any(|s| *s == "." || *s == "..") {
+        return true;
+    }
+    if segments.last() == Some(&"_close") {
+        return true;
+    }
+    *method == Method::DELETE
+        && (segments.len() == 1 || (segments.len() == 2 && segments[0] == "indices"))
+}
+
 /// Permission enforcement errors
 #[derive(Debug)]
 pub enum PermissionError {
     Unauthenticated,
     Forbidden(String),
+    WriteForbidden(String),
+    AdminForbidden(String),
 }
 
 impl IntoResponse for PermissionError {
@@ -83,6 +178,22 @@ impl IntoResponse for PermissionError {
                 )
                     .into_response()
             }
+            PermissionError::WriteForbidden(cluster_id) => {
+                tracing::warn!(cluster_id = %cluster_id, "User without write permission attempted to modify cluster");
+                (
+                    StatusCode::FORBIDDEN,
+                    format!("Write permission required on cluster: {}", cluster_id),
+                )
+                    .into_response()
+            }
+            PermissionError::AdminForbidden(cluster_id) => {
+                tracing::warn!(cluster_id = %cluster_id, "User without admin permission attempted a destructive operation");
+                (
+                    StatusCode::FORBIDDEN,
+                    format!("Admin permission required on cluster: {}", cluster_id),
+                )
+                    .into_response()
+            }
         }
     }
 }
@@ -90,7 +201,7 @@ impl IntoResponse for PermissionError {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::RoleConfig;
+    use crate::config::{Permission, RoleConfig};
 
     fn make_rbac(patterns: &[(&str, &str)]) -> RbacManager {
         let roles = patterns
@@ -98,6 +209,8 @@ mod tests {
             .map(|(name, pattern)| RoleConfig {
                 name: name.to_string(),
                 cluster_patterns: vec![pattern.to_string()],
+                permission: Permission::Read,
+                proxy_allow: None,
             })
             .collect();
         RbacManager::new(roles)
@@ -117,6 +230,11 @@ mod tests {
             extract_cluster_id_from_path("/api/clusters/test-123"),
             Some("test-123".to_string())
         );
+        // A cluster named "health" is still gated on its own routes
+        assert_eq!(
+            extract_cluster_id_from_path("/api/clusters/health/stats"),
+            Some("health".to_string())
+        );
     }
 
     #[test]
@@ -124,6 +242,159 @@ mod tests {
         assert_eq!(extract_cluster_id_from_path("/api/clusters"), None);
         assert_eq!(extract_cluster_id_from_path("/health"), None);
         assert_eq!(extract_cluster_id_from_path("/api/version"), None);
+        assert_eq!(extract_cluster_id_from_path("/api/clusters/health"), None);
+        assert_eq!(extract_cluster_id_from_path("/api/clusters/compare"), None);
+        assert_eq!(extract_cluster_id_from_path("/api/clusters/search"), None);
+    }
+
+    #[test]
+    fn test_requires_write() {
+        let path = "/api/clusters/prod-1";
+        assert!(!requires_write(
+            &Method::GET,
+            &format!("{path}/_cluster/settings")
+        ));
+        assert!(!requires_write(&Method::HEAD, &format!("{path}/logs")));
+        assert!(requires_write(
+            &Method::PUT,
+            &format!("{path}/_cluster/settings")
+        ));
+        assert!(requires_write(&Method::PUT, &format!("{path}/settings")));
+        assert!(!requires_write(&Method::GET, &format!("{path}/settings")));
+        // Deleting an index also needs admin, see test_requires_admin
+        assert!(requires_write(&Method::DELETE, &format!("{path}/logs")));
+        assert!(requires_write(
+            &Method::POST,
+            &format!("{path}/shards/relocate")
+        ));
+        assert!(requires_write(&Method::POST, &format!("{path}/logs/_doc")));
+
+        // Read-only POST endpoints
+        assert!(!requires_write(
+            &Method::POST,
+            &format!("{path}/logs/_search")
+        ));
+        assert!(!requires_write(&Method::POST, &format!("{path}/_count")));
+        assert!(!requires_write(
+            &Method::POST,
+            &format!("{path}/topology/tiles")
+        ));
+        assert!(!requires_write(
+            &Method::POST,
+            &format!("{path}/index-templates/_simulate")
+        ));
+
+        // Dot segments could resolve to a different endpoint upstream
+        assert!(requires_write(
+            &Method::POST,
+            &format!("{path}/_search/../logs/_delete_by_query/_search")
+        ));
+        assert!(requires_write(
+            &Method::POST,
+            &format!("{path}/%2E%2E/_search")
+        ));
+    }
+
+    #[test]
+    fn test_requires_admin() {
+        let path = "/api/clusters/prod-1";
+        assert!(requires_admin(&Method::DELETE, &format!("{path}/logs")));
+        assert!(requires_admin(
+            &Method::DELETE,
+            &format!("{path}/logs-*,metrics/")
+        ));
+        assert!(requires_admin(&Method::DELETE, &format!("{path}/_all")));
+        assert!(requires_admin(
+            &Method::DELETE,
+            &format!("{path}/indices/logs")
+        ));
+        assert!(requires_admin(
+            &Method::POST,
+            &format!("{path}/logs/_close")
+        ));
+        assert!(requires_admin(
+            &Method::POST,
+            &format!("{path}/indices/logs/_close")
+        ));
+        assert!(requires_admin(
+            &Method::POST,
+            &format!("{path}/logs/%5Fclose")
+        ));
+        assert!(requires_admin(
+            &Method::DELETE,
+            &format!("{path}/x/%2E%2E/logs")
+        ));
+
+        assert!(!requires_admin(&Method::GET, &format!("{path}/logs")));
+        assert!(!requires_admin(
+            &Method::POST,
+            &format!("{path}/logs/_open")
+        ));
+        assert!(!requires_admin(
+            &Method::DELETE,
+            &format!("{path}/logs/_doc/1")
+        ));
+        assert!(!requires_admin(
+            &Method::DELETE,
+            &format!("{path}/aliases/recent")
+        ));
+        assert!(!requires_admin(&Method::PUT, &format!("{path}/logs")));
+    }
+
+    #[tokio::test]
+    async fn test_write_role_cannot_delete_or_close_through_the_proxy() {
+        use axum::{body::Body, routing::any, Router};
+        use tower::ServiceExt;
+
+        let rbac = RbacManager::new(vec![RoleConfig {
+            name: "writer".to_string(),
+            cluster_patterns: vec!["*".to_string()],
+            permission: Permission::Write,
+            proxy_allow: None,
+        }]);
+        let state = Arc::new(PermissionState::new(
+            crate::config::AuthMode::LocalUsers,
+            rbac,
+        ));
+        let user = AuthenticatedUser(crate::auth::AuthUser::new(
+            "u1".to_string(),
+            "u1".to_string(),
+            vec!["writer".to_string()],
+        ));
+        let app = Router::new()
+            .route("/api/clusters/{id}/{*path}", any(|| async { "ok" }))
+            .layer(axum::middleware::from_fn_with_state(
+                state,
+                permission_middleware,
+            ));
+        let send = |method: Method, path: &str| {
+            let mut request = Request::builder()
+                .method(method)
+                .uri(path)
+                .body(Body::empty())
+                .expect("build request");
+            request.extensions_mut().insert(user.clone());
+            app.clone().oneshot(request)
+        };
+
+        for (method, path) in [
+            (Method::DELETE, "/api/clusters/prod/logs"),
+            (Method::POST, "/api/clusters/prod/logs/_close"),
+        ] {
+            let response = send(method.clone(), path).await.expect("send request");
+            assert_eq!(
+                response.status(),
+                StatusCode::FORBIDDEN,
+                "{} {}",
+                method,
+                path
+            );
+        }
+
+        let response = send(Method::POST, "/api/clusters/prod/logs/_open")
+            .await
+            .expect("send request");
+        assert_eq!(response.status(), StatusCode::OK);
     }
 
     #[test]
@@ -0,0 +1,259 @@
+use crate::auth::middleware::AuthenticatedUser;
+use crate::auth::{RateLimitConfig, RateLimitExceeded, RateLimiter};
+use crate::config::RateLimitRuleConfig;
+use crate::middleware::client_ip::ClientIp;
+use axum::{
+    extract::{Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+
+/// Request rate limit buckets shared with the middleware
+#[derive(Debug, Clone)]
+pub struct RateLimitState {
+    rules: Vec<RateLimitRuleConfig>,
+    limiter: RateLimiter,
+}
+
+impl RateLimitState {
+    /// Build one limiter bucket per configured rule
+    pub fn new(rules: Vec<RateLimitRuleConfig>) -> Self {
+        // Buckets only reject the request over the limit; clients are not
+        // blocked beyond the sliding window
+        let buckets = rules.iter().map(|rule| {
+            (
+                rule.name.clone(),
+                RateLimitConfig::new(rule.max_requests, rule.window_seconds, 0),
+            )
+        });
+        let limiter = RateLimiter::new(RateLimitConfig::default()).with_buckets(buckets);
+        Self { rules, limiter }
+    }
+}
+
+/// Middleware that applies the configured request rate limits
+///
+/// Requests are counted per authenticated user id. In open mode (and for
+/// requests without a user) the resolved client IP is used instead, since
+/// every open-mode request shares the same user. Requests over the limit get
+/// 429 with a `Retry-After` header.
+pub async fn rate_limit_middleware(
+    State(state): State<Arc<RateLimitState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(rule) = state
+        .rules
+        .iter()
+        .find(|rule| rule.matches(request.uri().path()))
+    else {
+        return next.run(request).await;
+    };
+
+    let Some(identifier) = rate_limit_key(&request) else {
+        tracing::debug!(
+            bucket = %rule.name,
+            "No user or client IP for request, skipping rate limit"
+        );
+        return next.run(request).await;
+    };
+
+    match state.limiter.check_request(&rule.name, &identifier).await {
+        Ok(()) => next.run(request).await,
+        Err(exceeded) => {
+            tracing::warn!(
+                bucket = %rule.name,
+                identifier = %identifier,
+                path = %request.uri().path(),
+                retry_after_seconds = exceeded.retry_after_seconds,
+                "Request rate limit exceeded"
+            );
+            rate_limited_response(exceeded)
+        }
+    }
+}
+
+/// Identify who a request counts against: the user, or the client IP
+fn rate_limit_key(request: &Request) -> Option<String> {
+    let user = request
+        .extensions()
+        .get::<AuthenticatedUser>()
+        .map(|AuthenticatedUser(user)| user);
+
+    match user {
+        Some(user) if user.auth_type != "open" => Some(format!("user:{}", user.id)),
+        user => user
+            .and_then(|user| user.client_ip)
+            .or_else(|| {
+                request
+                    .extensions()
+                    .get::<ClientIp>()
+                    .map(|ClientIp(ip)| *ip)
+            })
+            .map(|ip| format!("ip:{}", ip)),
+    }
+}
+
+fn rate_limited_response(exceeded: RateLimitExceeded) -> Response {
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        [(
+            header::RETRY_AFTER,
+            exceeded.retry_after_seconds.to_string(),
+        )],
+        serde_json::json!({
+            "error": "rate_limited",
+            "message": "Too many requests, retry later",
+            "retry_after_seconds": exceeded.retry_after_seconds,
+        })
+        .to_string(),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthUser;
+    use axum::{body::Body, routing::get, Router};
+    use std::net::IpAddr;
+    use tower::ServiceExt;
+
+    fn rule(name: &str, pattern: &str, max_requests: u32) -> RateLimitRuleConfig {
+        RateLimitRuleConfig {
+            name: name.to_string(),
+            path_patterns: vec![pattern.to_string()],
+            max_requests,
+            window_seconds: 60,
+        }
+    }
+
+    fn app(rules: Vec<RateLimitRuleConfig>) -> Router {
+        Router::new()
+            .route("/api/clusters/{id}/stats", get(|| async { "ok" }))
+            .route("/api/health", get(|| async { "ok" }))
+            .layer(axum::middleware::from_fn_with_state(
+                Arc::new(RateLimitState::new(rules)),
+                rate_limit_middleware,
+            ))
+    }
+
+    fn request(path: &str, user: Option<AuthUser>, ip: &str) -> Request {
+        let mut request = Request::builder()
+            .uri(path)
+            .body(Body::empty())
+            .expect("build request");
+        if let Some(user) = user {
+            request.extensions_mut().insert(AuthenticatedUser(user));
+        }
+        let ip: IpAddr = ip.parse().expect("valid IP");
+        request.extensions_mut().insert(ClientIp(ip));
+        request
+    }
+
+    fn user(id: &str, auth_type: &str) -> AuthUser {
+        let mut user = AuthUser::new(id.to_string(), id.to_string(), vec![]);
+        user.auth_type = auth_type.to_string();
+        user
+    }
+
+    async fn send(app: &Router, request: Request) -> Response {
+        app.clone().oneshot(request).await.expect("request")
+    }
+
+    #[tokio::test]
+    async fn test_requests_over_limit_get_retry_after() {
+        let app = app(vec![rule("clusters", "/api/clusters/*", 2)]);
+
+        for _ in 0..2 {
+            let response = send(
+                &app,
+                request(
+                    "/api/clusters/prod/stats",
+                    Some(user("alice", "local")),
+                    "10.0.0.1",
+                ),
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let response = send(
+            &app,
+            request(
+                "/api/clusters/prod/stats",
+                Some(user("alice", "local")),
+                "10.0.0.1",
+            ),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        let retry_after: u64 = response
+            .headers()
+            .get(header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+            .expect("Retry-After header");
+        assert!((1..=60).contains(&retry_after));
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_users_are_limited_separately() {
+        let app = app(vec![rule("clusters", "/api/clusters/*", 1)]);
+
+        // Same IP, different users
+        let alice = request(
+            "/api/clusters/prod/stats",
+            Some(user("alice", "local")),
+            "10.0.0.1",
+        );
+        let bob = request(
+            "/api/clusters/prod/stats",
+            Some(user("bob", "local")),
+            "10.0.0.1",
+        );
+        assert_eq!(send(&app, alice).await.status(), StatusCode::OK);
+        assert_eq!(send(&app, bob).await.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_open_mode_is_limited_by_client_ip() {
+        let app = app(vec![rule("clusters", "/api/clusters/*", 1)]);
+        let open_user =
+            |ip: &str| user("open", "open").with_client_ip(Some(ip.parse().expect("ip")));
+
+        let first = request(
+            "/api/clusters/prod/stats",
+            Some(open_user("10.0.0.1")),
+            "10.0.0.1",
+        );
+        let other_ip = request(
+            "/api/clusters/prod/stats",
+            Some(open_user("10.0.0.2")),
+            "10.0.0.2",
+        );
+        let again = request(
+            "/api/clusters/prod/stats",
+            Some(open_user("10.0.0.1")),
+            "10.0.0.1",
+        );
+        assert_eq!(send(&app, first).await.status(), StatusCode::OK);
+        assert_eq!(send(&app, other_ip).await.status(), StatusCode::OK);
+        assert_eq!(
+            send(&app, again).await.status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unmatched_paths_are_not_limited() {
+        let app = app(vec![rule("clusters", "/api/clusters/*", 1)]);
+
+        for _ in 0..3 {
+            let response = send(&app, request("/api/health", None, "10.0.0.1")).await;
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::routes::{ApiError, ErrorCode};
+use axum::{
+    extract::{Request, State},
+    http::Method,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+/// Middleware enforcing `server.read_only`
+///
+/// While read-only mode is on, every request to a cluster route other than
+/// GET or HEAD is rejected with 403, including shard relocation and write
+/// methods through the proxy. It runs after authentication but before the
+/// permission middleware, so no role can lift it.
+pub async fn read_only_middleware(
+    State(read_only): State<bool>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if read_only
+        && request.uri().path().starts_with("/api/clusters/")
+        && !matches!(*request.method(), Method::GET | Method::HEAD)
+    {
+        tracing::warn!(
+            method = %request.method(),
+            path = %request.uri().path(),
+            "Rejected change in read-only mode"
+        );
+        return ApiError::new(
+            ErrorCode::ReadOnlyMode,
+            "Secan is in read-only mode; changes to clusters are disabled",
+        )
+        .into_response();
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::Body,
+        http::StatusCode,
+        routing::{get, post},
+        Router,
+    };
+    use tower::ServiceExt;
+
+    fn app(read_only: bool) -> Router {
+        Router::new()
+            .route(
+                "/api/clusters/{id}/{*path}",
+                get(|| async { "ok" })
+                    .post(|| async { "ok" })
+                    .put(|| async { "ok" })
+                    .delete(|| async { "ok" }),
+            )
+            .route("/api/auth/login", post(|| async { "ok" }))
+            .layer(axum::middleware::from_fn_with_state(
+                read_only,
+                read_only_middleware,
+            ))
+    }
+
+    async fn send(app: &Router, method: Method, path: &str) -> Response {
+        let request = Request::builder()
+            .method(method)
+            .uri(path)
+            .body(Body::empty())
+            .expect("build request");
+        app.clone().oneshot(request).await.expect("request")
+    }
+
+    #[tokio::test]
+    async fn test_read_only_allows_reads() {
+        let app = app(true);
+
+        let response = send(&app, Method::GET, "/api/clusters/prod/_cat/indices").await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = send(&app, Method::HEAD, "/api/clusters/prod/logs").await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_read_only_blocks_cluster_writes() {
+        let app = app(true);
+
+        for (method, path) in [
+            (Method::POST, "/api/clusters/prod/shards/relocate"),
+            (Method::PUT, "/api/clusters/prod/logs/_settings"),
+            (Method::DELETE, "/api/clusters/prod/logs"),
+        ] {
+            let response = send(&app, method.clone(), path).await;
+            assert_eq!(
+                response.status(),
+                StatusCode::FORBIDDEN,
+                "{} {}",
+                method,
+                path
+            );
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .expect("read body");
+            let error: ApiError = serde_json::from_slice(&body).expect("parse error");
+            assert_eq!(error.code, ErrorCode::ReadOnlyMode);
+        }
+
+        // Logging in is not a cluster change
+        let response = send(&app, Method::POST, "/api/auth/login").await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_writes_pass_when_not_read_only() {
+        let app = app(false);
+
+        let response = send(&app, Method::DELETE, "/api/clusters/prod/logs").await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
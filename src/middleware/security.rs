@@ -1,10 +1,33 @@
 use axum::{
     body::Body,
-    http::{header, HeaderName, Request, StatusCode},
+    extract::State,
+    http::{header, HeaderName, HeaderValue, Request, StatusCode},
     middleware::Next,
     response::Response,
 };
 
+/// Build the Content-Security-Policy header value
+///
+/// `img_origin` is added to `img-src`, so a branding logo hosted on another
+/// origin can load on the login screen.
+///
+/// 'unsafe-inline' in style-src is required because Mantine injects styles at runtime.
+/// TODO: migrate to nonce-based CSP (requires server-generated nonces threaded through
+/// HTML responses) to eliminate 'unsafe-inline' without breaking Mantine.
+pub fn content_security_policy(img_origin: Option<&str>) -> HeaderValue {
+    let img_src = match img_origin {
+        Some(origin) => format!("'self' data: {}", origin),
+        None => "'self' data:".to_string(),
+    };
+    let csp = format!(
+        "default-src 'self'; script-src 'self'; style-src 'self' 'unsafe-inline'; img-src {}; font-src 'self' data:; connect-src 'self'; frame-ancestors 'none'; worker-src 'self' blob:",
+        img_src
+    );
+    // An origin that is not a valid header value is dropped rather than
+    // leaving the app without a CSP
+    HeaderValue::from_str(&csp).unwrap_or_else(|_| content_security_policy(None))
+}
+
 /// Security headers middleware
 ///
 /// Adds security headers to all responses:
@@ -18,6 +41,7 @@ use axum::{
 ///
 /// Validates: Requirements 30.1, 30.2
 pub async fn security_headers_middleware(
+    State(csp): State<HeaderValue>,
     request: Request<Body>,
     next: Next,
 ) -> Result<Response, StatusCode> {
@@ -27,17 +51,8 @@ pub async fn security_headers_middleware(
     // Get headers map
     let headers = response.headers_mut();
 
-    // Content-Security-Policy: Restrict resource loading
-    // 'unsafe-inline' in style-src is required because Mantine injects styles at runtime.
-    // TODO: migrate to nonce-based CSP (requires server-generated nonces threaded through
-    // HTML responses) to eliminate 'unsafe-inline' without breaking Mantine.
-    // SAFETY: Static CSP header string is always valid
-    headers.insert(
-        header::CONTENT_SECURITY_POLICY,
-        "default-src 'self'; script-src 'self'; style-src 'self' 'unsafe-inline'; img-src 'self' data:; font-src 'self' data:; connect-src 'self'; frame-ancestors 'none'; worker-src 'self' blob:"
-            .parse()
-            .expect("parse static CSP header value"),
-    );
+    // Content-Security-Policy: Restrict resource loading (see content_security_policy)
+    headers.insert(header::CONTENT_SECURITY_POLICY, csp);
 
     // Strict-Transport-Security: Force HTTPS for 1 year
     // includeSubDomains: Apply to all subdomains
@@ -107,7 +122,10 @@ mod tests {
         // Create a simple test route
         let app = Router::new()
             .route("/test", get(|| async { "test response" }))
-            .layer(axum::middleware::from_fn(security_headers_middleware));
+            .layer(axum::middleware::from_fn_with_state(
+                content_security_policy(None),
+                security_headers_middleware,
+            ));
 
         // Make a request
         let response = app
@@ -156,7 +174,10 @@ mod tests {
     async fn test_csp_header_content() {
         let app = Router::new()
             .route("/test", get(|| async { "test" }))
-            .layer(axum::middleware::from_fn(security_headers_middleware));
+            .layer(axum::middleware::from_fn_with_state(
+                content_security_policy(None),
+                security_headers_middleware,
+            ));
 
         let response = app
             .oneshot(
@@ -181,13 +202,28 @@ mod tests {
         assert!(csp.contains("style-src 'self' 'unsafe-inline'"));
         assert!(csp.contains("frame-ancestors 'none'"));
         assert!(csp.contains("worker-src 'self' blob:"));
+        assert!(csp.contains("img-src 'self' data:;"));
+    }
+
+    #[test]
+    fn test_csp_allows_logo_origin() {
+        let csp = content_security_policy(Some("https://static.example.com"));
+        let csp = csp.to_str().expect("CSP header valid str");
+        assert!(
+            csp.contains("img-src 'self' data: https://static.example.com;"),
+            "{}",
+            csp
+        );
     }
 
     #[tokio::test]
     async fn test_hsts_header_content() {
         let app = Router::new()
             .route("/test", get(|| async { "test" }))
-            .layer(axum::middleware::from_fn(security_headers_middleware));
+            .layer(axum::middleware::from_fn_with_state(
+                content_security_policy(None),
+                security_headers_middleware,
+            ));
 
         let response = app
             .oneshot(
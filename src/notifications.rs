@@ -0,0 +1,564 @@
+use crate::cluster::HealthStatus;
+use crate::config::{NotificationsConfig, WebhookConfig, WebhookFormat};
+use chrono::Utc;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Attempts made to deliver one notification to one webhook
+const DELIVERY_ATTEMPTS: u32 = 3;
+/// Delay before the first retry; doubled for each further retry
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+/// Timeout for a single delivery attempt
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Payload posted to webhooks when a cluster's health status changes
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct HealthTransition {
+    pub cluster_id: String,
+    /// Configured display name, if the cluster has one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_name: Option<String>,
+    pub old_status: HealthStatus,
+    pub new_status: HealthStatus,
+    /// RFC 3339 time the change was observed
+    pub timestamp: String,
+}
+
+/// Notification state of a cluster
+#[derive(Debug)]
+struct ClusterStatus {
+    /// Status webhooks last heard about (or the first status seen)
+    notified_status: HealthStatus,
+    /// When a notification for this cluster was last sent
+    last_notified: Option<Instant>,
+}
+
+/// Sends webhook notifications when a cluster's health status changes
+///
+/// Fed by [`crate::cluster::Manager::refresh_health`]. The first status seen
+/// for a cluster is only recorded; later changes are posted to every webhook
+/// whose `on_status` includes the new status, at most once per
+/// `min_interval_seconds` per cluster. Changes inside the interval are held
+/// back, and the status the cluster has settled on is sent with the first
+/// observation after the interval, unless it is back where it was.
+#[derive(Debug)]
+pub struct HealthNotifier {
+    webhooks: Vec<WebhookConfig>,
+    min_interval: Duration,
+    client: reqwest::Client,
+    clusters: Mutex<HashMap<String, ClusterStatus>>,
+}
+
+impl HealthNotifier {
+    pub fn new(config: &NotificationsConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(DELIVERY_TIMEOUT)
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new());
+
+        Self {
+            webhooks: config.webhooks.clone(),
+            min_interval: Duration::from_secs(config.min_interval_seconds),
+            client,
+            clusters: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Record a cluster's current status and notify webhooks if it changed
+    ///
+    /// Deliveries run in background tasks so the caller is not delayed.
+    pub fn observe(&self, cluster_id: &str, cluster_name: Option<&str>, status: &HealthStatus) {
+        let Some((mut transition, webhooks)) = self.record(cluster_id, status, Instant::now())
+        else {
+            return;
+        };
+        transition.cluster_name = cluster_name.map(str::to_string);
+
+        tracing::info!(
+            cluster_id = %cluster_id,
+            old_status = ?transition.old_status,
+            new_status = ?transition.new_status,
+            webhooks = webhooks.len(),
+            "Cluster health status changed, sending notifications"
+        );
+
+        for webhook in webhooks {
+            let client = self.client.clone();
+            let transition = transition.clone();
+            tokio::spawn(async move {
+                deliver(&client, &webhook, &transition, RETRY_BASE_DELAY).await;
+            });
+        }
+    }
+
+    /// Compare with the last notified status and return the transition to
+    /// send, if any, with the webhooks it should go to
+    fn record(
+        &self,
+        cluster_id: &str,
+        status: &HealthStatus,
+        now: Instant,
+    ) -> Option<(HealthTransition, Vec<WebhookConfig>)> {
+        let mut clusters = self
+            .clusters
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let Some(known) = clusters.get_mut(cluster_id) else {
+            clusters.insert(
+                cluster_id.to_string(),
+                ClusterStatus {
+                    notified_status: status.clone(),
+                    last_notified: None,
+                },
+            );
+            return None;
+        };
+        if known.notified_status == *status {
+            return None;
+        }
+
+        // Held back, not dropped: the next observation after the interval
+        // still differs from the notified status and is sent then
+        if known
+            .last_notified
+            .is_some_and(|last| now.duration_since(last) < self.min_interval)
+        {
+            tracing::debug!(
+                cluster_id = %cluster_id,
+                old_status = ?known.notified_status,
+                new_status = ?status,
+                "Health status change inside the notification interval, deferring"
+            );
+            return None;
+        }
+        let old_status = std::mem::replace(&mut known.notified_status, status.clone());
+
+        let webhooks: Vec<WebhookConfig> = self
+            .webhooks
+            .iter()
+            .filter(|webhook| webhook.on_status.contains(status))
+            .cloned()
+            .collect();
+        if webhooks.is_empty() {
+            return None;
+        }
+        known.last_notified = Some(now);
+
+        Some((
+            HealthTransition {
+                cluster_id: cluster_id.to_string(),
+                cluster_name: None,
+                old_status,
+                new_status: status.clone(),
+                timestamp: Utc::now().to_rfc3339(),
+            },
+            webhooks,
+        ))
+    }
+}
+
+/// Post a transition to a webhook, retrying with exponential backoff
+///
+/// Returns whether the webhook accepted the notification.
+async fn deliver(
+    client: &reqwest::Client,
+    webhook: &WebhookConfig,
+    transition: &HealthTransition,
+    retry_delay: Duration,
+) -> bool {
+    let host = webhook_host(&webhook.url);
+    let body = payload(webhook.format, transition);
+    let mut delay = retry_delay;
+    for attempt in 1..=DELIVERY_ATTEMPTS {
+        let error = match client.post(&webhook.url).json(&body).send().await {
+            Ok(response) if response.status().is_success() => {
+                tracing::info!(
+                    cluster_id = %transition.cluster_id,
+                    webhook = %host,
+                    attempt,
+                    "Health notification delivered"
+                );
+                return true;
+            }
+            Ok(response) => format!("webhook returned {}", response.status()),
+            Err(e) => e.without_url().to_string(),
+        };
+
+        if attempt == DELIVERY_ATTEMPTS {
+            tracing::error!(
+                cluster_id = %transition.cluster_id,
+                webhook = %host,
+                error = %error,
+                "Health notification delivery failed, giving up"
+            );
+            break;
+        }
+
+        tracing::warn!(
+            cluster_id = %transition.cluster_id,
+            webhook = %host,
+            attempt,
+            error = %error,
+            "Health notification delivery failed, retrying"
+        );
+        tokio::time::sleep(delay).await;
+        delay *= 2;
+    }
+
+    false
+}
+
+/// Build the webhook body for a transition in the requested format
+fn payload(format: WebhookFormat, transition: &HealthTransition) -> Value {
+    match format {
+        WebhookFormat::Generic => json!(transition),
+        WebhookFormat::Slack => slack_payload(transition),
+    }
+}
+
+/// Slack incoming-webhook message with an attachment coloured by the new status
+///
+/// Falls back to the cluster id when the cluster has no configured name.
+fn slack_payload(transition: &HealthTransition) -> Value {
+    let cluster = slack_escape(
+        transition
+            .cluster_name
+            .as_deref()
+            .unwrap_or(&transition.cluster_id),
+    );
+    let old_status = status_name(&transition.old_status);
+    let new_status = status_name(&transition.new_status);
+    let (emoji, color) = match transition.new_status {
+        HealthStatus::Green => (":large_green_circle:", "good"),
+        HealthStatus::Yellow => (":large_yellow_circle:", "warning"),
+        HealthStatus::Red => (":red_circle:", "danger"),
+    };
+    let text = format!(
+        "{} Cluster *{}* is now {} (was {})",
+        emoji, cluster, new_status, old_status
+    );
+    let ts = chrono::DateTime::parse_from_rfc3339(&transition.timestamp)
+        .map(|time| time.timestamp())
+        .unwrap_or_else(|_| Utc::now().timestamp());
+
+    json!({
+        "text": text,
+        "attachments": [{
+            "color": color,
+            "fallback": text,
+            "fields": [
+                {"title": "Cluster", "value": cluster, "short": true},
+                {"title": "Cluster ID", "value": slack_escape(&transition.cluster_id), "short": true},
+                {"title": "Previous status", "value": old_status, "short": true},
+                {"title": "New status", "value": new_status, "short": true},
+            ],
+            "footer": "Secan",
+            "ts": ts,
+        }]
+    })
+}
+
+/// Escape the characters Slack treats as control sequences in message text
+fn slack_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn status_name(status: &HealthStatus) -> &'static str {
+    match status {
+        HealthStatus::Green => "green",
+        HealthStatus::Yellow => "yellow",
+        HealthStatus::Red => "red",
+    }
+}
+
+/// Host of a webhook URL, for logging
+///
+/// Webhook URLs often carry a secret token in the path, so only the host is
+/// ever logged.
+fn webhook_host(url: &str) -> String {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn notifier(webhooks: Vec<WebhookConfig>, min_interval_seconds: u64) -> HealthNotifier {
+        HealthNotifier::new(&NotificationsConfig {
+            webhooks,
+            min_interval_seconds,
+        })
+    }
+
+    fn webhook(url: &str, on_status: Vec<HealthStatus>) -> WebhookConfig {
+        WebhookConfig {
+            url: url.to_string(),
+            on_status,
+            format: WebhookFormat::Generic,
+        }
+    }
+
+    fn transition(old_status: HealthStatus, new_status: HealthStatus) -> HealthTransition {
+        HealthTransition {
+            cluster_id: "prod".to_string(),
+            cluster_name: None,
+            old_status,
+            new_status,
+            timestamp: Utc::now().to_rfc3339(),
+        }
+    }
+
+    fn urls(webhooks: &[WebhookConfig]) -> Vec<&str> {
+        webhooks
+            .iter()
+            .map(|webhook| webhook.url.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_record_reports_transitions_only() {
+        let notifier = notifier(
+            vec![webhook(
+                "http://hooks/all",
+                vec![HealthStatus::Green, HealthStatus::Yellow, HealthStatus::Red],
+            )],
+            0,
+        );
+        let now = Instant::now();
+
+        assert!(notifier.record("prod", &HealthStatus::Green, now).is_none());
+        assert!(notifier.record("prod", &HealthStatus::Green, now).is_none());
+
+        let (transition, webhooks) = notifier
+            .record("prod", &HealthStatus::Yellow, now)
+            .expect("green to yellow is a transition");
+        assert_eq!(transition.old_status, HealthStatus::Green);
+        assert_eq!(transition.new_status, HealthStatus::Yellow);
+        assert_eq!(urls(&webhooks), vec!["http://hooks/all"]);
+    }
+
+    #[test]
+    fn test_record_filters_webhooks_by_status() {
+        let notifier = notifier(
+            vec![
+                webhook("http://hooks/red", vec![HealthStatus::Red]),
+                webhook("http://hooks/yellow", vec![HealthStatus::Yellow]),
+            ],
+            0,
+        );
+        let now = Instant::now();
+
+        notifier.record("prod", &HealthStatus::Yellow, now);
+        let (_, webhooks) = notifier
+            .record("prod", &HealthStatus::Red, now)
+            .expect("yellow to red is a transition");
+        assert_eq!(urls(&webhooks), vec!["http://hooks/red"]);
+
+        // No webhook listens for green
+        assert!(notifier.record("prod", &HealthStatus::Green, now).is_none());
+    }
+
+    #[test]
+    fn test_record_debounces_flapping() {
+        let notifier = notifier(
+            vec![webhook(
+                "http://hooks/all",
+                vec![HealthStatus::Green, HealthStatus::Yellow],
+            )],
+            60,
+        );
+        let start = Instant::now();
+
+        notifier.record("prod", &HealthStatus::Green, start);
+        assert!(notifier
+            .record("prod", &HealthStatus::Yellow, start)
+            .is_some());
+        assert!(notifier
+            .record("prod", &HealthStatus::Green, start + Duration::from_secs(5))
+            .is_none());
+
+        // Back on the notified status once the interval has passed: nothing new
+        assert!(notifier
+            .record(
+                "prod",
+                &HealthStatus::Yellow,
+                start + Duration::from_secs(61),
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn test_record_delivers_settled_status_after_interval() {
+        let notifier = notifier(
+            vec![webhook(
+                "http://hooks/all",
+                vec![HealthStatus::Green, HealthStatus::Yellow, HealthStatus::Red],
+            )],
+            60,
+        );
+        let start = Instant::now();
+
+        notifier.record("prod", &HealthStatus::Green, start);
+        assert!(notifier
+            .record("prod", &HealthStatus::Yellow, start)
+            .is_some());
+        assert!(notifier
+            .record("prod", &HealthStatus::Red, start + Duration::from_secs(5))
+            .is_none());
+        assert!(notifier
+            .record("prod", &HealthStatus::Red, start + Duration::from_secs(30))
+            .is_none());
+
+        let (transition, _) = notifier
+            .record("prod", &HealthStatus::Red, start + Duration::from_secs(61))
+            .expect("settled status is sent once the interval has passed");
+        assert_eq!(transition.old_status, HealthStatus::Yellow);
+        assert_eq!(transition.new_status, HealthStatus::Red);
+
+        // The recovery is held back the same way and still delivered
+        assert!(notifier
+            .record(
+                "prod",
+                &HealthStatus::Green,
+                start + Duration::from_secs(70)
+            )
+            .is_none());
+        let (transition, _) = notifier
+            .record(
+                "prod",
+                &HealthStatus::Green,
+                start + Duration::from_secs(122),
+            )
+            .expect("recovery is sent once the interval has passed");
+        assert_eq!(transition.old_status, HealthStatus::Red);
+        assert_eq!(transition.new_status, HealthStatus::Green);
+    }
+
+    #[test]
+    fn test_webhook_host_hides_path() {
+        assert_eq!(
+            webhook_host("https://hooks.slack.com/services/T000/B000/secret"),
+            "hooks.slack.com"
+        );
+        assert_eq!(webhook_host("not a url"), "");
+    }
+
+    #[tokio::test]
+    async fn test_deliver_retries_failed_attempts() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "cluster_id": "prod",
+                "old_status": "green",
+                "new_status": "red"
+            })))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let transition = transition(HealthStatus::Green, HealthStatus::Red);
+
+        assert!(
+            deliver(
+                &reqwest::Client::new(),
+                &webhook(&server.uri(), vec![HealthStatus::Red]),
+                &transition,
+                Duration::from_millis(10)
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deliver_gives_up_after_attempts() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(u64::from(DELIVERY_ATTEMPTS))
+            .mount(&server)
+            .await;
+
+        let transition = transition(HealthStatus::Yellow, HealthStatus::Red);
+
+        assert!(
+            !deliver(
+                &reqwest::Client::new(),
+                &webhook(&server.uri(), vec![HealthStatus::Red]),
+                &transition,
+                Duration::from_millis(10)
+            )
+            .await
+        );
+    }
+
+    #[test]
+    fn test_slack_payload_uses_cluster_name() {
+        let mut transition = transition(HealthStatus::Yellow, HealthStatus::Red);
+        transition.cluster_name = Some("Production".to_string());
+
+        let body = payload(WebhookFormat::Slack, &transition);
+
+        assert_eq!(
+            body["text"],
+            ":red_circle: Cluster *Production* is now red (was yellow)"
+        );
+        assert_eq!(body["attachments"][0]["color"], "danger");
+        assert!(body["attachments"][0]["ts"].is_i64());
+    }
+
+    #[test]
+    fn test_slack_payload_without_cluster_name() {
+        let transition = transition(HealthStatus::Red, HealthStatus::Green);
+
+        let body = payload(WebhookFormat::Slack, &transition);
+
+        assert_eq!(
+            body["text"],
+            ":large_green_circle: Cluster *prod* is now green (was red)"
+        );
+        assert_eq!(body["attachments"][0]["color"], "good");
+        assert_eq!(body["attachments"][0]["fields"][0]["value"], "prod");
+    }
+
+    #[test]
+    fn test_slack_payload_escapes_cluster_name() {
+        let mut transition = transition(HealthStatus::Green, HealthStatus::Yellow);
+        transition.cluster_name = Some("<dev & test>".to_string());
+
+        let body = payload(WebhookFormat::Slack, &transition);
+
+        assert_eq!(
+            body["attachments"][0]["fields"][0]["value"],
+            "&lt;dev &amp; test&gt;"
+        );
+    }
+
+    #[test]
+    fn test_generic_payload_omits_unknown_cluster_name() {
+        let body = payload(
+            WebhookFormat::Generic,
+            &transition(HealthStatus::Green, HealthStatus::Yellow),
+        );
+
+        assert!(body.get("cluster_name").is_none());
+        assert_eq!(body["old_status"], "green");
+        assert_eq!(body["new_status"], "yellow");
+    }
+}
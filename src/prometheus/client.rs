@@ -1,4 +1,5 @@
 use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
 use reqwest::{Client as HttpClient, Url};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -47,11 +48,31 @@ pub struct TimeSeriesData {
     pub value: Option<InstantValue>,
 }
 
-/// Query result from Prometheus API
+/// Query result envelope from Prometheus API
+///
+/// On failure Prometheus returns `status: "error"` with `errorType` and
+/// `error` instead of `data`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct QueryResult {
     status: String,
-    data: QueryData,
+    #[serde(default)]
+    data: Option<QueryData>,
+    #[serde(rename = "errorType", default)]
+    error_type: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+impl QueryResult {
+    /// Describe an error envelope for error messages
+    fn error_message(&self) -> String {
+        match (&self.error_type, &self.error) {
+            (Some(kind), Some(message)) => format!("{}: {}", kind, message),
+            (None, Some(message)) => message.clone(),
+            (Some(kind), None) => kind.clone(),
+            (None, None) => self.status.clone(),
+        }
+    }
 }
 
 /// Query data structure matching Prometheus API format
@@ -123,7 +144,7 @@ impl Client {
     /// * `start` - Unix timestamp (seconds) for range start
     /// * `end` - Unix timestamp (seconds) for range end
     /// * `step` - Query resolution step (interval between samples, in seconds)
-    pub async fn query_range(
+    pub async fn query_range_series(
         &self,
         query: &str,
         start: i64,
@@ -140,7 +161,60 @@ impl Client {
             ("step", step.to_string()),
         ];
 
-        self.execute_query("/api/v1/query_range", params).await
+        let data = self.fetch_query_data("/api/v1/query_range", params).await?;
+        anyhow::ensure!(
+            data.result_type == "matrix",
+            "Unexpected Prometheus result type for range query: {}",
+            data.result_type
+        );
+        Ok(data.into_timeseries())
+    }
+
+    /// Execute a range query with a custom step and return its samples
+    ///
+    /// Intended for queries that yield a single series, such as aggregations
+    /// used for charts. Returns an empty list when nothing matched and an
+    /// error when the query yields more than one series; use
+    /// [`Client::query_range_series`] to keep per-series labels.
+    ///
+    /// # Arguments
+    /// * `query` - PromQL query string
+    /// * `start` - Range start
+    /// * `end` - Range end
+    /// * `step` - Interval between samples (sub-second precision is supported)
+    pub async fn query_range(
+        &self,
+        query: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        step: Duration,
+    ) -> Result<Vec<RangeValue>> {
+        anyhow::ensure!(start < end, "start time must be before end time");
+        anyhow::ensure!(!step.is_zero(), "step must be positive");
+
+        let params = vec![
+            ("query", query.to_string()),
+            ("start", start.timestamp().to_string()),
+            ("end", end.timestamp().to_string()),
+            // Float seconds, which Prometheus accepts alongside duration strings
+            ("step", step.as_secs_f64().to_string()),
+        ];
+
+        let data = self.fetch_query_data("/api/v1/query_range", params).await?;
+        anyhow::ensure!(
+            data.result_type == "matrix",
+            "Unexpected Prometheus result type for range query: {}",
+            data.result_type
+        );
+
+        let mut series = data.into_timeseries();
+        anyhow::ensure!(
+            series.len() <= 1,
+            "Prometheus range query returned {} series; aggregate the query to a single series",
+            series.len()
+        );
+
+        Ok(series.pop().and_then(|s| s.values).unwrap_or_default())
     }
 
     /// Test connectivity to Prometheus endpoint
@@ -164,6 +238,22 @@ impl Client {
         endpoint: &str,
         params: Vec<(&str, String)>,
     ) -> Result<Vec<TimeSeriesData>> {
+        let results = self
+            .fetch_query_data(endpoint, params)
+            .await?
+            .into_timeseries();
+
+        debug!("Prometheus query returned {} time series", results.len());
+
+        Ok(results)
+    }
+
+    /// Execute a query and return the `data` section of a successful response
+    async fn fetch_query_data(
+        &self,
+        endpoint: &str,
+        params: Vec<(&str, String)>,
+    ) -> Result<QueryData> {
         let url = format!("{}{}", self.base_url, endpoint);
 
         let mut request = self.http_client.get(&url);
@@ -186,29 +276,31 @@ impl Client {
             .context("Failed to connect to Prometheus")?;
 
         let status = response.status();
-        if !status.is_success() {
-            let error_body = response.text().await.unwrap_or_default();
-            return Err(anyhow!(
-                "Prometheus query failed with status {}: {}",
-                status,
-                error_body
-            ));
-        }
-
-        let body = response
-            .json::<QueryResult>()
+        let text = response
+            .text()
             .await
-            .context("Failed to parse Prometheus response")?;
+            .context("Failed to read Prometheus response")?;
+
+        // Prometheus reports query errors (e.g. bad PromQL) as an error
+        // envelope, usually with a 4xx/5xx status
+        let body = match serde_json::from_str::<QueryResult>(&text) {
+            Ok(body) => body,
+            Err(_) if !status.is_success() => {
+                return Err(anyhow!(
+                    "Prometheus query failed with status {}: {}",
+                    status,
+                    text
+                ));
+            }
+            Err(e) => return Err(e).context("Failed to parse Prometheus response"),
+        };
 
         if body.status != "success" {
-            return Err(anyhow!("Prometheus query failed: {}", body.status));
+            return Err(anyhow!("Prometheus query failed: {}", body.error_message()));
         }
 
-        let results = body.data.into_timeseries();
-
-        debug!("Prometheus query returned {} time series", results.len());
-
-        Ok(results)
+        body.data
+            .ok_or_else(|| anyhow!("Prometheus response is missing data"))
     }
 
     /// Parse a time series value string to f64
@@ -291,6 +383,104 @@ mod tests {
         assert_eq!(query, "elasticsearch_jvm_memory_used_bytes");
     }
 
+    async fn mock_client(response: wiremock::ResponseTemplate) -> (wiremock::MockServer, Client) {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/query_range"))
+            .respond_with(response)
+            .mount(&server)
+            .await;
+
+        let client = Client::new(PrometheusConfig {
+            url: server.uri(),
+            ..Default::default()
+        })
+        .expect("create client");
+        (server, client)
+    }
+
+    fn range() -> (DateTime<Utc>, DateTime<Utc>) {
+        (
+            DateTime::from_timestamp(1_700_000_000, 0).expect("valid timestamp"),
+            DateTime::from_timestamp(1_700_000_120, 0).expect("valid timestamp"),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_query_range_parses_matrix() {
+        let (_server, client) = mock_client(wiremock::ResponseTemplate::new(200).set_body_json(
+            serde_json::json!({
+                "status": "success",
+                "data": {
+                    "resultType": "matrix",
+                    "result": [{
+                        "metric": {},
+                        "values": [[1700000000, "1"], [1700000060, "2.5"]]
+                    }]
+                }
+            }),
+        ))
+        .await;
+
+        let (start, end) = range();
+        let values = client
+            .query_range("sum(up)", start, end, Duration::from_secs(60))
+            .await
+            .expect("range query");
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[1].0, 1_700_000_060);
+        assert_eq!(values[1].1, "2.5");
+    }
+
+    #[tokio::test]
+    async fn test_query_range_reports_error_envelope() {
+        let (_server, client) = mock_client(wiremock::ResponseTemplate::new(400).set_body_json(
+            serde_json::json!({
+                "status": "error",
+                "errorType": "bad_data",
+                "error": "parse error at char 4"
+            }),
+        ))
+        .await;
+
+        let (start, end) = range();
+        let err = client
+            .query_range("sum(", start, end, Duration::from_secs(60))
+            .await
+            .expect_err("bad query should fail");
+        assert!(err.to_string().contains("bad_data: parse error at char 4"));
+    }
+
+    #[tokio::test]
+    async fn test_query_range_rejects_multiple_series() {
+        let (_server, client) = mock_client(wiremock::ResponseTemplate::new(200).set_body_json(
+            serde_json::json!({
+                "status": "success",
+                "data": {
+                    "resultType": "matrix",
+                    "result": [
+                        {"metric": {"node": "a"}, "values": [[1700000000, "1"]]},
+                        {"metric": {"node": "b"}, "values": [[1700000000, "2"]]}
+                    ]
+                }
+            }),
+        ))
+        .await;
+
+        let (start, end) = range();
+        assert!(client
+            .query_range("up", start, end, Duration::from_secs(60))
+            .await
+            .is_err());
+        assert!(client
+            .query_range("up", end, start, Duration::from_secs(60))
+            .await
+            .is_err());
+    }
+
     #[test]
     fn test_parse_value() {
         assert_eq!(
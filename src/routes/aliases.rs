@@ -1,9 +1,8 @@
 use axum::{extract::Path, response::IntoResponse, Json};
 use serde::{Deserialize, Serialize};
 
-use crate::cluster::client::ElasticsearchClient;
 use crate::routes::cluster_client::ClusterClient;
-use crate::routes::clusters::ClusterErrorResponse;
+use crate::routes::{ApiError, ErrorCode};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AliasInfo {
@@ -19,9 +18,12 @@ pub struct AliasInfo {
 
 pub async fn list_aliases(
     ClusterClient { client, .. }: ClusterClient,
-) -> Result<impl IntoResponse, ClusterErrorResponse> {
+) -> Result<impl IntoResponse, ApiError> {
     let response = client.get_aliases().await.map_err(|e| {
-        ClusterErrorResponse::simple("es_request_failed", format!("ES request failed: {}", e))
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("ES request failed: {}", e),
+        )
     })?;
 
     let mut result = Vec::new();
@@ -58,9 +60,12 @@ pub async fn list_aliases(
 pub async fn get_alias(
     ClusterClient { client, .. }: ClusterClient,
     Path((_, name)): Path<(String, String)>,
-) -> Result<impl IntoResponse, ClusterErrorResponse> {
+) -> Result<impl IntoResponse, ApiError> {
     let response = client.get_alias(&name).await.map_err(|e| {
-        ClusterErrorResponse::simple("es_request_failed", format!("ES request failed: {}", e))
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("ES request failed: {}", e),
+        )
     })?;
 
     // Reuse list parsing but only include entries where alias matches
@@ -98,9 +103,12 @@ pub async fn put_alias(
     ClusterClient { client, .. }: ClusterClient,
     Path((_, name)): Path<(String, String)>,
     Json(body): Json<serde_json::Value>,
-) -> Result<impl IntoResponse, ClusterErrorResponse> {
+) -> Result<impl IntoResponse, ApiError> {
     let response = client.put_alias(&name, body).await.map_err(|e| {
-        ClusterErrorResponse::simple("es_request_failed", format!("ES request failed: {}", e))
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("ES request failed: {}", e),
+        )
     })?;
 
     Ok(Json(response))
@@ -109,9 +117,12 @@ pub async fn put_alias(
 pub async fn delete_alias(
     ClusterClient { client, .. }: ClusterClient,
     Path((_, name)): Path<(String, String)>,
-) -> Result<impl IntoResponse, ClusterErrorResponse> {
+) -> Result<impl IntoResponse, ApiError> {
     let response = client.delete_alias(&name).await.map_err(|e| {
-        ClusterErrorResponse::simple("es_request_failed", format!("ES request failed: {}", e))
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("ES request failed: {}", e),
+        )
     })?;
 
     Ok(Json(response))
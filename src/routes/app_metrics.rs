@@ -1,21 +1,70 @@
 //! Application metrics route handler
 //!
-//! Exposes application metrics in Prometheus format at /app-metrics
+//! Exposes secan's own operational metrics in Prometheus format at /metrics
 
-use axum::{routing::get, Router};
+use axum::{
+    http::{header, StatusCode},
+    response::IntoResponse,
+    routing::get,
+    Router,
+};
+
+/// Content type of the Prometheus text exposition format
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
 
 /// Create the app metrics router
-pub fn app_metrics_router() -> Router {
-    Router::new().route("/app-metrics", get(app_metrics_handler))
+pub fn app_metrics_router<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new().route("/metrics", get(app_metrics_handler))
 }
 
 /// Handler that returns Prometheus-format metrics
-/// Note: This requires the metrics to be initialized at startup
-async fn app_metrics_handler() -> (axum::http::StatusCode, String) {
-    // The metrics are served by the Prometheus exporter running on port 9090
-    // This endpoint redirects to that or could be used for a combined view
+async fn app_metrics_handler() -> impl IntoResponse {
     (
-        axum::http::StatusCode::OK,
-        "# Metrics available at :9090/metrics\n".to_string(),
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+        crate::app_metrics::render(),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_metrics_endpoint_renders_recorded_metrics() {
+        crate::app_metrics::init_metrics();
+        crate::app_metrics::http::record_request("GET", "/api/test-route", 200, 1.5);
+
+        let response = app_metrics_router::<()>()
+            .oneshot(
+                Request::builder()
+                    .uri("/metrics")
+                    .body(Body::empty())
+                    .expect("build request"),
+            )
+            .await
+            .expect("request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response
+                .headers()
+                .get(header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok()),
+            Some(PROMETHEUS_CONTENT_TYPE)
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("read body");
+        let text = String::from_utf8(body.to_vec()).expect("utf8 body");
+        assert!(text.contains("http_requests_total"));
+        assert!(text.contains("route=\"/api/test-route\""));
+    }
+}
@@ -1,5 +1,7 @@
 use crate::auth::middleware::AuthenticatedUser;
-use crate::auth::{OidcAuthProvider, SessionManager};
+use crate::auth::{ActiveSession, OidcAuthProvider, SessionManager};
+use crate::middleware::client_ip::ClientIp;
+use crate::routes::{ApiError, ErrorCode};
 use axum::body::Body;
 use axum::{
     extract::{Query, State},
@@ -192,7 +194,7 @@ pub async fn oidc_callback(
 
     // Validate and consume state using the provider so CSRF handling (including TTL)
     // is centralized in OidcAuthProvider.
-    let redirect_from_state = oidc_provider
+    let pending_login = oidc_provider
         .validate_and_consume_state(&params.state)
         .map_err(|_| ErrorResponse {
             error: "invalid_state".to_string(),
@@ -217,7 +219,7 @@ pub async fn oidc_callback(
 
     // Validate and decode ID token (async)
     let claims = oidc_provider
-        .validate_id_token(&token_response.id_token)
+        .validate_id_token(&token_response.id_token, Some(&pending_login.nonce))
         .await
         .map_err(|e| {
             tracing::error!(
@@ -256,11 +258,17 @@ pub async fn oidc_callback(
 
     // Use redirect returned by state validator if present. Validate to avoid
     // open-redirect attacks. If invalid, fall back to '/'.
-    let redirect_path = redirect_from_state
+    let redirect_path = pending_login
+        .redirect_to
         .filter(|r| is_safe_redirect(r))
         .unwrap_or_else(|| "/".to_string());
-    // Normalize redirect path to collapse duplicate slashes (defensive)
-    let redirect_path = collapse_duplicate_slashes(&redirect_path);
+    // Normalize redirect path to collapse duplicate slashes (defensive), then
+    // put it under the base path: frontend routes are relative to it
+    let redirect_path = format!(
+        "{}{}",
+        state.config.server.path_prefix(),
+        collapse_duplicate_slashes(&redirect_path)
+    );
 
     // Get session timeout from config
     let max_age_seconds = state.config.auth.session_timeout_minutes * 60;
@@ -269,7 +277,9 @@ pub async fn oidc_callback(
     let mut response = Response::new(Body::empty());
     response.headers_mut().insert(
         http::header::SET_COOKIE,
-        crate::auth::build_session_cookie_header(&session_token, max_age_seconds),
+        state
+            .session_manager
+            .session_cookie_header(&session_token, max_age_seconds),
     );
     response.headers_mut().insert(
         http::header::LOCATION,
@@ -281,7 +291,7 @@ pub async fn oidc_callback(
     Ok(response)
 }
 
-fn build_login_response(token: &str, max_age_seconds: u64) -> Result<Response, ErrorResponse> {
+fn build_login_response(cookie: http::HeaderValue) -> Result<Response, ErrorResponse> {
     let body = serde_json::to_string(&LoginResponse {
         success: true,
         message: "Login successful".to_string(),
@@ -294,10 +304,9 @@ fn build_login_response(token: &str, max_age_seconds: u64) -> Result<Response, E
         }
     })?;
     let mut response = axum::response::Response::new(axum::body::Body::from(body));
-    response.headers_mut().insert(
-        http::header::SET_COOKIE,
-        crate::auth::build_session_cookie_header(token, max_age_seconds),
-    );
+    response
+        .headers_mut()
+        .insert(http::header::SET_COOKIE, cookie);
     response.headers_mut().insert(
         http::header::CONTENT_TYPE,
         http::HeaderValue::from_static("application/json"),
@@ -326,6 +335,7 @@ fn build_login_response(token: &str, max_age_seconds: u64) -> Result<Response, E
 #[instrument(skip(state, payload))]
 pub async fn login(
     State(state): State<AuthState>,
+    client_ip: Option<Extension<ClientIp>>,
     Json(payload): Json<LoginRequest>,
 ) -> Result<Response, ErrorResponse> {
     // OIDC mode: password credentials are not accepted — redirect to the OIDC flow.
@@ -333,13 +343,19 @@ pub async fn login(
     // reaching POST /auth/login in OIDC mode means the UI sent the wrong request.
     if state.oidc_provider.is_some() {
         tracing::debug!("Password login attempted while OIDC is active — redirecting to OIDC flow");
-        return Ok(Redirect::to("/auth/oidc/login").into_response());
+        let oidc_login = format!("{}/auth/oidc/login", state.config.server.path_prefix());
+        return Ok(Redirect::to(&oidc_login).into_response());
     }
 
     // If a local auth provider is configured (covers local users with rate limiting and proper bcrypt handling)
     if let Some(local_provider) = &state.local_provider {
+        // Failed attempts are counted per client, so they cannot lock the
+        // user out everywhere
+        let client_ip = client_ip
+            .map(|Extension(ClientIp(ip))| ip.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
         match local_provider
-            .authenticate(&payload.username, &payload.password)
+            .authenticate_with_ip(&payload.username, &payload.password, &client_ip)
             .await
             .map_err(|e| {
                 tracing::error!(error = %e, "Local authentication failed");
@@ -351,7 +367,11 @@ pub async fn login(
             Some(token) => {
                 tracing::info!(username = %payload.username, "User authenticated successfully (local)");
                 let max_age_seconds = state.config.auth.session_timeout_minutes * 60;
-                return build_login_response(&token, max_age_seconds);
+                return build_login_response(
+                    state
+                        .session_manager
+                        .session_cookie_header(&token, max_age_seconds),
+                );
             }
             None => {
                 tracing::warn!(username = %payload.username, "Invalid credentials (local)");
@@ -383,7 +403,11 @@ pub async fn login(
         tracing::info!(username = %payload.username, "LDAP user authenticated successfully");
 
         let max_age_seconds = state.config.auth.session_timeout_minutes * 60;
-        return build_login_response(&session_token, max_age_seconds);
+        return build_login_response(
+            state
+                .session_manager
+                .session_cookie_header(&session_token, max_age_seconds),
+        );
     }
 
     Err(ErrorResponse {
@@ -445,7 +469,15 @@ pub async fn logout(
     headers: axum::http::HeaderMap,
 ) -> Result<Response, ErrorResponse> {
     // Extract session token from cookie
-    if let Some(token) = crate::auth::session::extract_session_token(&headers) {
+    if let Some(token) = state.session_manager.extract_session_token(&headers) {
+        // Drop any OIDC tokens held for the session
+        if let (Some(oidc_provider), Some(session_id)) = (
+            &state.oidc_provider,
+            state.session_manager.session_id(&token),
+        ) {
+            oidc_provider.forget_session(&session_id);
+        }
+
         // Invalidate session
         if let Err(e) = state.session_manager.invalidate_session(&token).await {
             tracing::error!(error = %e, "Failed to invalidate session");
@@ -456,7 +488,7 @@ pub async fn logout(
 
     // Clear session cookie using the same Secure flag as the one used when
     // setting the cookie so browsers will correctly remove it.
-    let clear_cookie = crate::auth::build_clear_session_cookie_header();
+    let clear_cookie = state.session_manager.clear_session_cookie_header();
 
     tracing::debug!("User logged out");
 
@@ -465,15 +497,121 @@ pub async fn logout(
     response
         .headers_mut()
         .insert(header::SET_COOKIE, clear_cookie);
+    let login = format!(
+        "{}/login?logged_out=true",
+        state.config.server.path_prefix()
+    );
     response.headers_mut().insert(
         header::LOCATION,
-        header::HeaderValue::from_static("/login?logged_out=true"),
+        header::HeaderValue::from_str(&login)
+            .unwrap_or_else(|_| header::HeaderValue::from_static("/login?logged_out=true")),
     );
     *response.status_mut() = StatusCode::FOUND;
 
     Ok(response)
 }
 
+/// Request to revoke every session of a user
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RevokeSessionsRequest {
+    /// Username or user ID whose sessions are revoked
+    #[schema(example = "alice")]
+    pub user: String,
+}
+
+/// Result of revoking a user's sessions
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RevokeSessionsResponse {
+    /// Number of sessions revoked
+    #[schema(example = 2)]
+    pub revoked: usize,
+}
+
+/// Reject users without admin permission on every cluster
+fn require_global_admin(state: &AuthState, user: &AuthenticatedUser) -> Result<(), ApiError> {
+    if state.rbac.is_global_admin(&user.0) {
+        return Ok(());
+    }
+    tracing::warn!(username = %user.0.username, "Session administration denied");
+    Err(ApiError::new(
+        ErrorCode::AccessDenied,
+        "Managing sessions requires admin permission on all clusters",
+    ))
+}
+
+/// List active sessions
+///
+/// Lists the sessions this instance has seen that have not expired or been
+/// logged out, most recently active first. Requires a role with `admin`
+/// permission on all clusters.
+#[utoipa::path(
+    get,
+    path = "/auth/sessions",
+    responses(
+        (status = 200, body = Vec<ActiveSession>),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError)
+    ),
+    tag = "Authentication"
+)]
+#[instrument(skip(state), fields(username = %user.0.username))]
+pub async fn list_sessions(
+    State(state): State<AuthState>,
+    Extension(user): Extension<AuthenticatedUser>,
+) -> Result<Json<Vec<ActiveSession>>, ApiError> {
+    require_global_admin(&state, &user)?;
+    Ok(Json(state.session_manager.list_sessions()))
+}
+
+/// Revoke every session of a user
+///
+/// Logs the user out everywhere: all of their sessions are rejected from the
+/// next request on. Requires a role with `admin` permission on all clusters.
+#[utoipa::path(
+    post,
+    path = "/auth/sessions/revoke",
+    request_body = RevokeSessionsRequest,
+    responses(
+        (status = 200, body = RevokeSessionsResponse),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError)
+    ),
+    tag = "Authentication"
+)]
+#[instrument(skip(state, request), fields(username = %user.0.username))]
+pub async fn revoke_sessions(
+    State(state): State<AuthState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Json(request): Json<RevokeSessionsRequest>,
+) -> Result<Json<RevokeSessionsResponse>, ApiError> {
+    require_global_admin(&state, &user)?;
+    let target = request.user.trim();
+    if target.is_empty() {
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            "user must not be empty",
+        ));
+    }
+
+    let session_ids = state.session_manager.revoke_user_sessions(target).await;
+    if let Some(oidc_provider) = &state.oidc_provider {
+        for session_id in &session_ids {
+            oidc_provider.forget_session(session_id);
+        }
+    }
+    tracing::info!(
+        admin = %user.0.username,
+        user = %target,
+        revoked = session_ids.len(),
+        "Sessions revoked by administrator"
+    );
+
+    Ok(Json(RevokeSessionsResponse {
+        revoked: session_ids.len(),
+    }))
+}
+
 /// Return true if the given redirect target is considered safe.
 ///
 /// Rules:
@@ -504,6 +642,10 @@ pub struct AuthStatusResponse {
     pub oidc_enabled: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub oidc_redirect_delay: Option<u64>,
+    /// Cluster the UI opens by default, if configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "production")]
+    pub default_cluster: Option<String>,
 }
 
 /// Get authentication status
@@ -541,6 +683,7 @@ pub async fn get_auth_status(
         mode: mode.to_string(),
         oidc_enabled,
         oidc_redirect_delay,
+        default_cluster: state.config.default_cluster.clone(),
     }))
 }
 
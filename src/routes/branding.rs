@@ -0,0 +1,21 @@
+use crate::config::BrandingConfig;
+use axum::{extract::State, Json};
+use std::sync::Arc;
+
+/// Get the login screen branding
+///
+/// Returns the configured `branding` block; fields that are not configured
+/// are omitted and the frontend keeps its built-in look for them.
+/// This endpoint does not require authentication, so the login screen can
+/// read it before anyone signs in.
+#[utoipa::path(
+    get,
+    path = "/branding",
+    responses(
+        (status = 200, description = "Login screen branding", body = BrandingConfig)
+    ),
+    tag = "Authentication"
+)]
+pub async fn get_branding(State(branding): State<Arc<BrandingConfig>>) -> Json<BrandingConfig> {
+    Json(branding.as_ref().clone())
+}
@@ -4,19 +4,19 @@ use axum::{
 };
 use std::sync::Arc;
 
-use crate::cluster::Client;
-use crate::routes::{ClusterErrorResponse, ClusterState};
+use crate::cluster::ElasticsearchClient;
+use crate::routes::{ApiError, ClusterState, ErrorCode};
 
 /// Axum extractor that resolves a cluster ID from the path and returns the
 /// associated Elasticsearch client. Eliminates the 5-line get_cluster +
 /// client-extract boilerplate that appeared in every cluster route handler.
 pub struct ClusterClient {
-    pub client: Arc<Client>,
+    pub client: Arc<dyn ElasticsearchClient>,
     pub cluster_id: String,
 }
 
 impl FromRequestParts<ClusterState> for ClusterClient {
-    type Rejection = ClusterErrorResponse;
+    type Rejection = ApiError;
 
     async fn from_request_parts(
         parts: &mut Parts,
@@ -25,24 +25,19 @@ impl FromRequestParts<ClusterState> for ClusterClient {
         let Path(cluster_id) = Path::<String>::from_request_parts(parts, state)
             .await
             .map_err(|_| {
-                ClusterErrorResponse::simple("invalid_path", "Missing or invalid cluster ID")
+                ApiError::new(ErrorCode::ValidationFailed, "Missing or invalid cluster ID")
             })?;
 
         let cluster = state
             .cluster_manager
             .get_cluster(&cluster_id)
             .await
-            .map_err(|e| {
-                ClusterErrorResponse::simple(
-                    "cluster_not_found",
-                    format!("Cluster not found: {}", e),
-                )
-            })?;
+            .map_err(|_| ApiError::cluster_not_found(&cluster_id))?;
 
         let client = cluster
             .client
             .clone()
-            .ok_or_else(|| ClusterErrorResponse::unavailable(&cluster_id, None))?;
+            .ok_or_else(|| ApiError::inaccessible(&cluster))?;
 
         Ok(ClusterClient { client, cluster_id })
     }
@@ -1,13 +1,16 @@
 use crate::auth::middleware::AuthenticatedUser;
 use crate::auth::RbacManager;
 use crate::cache::MetadataCache;
-use crate::cluster::{manager::ProxyAuditRequest, ClusterInfo, Manager as ClusterManager};
+use crate::cluster::{
+    manager::ProxyAuditRequest, CachedResource, CircuitOpenError, CircuitState, ClusterConnection,
+    ClusterHealth, ClusterInfo, HealthStatus, Manager as ClusterManager,
+};
 use crate::middleware::logging::RequestId;
+use crate::routes::{ApiError, ErrorCode};
 use anyhow::Context;
 use axum::{
     extract::{Extension, Path, Query, State},
-    http::{Method, StatusCode},
-    response::{IntoResponse, Response},
+    http::Method,
     Json,
 };
 use moka::future::Cache as MokaCache;
@@ -15,12 +18,25 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::Arc;
 use tokio::sync::Semaphore;
-use tracing::instrument;
+use tracing::{instrument, Instrument};
 use utoipa::{IntoParams, ToSchema};
 
+pub mod allocation;
+mod audited;
+pub mod cat;
+pub mod compare;
+pub mod deprecations;
+pub mod diagnostics;
+pub mod flush;
+pub mod health_stream;
+pub mod hot_threads;
+pub mod index_operations;
 mod pagination;
 pub mod proxy;
+pub mod recovery;
+pub mod reindex;
 pub mod relocation;
+pub mod search;
 pub mod tasks;
 pub mod transform;
 
@@ -29,11 +45,13 @@ pub use relocation::{relocate_shard, RelocateShardRequest};
 
 use pagination::{paginate_vec, PaginatedResponse};
 use transform::{
-    aggregate_shards_by_node, transform_cluster_stats, transform_indices_from_cat,
-    transform_node_detail_stats, transform_nodes, transform_routing_nodes_to_shards,
-    transform_shards, ClusterStatsResponse, IndexInfoResponse, NodeDetailStatsResponse,
-    NodeInfoResponse, NodeShardSummary, PaginatedShardsResponse, PaginatedShardsWithNodes,
-    ShardInfoResponse,
+    aggregate_shards_by_node, transform_cluster_settings, transform_cluster_stats,
+    transform_index_ilm, transform_indices_from_cat, transform_node_detail_stats, transform_nodes,
+    transform_routing_nodes_to_shards, transform_shards, transform_snapshot_repositories,
+    transform_snapshots, ClusterSettingsResponse, ClusterStatsResponse, DiskWatermarks,
+    IndexIlmResponse, IndexInfoResponse, NodeDetailStatsResponse, NodeInfoResponse,
+    NodeShardSummary, PaginatedShardsResponse, PaginatedShardsWithNodes, ShardInfoResponse,
+    SnapshotInfoResponse, SnapshotRepositoryResponse,
 };
 
 /// Shared application state for cluster routes
@@ -58,73 +76,15 @@ pub struct ClusterState {
     pub audit_log: bool,
     /// RBAC manager for per-request cluster access filtering
     pub rbac: RbacManager,
-}
-
-/// Error response for cluster operations
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
-pub struct ClusterErrorResponse {
-    pub error: String,
-    pub message: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub accessible_reason: Option<String>,
-}
-
-impl ClusterErrorResponse {
-    /// Helper to build a simple error response with no accessible reason.
-    pub fn simple<S: Into<String>>(error: &str, message: S) -> Self {
-        ClusterErrorResponse {
-            error: error.to_string(),
-            message: message.into(),
-            accessible_reason: None,
-        }
-    }
-
-    /// Helper to build a cluster_unavailable error including an optional reason.
-    pub fn unavailable(cluster_id: &str, reason: Option<String>) -> Self {
-        ClusterErrorResponse {
-            error: "cluster_unavailable".to_string(),
-            message: format!("Cluster '{}' is inaccessible", cluster_id),
-            accessible_reason: reason,
-        }
-    }
-}
-
-impl IntoResponse for ClusterErrorResponse {
-    fn into_response(self) -> Response {
-        // Map certain error codes to more appropriate HTTP status codes.
-        // In particular, generation concurrency limits should return 429
-        // so clients can retry later, while other errors remain 400.
-        let status = match self.error.as_str() {
-            // Client / auth errors
-            "access_denied" => StatusCode::FORBIDDEN,
-            "unauthorized" | "authentication_required" => StatusCode::UNAUTHORIZED,
-            // Not found
-            "cluster_not_found" => StatusCode::NOT_FOUND,
-
-            // Rate / concurrency
-            "generation_concurrency_limited" => StatusCode::TOO_MANY_REQUESTS,
-
-            // Upstream/proxy errors
-            "proxy_timeout" | "response_read_timeout" => StatusCode::GATEWAY_TIMEOUT,
-            "proxy_failed"
-            | "elasticsearch_error"
-            | "response_read_failed"
-            | "response_build_failed" => StatusCode::BAD_GATEWAY,
-            // Cluster inaccessible - return 503 so frontend can show why
-            "cluster_unavailable" => StatusCode::SERVICE_UNAVAILABLE,
-
-            // Server errors
-            "semaphore_error" | "internal_error" => StatusCode::INTERNAL_SERVER_ERROR,
-
-            // Validation / client input
-            "validation_failed" | "parse_failed" | "parse_error" | "no_settings"
-            | "too_many_tiles" => StatusCode::BAD_REQUEST,
-
-            // Default to 400 for other structured errors
-            _ => StatusCode::BAD_REQUEST,
-        };
-        (status, Json(self)).into_response()
-    }
+    /// Global allowlist for the generic proxy endpoint (unrestricted if None)
+    pub proxy_allow: Option<crate::config::ProxyAllowConfig>,
+    /// Largest request or response body passed through the generic proxy
+    pub max_proxy_body_bytes: usize,
+    /// Origins besides Secan's own allowed to open WebSockets
+    /// (`server.cors_allowed_origins`)
+    pub cors_allowed_origins: Vec<String>,
+    /// Open recovery streams per cluster, to cap them
+    pub recovery_stream_slots: recovery::RecoveryStreamSlots,
 }
 
 /// List all configured clusters
@@ -159,8 +119,8 @@ pub struct ClustersQueryParams {
     params(ClustersQueryParams),
     responses(
         (status = 200, body = PaginatedResponse<ClusterInfo>),
-        (status = 400, body = ClusterErrorResponse),
-        (status = 401, body = ClusterErrorResponse)
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError)
     ),
     tag = "Clusters"
 )]
@@ -169,7 +129,7 @@ pub async fn list_clusters(
     State(state): State<ClusterState>,
     Query(params): Query<ClustersQueryParams>,
     user_ext: Option<axum::Extension<AuthenticatedUser>>,
-) -> Result<Json<PaginatedResponse<ClusterInfo>>, ClusterErrorResponse> {
+) -> Result<Json<PaginatedResponse<ClusterInfo>>, ApiError> {
     tracing::debug!(
         page = params.page,
         page_size = params.page_size,
@@ -343,20 +303,30 @@ fn filter_clusters(clusters: &[ClusterInfo], params: &ClustersQueryParams) -> Ve
 }
 
 /// Validate cluster id and return a clear client error when empty.
-fn validate_cluster_id(cluster_id: &str) -> Result<(), ClusterErrorResponse> {
+fn validate_cluster_id(cluster_id: &str) -> Result<(), ApiError> {
     if cluster_id.is_empty() {
         tracing::debug!(cluster_id = %cluster_id, "Empty cluster id in request");
-        return Err(ClusterErrorResponse::simple(
-            "validation_failed",
-            "Cluster id is empty",
-        ));
+        return Err(
+            ApiError::new(ErrorCode::ValidationFailed, "Cluster id is empty")
+                .with_cluster(cluster_id),
+        );
     }
     Ok(())
 }
 
+/// Query parameters for cluster stats
+#[derive(Debug, Default, Deserialize, ToSchema, IntoParams)]
+pub struct ClusterStatsQueryParams {
+    /// Add formatted `*Human` fields (e.g. `diskUsedHuman: "1.5 TiB"`)
+    /// alongside the raw byte and millisecond values
+    #[serde(default)]
+    pub human: bool,
+}
+
 /// Get cluster statistics using SDK typed methods
 ///
-/// Returns cluster stats in frontend-compatible format
+/// Returns cluster stats in frontend-compatible format. With `human=true`
+/// the response also carries formatted sizes and uptime.
 ///
 /// # Requirements
 ///
@@ -364,12 +334,15 @@ fn validate_cluster_id(cluster_id: &str) -> Result<(), ClusterErrorResponse> {
 #[utoipa::path(
     get,
     path = "/clusters/{cluster_id}/stats",
-    params(("cluster_id" = String, Path, description = "Cluster ID")),
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ClusterStatsQueryParams
+    ),
     responses(
         (status = 200, body = ClusterStatsResponse),
-        (status = 400, body = ClusterErrorResponse),
-        (status = 401, body = ClusterErrorResponse),
-        (status = 404, body = ClusterErrorResponse)
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
     ),
     tag = "Clusters"
 )]
@@ -377,7 +350,8 @@ fn validate_cluster_id(cluster_id: &str) -> Result<(), ClusterErrorResponse> {
 pub async fn get_cluster_stats(
     State(state): State<ClusterState>,
     Path(cluster_id): Path<String>,
-) -> Result<Json<ClusterStatsResponse>, ClusterErrorResponse> {
+    Query(params): Query<ClusterStatsQueryParams>,
+) -> Result<Json<ClusterStatsResponse>, ApiError> {
     tracing::debug!(cluster_id = %cluster_id, "Getting cluster stats");
 
     // Validate cluster id (defensive) and check cluster access
@@ -394,18 +368,12 @@ pub async fn get_cluster_stats(
                 error = %e,
                 "Cluster not found"
             );
-            ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Cluster '{}' not found: {}", cluster_id, e),
-            )
+            ApiError::cluster_not_found(&cluster_id)
         })?;
 
     // Short-circuit if cluster is known to be inaccessible
     if !cluster.accessible {
-        return Err(ClusterErrorResponse::unavailable(
-            &cluster_id,
-            cluster.accessible_reason.clone(),
-        ));
+        return Err(ApiError::inaccessible(&cluster));
     }
 
     // Get cluster stats and health using SDK typed methods
@@ -415,10 +383,7 @@ pub async fn get_cluster_stats(
             error = %e,
             "Failed to get cluster stats"
         );
-        ClusterErrorResponse::simple(
-            "stats_failed",
-            format!("Failed to get cluster stats: {}", e),
-        )
+        ApiError::upstream(&cluster_id, "get cluster stats", &e)
     })?;
 
     let health = cluster.health().await.map_err(|e| {
@@ -427,10 +392,7 @@ pub async fn get_cluster_stats(
             error = %e,
             "Failed to get cluster health"
         );
-        ClusterErrorResponse::simple(
-            "health_failed",
-            format!("Failed to get cluster health: {}", e),
-        )
+        ApiError::upstream(&cluster_id, "get cluster health", &e)
     })?;
 
     // Get nodes stats for CPU metrics
@@ -746,10 +708,11 @@ pub async fn get_cluster_stats(
             error = %e,
             "Failed to transform cluster stats"
         );
-        ClusterErrorResponse::simple(
-            "transform_failed",
+        ApiError::new(
+            ErrorCode::UpstreamError,
             format!("Failed to transform cluster stats: {}", e),
         )
+        .with_cluster(&cluster_id)
     })?;
 
     tracing::debug!(
@@ -758,7 +721,11 @@ pub async fn get_cluster_stats(
         "Cluster stats retrieved successfully"
     );
 
-    Ok(Json(response))
+    if params.human {
+        Ok(Json(response.humanize()))
+    } else {
+        Ok(Json(response))
+    }
 }
 
 /// Per-cluster details response wrapper
@@ -790,14 +757,19 @@ pub async fn get_cluster_details(
     State(state): State<ClusterState>,
     Path(cluster_id): Path<String>,
     user_ext: Option<axum::Extension<AuthenticatedUser>>,
-) -> Result<Json<ClusterDetailsResponse>, ClusterErrorResponse> {
+) -> Result<Json<ClusterDetailsResponse>, ApiError> {
     tracing::debug!(cluster_id = %cluster_id, "Getting cluster details");
 
     // Validate cluster id (defensive) and RBAC check
     validate_cluster_id(&cluster_id)?;
 
     // Try cache first
-    if let Some(cached) = state.details_cache.get(&cluster_id).await {
+    if let Some(cached) = state
+        .details_cache
+        .get(&cluster_id)
+        .instrument(tracing::info_span!("cache.get", cache = "details", cluster_id = %cluster_id))
+        .await
+    {
         tracing::debug!(cluster_id = %cluster_id, "Returning cached cluster details");
         let resp = ClusterDetailsResponse {
             status: "ok".to_string(),
@@ -930,6 +902,11 @@ pub async fn get_cluster_details(
             state
                 .details_cache
                 .insert(cluster_id.clone(), data_value.clone())
+                .instrument(tracing::info_span!(
+                    "cache.insert",
+                    cache = "details",
+                    cluster_id = %cluster_id
+                ))
                 .await;
 
             // Release permit by dropping 'permit'
@@ -980,38 +957,28 @@ pub async fn get_cluster_details(
     }
 }
 
-/// Get cluster settings
+/// Get effective cluster settings
 ///
-/// Returns cluster settings in JSON format
-/// Optionally includes default settings with `include_defaults` query parameter
+/// Merges the transient, persistent and default settings layers into one
+/// flat list showing each setting's effective value and where it comes from.
 #[utoipa::path(
     get,
     path = "/clusters/{cluster_id}/settings",
-    params(
-        ("cluster_id" = String, Path, description = "Cluster ID"),
-        ("include_defaults" = bool, Query, description = "Include default settings")
-    ),
+    params(("cluster_id" = String, Path, description = "Cluster ID")),
     responses(
-        (status = 200, description = "Cluster settings"),
-        (status = 400, body = ClusterErrorResponse),
-        (status = 401, body = ClusterErrorResponse),
-        (status = 404, body = ClusterErrorResponse)
+        (status = 200, body = ClusterSettingsResponse),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
     ),
     tag = "Clusters"
 )]
-#[instrument(skip(state, params), fields(cluster_id = %cluster_id))]
+#[instrument(skip(state), fields(cluster_id = %cluster_id))]
 pub async fn get_cluster_settings(
     State(state): State<ClusterState>,
     Path(cluster_id): Path<String>,
-    Query(params): Query<std::collections::HashMap<String, String>>,
-) -> Result<Json<Value>, ClusterErrorResponse> {
-    let include_defaults = params.contains_key("include_defaults");
-
-    tracing::debug!(
-        cluster_id = %cluster_id,
-        include_defaults = %include_defaults,
-        "Getting cluster settings"
-    );
+) -> Result<Json<ClusterSettingsResponse>, ApiError> {
+    tracing::debug!(cluster_id = %cluster_id, "Getting cluster settings");
 
     // Validate cluster id (defensive) and check cluster access
     validate_cluster_id(&cluster_id)?;
@@ -1027,40 +994,41 @@ pub async fn get_cluster_settings(
                 error = %e,
                 "Cluster not found"
             );
-            ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Cluster '{}' not found: {}", cluster_id, e),
-            )
+            ApiError::cluster_not_found(&cluster_id)
         })?;
 
-    // Get cluster settings via ClusterConnection proxy
-    let settings = cluster
-        .cluster_settings(include_defaults)
-        .await
-        .map_err(|e| {
-            tracing::error!(
-                cluster_id = %cluster_id,
-                error = %e,
-                "Failed to get cluster settings"
-            );
-            ClusterErrorResponse::simple(
-                "settings_failed",
-                format!("Failed to get cluster settings: {}", e),
-            )
-        })?;
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    // Defaults are needed to resolve the effective value of every setting
+    let settings = cluster.cluster_settings(true).await.map_err(|e| {
+        tracing::error!(
+            cluster_id = %cluster_id,
+            error = %e,
+            "Failed to get cluster settings"
+        );
+        ApiError::upstream(&cluster_id, "get cluster settings", &e)
+    })?;
 
     tracing::debug!(
         cluster_id = %cluster_id,
         "Cluster settings retrieved successfully"
     );
 
-    Ok(Json(settings))
+    Ok(Json(transform_cluster_settings(&settings)))
 }
 
+/// Warning returned when an update uses the deprecated transient layer
+const TRANSIENT_SETTINGS_WARNING: &str =
+    "Transient cluster settings are deprecated and do not survive a full cluster restart; use persistent settings instead";
+
 /// Update cluster settings
 ///
 /// Updates persistent and/or transient cluster settings
-/// Only modified settings need to be provided in the request body
+/// Only modified settings need to be provided in the request body.
+/// Transient settings are deprecated in newer Elasticsearch versions; any
+/// deprecation warnings returned by the cluster are passed back in `warnings`.
 #[utoipa::path(
     put,
     path = "/clusters/{cluster_id}/settings",
@@ -1068,9 +1036,9 @@ pub async fn get_cluster_settings(
     request_body = ClusterSettingsUpdateRequest,
     responses(
         (status = 200, description = "Settings updated"),
-        (status = 400, body = ClusterErrorResponse),
-        (status = 401, body = ClusterErrorResponse),
-        (status = 404, body = ClusterErrorResponse)
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
     ),
     tag = "Clusters"
 )]
@@ -1081,7 +1049,7 @@ pub async fn update_cluster_settings(
     user_ext: Option<axum::Extension<AuthenticatedUser>>,
     request_id_ext: Option<axum::Extension<crate::middleware::logging::RequestId>>,
     Json(request): Json<ClusterSettingsUpdateRequest>,
-) -> Result<Json<Value>, ClusterErrorResponse> {
+) -> Result<Json<Value>, ApiError> {
     tracing::debug!(
         cluster_id = %cluster_id,
         "Updating cluster settings"
@@ -1101,10 +1069,7 @@ pub async fn update_cluster_settings(
                 error = %e,
                 "Cluster not found"
             );
-            ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Cluster '{}' not found: {}", cluster_id, e),
-            )
+            ApiError::cluster_not_found(&cluster_id)
         })?;
 
     // Build request body for Elasticsearch
@@ -1121,22 +1086,33 @@ pub async fn update_cluster_settings(
     }
 
     if settings_body.is_empty() {
-        return Err(ClusterErrorResponse::simple(
-            "no_settings",
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
             "At least one of 'persistent' or 'transient' settings must be provided",
-        ));
+        )
+        .with_cluster(&cluster_id));
     }
 
+    let changed_keys: Vec<String> = settings_body
+        .iter()
+        .flat_map(|(layer, settings)| {
+            transform::flatten_settings(settings)
+                .into_keys()
+                .map(move |key| format!("{}.{}", layer, key))
+        })
+        .collect();
+
     // Build JSON body value
     let body_value = serde_json::Value::Object(settings_body);
 
     // Extract user roles and id for client selection and audit
     let user = user_ext.as_ref().map(|u| u.0 .0.clone()).ok_or_else(|| {
         tracing::error!("Authentication required but user not found in request");
-        ClusterErrorResponse::simple(
-            "authentication_required",
+        ApiError::new(
+            ErrorCode::AuthenticationRequired,
             "Authentication is required for this operation",
         )
+        .with_cluster(&cluster_id)
     })?;
 
     let request_id = request_id_ext
@@ -1147,7 +1123,7 @@ pub async fn update_cluster_settings(
     // Use centralized proxy helper which performs client selection, timeouts,
     // response read timeout, and emits an audit entry when the request is
     // actually forwarded to Elasticsearch.
-    let (_status, _headers, body_vec, _matched_role_label) = match state
+    let (status, headers, body_vec, _matched_role_label) = match state
         .cluster_manager
         .proxy_request_with_audit(ProxyAuditRequest {
             cluster_id: cluster_id.clone(),
@@ -1158,6 +1134,7 @@ pub async fn update_cluster_settings(
             user_roles: user.roles.clone(),
             request_id: request_id.clone(),
             audit_enabled: state.audit_log,
+            max_response_bytes: None,
         })
         .await
     {
@@ -1166,63 +1143,126 @@ pub async fn update_cluster_settings(
             use crate::cluster::ProxyRequestError;
 
             match e {
+                ProxyRequestError::ResponseTooLarge { limit } => {
+                    return Err(ApiError::response_too_large(&cluster_id, limit));
+                }
+                ProxyRequestError::ClusterBusy(busy) => {
+                    return Err(ApiError::cluster_busy(&busy));
+                }
+                ProxyRequestError::ClusterNotFound(_) => {
+                    return Err(ApiError::cluster_not_found(&cluster_id));
+                }
                 ProxyRequestError::AccessDenied => {
                     tracing::warn!(error = %"access_denied", cluster_id = %cluster_id, "Access denied: no matching role client");
-                    return Err(ClusterErrorResponse::simple(
-                        "access_denied",
+                    return Err(ApiError::new(
+                        ErrorCode::AccessDenied,
                         "You do not have permissions to perform this operation on the requested cluster",
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ProxyTimeout => {
                     tracing::error!(cluster_id = %cluster_id, "UPDATE SETTINGS: request timed out");
-                    return Err(ClusterErrorResponse::simple(
-                        "proxy_timeout",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamTimeout,
                         "Elasticsearch request timed out: PUT /_cluster/settings (timeout: 30s)"
                             .to_string(),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::RequestFailed(reason) => {
                     tracing::error!(cluster_id = %cluster_id, error = %reason, "UPDATE SETTINGS: request failed");
-                    return Err(ClusterErrorResponse::simple(
-                        "proxy_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::ClusterUnreachable,
                         format!("Failed to update cluster settings: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ResponseReadTimeout => {
                     tracing::error!(cluster_id = %cluster_id, "Timeout reading update response body");
-                    return Err(ClusterErrorResponse::simple(
-                        "response_read_timeout",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamTimeout,
                         "Timeout reading Elasticsearch response body",
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ResponseReadFailed(reason) => {
                     tracing::error!(cluster_id = %cluster_id, error = %reason, "Failed to read update response body");
-                    return Err(ClusterErrorResponse::simple(
-                        "response_read_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to read response body: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::Other(reason) => {
                     tracing::warn!(error = %reason, "UPDATE SETTINGS: Unexpected error");
-                    return Err(ClusterErrorResponse::simple(
-                        "proxy_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to proxy request: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
             }
         }
     };
 
-    let response_value = serde_json::from_slice::<Value>(&body_vec).map_err(|e| {
+    let mut response_value = serde_json::from_slice::<Value>(&body_vec).map_err(|e| {
         tracing::error!(cluster_id = %cluster_id, error = %e, "Failed to parse update response");
-        ClusterErrorResponse::simple(
-            "parse_failed",
+        ApiError::new(
+            ErrorCode::UpstreamError,
             format!("Failed to parse update response: {}", e),
         )
+        .with_cluster(&cluster_id)
     })?;
 
+    if !status.is_success() {
+        let reason = response_value["error"]["reason"]
+            .as_str()
+            .map(str::to_string)
+            .unwrap_or_else(|| response_value["error"].to_string());
+        tracing::warn!(
+            cluster_id = %cluster_id,
+            status = %status,
+            changed_keys = ?changed_keys,
+            error = %reason,
+            "Cluster settings update rejected"
+        );
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            format!(
+                "Elasticsearch rejected the settings update ({}): {}",
+                status, reason
+            ),
+        )
+        .with_cluster(&cluster_id));
+    }
+
+    // The cached disk watermarks come from these settings
+    state
+        .cluster_manager
+        .invalidate_resource(CachedResource::Nodes, &cluster_id);
+
+    let mut warnings: Vec<String> = headers
+        .get_all(axum::http::header::WARNING)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .map(transform::parse_warning_header)
+        .collect();
+    // Older clusters accept transient settings silently; flag them anyway
+    let uses_transient = changed_keys.iter().any(|key| key.starts_with("transient."));
+    if uses_transient && !warnings.iter().any(|w| w.contains("transient")) {
+        warnings.push(TRANSIENT_SETTINGS_WARNING.to_string());
+    }
+    if !warnings.is_empty() {
+        tracing::warn!(cluster_id = %cluster_id, warnings = ?warnings, "Cluster settings update returned warnings");
+        if let Some(object) = response_value.as_object_mut() {
+            object.insert("warnings".to_string(), serde_json::json!(warnings));
+        }
+    }
+
     tracing::info!(
         cluster_id = %cluster_id,
+        user_id = %user.id,
+        changed_keys = ?changed_keys,
         "Cluster settings updated successfully"
     );
 
@@ -1235,7 +1275,8 @@ pub struct ClusterSettingsUpdateRequest {
     /// Persistent settings (survive cluster restarts)
     #[schema(example = "{\"indices.queries.cache.size\": \"20%\"}")]
     pub persistent: Option<Value>,
-    /// Transient settings (do not survive cluster restarts)
+    /// Transient settings (do not survive cluster restarts; deprecated in newer
+    /// Elasticsearch versions in favour of persistent settings)
     #[schema(example = "{\"indices.queries.cache.size\": \"30%\"}")]
     pub transient: Option<Value>,
 }
@@ -1258,11 +1299,117 @@ pub struct NodesQueryParams {
     #[schema(example = "node-1")]
     #[serde(default)]
     pub search: String,
+    /// Only nodes with any of these exact Elasticsearch role names,
+    /// comma-separated; an empty value matches no nodes. Unlike `role`,
+    /// `data` does not match the data tier roles such as `data_hot`
     #[schema(example = "master,data")]
-    pub roles: Option<String>, // comma-separated: master,data,ingest; None = not set, Some("") = explicitly empty
+    pub roles: Option<String>,
     #[schema(example = "node-1,node-2")]
     #[serde(default)]
     pub nodes: Option<String>, // comma-separated node ids or names
+    /// Only nodes with any of these roles, comma-separated: data (including
+    /// the data tier roles), master, ingest, ml or coordinating (nodes
+    /// without roles). Applied on top of `roles` when both are set
+    #[schema(example = "data,ingest")]
+    #[serde(default)]
+    pub role: Option<String>,
+}
+
+/// Disk watermarks of a cluster, for the nodes' `diskWatermarkState`
+///
+/// The resolved watermarks are cached with the node info, so node listings
+/// do not read the full cluster settings on every poll. Falls back to the
+/// Elasticsearch defaults when the cluster settings cannot be read, so node
+/// listings keep working without that permission.
+pub(crate) async fn disk_watermarks(
+    cluster_manager: &ClusterManager,
+    cluster: &ClusterConnection,
+) -> DiskWatermarks {
+    let resolved = cluster_manager
+        .cached_resource(
+            CachedResource::Nodes,
+            &cluster.id,
+            "disk_watermarks",
+            || async {
+                let settings = cluster.cluster_settings(true).await?;
+                Ok(serde_json::to_value(
+                    DiskWatermarks::from_cluster_settings(&settings),
+                )?)
+            },
+        )
+        .await
+        .and_then(|value| Ok(serde_json::from_value(value)?));
+    match resolved {
+        Ok(watermarks) => watermarks,
+        Err(e) => {
+            tracing::warn!(
+                cluster_id = %cluster.id,
+                error = %e,
+                "Failed to get disk watermarks, using the defaults"
+            );
+            DiskWatermarks::default()
+        }
+    }
+}
+
+/// Node role accepted by the `role` filter of the nodes endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeRoleFilter {
+    Data,
+    Master,
+    Ingest,
+    Ml,
+    Coordinating,
+}
+
+impl NodeRoleFilter {
+    fn parse(role: &str) -> Option<Self> {
+        match role.trim().to_ascii_lowercase().as_str() {
+            "data" => Some(Self::Data),
+            "master" => Some(Self::Master),
+            "ingest" => Some(Self::Ingest),
+            "ml" => Some(Self::Ml),
+            "coordinating" => Some(Self::Coordinating),
+            _ => None,
+        }
+    }
+
+    /// Whether a node with `roles` has this role
+    ///
+    /// `data` also covers the data tier roles (`data_hot`, `data_content`,
+    /// ...), and coordinating-only nodes are the ones without any role.
+    fn matches(self, roles: &[String]) -> bool {
+        let has = |name: &str| roles.iter().any(|role| role == name);
+        match self {
+            Self::Data => roles
+                .iter()
+                .any(|role| role == "data" || role.starts_with("data_")),
+            Self::Master => has("master"),
+            Self::Ingest => has("ingest"),
+            Self::Ml => has("ml"),
+            Self::Coordinating => roles.is_empty(),
+        }
+    }
+}
+
+/// Parse the comma-separated `role` filter; empty means no filter
+fn parse_role_filter(value: Option<&str>) -> Result<Vec<NodeRoleFilter>, ApiError> {
+    value
+        .unwrap_or("")
+        .split(',')
+        .filter(|role| !role.trim().is_empty())
+        .map(|role| {
+            NodeRoleFilter::parse(role).ok_or_else(|| {
+                ApiError::new(
+                    ErrorCode::ValidationFailed,
+                    format!(
+                        "Unknown node role '{}'; expected data, master, ingest, ml or coordinating",
+                        role.trim()
+                    ),
+                )
+            })
+        })
+        .collect()
 }
 
 #[utoipa::path(
@@ -1274,9 +1421,9 @@ pub struct NodesQueryParams {
     ),
     responses(
         (status = 200, body = PaginatedResponse<NodeInfoResponse>),
-        (status = 400, body = ClusterErrorResponse),
-        (status = 401, body = ClusterErrorResponse),
-        (status = 404, body = ClusterErrorResponse)
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
     ),
     tag = "Clusters"
 )]
@@ -1285,7 +1432,7 @@ pub async fn get_nodes(
     State(state): State<ClusterState>,
     Path(cluster_id): Path<String>,
     Query(params): Query<NodesQueryParams>,
-) -> Result<Json<PaginatedResponse<NodeInfoResponse>>, ClusterErrorResponse> {
+) -> Result<Json<PaginatedResponse<NodeInfoResponse>>, ApiError> {
     tracing::debug!(
         cluster_id = %cluster_id,
         page = params.page,
@@ -1293,11 +1440,13 @@ pub async fn get_nodes(
         search = %params.search,
         roles = ?params.roles,
         nodes = ?params.nodes,
+        role = ?params.role,
         "Getting nodes with filters"
     );
 
     // Validate cluster id (defensive) and check cluster access
     validate_cluster_id(&cluster_id)?;
+    let role_filter = parse_role_filter(params.role.as_deref())?;
 
     // Get the cluster
     let cluster = state
@@ -1310,24 +1459,24 @@ pub async fn get_nodes(
                 error = %e,
                 "Cluster not found"
             );
-            ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Cluster '{}' not found: {}", cluster_id, e),
-            )
+            ApiError::cluster_not_found(&cluster_id)
         })?;
 
-    // Get nodes info and stats using SDK typed methods
-    let nodes_info = cluster.nodes_info().await.map_err(|e| {
-        tracing::error!(
-            cluster_id = %cluster_id,
-            error = %e,
-            "Failed to get nodes info"
-        );
-        ClusterErrorResponse::simple(
-            "nodes_info_failed",
-            format!("Failed to get nodes info: {}", e),
-        )
-    })?;
+    // Get nodes info (cached, it rarely changes) and stats using SDK typed methods
+    let nodes_info = state
+        .cluster_manager
+        .cached_resource(CachedResource::Nodes, &cluster_id, "", || {
+            cluster.nodes_info()
+        })
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                cluster_id = %cluster_id,
+                error = %e,
+                "Failed to get nodes info"
+            );
+            ApiError::upstream(&cluster_id, "get nodes info", &e)
+        })?;
 
     let nodes_stats = cluster.nodes_stats().await.map_err(|e| {
         tracing::error!(
@@ -1335,10 +1484,7 @@ pub async fn get_nodes(
             error = %e,
             "Failed to get nodes stats"
         );
-        ClusterErrorResponse::simple(
-            "nodes_stats_failed",
-            format!("Failed to get nodes stats: {}", e),
-        )
+        ApiError::upstream(&cluster_id, "get nodes stats", &e)
     })?;
 
     // Get master node ID using lightweight _cat/master API instead of full cluster state
@@ -1569,12 +1715,15 @@ pub async fn get_nodes(
         None
     };
 
+    let watermarks = disk_watermarks(&state.cluster_manager, &cluster).await;
+
     // Transform to frontend format
     let all_nodes = transform_nodes(
         &nodes_info,
         &nodes_stats,
         master_node_id.as_deref(),
         prometheus_node_metrics.as_ref(),
+        &watermarks,
     );
 
     // Apply filters
@@ -1650,7 +1799,8 @@ pub async fn get_nodes(
                 }
             }
 
-            true
+            // Role filter: any of the requested roles
+            role_filter.is_empty() || role_filter.iter().any(|role| role.matches(&node.roles))
         })
         .collect();
 
@@ -1696,9 +1846,9 @@ pub async fn get_nodes(
     ),
     responses(
         (status = 200, body = NodeDetailStatsResponse),
-        (status = 400, body = ClusterErrorResponse),
-        (status = 401, body = ClusterErrorResponse),
-        (status = 404, body = ClusterErrorResponse)
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
     ),
     tag = "Clusters"
 )]
@@ -1706,7 +1856,7 @@ pub async fn get_nodes(
 pub async fn get_node_stats(
     State(state): State<ClusterState>,
     Path((cluster_id, node_id)): Path<(String, String)>,
-) -> Result<Json<NodeDetailStatsResponse>, ClusterErrorResponse> {
+) -> Result<Json<NodeDetailStatsResponse>, ApiError> {
     tracing::debug!(
         cluster_id = %cluster_id,
         node_id = %node_id,
@@ -1726,33 +1876,30 @@ pub async fn get_node_stats(
                 error = %e,
                 "Cluster not found"
             );
-            ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Cluster '{}' not found: {}", cluster_id, e),
-            )
+            ApiError::cluster_not_found(&cluster_id)
         })?;
 
     // Short-circuit if cluster is known to be inaccessible
     if !cluster.accessible {
-        return Err(ClusterErrorResponse::unavailable(
-            &cluster_id,
-            cluster.accessible_reason.clone(),
-        ));
+        return Err(ApiError::inaccessible(&cluster));
     }
 
     // Get nodes info for the specific node
-    let nodes_info = cluster.nodes_info().await.map_err(|e| {
-        tracing::error!(
-            cluster_id = %cluster_id,
-            node_id = %node_id,
-            error = %e,
-            "Failed to get node info"
-        );
-        ClusterErrorResponse::simple(
-            "node_info_failed",
-            format!("Failed to get node info: {}", e),
-        )
-    })?;
+    let nodes_info = state
+        .cluster_manager
+        .cached_resource(CachedResource::Nodes, &cluster_id, "", || {
+            cluster.nodes_info()
+        })
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                cluster_id = %cluster_id,
+                node_id = %node_id,
+                error = %e,
+                "Failed to get node info"
+            );
+            ApiError::upstream(&cluster_id, "get node info", &e)
+        })?;
 
     // Get detailed node stats for the specific node using SDK
     let node_stats = cluster.node_stats(&node_id).await.map_err(|e| {
@@ -1762,10 +1909,7 @@ pub async fn get_node_stats(
             error = %e,
             "Failed to get node stats"
         );
-        ClusterErrorResponse::simple(
-            "node_stats_failed",
-            format!("Failed to get node stats: {}", e),
-        )
+        ApiError::upstream(&cluster_id, "get node stats", &e)
     })?;
 
     // Get shards for data nodes using lightweight _cat/shards API
@@ -1840,6 +1984,8 @@ pub async fn get_node_stats(
         None
     };
 
+    let watermarks = disk_watermarks(&state.cluster_manager, &cluster).await;
+
     // Transform to frontend format (dummy cluster_state for compatibility)
     let dummy_cluster_state = serde_json::json!({});
     let response = transform_node_detail_stats(
@@ -1848,6 +1994,7 @@ pub async fn get_node_stats(
         &node_stats,
         &dummy_cluster_state,
         shards.as_ref(),
+        &watermarks,
     )
     .map_err(|e| {
         tracing::error!(
@@ -1856,10 +2003,11 @@ pub async fn get_node_stats(
             error = %e,
             "Failed to transform node stats"
         );
-        ClusterErrorResponse::simple(
-            "transform_failed",
+        ApiError::new(
+            ErrorCode::UpstreamError,
             format!("Failed to transform node stats: {}", e),
         )
+        .with_cluster(&cluster_id)
     })?;
 
     tracing::debug!(
@@ -1901,6 +2049,45 @@ pub struct IndicesQueryParams {
     #[schema(default = false)]
     #[serde(default)]
     pub affected: bool, // show only indices with problems
+    /// Index pattern passed to Elasticsearch, e.g. `logs-*` (default: the
+    /// cluster's `default_index_pattern`, or all indices)
+    #[schema(example = "logs-*")]
+    #[serde(default)]
+    pub pattern: Option<String>,
+    /// Comma-separated patterns of indices to leave out (default: `.*`
+    /// unless `show_special` is set)
+    #[schema(example = ".*")]
+    #[serde(default)]
+    pub exclude: Option<String>,
+}
+
+/// Index expression limiting `_cat/indices` to `pattern` minus `exclude`
+///
+/// Exclusions only apply after a wildcard, so they are appended to `*` when
+/// no pattern is given. `None` means all indices.
+fn index_expression(pattern: Option<&str>, exclude: Option<&str>) -> Option<String> {
+    let mut parts: Vec<String> = pattern
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .collect();
+    let excluded: Vec<&str> = exclude
+        .unwrap_or_default()
+        .split(',')
+        .map(|e| e.trim().trim_start_matches('-'))
+        .filter(|e| !e.is_empty())
+        .collect();
+
+    if parts.is_empty() {
+        if excluded.is_empty() {
+            return None;
+        }
+        parts.push("*".to_string());
+    }
+    parts.extend(excluded.iter().map(|e| format!("-{}", e)));
+    Some(parts.join(","))
 }
 
 fn default_page() -> u32 {
@@ -1923,9 +2110,9 @@ fn default_true() -> bool {
     ),
     responses(
         (status = 200, body = PaginatedResponse<IndexInfoResponse>),
-        (status = 400, body = ClusterErrorResponse),
-        (status = 401, body = ClusterErrorResponse),
-        (status = 404, body = ClusterErrorResponse)
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
     ),
     tag = "Clusters"
 )]
@@ -1934,7 +2121,7 @@ pub async fn get_indices(
     State(state): State<ClusterState>,
     Path(cluster_id): Path<String>,
     Query(params): Query<IndicesQueryParams>,
-) -> Result<Json<PaginatedResponse<IndexInfoResponse>>, ClusterErrorResponse> {
+) -> Result<Json<PaginatedResponse<IndexInfoResponse>>, ApiError> {
     tracing::debug!(
         cluster_id = %cluster_id,
         page = params.page,
@@ -1944,6 +2131,8 @@ pub async fn get_indices(
         status = %params.status,
         show_special = params.show_special,
         affected = params.affected,
+        pattern = ?params.pattern,
+        exclude = ?params.exclude,
         "Getting indices with filters"
     );
 
@@ -1960,33 +2149,46 @@ pub async fn get_indices(
                 error = %e,
                 "Cluster not found"
             );
-            ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Cluster '{}' not found: {}", cluster_id, e),
-            )
+            ApiError::cluster_not_found(&cluster_id)
         })?;
 
     // Short-circuit if cluster is known to be inaccessible
     if !cluster.accessible {
-        return Err(ClusterErrorResponse::unavailable(
-            &cluster_id,
-            cluster.accessible_reason.clone(),
-        ));
+        return Err(ApiError::inaccessible(&cluster));
     }
 
+    // Let Elasticsearch drop indices outside the requested pattern so large
+    // clusters don't return (and we don't transform) every index
+    let pattern = params
+        .pattern
+        .as_deref()
+        .or(cluster.default_index_pattern.as_deref());
+    let exclude = match params.exclude.as_deref() {
+        Some(exclude) => Some(exclude),
+        None if !params.show_special => Some(".*"),
+        None => None,
+    };
+    let expression = index_expression(pattern, exclude);
+
     // Use lightweight _cat/indices API instead of heavy _stats API
     // This is MUCH faster for large clusters with many indices
-    let cat_indices = cluster.cat_indices().await.map_err(|e| {
-        tracing::error!(
-            cluster_id = %cluster_id,
-            error = %e,
-            "Failed to get indices information"
-        );
-        ClusterErrorResponse::simple(
-            "indices_failed",
-            format!("Failed to get indices information: {}", e),
+    let cat_indices = state
+        .cluster_manager
+        .cached_resource(
+            CachedResource::Indices,
+            &cluster_id,
+            expression.as_deref().unwrap_or_default(),
+            || cluster.cat_indices(expression.as_deref()),
         )
-    })?;
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                cluster_id = %cluster_id,
+                error = %e,
+                "Failed to get indices information"
+            );
+            ApiError::upstream(&cluster_id, "get indices information", &e)
+        })?;
 
     // Transform to frontend format
     let all_indices = transform_indices_from_cat(&cat_indices);
@@ -2072,9 +2274,9 @@ pub async fn get_indices(
     ),
     responses(
         (status = 200, description = "Shard stats with optional allocation explain"),
-        (status = 400, body = ClusterErrorResponse),
-        (status = 401, body = ClusterErrorResponse),
-        (status = 404, body = ClusterErrorResponse)
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
     ),
     tag = "Clusters"
 )]
@@ -2083,7 +2285,7 @@ pub async fn get_shard_stats(
     State(state): State<ClusterState>,
     Path((cluster_id, index_name, shard_num)): Path<(String, String, String)>,
     user_ext: Option<axum::Extension<AuthenticatedUser>>,
-) -> Result<Json<Value>, ClusterErrorResponse> {
+) -> Result<Json<Value>, ApiError> {
     tracing::debug!(
         cluster_id = %cluster_id,
         index = %index_name,
@@ -2104,18 +2306,12 @@ pub async fn get_shard_stats(
                 error = %e,
                 "Cluster not found"
             );
-            ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Cluster '{}' not found: {}", cluster_id, e),
-            )
+            ApiError::cluster_not_found(&cluster_id)
         })?;
 
     // Short-circuit if cluster is known to be inaccessible
     if !cluster.accessible {
-        return Err(ClusterErrorResponse::unavailable(
-            &cluster_id,
-            cluster.accessible_reason.clone(),
-        ));
+        return Err(ApiError::inaccessible(&cluster));
     }
 
     // Use the SDK method to get shard-level stats
@@ -2129,10 +2325,7 @@ pub async fn get_shard_stats(
                 error = %e,
                 "Failed to get indices stats with shards"
             );
-            ClusterErrorResponse::simple(
-                "indices_stats_failed",
-                format!("Failed to get indices stats with shards: {}", e),
-            )
+            ApiError::upstream(&cluster_id, "get indices stats with shards", &e)
         })?;
 
     // Navigate to the specific shard in the response
@@ -2318,10 +2511,14 @@ async fn fetch_shard_from_cluster_state(
             user_roles: user_roles.clone(),
             request_id: request_id.0.clone(),
             audit_enabled,
+            max_response_bytes: None,
         })
         .await
         .map_err(|e| match e {
+            e @ ProxyRequestError::ResponseTooLarge { .. } => anyhow::anyhow!(e),
+            ProxyRequestError::ClusterBusy(busy) => anyhow::anyhow!(busy),
             ProxyRequestError::AccessDenied => anyhow::anyhow!("Access denied"),
+            e @ ProxyRequestError::ClusterNotFound(_) => anyhow::anyhow!(e),
             ProxyRequestError::ProxyTimeout => anyhow::anyhow!("Request timeout"),
             ProxyRequestError::RequestFailed(s) => anyhow::anyhow!("Request failed: {}", s),
             ProxyRequestError::ResponseReadTimeout => anyhow::anyhow!("Response read timeout"),
@@ -2461,10 +2658,14 @@ async fn fetch_allocation_explain(
             user_roles,
             request_id: request_id.0.clone(),
             audit_enabled,
+            max_response_bytes: None,
         })
         .await
         .map_err(|e| match e {
+            e @ ProxyRequestError::ResponseTooLarge { .. } => anyhow::anyhow!(e),
+            ProxyRequestError::ClusterBusy(busy) => anyhow::anyhow!(busy),
             ProxyRequestError::AccessDenied => anyhow::anyhow!("Access denied"),
+            e @ ProxyRequestError::ClusterNotFound(_) => anyhow::anyhow!(e),
             ProxyRequestError::ProxyTimeout => anyhow::anyhow!("Request timeout"),
             ProxyRequestError::RequestFailed(s) => anyhow::anyhow!("Request failed: {}", s),
             ProxyRequestError::ResponseReadTimeout => anyhow::anyhow!("Response read timeout"),
@@ -2523,128 +2724,41 @@ pub struct ShardsQueryParams {
     #[schema(example = "logs")]
     #[serde(default)]
     pub search: String, // search both index and node (OR logic)
+    /// Only primaries (`true`) or only replicas (`false`)
+    #[schema(example = false)]
+    #[serde(default)]
+    pub primary: Option<bool>,
 }
 
-#[utoipa::path(
-    get,
-    path = "/clusters/{cluster_id}/shards",
-    params(
-        ("cluster_id" = String, Path, description = "Cluster ID"),
-        ShardsQueryParams
-    ),
-    responses(
-        (status = 200, body = PaginatedShardsWithNodes),
-        (status = 400, body = ClusterErrorResponse),
-        (status = 401, body = ClusterErrorResponse),
-        (status = 404, body = ClusterErrorResponse)
-    ),
-    tag = "Clusters"
-)]
-#[instrument(skip(state, params), fields(cluster_id = %cluster_id))]
-pub async fn get_shards(
-    State(state): State<ClusterState>,
-    Path(cluster_id): Path<String>,
-    Query(params): Query<ShardsQueryParams>,
-) -> Result<Json<PaginatedShardsWithNodes>, ClusterErrorResponse> {
-    tracing::debug!(
-        cluster_id = %cluster_id,
-        page = params.page,
-        page_size = params.page_size,
-        state = %params.state,
-        index = %params.index,
-        node = %params.node,
-        search = %params.search,
-        "Getting shards with filters"
-    );
+/// Apply the `get_shards` query filters to a flat shard list
+///
+/// Filters combine with AND; `search` matches index or node and replaces the
+/// separate `index` and `node` filters.
+fn filter_shards(
+    shards: Vec<ShardInfoResponse>,
+    params: &ShardsQueryParams,
+) -> Vec<ShardInfoResponse> {
+    let state_filter: Vec<&str> = params.state.split(',').filter(|s| !s.is_empty()).collect();
 
-    // Check cluster access
+    // Apply filters step-by-step with debug logs so we can see where shards are dropped
+    let mut step_shards = shards;
 
-    // Get the cluster
-    let cluster = state
-        .cluster_manager
-        .get_cluster(&cluster_id)
-        .await
-        .map_err(|e| {
-            tracing::error!(
-                cluster_id = %cluster_id,
-                error = %e,
-                "Cluster not found"
-            );
-            ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Cluster '{}' not found: {}", cluster_id, e),
-            )
-        })?;
+    // Hide special indices
+    if params.hide_special {
+        step_shards.retain(|s| !s.index.starts_with('.'));
+    }
+    let after_hide = step_shards.len();
 
-    // Short-circuit if cluster is known to be inaccessible
-    if !cluster.accessible {
-        return Err(ClusterErrorResponse::unavailable(
-            &cluster_id,
-            cluster.accessible_reason.clone(),
-        ));
-    }
-
-    // Phase 1: Get all shards from routing_nodes (minimal fields for pagination/filtering)
-    let state_response = cluster
-        .cluster_state_routing_nodes(None)
-        .await
-        .map_err(|e| {
-            tracing::error!(
-                cluster_id = %cluster_id,
-                error = %e,
-                "Failed to get cluster state with routing nodes"
-            );
-            ClusterErrorResponse::simple(
-                "shards_failed",
-                format!("Failed to get shard information: {}", e),
-            )
-        })?;
-
-    // Log routing_nodes summary for debugging
-    let unassigned_count = state_response["routing_nodes"]["unassigned"]
-        .as_array()
-        .map(|a| a.len())
-        .unwrap_or(0);
-    let nodes_count = state_response["routing_nodes"]["nodes"]
-        .as_object()
-        .map(|m| m.len())
-        .unwrap_or(0);
-    tracing::debug!(
-        cluster_id = %cluster_id,
-        unassigned_count,
-        nodes_count,
-        "Routing nodes summary before transform"
-    );
-
-    // Transform to flat shard list (minimal fields: index, shard, primary, state, node)
-    let all_shards = transform_routing_nodes_to_shards(&state_response);
-
-    // Log breakdown of shard states (counts) for debugging filters
-    let mut state_counts: std::collections::HashMap<String, usize> =
-        std::collections::HashMap::new();
-    for s in &all_shards {
-        *state_counts.entry(s.state.clone()).or_insert(0) += 1;
-    }
-    tracing::debug!(cluster_id = %cluster_id, all_shards = all_shards.len(), state_counts = ?state_counts, "Transformed routing_nodes to flat shard list");
-
-    // Apply filters
-    let state_filter: Vec<&str> = params.state.split(',').filter(|s| !s.is_empty()).collect();
-
-    // Apply filters step-by-step with debug logs so we can see where shards are dropped
-    let mut step_shards = all_shards;
-
-    // Hide special indices
-    if params.hide_special {
-        step_shards.retain(|s| !s.index.starts_with('.'));
-    }
-    let after_hide = step_shards.len();
-
-    // State filter - apply early so explicit state requests (eg. UNASSIGNED)
-    // are respected and not accidentally removed by primary/replica filtering.
-    let mut after_state = after_hide;
-    if !state_filter.is_empty() {
-        step_shards.retain(|s| state_filter.contains(&s.state.as_str()));
-        after_state = step_shards.len();
+    // State filter - apply early so explicit state requests (eg. UNASSIGNED)
+    // are respected and not accidentally removed by primary/replica filtering.
+    let mut after_state = after_hide;
+    if !state_filter.is_empty() {
+        step_shards.retain(|s| {
+            state_filter
+                .iter()
+                .any(|state| state.eq_ignore_ascii_case(&s.state))
+        });
+        after_state = step_shards.len();
     }
 
     // Primary/Replica filter
@@ -2659,6 +2773,9 @@ pub async fn get_shards(
             true
         });
     }
+    if let Some(primary) = params.primary {
+        step_shards.retain(|s| s.primary == primary);
+    }
     let after_primary_replica = step_shards.len();
 
     // Search filter (OR logic)
@@ -2698,7 +2815,6 @@ pub async fn get_shards(
     let after_node = step_shards.len();
 
     tracing::debug!(
-        cluster_id = %cluster_id,
         initial = after_hide, // after hide_special step is first count from all_shards
         after_primary_replica,
         after_state,
@@ -2708,7 +2824,104 @@ pub async fn get_shards(
         "Shard filter pipeline counts"
     );
 
-    let filtered_shards: Vec<ShardInfoResponse> = step_shards;
+    step_shards
+}
+
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/shards",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ShardsQueryParams
+    ),
+    responses(
+        (status = 200, body = PaginatedShardsWithNodes),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, params), fields(cluster_id = %cluster_id))]
+pub async fn get_shards(
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+    Query(params): Query<ShardsQueryParams>,
+) -> Result<Json<PaginatedShardsWithNodes>, ApiError> {
+    tracing::debug!(
+        cluster_id = %cluster_id,
+        page = params.page,
+        page_size = params.page_size,
+        state = %params.state,
+        index = %params.index,
+        node = %params.node,
+        search = %params.search,
+        primary = ?params.primary,
+        "Getting shards with filters"
+    );
+
+    // Check cluster access
+
+    // Get the cluster
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                cluster_id = %cluster_id,
+                error = %e,
+                "Cluster not found"
+            );
+            ApiError::cluster_not_found(&cluster_id)
+        })?;
+
+    // Short-circuit if cluster is known to be inaccessible
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    // Phase 1: Get all shards from routing_nodes (minimal fields for pagination/filtering)
+    let state_response = cluster
+        .cluster_state_routing_nodes(None)
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                cluster_id = %cluster_id,
+                error = %e,
+                "Failed to get cluster state with routing nodes"
+            );
+            ApiError::upstream(&cluster_id, "get shard information", &e)
+        })?;
+
+    // Log routing_nodes summary for debugging
+    let unassigned_count = state_response["routing_nodes"]["unassigned"]
+        .as_array()
+        .map(|a| a.len())
+        .unwrap_or(0);
+    let nodes_count = state_response["routing_nodes"]["nodes"]
+        .as_object()
+        .map(|m| m.len())
+        .unwrap_or(0);
+    tracing::debug!(
+        cluster_id = %cluster_id,
+        unassigned_count,
+        nodes_count,
+        "Routing nodes summary before transform"
+    );
+
+    // Transform to flat shard list (minimal fields: index, shard, primary, state, node)
+    let all_shards = transform_routing_nodes_to_shards(&state_response);
+
+    // Log breakdown of shard states (counts) for debugging filters
+    let mut state_counts: std::collections::HashMap<String, usize> =
+        std::collections::HashMap::new();
+    for s in &all_shards {
+        *state_counts.entry(s.state.clone()).or_insert(0) += 1;
+    }
+    tracing::debug!(cluster_id = %cluster_id, all_shards = all_shards.len(), state_counts = ?state_counts, "Transformed routing_nodes to flat shard list");
+
+    let filtered_shards = filter_shards(all_shards, &params);
 
     tracing::debug!(
         cluster_id = %cluster_id,
@@ -2787,18 +3000,12 @@ pub async fn get_shards(
     let nodes_vec: Vec<NodeInfoResponse> = {
         let nodes_info = cluster.nodes_info().await.map_err(|e| {
             tracing::error!(cluster_id = %cluster_id, error = %e, "Failed to get nodes info for shards response");
-            ClusterErrorResponse::simple(
-                "nodes_info_failed",
-                format!("Failed to get nodes info: {}", e),
-            )
+            ApiError::upstream(&cluster_id, "get nodes info", &e)
         })?;
 
         let nodes_stats = cluster.nodes_stats().await.map_err(|e| {
             tracing::error!(cluster_id = %cluster_id, error = %e, "Failed to get nodes stats for shards response");
-            ClusterErrorResponse::simple(
-                "nodes_stats_failed",
-                format!("Failed to get nodes stats: {}", e),
-            )
+            ApiError::upstream(&cluster_id, "get nodes stats", &e)
         })?;
 
         // Best-effort master node id
@@ -2810,7 +3017,14 @@ pub async fn get_shards(
             }
         };
 
-        let all_nodes = transform_nodes(&nodes_info, &nodes_stats, master_node_id.as_deref(), None);
+        // Only node ids and names are used here, so skip the watermark lookup
+        let all_nodes = transform_nodes(
+            &nodes_info,
+            &nodes_stats,
+            master_node_id.as_deref(),
+            None,
+            &DiskWatermarks::default(),
+        );
         // Build quick lookup maps from node id -> name and name set for
         // normalizing node identifiers referenced by shards. Some cluster
         // APIs return node IDs (routing_nodes keys) while others return node
@@ -2888,13 +3102,14 @@ pub async fn get_shards(
                 "Missing NodeInfo for referenced nodes in shards page"
             );
 
-            return Err(ClusterErrorResponse::simple(
-                "nodes_missing",
+            return Err(ApiError::new(
+                ErrorCode::UpstreamError,
                 format!(
                     "Missing NodeInfo for nodes referenced by shards: {}. Backend must supply full node metadata.",
                     missing_names.join(", ")
                 ),
-            ));
+            )
+            .with_cluster(&cluster_id));
         }
 
         page_nodes
@@ -2931,9 +3146,9 @@ pub async fn get_shards(
     ),
     responses(
         (status = 200, body = Vec<NodeShardSummary>),
-        (status = 400, body = ClusterErrorResponse),
-        (status = 401, body = ClusterErrorResponse),
-        (status = 404, body = ClusterErrorResponse)
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
     ),
     tag = "Clusters"
 )]
@@ -2941,7 +3156,7 @@ pub async fn get_shards(
 pub async fn get_nodes_shard_summary(
     State(state): State<ClusterState>,
     Path(cluster_id): Path<String>,
-) -> Result<Json<Vec<NodeShardSummary>>, ClusterErrorResponse> {
+) -> Result<Json<Vec<NodeShardSummary>>, ApiError> {
     tracing::debug!(
         cluster_id = %cluster_id,
         "Getting per-node shard summary"
@@ -2957,18 +3172,12 @@ pub async fn get_nodes_shard_summary(
                 error = %e,
                 "Cluster not found"
             );
-            ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Cluster '{}' not found: {}", cluster_id, e),
-            )
+            ApiError::cluster_not_found(&cluster_id)
         })?;
 
     // Short-circuit if cluster is known to be inaccessible
     if !cluster.accessible {
-        return Err(ClusterErrorResponse::unavailable(
-            &cluster_id,
-            cluster.accessible_reason.clone(),
-        ));
+        return Err(ApiError::inaccessible(&cluster));
     }
 
     let shards_data = cluster.cat_shards().await.map_err(|e| {
@@ -2977,10 +3186,7 @@ pub async fn get_nodes_shard_summary(
             error = %e,
             "Failed to fetch cat shards for shard summary"
         );
-        ClusterErrorResponse::simple(
-            "shards_failed",
-            format!("Failed to get shard information: {}", e),
-        )
+        ApiError::upstream(&cluster_id, "get shard information", &e)
     })?;
 
     let summary = aggregate_shards_by_node(&shards_data);
@@ -2994,6 +3200,508 @@ pub async fn get_nodes_shard_summary(
     Ok(Json(summary))
 }
 
+/// Health summary for a single cluster in the bulk health response
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ClusterHealthSummary {
+    /// Health status, absent when the check failed
+    pub status: Option<HealthStatus>,
+    /// Error message when the cluster could not be checked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Circuit breaker state; `open` means the cluster is temporarily unavailable
+    pub circuit_state: CircuitState,
+}
+
+/// Get health of all accessible clusters
+///
+/// Returns a map of cluster ID to health status for every cluster the user
+/// can access. Checks run concurrently and are served from the health cache;
+/// unreachable clusters report their error instead of failing the request.
+///
+/// # Requirements
+///
+/// Validates: Requirements 2.14, 31.2
+#[utoipa::path(
+    get,
+    path = "/clusters/health",
+    responses(
+        (status = 200, body = std::collections::HashMap<String, ClusterHealthSummary>),
+        (status = 401, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext))]
+pub async fn get_all_clusters_health(
+    State(state): State<ClusterState>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+) -> Json<std::collections::HashMap<String, ClusterHealthSummary>> {
+    let all_ids: Vec<String> = state
+        .cluster_manager
+        .list_clusters()
+        .await
+        .into_iter()
+        .map(|c| c.id)
+        .collect();
+
+    // Open mode has no authenticated user and sees every cluster
+    let cluster_ids = match user_ext {
+        Some(user) => state.rbac.get_accessible_clusters(&user.0 .0, &all_ids),
+        None => all_ids,
+    };
+
+    let results = state.cluster_manager.check_health_for(&cluster_ids).await;
+
+    let mut summaries = std::collections::HashMap::with_capacity(results.len());
+    for (id, result) in results {
+        let circuit_state = state
+            .cluster_manager
+            .circuit_state(&id)
+            .await
+            .unwrap_or(CircuitState::Closed);
+        let summary = match result {
+            Ok(health) => ClusterHealthSummary {
+                status: Some(health.status),
+                error: None,
+                circuit_state,
+            },
+            Err(e) => {
+                tracing::warn!(cluster_id = %id, error = %e, "Cluster health check failed");
+                ClusterHealthSummary {
+                    status: None,
+                    error: Some(e.to_string()),
+                    circuit_state,
+                }
+            }
+        };
+        summaries.insert(id, summary);
+    }
+
+    Json(summaries)
+}
+
+/// Health and size totals across all accessible clusters
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct ClustersSummary {
+    /// Number of clusters included in the summary
+    pub clusters: u32,
+    pub green: u32,
+    pub yellow: u32,
+    pub red: u32,
+    /// Clusters whose health check failed
+    pub unreachable: u32,
+    /// Worst status across all clusters, counting unreachable ones as red;
+    /// absent when there are no clusters
+    pub status: Option<HealthStatus>,
+    /// Nodes across reachable clusters
+    pub nodes: u64,
+    /// Indices across reachable clusters
+    pub indices: u64,
+    /// Primary documents across reachable clusters
+    pub documents: u64,
+}
+
+impl ClustersSummary {
+    /// Count one cluster's health into the summary
+    fn add_health(&mut self, health: Option<&ClusterHealth>) {
+        self.clusters += 1;
+        let status = match health {
+            Some(health) => {
+                self.nodes += u64::from(health.number_of_nodes);
+                match health.status {
+                    HealthStatus::Green => self.green += 1,
+                    HealthStatus::Yellow => self.yellow += 1,
+                    HealthStatus::Red => self.red += 1,
+                }
+                health.status.clone()
+            }
+            None => {
+                self.unreachable += 1;
+                HealthStatus::Red
+            }
+        };
+        let severity = |status: &HealthStatus| match status {
+            HealthStatus::Green => 0,
+            HealthStatus::Yellow => 1,
+            HealthStatus::Red => 2,
+        };
+        if self
+            .status
+            .as_ref()
+            .is_none_or(|worst| severity(&status) > severity(worst))
+        {
+            self.status = Some(status);
+        }
+    }
+}
+
+/// Get a health summary across all accessible clusters
+///
+/// Counts the user's clusters by health status, reports the worst status
+/// and sums nodes, indices and documents. Health comes from the health
+/// cache and index counts from the cached `_cat/indices` listing; both are
+/// fetched concurrently. Clusters that fail the health check are counted as
+/// unreachable and left out of the totals.
+#[utoipa::path(
+    get,
+    path = "/summary",
+    responses(
+        (status = 200, body = ClustersSummary),
+        (status = 401, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext))]
+pub async fn get_clusters_summary(
+    State(state): State<ClusterState>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+) -> Json<ClustersSummary> {
+    let all_ids: Vec<String> = state
+        .cluster_manager
+        .list_clusters()
+        .await
+        .into_iter()
+        .map(|c| c.id)
+        .collect();
+
+    // The anonymous open-mode user has no roles and sees every cluster
+    let cluster_ids = match user_ext {
+        Some(user) if user.0 .0.auth_type != "open" => {
+            state.rbac.get_accessible_clusters(&user.0 .0, &all_ids)
+        }
+        _ => all_ids,
+    };
+
+    let results = state.cluster_manager.check_health_for(&cluster_ids).await;
+
+    let mut summary = ClustersSummary::default();
+    let mut reachable = Vec::new();
+    for (id, result) in &results {
+        match result {
+            Ok(health) => {
+                summary.add_health(Some(health));
+                reachable.push(id.as_str());
+            }
+            Err(e) => {
+                tracing::warn!(cluster_id = %id, error = %e, "Cluster health check failed");
+                summary.add_health(None);
+            }
+        }
+    }
+
+    let index_counts = futures::future::join_all(
+        reachable
+            .into_iter()
+            .map(|id| cluster_index_counts(&state, id)),
+    )
+    .await;
+    for (indices, documents) in index_counts.into_iter().flatten() {
+        summary.indices += indices;
+        summary.documents += documents;
+    }
+
+    Json(summary)
+}
+
+/// Index and primary document counts of a cluster, from the cached
+/// `_cat/indices` listing; `None` if it could not be fetched
+async fn cluster_index_counts(state: &ClusterState, cluster_id: &str) -> Option<(u64, u64)> {
+    let cluster = state.cluster_manager.get_cluster(cluster_id).await.ok()?;
+    let cat_indices = state
+        .cluster_manager
+        .cached_resource(CachedResource::Indices, cluster_id, "", || {
+            cluster.cat_indices(None)
+        })
+        .await
+        .map_err(|e| {
+            tracing::warn!(cluster_id, error = %e, "Failed to get indices for summary");
+        })
+        .ok()?;
+
+    let indices = transform_indices_from_cat(&cat_indices);
+    let documents = indices.iter().map(|index| index.docs_count).sum();
+    Some((indices.len() as u64, documents))
+}
+
+/// Query parameters for cluster health
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct ClusterHealthQueryParams {
+    /// Bypass the health cache and fetch fresh health from the cluster
+    #[serde(default)]
+    pub refresh: bool,
+}
+
+/// Get cluster health
+///
+/// Returns the cached cluster health, a lightweight alternative to the full
+/// stats endpoint for dashboards that poll frequently. Pass `refresh=true`
+/// to bypass the cache; the fresh result repopulates it.
+///
+/// # Requirements
+///
+/// Validates: Requirements 2.14, 31.2
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/health",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ClusterHealthQueryParams
+    ),
+    responses(
+        (status = 200, body = ClusterHealth),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError),
+        (status = 503, description = "Circuit breaker open", body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state), fields(cluster_id = %cluster_id))]
+pub async fn get_cluster_health(
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+    Query(params): Query<ClusterHealthQueryParams>,
+) -> Result<Json<ClusterHealth>, ApiError> {
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                cluster_id = %cluster_id,
+                error = %e,
+                "Cluster not found"
+            );
+            ApiError::cluster_not_found(&cluster_id)
+        })?;
+
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    let health = if params.refresh {
+        state.cluster_manager.refresh_health(&cluster_id).await
+    } else {
+        state.cluster_manager.check_health(&cluster_id).await
+    }
+    .map_err(|e| cluster_health_error(&cluster_id, &e))?;
+
+    Ok(Json(health))
+}
+
+/// Map a failed health check to an error response
+fn cluster_health_error(cluster_id: &str, e: &anyhow::Error) -> ApiError {
+    if let Some(open) = e.downcast_ref::<CircuitOpenError>() {
+        tracing::debug!(cluster_id = %cluster_id, "Health check skipped: circuit open");
+        return ApiError::new(
+            ErrorCode::CircuitOpen,
+            format!(
+                "Cluster '{}' is temporarily unavailable after repeated connection failures. Retrying in {}s.",
+                cluster_id, open.retry_after_secs
+            ),
+        )
+        .with_cluster(cluster_id);
+    }
+    tracing::error!(
+        cluster_id = %cluster_id,
+        error = %e,
+        "Failed to get cluster health"
+    );
+    ApiError::upstream(cluster_id, "get cluster health", e)
+}
+
+/// Get snapshot repositories registered on a cluster
+///
+/// Returns repository name, type and settings, sorted by name.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/snapshots/repositories",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID")
+    ),
+    responses(
+        (status = 200, body = Vec<SnapshotRepositoryResponse>),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state), fields(cluster_id = %cluster_id))]
+pub async fn get_snapshot_repositories(
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+) -> Result<Json<Vec<SnapshotRepositoryResponse>>, ApiError> {
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                cluster_id = %cluster_id,
+                error = %e,
+                "Cluster not found"
+            );
+            ApiError::cluster_not_found(&cluster_id)
+        })?;
+
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    let repositories = cluster.snapshot_repositories().await.map_err(|e| {
+        tracing::error!(
+            cluster_id = %cluster_id,
+            error = %e,
+            "Failed to fetch snapshot repositories"
+        );
+        ApiError::upstream(&cluster_id, "get snapshot repositories", &e)
+    })?;
+
+    Ok(Json(transform_snapshot_repositories(&repositories)))
+}
+
+/// Get snapshots stored in a snapshot repository
+///
+/// Returns snapshot name, state, start/end time, indices count and shard
+/// failure counts, newest first.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/snapshots/{repository}",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ("repository" = String, Path, description = "Snapshot repository name")
+    ),
+    responses(
+        (status = 200, body = Vec<SnapshotInfoResponse>),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state), fields(cluster_id = %cluster_id, repository = %repository))]
+pub async fn get_snapshots(
+    State(state): State<ClusterState>,
+    Path((cluster_id, repository)): Path<(String, String)>,
+) -> Result<Json<Vec<SnapshotInfoResponse>>, ApiError> {
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                cluster_id = %cluster_id,
+                error = %e,
+                "Cluster not found"
+            );
+            ApiError::cluster_not_found(&cluster_id)
+        })?;
+
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    let snapshots = cluster.snapshots(&repository).await.map_err(|e| {
+        tracing::error!(
+            cluster_id = %cluster_id,
+            repository = %repository,
+            error = %e,
+            "Failed to fetch snapshots"
+        );
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!(
+                "Failed to get snapshots for repository '{}': {}",
+                repository, e
+            ),
+        )
+        .with_cluster(&cluster_id)
+    })?;
+
+    Ok(Json(transform_snapshots(&snapshots)))
+}
+
+/// Get the index lifecycle management (ILM) state of an index
+///
+/// Returns the governing policy, current phase, index age and current
+/// action/step, plus the next phase and action defined by the policy.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/indices/{index}/ilm",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ("index" = String, Path, description = "Index name")
+    ),
+    responses(
+        (status = 200, body = IndexIlmResponse),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state), fields(cluster_id = %cluster_id, index = %index))]
+pub async fn get_index_ilm(
+    State(state): State<ClusterState>,
+    Path((cluster_id, index)): Path<(String, String)>,
+) -> Result<Json<IndexIlmResponse>, ApiError> {
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                cluster_id = %cluster_id,
+                error = %e,
+                "Cluster not found"
+            );
+            ApiError::cluster_not_found(&cluster_id)
+        })?;
+
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    let explain = cluster.ilm_explain(&index).await.map_err(|e| {
+        tracing::error!(
+            cluster_id = %cluster_id,
+            index = %index,
+            error = %e,
+            "Failed to explain ILM state"
+        );
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("Failed to get ILM state for index '{}': {}", index, e),
+        )
+        .with_cluster(&cluster_id)
+    })?;
+
+    // The policy is only needed to work out what runs next; the current state
+    // is still useful if it cannot be fetched.
+    let policies = match explain["indices"][index.as_str()]["policy"].as_str() {
+        Some(policy) => cluster.ilm_policy(policy).await.unwrap_or_else(|e| {
+            tracing::warn!(
+                cluster_id = %cluster_id,
+                policy = %policy,
+                error = %e,
+                "Failed to fetch ILM policy, next phase will be omitted"
+            );
+            Value::Null
+        }),
+        None => Value::Null,
+    };
+
+    transform_index_ilm(&explain, &policies, &index)
+        .map(Json)
+        .ok_or_else(|| {
+            ApiError::new(
+                ErrorCode::NotFound,
+                format!("Index '{}' not found in ILM explain response", index),
+            )
+            .with_cluster(&cluster_id)
+        })
+}
+
 /// Get shards allocated on a specific node
 ///
 /// Returns shard information for shards on the specified node only.
@@ -3011,9 +3719,9 @@ pub async fn get_nodes_shard_summary(
     ),
     responses(
         (status = 200, body = Vec<ShardInfoResponse>),
-        (status = 400, body = ClusterErrorResponse),
-        (status = 401, body = ClusterErrorResponse),
-        (status = 404, body = ClusterErrorResponse)
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
     ),
     tag = "Clusters"
 )]
@@ -3021,7 +3729,7 @@ pub async fn get_nodes_shard_summary(
 pub async fn get_node_shards(
     State(state): State<ClusterState>,
     Path((cluster_id, node_id)): Path<(String, String)>,
-) -> Result<Json<Vec<ShardInfoResponse>>, ClusterErrorResponse> {
+) -> Result<Json<Vec<ShardInfoResponse>>, ApiError> {
     tracing::debug!(
         cluster_id = %cluster_id,
         node_id = %node_id,
@@ -3041,10 +3749,7 @@ pub async fn get_node_shards(
                 error = %e,
                 "Cluster not found"
             );
-            ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Cluster '{}' not found: {}", cluster_id, e),
-            )
+            ApiError::cluster_not_found(&cluster_id)
         })?;
 
     // Use _cluster/state/routing_nodes for native JSON API
@@ -3058,10 +3763,7 @@ pub async fn get_node_shards(
                 error = %e,
                 "Failed to get cluster state with routing nodes"
             );
-            ClusterErrorResponse::simple(
-                "shards_failed",
-                format!("Failed to get shard information: {}", e),
-            )
+            ApiError::upstream(&cluster_id, "get shard information", &e)
         })?;
 
     // Transform to flat shard list
@@ -3094,23 +3796,87 @@ pub async fn get_node_shards(
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_cluster_error_response_serialization() {
-        let error = ClusterErrorResponse::simple("cluster_not_found", "Cluster 'test' not found");
+    fn shard(
+        index: &str,
+        shard: u32,
+        primary: bool,
+        state: &str,
+        node: Option<&str>,
+    ) -> ShardInfoResponse {
+        ShardInfoResponse {
+            index: index.to_string(),
+            shard,
+            primary,
+            state: state.to_string(),
+            node: node.map(str::to_string),
+            docs: 0,
+            store: 0,
+        }
+    }
+
+    fn filtered(params: serde_json::Value) -> Vec<(String, u32, bool)> {
+        let shards = vec![
+            shard("logs-1", 0, true, "STARTED", Some("node-1")),
+            shard("logs-1", 0, false, "UNASSIGNED", None),
+            shard("logs-2", 0, true, "UNASSIGNED", None),
+            shard("logs-2", 0, false, "STARTED", Some("node-2")),
+            shard("metrics-1", 0, true, "RELOCATING", Some("node-2")),
+            shard(".security", 0, true, "STARTED", Some("node-1")),
+        ];
+        let params: ShardsQueryParams = serde_json::from_value(params).expect("parse params");
+        filter_shards(shards, &params)
+            .into_iter()
+            .map(|s| (s.index, s.shard, s.primary))
+            .collect()
+    }
 
-        let json = serde_json::to_string(&error).expect("serialize ClusterErrorResponse to JSON");
-        assert!(json.contains("\"error\":\"cluster_not_found\""));
-        assert!(json.contains("\"message\":\"Cluster 'test' not found\""));
+    #[test]
+    fn test_filter_shards_without_params_keeps_all() {
+        assert_eq!(filtered(serde_json::json!({})).len(), 6);
     }
 
     #[test]
-    fn test_cluster_error_response_deserialization() {
-        let json = r#"{"error":"proxy_failed","message":"Connection timeout"}"#;
-        let error: ClusterErrorResponse =
-            serde_json::from_str(json).expect("deserialize ClusterErrorResponse from JSON");
+    fn test_filter_shards_combinations() {
+        let key = |index: &str, primary| (index.to_string(), 0, primary);
 
-        assert_eq!(error.error, "proxy_failed");
-        assert_eq!(error.message, "Connection timeout");
+        assert_eq!(
+            filtered(serde_json::json!({"state": "unassigned"})),
+            vec![key("logs-1", false), key("logs-2", true)]
+        );
+        assert_eq!(
+            filtered(serde_json::json!({"state": "UNASSIGNED", "primary": true})),
+            vec![key("logs-2", true)]
+        );
+        assert_eq!(
+            filtered(serde_json::json!({"index": "logs", "primary": false})),
+            vec![key("logs-1", false), key("logs-2", false)]
+        );
+        assert_eq!(
+            filtered(serde_json::json!({"node": "node-2", "state": "STARTED,RELOCATING"})),
+            vec![key("logs-2", false), key("metrics-1", true)]
+        );
+        assert_eq!(
+            filtered(serde_json::json!({"node": "node-1", "hide_special": true})),
+            vec![key("logs-1", true)]
+        );
+        assert!(
+            filtered(serde_json::json!({"index": "metrics", "state": "UNASSIGNED"})).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_index_expression() {
+        assert_eq!(index_expression(None, None), None);
+        assert_eq!(index_expression(Some(" "), Some("")), None);
+        assert_eq!(
+            index_expression(Some("logs-*"), None).as_deref(),
+            Some("logs-*")
+        );
+        assert_eq!(
+            index_expression(Some("logs-*, metrics-*"), Some(".*,-tmp-*")).as_deref(),
+            Some("logs-*,metrics-*,-.*,-tmp-*")
+        );
+        assert_eq!(index_expression(None, Some(".*")).as_deref(), Some("*,-.*"));
     }
 
     #[test]
@@ -3152,7 +3918,7 @@ mod tests {
         let paginated = PaginatedShardsResponse::new(items.clone(), 1, 10);
 
         // Build nodes_info and nodes_stats matching node names referenced by shards
-        let nodes_info = json!({
+        let nodes_info = serde_json::json!({
             "nodes": {
                 "n1": { "name": "node1", "roles": ["data"], "ip": "10.0.0.1", "version": "8.0.0" },
                 "n2": { "name": "node2", "roles": ["data"], "ip": "10.0.0.2", "version": "8.0.0" }
@@ -3175,7 +3941,13 @@ mod tests {
         });
 
         // Simulate handler logic: transform nodes and filter by referenced names
-        let all_nodes = transform_nodes(&nodes_info, &nodes_stats, Some("n1"), None);
+        let all_nodes = transform_nodes(
+            &nodes_info,
+            &nodes_stats,
+            Some("n1"),
+            None,
+            &DiskWatermarks::default(),
+        );
 
         let referenced_node_names: HashSet<String> = paginated
             .items
@@ -0,0 +1,375 @@
+use super::audited::{es_error_reason, require_user, send};
+use super::ClusterState;
+use crate::auth::middleware::AuthenticatedUser;
+use crate::middleware::logging::RequestId;
+use crate::routes::{ApiError, ErrorCode};
+use axum::{
+    extract::{Path, Query, State},
+    http::Method,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::instrument;
+use utoipa::{IntoParams, ToSchema};
+
+/// Message returned when the cluster has nothing to explain
+const NO_UNASSIGNED_SHARDS: &str = "No unassigned shards to explain";
+
+/// Query parameters selecting the shard to explain
+///
+/// Without parameters Elasticsearch explains an arbitrary unassigned shard.
+#[derive(Debug, Default, Deserialize, IntoParams, ToSchema)]
+pub struct AllocationExplainParams {
+    /// Index of the shard to explain (requires `shard`)
+    #[param(example = "logs-2024.01")]
+    pub index: Option<String>,
+    /// Shard number to explain (requires `index`)
+    #[param(example = 0)]
+    pub shard: Option<u32>,
+    /// Explain the primary copy rather than a replica (default: false)
+    pub primary: Option<bool>,
+}
+
+/// A single allocation decider result
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AllocationDeciderResponse {
+    /// Node the decision applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<String>,
+    /// Name of that node
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_name: Option<String>,
+    /// Decider name (e.g. "same_shard", "disk_threshold")
+    pub decider: String,
+    /// Decision (YES, NO, THROTTLE)
+    pub decision: String,
+    /// Why the decider reached this decision
+    pub explanation: String,
+}
+
+/// Summary of the cluster allocation explain API
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AllocationExplainResponse {
+    /// False when there was no unassigned shard to explain
+    pub explained: bool,
+    /// Index of the explained shard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<String>,
+    /// Number of the explained shard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shard: Option<u64>,
+    /// Whether the explained copy is the primary
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<bool>,
+    /// Whether the shard can be allocated (e.g. "no", "yes", "throttled")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_allocate: Option<String>,
+    /// Shard state (e.g. "unassigned", "started")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_state: Option<String>,
+    /// Node currently holding an assigned shard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_node: Option<String>,
+    /// Why the shard became unassigned (e.g. "NODE_LEFT")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unassigned_reason: Option<String>,
+    /// Human-readable summary from Elasticsearch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+    /// Deciders that affected the decision, per node
+    pub deciders: Vec<AllocationDeciderResponse>,
+}
+
+impl AllocationExplainResponse {
+    fn nothing_to_explain() -> Self {
+        Self {
+            explained: false,
+            index: None,
+            shard: None,
+            primary: None,
+            can_allocate: None,
+            current_state: None,
+            current_node: None,
+            unassigned_reason: None,
+            explanation: Some(NO_UNASSIGNED_SHARDS.to_string()),
+            deciders: Vec::new(),
+        }
+    }
+}
+
+/// Explain why a shard is (or is not) allocated
+///
+/// Calls the Elasticsearch cluster allocation explain API. When no shard is
+/// specified and the cluster has no unassigned shards, returns
+/// `explained: false` instead of an error.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/allocation/explain",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        AllocationExplainParams
+    ),
+    responses(
+        (status = 200, body = AllocationExplainResponse),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext, request_id_ext), fields(cluster_id = %cluster_id))]
+pub async fn explain_allocation(
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+    Query(params): Query<AllocationExplainParams>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+) -> Result<Json<AllocationExplainResponse>, ApiError> {
+    let user = require_user(user_ext)?;
+    let body = explain_request_body(&params)?;
+
+    let (status, response) = send(
+        &state,
+        &cluster_id,
+        &user,
+        request_id_ext,
+        Method::GET,
+        "/_cluster/allocation/explain".to_string(),
+        body,
+    )
+    .await?;
+
+    if status == reqwest::StatusCode::BAD_REQUEST && is_nothing_to_explain(&response) {
+        tracing::debug!(cluster_id = %cluster_id, "No unassigned shards to explain");
+        return Ok(Json(AllocationExplainResponse::nothing_to_explain()));
+    }
+
+    if !status.is_success() {
+        return Err(ApiError::new(
+            ErrorCode::UpstreamError,
+            format!(
+                "Failed to explain shard allocation: {}",
+                es_error_reason(&response)
+            ),
+        )
+        .with_cluster(&cluster_id));
+    }
+
+    Ok(Json(transform_allocation_explain(&response)))
+}
+
+/// Build the explain request body from the query parameters
+fn explain_request_body(params: &AllocationExplainParams) -> Result<Option<Value>, ApiError> {
+    match (&params.index, params.shard) {
+        (None, None) if params.primary.is_none() => Ok(None),
+        (Some(index), Some(shard)) if !index.is_empty() => Ok(Some(serde_json::json!({
+            "index": index,
+            "shard": shard,
+            "primary": params.primary.unwrap_or(false),
+        }))),
+        _ => Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            "Both 'index' and 'shard' are required to explain a specific shard",
+        )),
+    }
+}
+
+/// Whether an explain error means the cluster has no unassigned shards
+///
+/// Elasticsearch answers 400 in that case; the wording differs between
+/// versions but always mentions unassigned shards.
+fn is_nothing_to_explain(response: &Value) -> bool {
+    let reason = es_error_reason(response).to_ascii_lowercase();
+    reason.contains("no unassigned shards")
+        || reason.contains("unable to find any unassigned shards")
+}
+
+/// Transform a `_cluster/allocation/explain` response
+fn transform_allocation_explain(response: &Value) -> AllocationExplainResponse {
+    let text = |value: &Value| value.as_str().map(str::to_string);
+    let current_node = &response["current_node"];
+
+    // Decisions for an assigned shard staying where it is
+    let remain = response["can_remain_decisions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|decider| decider_response(current_node, decider));
+
+    // Decisions for each candidate node
+    let nodes = response["node_allocation_decisions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .flat_map(|node| {
+            node["deciders"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(move |decider| decider_response(node, decider))
+        });
+
+    AllocationExplainResponse {
+        explained: true,
+        index: text(&response["index"]),
+        shard: response["shard"].as_u64(),
+        primary: response["primary"].as_bool(),
+        can_allocate: text(&response["can_allocate"])
+            .or_else(|| text(&response["can_remain_on_current_node"])),
+        current_state: text(&response["current_state"]),
+        current_node: text(&current_node["name"]),
+        unassigned_reason: text(&response["unassigned_info"]["reason"]),
+        explanation: text(&response["allocate_explanation"])
+            .or_else(|| text(&response["rebalance_explanation"])),
+        deciders: remain.chain(nodes).collect(),
+    }
+}
+
+fn decider_response(node: &Value, decider: &Value) -> AllocationDeciderResponse {
+    AllocationDeciderResponse {
+        node_id: node["node_id"]
+            .as_str()
+            .or_else(|| node["id"].as_str())
+            .map(str::to_string),
+        node_name: node["node_name"]
+            .as_str()
+            .or_else(|| node["name"].as_str())
+            .map(str::to_string),
+        decider: decider["decider"].as_str().unwrap_or_default().to_string(),
+        decision: decider["decision"].as_str().unwrap_or_default().to_string(),
+        explanation: decider["explanation"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_transform_unassigned_shard() {
+        let response = json!({
+            "index": "logs",
+            "shard": 0,
+            "primary": false,
+            "current_state": "unassigned",
+            "unassigned_info": {"reason": "NODE_LEFT", "at": "2024-01-01T00:00:00.000Z"},
+            "can_allocate": "no",
+            "allocate_explanation": "cannot allocate because allocation is not permitted to any of the nodes",
+            "node_allocation_decisions": [
+                {
+                    "node_id": "n1",
+                    "node_name": "node-1",
+                    "node_decision": "no",
+                    "deciders": [
+                        {
+                            "decider": "same_shard",
+                            "decision": "NO",
+                            "explanation": "a copy of this shard is already allocated to this node"
+                        }
+                    ]
+                },
+                {
+                    "node_id": "n2",
+                    "node_name": "node-2",
+                    "node_decision": "no",
+                    "deciders": [
+                        {
+                            "decider": "disk_threshold",
+                            "decision": "NO",
+                            "explanation": "the node is above the high watermark"
+                        }
+                    ]
+                }
+            ]
+        });
+
+        let result = transform_allocation_explain(&response);
+
+        assert!(result.explained);
+        assert_eq!(result.can_allocate.as_deref(), Some("no"));
+        assert_eq!(result.current_state.as_deref(), Some("unassigned"));
+        assert_eq!(result.unassigned_reason.as_deref(), Some("NODE_LEFT"));
+        assert_eq!(result.deciders.len(), 2);
+        assert_eq!(result.deciders[1].node_name.as_deref(), Some("node-2"));
+        assert_eq!(result.deciders[1].decider, "disk_threshold");
+        assert_eq!(result.deciders[1].decision, "NO");
+    }
+
+    #[test]
+    fn test_transform_assigned_shard() {
+        let response = json!({
+            "index": "logs",
+            "shard": 1,
+            "primary": true,
+            "current_state": "started",
+            "current_node": {"id": "n1", "name": "node-1"},
+            "can_remain_on_current_node": "no",
+            "can_remain_decisions": [
+                {
+                    "decider": "filter",
+                    "decision": "NO",
+                    "explanation": "node matches cluster setting [cluster.routing.allocation.exclude]"
+                }
+            ],
+            "can_move_to_other_node": "no"
+        });
+
+        let result = transform_allocation_explain(&response);
+
+        assert_eq!(result.can_allocate.as_deref(), Some("no"));
+        assert_eq!(result.current_node.as_deref(), Some("node-1"));
+        assert_eq!(result.deciders.len(), 1);
+        assert_eq!(result.deciders[0].node_id.as_deref(), Some("n1"));
+        assert_eq!(result.deciders[0].decider, "filter");
+    }
+
+    #[test]
+    fn test_nothing_to_explain_detection() {
+        let es7 = json!({"error": {
+            "type": "illegal_argument_exception",
+            "reason": "unable to find any unassigned shards to explain [ClusterAllocationExplainRequest[useAnyUnassignedShard=true,includeYesDecisions?=false]"
+        }});
+        let es8 = json!({"error": {
+            "type": "illegal_argument_exception",
+            "reason": "No shard was specified in the explain API request, so this request explains a randomly chosen unassigned shard. There are no unassigned shards in this cluster."
+        }});
+        let other = json!({"error": {
+            "type": "illegal_argument_exception",
+            "reason": "shard [3] does not exist"
+        }});
+
+        assert!(is_nothing_to_explain(&es7));
+        assert!(is_nothing_to_explain(&es8));
+        assert!(!is_nothing_to_explain(&other));
+    }
+
+    #[test]
+    fn test_explain_request_body() {
+        assert_eq!(
+            explain_request_body(&AllocationExplainParams::default()).expect("no params"),
+            None
+        );
+
+        let body = explain_request_body(&AllocationExplainParams {
+            index: Some("logs".to_string()),
+            shard: Some(2),
+            primary: Some(true),
+        })
+        .expect("valid params");
+        assert_eq!(
+            body,
+            Some(json!({"index": "logs", "shard": 2, "primary": true}))
+        );
+
+        assert!(explain_request_body(&AllocationExplainParams {
+            index: Some("logs".to_string()),
+            ..Default::default()
+        })
+        .is_err());
+    }
+}
@@ -0,0 +1,162 @@
+//! Requests to Elasticsearch through the audited proxy
+//!
+//! Shared by handlers that call Elasticsearch on behalf of the user, so the
+//! request is audited and proxy failures map to the same error responses.
+
+use super::ClusterState;
+use crate::auth::middleware::AuthenticatedUser;
+use crate::auth::AuthUser;
+use crate::cluster::{manager::ProxyAuditRequest, ProxyRequestError};
+use crate::middleware::logging::RequestId;
+use crate::routes::{ApiError, ErrorCode};
+use axum::http::Method;
+use serde_json::Value;
+
+/// Require an authenticated user on the request
+pub(super) fn require_user(
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+) -> Result<AuthUser, ApiError> {
+    user_ext.map(|ext| ext.0 .0).ok_or_else(|| {
+        tracing::error!("Authentication required but user not found in request");
+        ApiError::new(
+            ErrorCode::AuthenticationRequired,
+            "Authentication is required for this operation",
+        )
+    })
+}
+
+/// Send a request through the audited proxy and parse the JSON response
+pub(super) async fn send(
+    state: &ClusterState,
+    cluster_id: &str,
+    user: &AuthUser,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+    method: Method,
+    path: String,
+    body: Option<Value>,
+) -> Result<(reqwest::StatusCode, Value), ApiError> {
+    let (status, body_vec) =
+        send_raw(state, cluster_id, user, request_id_ext, method, path, body).await?;
+
+    let response: Value = serde_json::from_slice(&body_vec).map_err(|e| {
+        tracing::error!(cluster_id = %cluster_id, error = %e, "Failed to parse Elasticsearch response");
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("Failed to parse response: {}", e),
+        )
+        .with_cluster(cluster_id)
+    })?;
+
+    Ok((status, response))
+}
+
+/// Send a request through the audited proxy and return the raw response body
+pub(super) async fn send_raw(
+    state: &ClusterState,
+    cluster_id: &str,
+    user: &AuthUser,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+    method: Method,
+    path: String,
+    body: Option<Value>,
+) -> Result<(reqwest::StatusCode, Vec<u8>), ApiError> {
+    let request_id = request_id_ext
+        .as_ref()
+        .map(|r| r.0.as_str().to_string())
+        .unwrap_or_default();
+
+    let (status, _headers, body_vec, _matched_role) = state
+        .cluster_manager
+        .proxy_request_with_audit(ProxyAuditRequest {
+            cluster_id: cluster_id.to_string(),
+            method: method.clone(),
+            path: path.clone(),
+            body,
+            user_id: Some(user.id.clone()),
+            user_roles: user.roles.clone(),
+            request_id,
+            audit_enabled: state.audit_log,
+            max_response_bytes: None,
+        })
+        .await
+        .map_err(|e| map_proxy_error(cluster_id, &method, &path, e))?;
+
+    Ok((status, body_vec))
+}
+
+/// Map a proxy error to an actionable error response
+pub(super) fn map_proxy_error(
+    cluster_id: &str,
+    method: &Method,
+    path: &str,
+    error: ProxyRequestError,
+) -> ApiError {
+    match error {
+        ProxyRequestError::ResponseTooLarge { limit } => {
+            ApiError::response_too_large(cluster_id, limit)
+        }
+        ProxyRequestError::ClusterBusy(busy) => ApiError::cluster_busy(&busy),
+        ProxyRequestError::ClusterNotFound(_) => ApiError::cluster_not_found(cluster_id),
+        ProxyRequestError::AccessDenied => {
+            tracing::warn!(cluster_id = %cluster_id, "Access denied: no matching role client");
+            ApiError::new(
+                ErrorCode::AccessDenied,
+                "You do not have permissions to perform this operation on the requested cluster",
+            )
+            .with_cluster(cluster_id)
+        }
+        ProxyRequestError::ProxyTimeout => {
+            tracing::error!(cluster_id = %cluster_id, path = %path, "Request timed out");
+            ApiError::new(
+                ErrorCode::UpstreamTimeout,
+                format!(
+                    "Elasticsearch request timed out: {} {} (timeout: 30s)",
+                    method, path
+                ),
+            )
+            .with_cluster(cluster_id)
+        }
+        ProxyRequestError::RequestFailed(reason) => {
+            tracing::error!(cluster_id = %cluster_id, error = %reason, "Request failed");
+            ApiError::new(
+                ErrorCode::ClusterUnreachable,
+                format!("Request to cluster '{}' failed: {}", cluster_id, reason),
+            )
+            .with_cluster(cluster_id)
+        }
+        ProxyRequestError::Other(reason) => {
+            tracing::error!(cluster_id = %cluster_id, error = %reason, "Request failed");
+            ApiError::new(
+                ErrorCode::UpstreamError,
+                format!("Request to cluster '{}' failed: {}", cluster_id, reason),
+            )
+            .with_cluster(cluster_id)
+        }
+        ProxyRequestError::ResponseReadTimeout => {
+            tracing::error!(cluster_id = %cluster_id, "Timeout reading response body");
+            ApiError::new(
+                ErrorCode::UpstreamTimeout,
+                "Timeout reading Elasticsearch response body",
+            )
+            .with_cluster(cluster_id)
+        }
+        ProxyRequestError::ResponseReadFailed(reason) => {
+            tracing::error!(cluster_id = %cluster_id, error = %reason, "Failed to read response body");
+            ApiError::new(
+                ErrorCode::UpstreamError,
+                format!("Failed to read response: {}", reason),
+            )
+            .with_cluster(cluster_id)
+        }
+    }
+}
+
+/// Extract `error.reason` from an Elasticsearch error response
+pub(super) fn es_error_reason(response: &Value) -> String {
+    response
+        .get("error")
+        .and_then(|e| e.get("reason").or(Some(e)))
+        .and_then(|r| r.as_str())
+        .unwrap_or("Unknown error")
+        .to_string()
+}
@@ -0,0 +1,201 @@
+use super::audited::{es_error_reason, require_user, send_raw};
+use super::ClusterState;
+use crate::auth::middleware::AuthenticatedUser;
+use crate::middleware::logging::RequestId;
+use crate::routes::{ApiError, ErrorCode};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, Method},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::instrument;
+use utoipa::IntoParams;
+
+/// `_cat` APIs available through the typed endpoint
+const CAT_TARGETS: &[&str] = &["indices", "shards", "nodes", "allocation", "thread_pool"];
+
+/// Query parameters passed through to the `_cat` API
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct CatParams {
+    /// Include column headers in text output; present without a value means true
+    pub v: Option<String>,
+    /// Comma-separated columns to return
+    #[param(example = "index,health,docs.count")]
+    pub h: Option<String>,
+    /// Comma-separated columns to sort by, optionally suffixed with `:desc`
+    #[param(example = "store.size:desc")]
+    pub s: Option<String>,
+}
+
+/// Query a `_cat` API
+///
+/// Returns the parsed JSON rows by default. With `Accept: text/plain` the
+/// compact tabular form is returned instead. Supported targets are
+/// `indices`, `shards`, `nodes`, `allocation` and `thread_pool`.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/cat/{target}",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ("target" = String, Path, description = "_cat API to query (e.g. indices)"),
+        CatParams
+    ),
+    responses(
+        (status = 200, description = "JSON rows, or a text table with Accept: text/plain"),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, headers, user_ext, request_id_ext), fields(cluster_id = %cluster_id))]
+pub async fn cat(
+    State(state): State<ClusterState>,
+    Path((cluster_id, target)): Path<(String, String)>,
+    Query(params): Query<CatParams>,
+    headers: HeaderMap,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+) -> Result<Response, ApiError> {
+    let user = require_user(user_ext)?;
+
+    if !CAT_TARGETS.contains(&target.as_str()) {
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            format!(
+                "Unsupported _cat target '{}'; expected one of: {}",
+                target,
+                CAT_TARGETS.join(", ")
+            ),
+        ));
+    }
+
+    let text = wants_text(&headers);
+    let (status, body) = send_raw(
+        &state,
+        &cluster_id,
+        &user,
+        request_id_ext,
+        Method::GET,
+        cat_path(&target, &params, text),
+        None,
+    )
+    .await?;
+
+    if !status.is_success() {
+        let reason = serde_json::from_slice::<Value>(&body)
+            .map(|response| es_error_reason(&response))
+            .unwrap_or_else(|_| String::from_utf8_lossy(&body).into_owned());
+        return Err(ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("Failed to query _cat/{}: {}", target, reason),
+        )
+        .with_cluster(&cluster_id));
+    }
+
+    if text {
+        return Ok(([(header::CONTENT_TYPE, "text/plain; charset=utf-8")], body).into_response());
+    }
+
+    let rows: Value = serde_json::from_slice(&body).map_err(|e| {
+        tracing::error!(cluster_id = %cluster_id, error = %e, "Failed to parse _cat response");
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("Failed to parse response: {}", e),
+        )
+        .with_cluster(&cluster_id)
+    })?;
+
+    Ok(Json(rows).into_response())
+}
+
+/// Whether the client asked for the text table rather than JSON
+fn wants_text(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .is_some_and(|accept| {
+            accept
+                .split(',')
+                .any(|media| media.split(';').next().unwrap_or_default().trim() == "text/plain")
+        })
+}
+
+/// Build the `_cat` request path, passing through the supported parameters
+fn cat_path(target: &str, params: &CatParams, text: bool) -> String {
+    let mut query = Vec::new();
+    if !text {
+        query.push("format=json".to_string());
+    }
+    if params.v.as_deref().is_some_and(|v| v != "false") {
+        query.push("v".to_string());
+    }
+    if let Some(h) = &params.h {
+        query.push(format!("h={}", urlencoding::encode(h)));
+    }
+    if let Some(s) = &params.s {
+        query.push(format!("s={}", urlencoding::encode(s)));
+    }
+
+    if query.is_empty() {
+        format!("/_cat/{}", target)
+    } else {
+        format!("/_cat/{}?{}", target, query.join("&"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn test_cat_path_defaults_to_json() {
+        assert_eq!(
+            cat_path("indices", &CatParams::default(), false),
+            "/_cat/indices?format=json"
+        );
+        assert_eq!(
+            cat_path("indices", &CatParams::default(), true),
+            "/_cat/indices"
+        );
+    }
+
+    #[test]
+    fn test_cat_path_passes_through_params() {
+        let params = CatParams {
+            v: Some(String::new()),
+            h: Some("index,docs.count".to_string()),
+            s: Some("store.size:desc".to_string()),
+        };
+
+        assert_eq!(
+            cat_path("shards", &params, true),
+            "/_cat/shards?v&h=index%2Cdocs.count&s=store.size%3Adesc"
+        );
+
+        let params = CatParams {
+            v: Some("false".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(cat_path("nodes", &params, false), "/_cat/nodes?format=json");
+    }
+
+    #[test]
+    fn test_wants_text() {
+        let mut headers = HeaderMap::new();
+        assert!(!wants_text(&headers));
+
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/json"));
+        assert!(!wants_text(&headers));
+
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("text/html, text/plain;q=0.9"),
+        );
+        assert!(wants_text(&headers));
+    }
+}
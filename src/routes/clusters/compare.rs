@@ -0,0 +1,330 @@
+use super::transform::flatten_settings;
+use super::ClusterState;
+use crate::auth::middleware::AuthenticatedUser;
+use crate::routes::{ApiError, ErrorCode};
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use tracing::instrument;
+use utoipa::{IntoParams, ToSchema};
+
+/// Resource compared between two clusters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CompareTarget {
+    /// Persistent and transient cluster settings
+    #[default]
+    Settings,
+    /// Composable index templates
+    Templates,
+}
+
+impl CompareTarget {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Settings => "cluster settings",
+            Self::Templates => "index templates",
+        }
+    }
+}
+
+/// Query parameters selecting the clusters and resource to compare
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct CompareQueryParams {
+    /// First cluster ID
+    #[param(example = "staging")]
+    pub a: String,
+    /// Second cluster ID
+    #[param(example = "production")]
+    pub b: String,
+    /// Resource to compare (default: settings)
+    #[serde(default)]
+    pub what: CompareTarget,
+}
+
+/// A key set on both clusters with different values
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct CompareDifference {
+    /// Dotted key (e.g. "persistent.cluster.routing.allocation.enable")
+    pub key: String,
+    pub a_value: Value,
+    pub b_value: Value,
+}
+
+/// Key-by-key diff of a resource on two clusters
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct ClusterComparisonResponse {
+    pub a: String,
+    pub b: String,
+    pub what: CompareTarget,
+    /// Keys only set on cluster `a`, with their values
+    pub only_in_a: BTreeMap<String, Value>,
+    /// Keys only set on cluster `b`, with their values
+    pub only_in_b: BTreeMap<String, Value>,
+    /// Keys set on both clusters with different values, sorted by key
+    pub different: Vec<CompareDifference>,
+}
+
+/// Compare settings or templates of two clusters
+///
+/// Fetches the resource from both clusters, flattens it into dotted keys and
+/// returns what differs. Settings compare the persistent and transient
+/// layers (keys are prefixed with the layer); templates compare composable
+/// index templates (keys are prefixed with the template name). The user
+/// needs access to both clusters.
+#[utoipa::path(
+    get,
+    path = "/clusters/compare",
+    params(CompareQueryParams),
+    responses(
+        (status = 200, body = ClusterComparisonResponse),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext), fields(a = %params.a, b = %params.b))]
+pub async fn compare_clusters(
+    State(state): State<ClusterState>,
+    Query(params): Query<CompareQueryParams>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+) -> Result<Json<ClusterComparisonResponse>, ApiError> {
+    for cluster_id in [&params.a, &params.b] {
+        if cluster_id.is_empty() {
+            return Err(ApiError::new(
+                ErrorCode::ValidationFailed,
+                "Both clusters to compare are required (a and b)",
+            ));
+        }
+
+        // The permission middleware only gates /api/clusters/{id}/... paths,
+        // so both clusters are checked here. The open-mode user has no roles.
+        if let Some(user) = &user_ext {
+            let user = &user.0 .0;
+            if user.auth_type != "open" && !state.rbac.can_access_cluster(user, cluster_id) {
+                tracing::warn!(cluster_id = %cluster_id, "User attempted to compare forbidden cluster");
+                return Err(ApiError::new(
+                    ErrorCode::AccessDenied,
+                    format!("Access denied to cluster: {}", cluster_id),
+                )
+                .with_cluster(cluster_id));
+            }
+        }
+    }
+
+    let (a, b) = tokio::try_join!(
+        fetch_flattened(&state, &params.a, params.what),
+        fetch_flattened(&state, &params.b, params.what),
+    )?;
+
+    let diff = diff_flattened(a, b);
+    tracing::debug!(
+        only_in_a = diff.only_in_a.len(),
+        only_in_b = diff.only_in_b.len(),
+        different = diff.different.len(),
+        "Clusters compared"
+    );
+
+    Ok(Json(ClusterComparisonResponse {
+        a: params.a,
+        b: params.b,
+        what: params.what,
+        only_in_a: diff.only_in_a,
+        only_in_b: diff.only_in_b,
+        different: diff.different,
+    }))
+}
+
+/// Fetch a resource from one cluster and flatten it into dotted keys
+async fn fetch_flattened(
+    state: &ClusterState,
+    cluster_id: &str,
+    what: CompareTarget,
+) -> Result<BTreeMap<String, Value>, ApiError> {
+    let cluster = state
+        .cluster_manager
+        .get_cluster(cluster_id)
+        .await
+        .map_err(|_| ApiError::cluster_not_found(cluster_id))?;
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    let response = match what {
+        CompareTarget::Settings => cluster.cluster_settings(false).await,
+        CompareTarget::Templates => cluster.index_templates().await,
+    }
+    .map_err(|e| {
+        tracing::error!(cluster_id = %cluster_id, error = %e, "Failed to fetch resource to compare");
+        ApiError::upstream(cluster_id, &format!("fetch {}", what.label()), &e)
+    })?;
+
+    Ok(match what {
+        CompareTarget::Settings => flatten_cluster_settings(&response),
+        CompareTarget::Templates => flatten_index_templates(&response),
+    })
+}
+
+/// Flatten the persistent and transient layers of `GET /_cluster/settings`
+fn flatten_cluster_settings(response: &Value) -> BTreeMap<String, Value> {
+    let layers: serde_json::Map<String, Value> = ["persistent", "transient"]
+        .into_iter()
+        .filter_map(|layer| {
+            response
+                .get(layer)
+                .map(|settings| (layer.to_string(), settings.clone()))
+        })
+        .collect();
+    flatten_settings(&Value::Object(layers))
+}
+
+/// Flatten `GET /_index_template` keyed by template name
+fn flatten_index_templates(response: &Value) -> BTreeMap<String, Value> {
+    let templates: serde_json::Map<String, Value> = response["index_templates"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry["name"].as_str()?;
+            Some((name.to_string(), entry["index_template"].clone()))
+        })
+        .collect();
+    flatten_settings(&Value::Object(templates))
+}
+
+/// Keys unique to each side and keys whose values differ
+struct FlattenedDiff {
+    only_in_a: BTreeMap<String, Value>,
+    only_in_b: BTreeMap<String, Value>,
+    different: Vec<CompareDifference>,
+}
+
+/// Split two flattened resources into keys unique to each side and keys
+/// whose values differ
+fn diff_flattened(mut a: BTreeMap<String, Value>, mut b: BTreeMap<String, Value>) -> FlattenedDiff {
+    let mut different = Vec::new();
+    let shared: Vec<String> = a
+        .keys()
+        .filter(|key| b.contains_key(*key))
+        .cloned()
+        .collect();
+    for key in shared {
+        let (Some(a_value), Some(b_value)) = (a.remove(&key), b.remove(&key)) else {
+            continue;
+        };
+        if a_value != b_value {
+            different.push(CompareDifference {
+                key,
+                a_value,
+                b_value,
+            });
+        }
+    }
+
+    FlattenedDiff {
+        only_in_a: a,
+        only_in_b: b,
+        different,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_compare_query_defaults_to_settings() {
+        let params: CompareQueryParams =
+            serde_json::from_value(json!({"a": "staging", "b": "prod"})).expect("parse params");
+        assert_eq!(params.what, CompareTarget::Settings);
+
+        let params: CompareQueryParams =
+            serde_json::from_value(json!({"a": "staging", "b": "prod", "what": "templates"}))
+                .expect("parse params");
+        assert_eq!(params.what, CompareTarget::Templates);
+    }
+
+    #[test]
+    fn test_diff_cluster_settings() {
+        let a = flatten_cluster_settings(&json!({
+            "persistent": {
+                "cluster": {"routing": {"allocation": {"enable": "all"}}},
+                "indices.recovery.max_bytes_per_sec": "100mb"
+            },
+            "transient": {"cluster.max_shards_per_node": "2000"}
+        }));
+        let b = flatten_cluster_settings(&json!({
+            "persistent": {
+                "cluster": {"routing": {"allocation": {"enable": "primaries"}}},
+                "action": {"auto_create_index": "false"}
+            },
+            "transient": {"cluster": {"max_shards_per_node": "2000"}}
+        }));
+
+        let diff = diff_flattened(a, b);
+
+        assert_eq!(
+            diff.only_in_a,
+            BTreeMap::from([(
+                "persistent.indices.recovery.max_bytes_per_sec".to_string(),
+                json!("100mb")
+            )])
+        );
+        assert_eq!(
+            diff.only_in_b,
+            BTreeMap::from([(
+                "persistent.action.auto_create_index".to_string(),
+                json!("false")
+            )])
+        );
+        assert_eq!(
+            diff.different,
+            vec![CompareDifference {
+                key: "persistent.cluster.routing.allocation.enable".to_string(),
+                a_value: json!("all"),
+                b_value: json!("primaries"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diff_identical_settings_is_empty() {
+        let settings = json!({"persistent": {"a": {"b": 1}}, "transient": {}});
+        let diff = diff_flattened(
+            flatten_cluster_settings(&settings),
+            flatten_cluster_settings(&settings),
+        );
+
+        assert!(diff.only_in_a.is_empty());
+        assert!(diff.only_in_b.is_empty());
+        assert!(diff.different.is_empty());
+    }
+
+    #[test]
+    fn test_flatten_index_templates_keys_by_name() {
+        let flattened = flatten_index_templates(&json!({
+            "index_templates": [
+                {
+                    "name": "logs",
+                    "index_template": {
+                        "index_patterns": ["logs-*"],
+                        "template": {"settings": {"index": {"number_of_shards": "1"}}}
+                    }
+                }
+            ]
+        }));
+
+        assert_eq!(flattened["logs.index_patterns"], json!(["logs-*"]));
+        assert_eq!(
+            flattened["logs.template.settings.index.number_of_shards"],
+            json!("1")
+        );
+    }
+}
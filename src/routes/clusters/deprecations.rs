@@ -0,0 +1,204 @@
+use super::ClusterState;
+use crate::routes::ApiError;
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::instrument;
+use utoipa::ToSchema;
+
+/// One deprecation warning reported by the cluster
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct DeprecationIssue {
+    /// Severity ("none", "info", "warning" or "critical")
+    pub level: String,
+    pub message: String,
+    pub details: Option<String>,
+    /// Documentation link explaining how to resolve the issue
+    pub resolve_hint: Option<String>,
+    /// Index the issue applies to (index issues only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<String>,
+}
+
+/// Deprecation warnings for the next major upgrade, by category
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct DeprecationsResponse {
+    /// Whether the cluster supports the deprecation info API
+    pub available: bool,
+    /// Why no checks were run, when the API is not available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Cluster setting issues
+    pub cluster: Vec<DeprecationIssue>,
+    /// Node setting issues
+    pub node: Vec<DeprecationIssue>,
+    /// Index setting issues, by index name
+    pub index: Vec<DeprecationIssue>,
+}
+
+/// Get deprecation warnings for the next major upgrade
+///
+/// Proxies `/_migration/deprecations` and groups the issues into cluster,
+/// node and index categories. Clusters without the API (OpenSearch, or an
+/// unknown version) get an empty result with a `note` instead of an error.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/deprecations",
+    params(("cluster_id" = String, Path, description = "Cluster ID")),
+    responses(
+        (status = 200, body = DeprecationsResponse),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError),
+        (status = 503, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state), fields(cluster_id = %cluster_id))]
+pub async fn get_deprecations(
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+) -> Result<Json<DeprecationsResponse>, ApiError> {
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|_| ApiError::cluster_not_found(&cluster_id))?;
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    if let Some(note) = cluster.deprecation_info_unavailable() {
+        tracing::debug!(cluster_id = %cluster_id, note = %note, "Skipping deprecation checks");
+        return Ok(Json(DeprecationsResponse {
+            note: Some(note),
+            ..Default::default()
+        }));
+    }
+
+    let response = cluster.migration_deprecations().await.map_err(|e| {
+        tracing::error!(cluster_id = %cluster_id, error = %e, "Failed to get deprecations");
+        ApiError::upstream(&cluster_id, "get deprecations", &e)
+    })?;
+
+    Ok(Json(transform_deprecations(&response)))
+}
+
+/// Group a `_migration/deprecations` response into cluster, node and index issues
+pub fn transform_deprecations(response: &Value) -> DeprecationsResponse {
+    let issues = |value: &Value, index: Option<&str>| -> Vec<DeprecationIssue> {
+        value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|issue| DeprecationIssue {
+                level: issue["level"].as_str().unwrap_or("warning").to_string(),
+                message: issue["message"].as_str().unwrap_or_default().to_string(),
+                details: issue["details"].as_str().map(str::to_string),
+                resolve_hint: issue["url"].as_str().map(str::to_string),
+                index: index.map(str::to_string),
+            })
+            .collect()
+    };
+
+    let mut indices: Vec<(&String, &Value)> = response["index_settings"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .collect();
+    indices.sort_by(|a, b| a.0.cmp(b.0));
+
+    DeprecationsResponse {
+        available: true,
+        note: None,
+        cluster: issues(&response["cluster_settings"], None),
+        node: issues(&response["node_settings"], None),
+        index: indices
+            .into_iter()
+            .flat_map(|(index, value)| issues(value, Some(index)))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cluster::mock::MockClient;
+    use crate::cluster::ClusterConnection;
+    use crate::config::{ClusterConfig, Distribution};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_transform_deprecations() {
+        let response = json!({
+            "cluster_settings": [{
+                "level": "critical",
+                "message": "Cluster name cannot contain ':'",
+                "url": "https://ela.st/es-deprecation-7-cluster-name",
+                "details": "This cluster is named [mycompany:logging]"
+            }],
+            "node_settings": [],
+            "index_settings": {
+                "logs-2": [{"level": "warning", "message": "Translog retention settings are deprecated"}],
+                "logs-1": [{
+                    "level": "critical",
+                    "message": "Index created before 7.0",
+                    "url": "https://ela.st/es-deprecation-7-reindex"
+                }]
+            },
+            "ml_settings": []
+        });
+
+        let result = transform_deprecations(&response);
+
+        assert!(result.available);
+        assert_eq!(result.cluster.len(), 1);
+        assert_eq!(result.cluster[0].level, "critical");
+        assert_eq!(
+            result.cluster[0].details.as_deref(),
+            Some("This cluster is named [mycompany:logging]")
+        );
+        assert_eq!(
+            result.cluster[0].resolve_hint.as_deref(),
+            Some("https://ela.st/es-deprecation-7-cluster-name")
+        );
+        assert!(result.cluster[0].index.is_none());
+        assert!(result.node.is_empty());
+        let indices: Vec<_> = result
+            .index
+            .iter()
+            .filter_map(|i| i.index.as_deref())
+            .collect();
+        assert_eq!(indices, vec!["logs-1", "logs-2"]);
+        assert_eq!(result.index[1].resolve_hint, None);
+    }
+
+    #[test]
+    fn test_deprecation_info_gated_on_version() {
+        let config = |es_version| ClusterConfig {
+            id: "prod".to_string(),
+            es_version,
+            ..Default::default()
+        };
+
+        let es8 = ClusterConnection::with_client(&config(Some(8)), Arc::new(MockClient::new()));
+        assert_eq!(es8.deprecation_info_unavailable(), None);
+
+        let unknown = ClusterConnection::with_client(&config(None), Arc::new(MockClient::new()));
+        assert!(unknown
+            .deprecation_info_unavailable()
+            .is_some_and(|note| note.contains("es_version")));
+
+        let opensearch = ClusterConnection::with_client(
+            &config(Some(2)),
+            Arc::new(MockClient::new().with_distribution(Distribution::Opensearch)),
+        );
+        assert!(opensearch
+            .deprecation_info_unavailable()
+            .is_some_and(|note| note.contains("OpenSearch")));
+    }
+}
@@ -0,0 +1,186 @@
+use super::ClusterState;
+use crate::routes::ApiError;
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use tracing::instrument;
+use utoipa::ToSchema;
+
+/// Snapshot of a cluster's state, bundled for support
+///
+/// Each section holds the raw Elasticsearch response. Sections that could
+/// not be fetched are `null`, with the reason in `errors`.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DiagnosticsBundle {
+    pub cluster_id: String,
+    /// When the bundle was gathered
+    #[schema(value_type = String, format = DateTime)]
+    pub timestamp: DateTime<Utc>,
+    /// `/_cluster/health`
+    #[schema(value_type = Option<Object>)]
+    pub health: Option<Value>,
+    /// `/_cluster/stats`
+    #[schema(value_type = Option<Object>)]
+    pub cluster_stats: Option<Value>,
+    /// `/_nodes`
+    #[schema(value_type = Option<Object>)]
+    pub nodes_info: Option<Value>,
+    /// `/_nodes/stats`
+    #[schema(value_type = Option<Object>)]
+    pub nodes_stats: Option<Value>,
+    /// `/_cluster/settings?include_defaults=true`
+    #[schema(value_type = Option<Object>)]
+    pub settings: Option<Value>,
+    /// `/_cluster/pending_tasks`
+    #[schema(value_type = Option<Object>)]
+    pub pending_tasks: Option<Value>,
+    /// Why a section is missing, by section name
+    pub errors: BTreeMap<String, String>,
+}
+
+/// Download a diagnostics bundle for a cluster
+///
+/// Fetches health, cluster stats, node info and stats, settings and pending
+/// tasks concurrently and returns them as one JSON attachment named
+/// `diag-{cluster}-{timestamp}.json`. A section that fails is left out and
+/// reported in `errors`; only a cluster that answers none of the requests
+/// fails the export.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/diagnostics",
+    params(("cluster_id" = String, Path, description = "Cluster ID")),
+    responses(
+        (status = 200, body = DiagnosticsBundle),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError),
+        (status = 502, body = ApiError),
+        (status = 503, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state), fields(cluster_id = %cluster_id))]
+pub async fn get_diagnostics(
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+) -> Result<Response, ApiError> {
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|_| ApiError::cluster_not_found(&cluster_id))?;
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    let timestamp = Utc::now();
+    let (health, cluster_stats, nodes_info, nodes_stats, settings, pending_tasks) = tokio::join!(
+        cluster.health(),
+        cluster.cluster_stats(),
+        cluster.nodes_info(),
+        cluster.nodes_stats(),
+        cluster.cluster_settings(true),
+        cluster.pending_tasks(),
+    );
+
+    let mut errors = BTreeMap::new();
+    let mut last_error = None;
+    let mut section = |name: &str, result: anyhow::Result<Value>| match result {
+        Ok(value) => Some(value),
+        Err(e) => {
+            tracing::warn!(cluster_id = %cluster_id, section = name, error = %e, "Diagnostics section failed");
+            errors.insert(name.to_string(), e.to_string());
+            last_error = Some(e);
+            None
+        }
+    };
+    let health = section("health", health);
+    let cluster_stats = section("cluster_stats", cluster_stats);
+    let nodes_info = section("nodes_info", nodes_info);
+    let nodes_stats = section("nodes_stats", nodes_stats);
+    let settings = section("settings", settings);
+    let pending_tasks = section("pending_tasks", pending_tasks);
+
+    let sections = [
+        &health,
+        &cluster_stats,
+        &nodes_info,
+        &nodes_stats,
+        &settings,
+        &pending_tasks,
+    ];
+    if sections.iter().all(|section| section.is_none()) {
+        if let Some(e) = &last_error {
+            return Err(ApiError::upstream(&cluster_id, "gather diagnostics", e));
+        }
+    }
+
+    let bundle = DiagnosticsBundle {
+        cluster_id: cluster_id.clone(),
+        timestamp,
+        health,
+        cluster_stats,
+        nodes_info,
+        nodes_stats,
+        settings,
+        pending_tasks,
+        errors,
+    };
+
+    let disposition = format!(
+        "attachment; filename=\"{}\"",
+        diagnostics_filename(&cluster_id, timestamp)
+    );
+    Ok(([(header::CONTENT_DISPOSITION, disposition)], Json(bundle)).into_response())
+}
+
+/// Attachment name for a bundle: `diag-{cluster}-{YYYYMMDDTHHMMSSZ}.json`
+///
+/// Characters other than letters, digits, `-`, `_` and `.` in the cluster
+/// id are replaced so the name is safe in the header and on disk.
+fn diagnostics_filename(cluster_id: &str, timestamp: DateTime<Utc>) -> String {
+    let cluster: String = cluster_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!(
+        "diag-{}-{}.json",
+        cluster,
+        timestamp.format("%Y%m%dT%H%M%SZ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_diagnostics_filename() {
+        let timestamp = Utc
+            .with_ymd_and_hms(2026, 3, 4, 5, 6, 7)
+            .single()
+            .expect("valid timestamp");
+        assert_eq!(
+            diagnostics_filename("prod-eu", timestamp),
+            "diag-prod-eu-20260304T050607Z.json"
+        );
+        assert_eq!(
+            diagnostics_filename("a b/\"c", timestamp),
+            "diag-a_b__c-20260304T050607Z.json"
+        );
+    }
+}
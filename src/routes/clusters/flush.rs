@@ -0,0 +1,258 @@
+use super::audited::{es_error_reason, require_user, send};
+use super::index_operations::ShardsAcknowledgement;
+use super::ClusterState;
+use crate::auth::middleware::AuthenticatedUser;
+use crate::middleware::logging::RequestId;
+use crate::routes::{ApiError, ErrorCode};
+use axum::{
+    extract::{Path, State},
+    http::Method,
+    Json,
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use tracing::instrument;
+use utoipa::ToSchema;
+
+/// Result of a synced flush, or of the regular flush run in its place
+#[derive(Debug, Clone, Serialize, ToSchema, PartialEq)]
+pub struct SyncedFlushResponse {
+    /// Whether a synced flush ran; false when a regular flush was run instead
+    pub synced: bool,
+    /// Why a regular flush was run instead of a synced flush
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Shard summary over all indices
+    #[serde(rename = "_shards")]
+    pub shards: ShardsAcknowledgement,
+    /// Per-index results of a synced flush (empty for a regular flush)
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub indices: BTreeMap<String, ShardsAcknowledgement>,
+}
+
+/// Flush all indices before maintenance, with synced flush where available
+///
+/// Runs `_flush/synced` on clusters that still have it (Elasticsearch 7 and
+/// OpenSearch 1, per `es_version`) and a regular `_flush` on newer or
+/// unknown versions, with a `note` saying why. Shards that could not be
+/// synced (e.g. because of ongoing indexing) are reported as failed rather
+/// than failing the request.
+#[utoipa::path(
+    post,
+    path = "/clusters/{cluster_id}/_flush/synced",
+    params(("cluster_id" = String, Path, description = "Cluster ID")),
+    responses(
+        (status = 200, body = SyncedFlushResponse),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError),
+        (status = 502, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext, request_id_ext), fields(cluster_id = %cluster_id))]
+pub async fn synced_flush(
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+) -> Result<Json<SyncedFlushResponse>, ApiError> {
+    let user = require_user(user_ext)?;
+
+    if !state.rbac.can_write(&user, &cluster_id) {
+        tracing::warn!(
+            cluster_id = %cluster_id,
+            user_id = %user.id,
+            "Synced flush denied: write permission required"
+        );
+        return Err(ApiError::new(
+            ErrorCode::AccessDenied,
+            format!(
+                "Write permission on cluster '{}' is required to flush indices",
+                cluster_id
+            ),
+        )
+        .with_cluster(&cluster_id));
+    }
+
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|_| ApiError::cluster_not_found(&cluster_id))?;
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+    let note = cluster.synced_flush_unavailable();
+    let synced = note.is_none();
+
+    tracing::info!(
+        cluster_id = %cluster_id,
+        user_id = %user.id,
+        username = %user.username,
+        synced,
+        "Synced flush requested"
+    );
+
+    let path = if synced { "/_flush/synced" } else { "/_flush" };
+    let (status, response) = send(
+        &state,
+        &cluster_id,
+        &user,
+        request_id_ext,
+        Method::POST,
+        path.to_string(),
+        None,
+    )
+    .await?;
+
+    // A synced flush answers 409 when some shards could not be synced; the
+    // per-shard results are still in the body
+    let partial = synced && status == reqwest::StatusCode::CONFLICT;
+    if !status.is_success() && !partial {
+        let error_msg = es_error_reason(&response);
+        tracing::error!(
+            cluster_id = %cluster_id,
+            status = status.as_u16(),
+            error = %error_msg,
+            "Elasticsearch rejected flush"
+        );
+        return Err(ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("Failed to flush indices: {}", error_msg),
+        )
+        .with_cluster(&cluster_id));
+    }
+
+    let result = transform_flush(&response, note).ok_or_else(|| {
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            "Elasticsearch returned no shard summary for the flush",
+        )
+        .with_cluster(&cluster_id)
+    })?;
+
+    tracing::info!(
+        cluster_id = %cluster_id,
+        user_id = %user.id,
+        synced,
+        shards_total = result.shards.total,
+        shards_failed = result.shards.failed,
+        "Indices flushed"
+    );
+
+    Ok(Json(result))
+}
+
+/// Summarize a `_flush` or `_flush/synced` response
+///
+/// Synced flush reports a result per index next to the overall `_shards`.
+fn transform_flush(response: &Value, note: Option<String>) -> Option<SyncedFlushResponse> {
+    let shards: ShardsAcknowledgement = serde_json::from_value(response["_shards"].clone()).ok()?;
+    let indices = response
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(key, _)| key.as_str() != "_shards")
+        .filter_map(|(index, value)| {
+            let result = serde_json::from_value(value.clone()).ok()?;
+            Some((index.clone(), result))
+        })
+        .collect();
+
+    Some(SyncedFlushResponse {
+        synced: note.is_none(),
+        note,
+        shards,
+        indices,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cluster::mock::MockClient;
+    use crate::cluster::ClusterConnection;
+    use crate::config::{ClusterConfig, Distribution};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn connection(distribution: Distribution, es_version: Option<u32>) -> ClusterConnection {
+        ClusterConnection::with_client(
+            &ClusterConfig {
+                id: "prod".to_string(),
+                es_version,
+                ..Default::default()
+            },
+            Arc::new(MockClient::new().with_distribution(distribution)),
+        )
+    }
+
+    #[test]
+    fn test_synced_flush_gated_on_version() {
+        // Synced flush exists up to Elasticsearch 7 and OpenSearch 1
+        assert_eq!(
+            connection(Distribution::Elasticsearch, Some(7)).synced_flush_unavailable(),
+            None
+        );
+        assert_eq!(
+            connection(Distribution::Opensearch, Some(1)).synced_flush_unavailable(),
+            None
+        );
+
+        for (distribution, version, removed_in) in [
+            (Distribution::Elasticsearch, 8, "Elasticsearch 8"),
+            (Distribution::Elasticsearch, 9, "Elasticsearch 8"),
+            (Distribution::Opensearch, 2, "OpenSearch 2"),
+        ] {
+            let note = connection(distribution, Some(version))
+                .synced_flush_unavailable()
+                .expect("regular flush");
+            assert!(note.contains(removed_in), "{}", note);
+        }
+
+        assert!(connection(Distribution::Elasticsearch, None)
+            .synced_flush_unavailable()
+            .is_some_and(|note| note.contains("es_version")));
+    }
+
+    #[test]
+    fn test_transform_synced_flush() {
+        let response = json!({
+            "_shards": {"total": 4, "successful": 3, "failed": 1},
+            "logs-1": {"total": 2, "successful": 2, "failed": 0},
+            "logs-2": {
+                "total": 2,
+                "successful": 1,
+                "failed": 1,
+                "failures": [{"shard": 0, "reason": "pending operations"}]
+            }
+        });
+
+        let result = transform_flush(&response, None).expect("flush summary");
+
+        assert!(result.synced);
+        assert_eq!(result.shards.failed, 1);
+        assert_eq!(
+            result.indices.keys().collect::<Vec<_>>(),
+            vec!["logs-1", "logs-2"]
+        );
+        assert_eq!(result.indices["logs-2"].failures.len(), 1);
+    }
+
+    #[test]
+    fn test_transform_regular_flush() {
+        let response = json!({"_shards": {"total": 4, "successful": 4, "failed": 0}});
+        let note = "Synced flush was removed in Elasticsearch 8".to_string();
+
+        let result = transform_flush(&response, Some(note.clone())).expect("flush summary");
+
+        assert!(!result.synced);
+        assert_eq!(result.note, Some(note));
+        assert_eq!(result.shards.total, 4);
+        assert!(result.indices.is_empty());
+
+        assert_eq!(transform_flush(&json!({"acknowledged": true}), None), None);
+    }
+}
@@ -0,0 +1,235 @@
+use super::{cluster_health_error, ClusterState};
+use crate::cluster::{ClusterHealth, HealthSubscription, Manager as ClusterManager};
+use crate::routes::{ApiError, ErrorCode};
+use axum::{
+    extract::{Path, State},
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures::Stream;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::instrument;
+
+/// How often a stream checks health itself when no refresh was published
+///
+/// The check goes through the health cache, so it only reaches the cluster
+/// once the cached health expired.
+const HEALTH_STREAM_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Stream health updates of a cluster as server-sent events
+///
+/// Sends the current health as a `health` event right away, then again
+/// whenever a refresh (by the background task, another request or the
+/// stream's own check every 10 seconds) reports a different health. Failed
+/// checks are sent as `error` events carrying the error message. Only a
+/// limited number of streams may be open at once; further requests get 429.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/health/stream",
+    params(("cluster_id" = String, Path, description = "Cluster ID")),
+    responses(
+        (status = 200, description = "Stream of `health` events, each carrying a ClusterHealth", content_type = "text/event-stream"),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError),
+        (status = 429, description = "Too many open health streams", body = ApiError),
+        (status = 503, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state), fields(cluster_id = %cluster_id))]
+pub async fn stream_cluster_health(
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, ApiError> {
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|_| ApiError::cluster_not_found(&cluster_id))?;
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    // Subscribe before the first check so no refresh in between is missed
+    let subscription = state.cluster_manager.subscribe_health().ok_or_else(|| {
+        tracing::warn!(cluster_id = %cluster_id, "Health stream rejected: subscriber limit reached");
+        ApiError::new(
+            ErrorCode::RateLimited,
+            "Too many open health streams; poll the health endpoint instead",
+        )
+        .with_cluster(&cluster_id)
+    })?;
+    let health = state
+        .cluster_manager
+        .check_health(&cluster_id)
+        .await
+        .map_err(|e| cluster_health_error(&cluster_id, &e))?;
+
+    tracing::debug!(cluster_id = %cluster_id, "Health stream opened");
+    let stream = HealthStream {
+        manager: state.cluster_manager.clone(),
+        cluster_id,
+        subscription,
+        poll: tokio::time::interval_at(
+            tokio::time::Instant::now() + HEALTH_STREAM_POLL_INTERVAL,
+            HEALTH_STREAM_POLL_INTERVAL,
+        ),
+        last: None,
+    };
+
+    Ok(Sse::new(stream.into_events(health)).keep_alive(KeepAlive::default()))
+}
+
+/// State of one open health stream
+struct HealthStream {
+    manager: Arc<ClusterManager>,
+    cluster_id: String,
+    subscription: HealthSubscription,
+    poll: tokio::time::Interval,
+    /// Last health (or error message) sent, so unchanged results are skipped
+    last: Option<Result<ClusterHealth, String>>,
+}
+
+impl HealthStream {
+    /// Events starting with `initial`, ending when the manager goes away
+    fn into_events(
+        mut self,
+        initial: ClusterHealth,
+    ) -> impl Stream<Item = Result<Event, axum::Error>> {
+        let first = self.event(Ok(initial));
+        futures::stream::unfold((self, Some(first)), |(mut stream, first)| async move {
+            let event = match first {
+                Some(event) => event,
+                None => stream.next_event().await?,
+            };
+            Some((event, (stream, None)))
+        })
+    }
+
+    /// Wait for the next health differing from the last one sent
+    async fn next_event(&mut self) -> Option<Result<Event, axum::Error>> {
+        loop {
+            let result = tokio::select! {
+                received = self.subscription.events.recv() => match received {
+                    Ok(event) if event.cluster_id == self.cluster_id => Ok(event.health),
+                    Ok(_) => continue,
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::debug!(cluster_id = %self.cluster_id, skipped, "Health stream lagged behind");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => return None,
+                },
+                _ = self.poll.tick() => self
+                    .manager
+                    .check_health(&self.cluster_id)
+                    .await
+                    .map_err(|e| cluster_health_error(&self.cluster_id, &e).message),
+            };
+
+            if self.last.as_ref() != Some(&result) {
+                return Some(self.event(result));
+            }
+        }
+    }
+
+    fn event(&mut self, result: Result<ClusterHealth, String>) -> Result<Event, axum::Error> {
+        let event = match &result {
+            Ok(health) => Event::default().event("health").json_data(health),
+            Err(message) => Ok(Event::default().event("error").data(message)),
+        };
+        self.last = Some(result);
+        event
+    }
+}
+
+impl Drop for HealthStream {
+    fn drop(&mut self) {
+        tracing::debug!(cluster_id = %self.cluster_id, "Health stream closed");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ClusterConfig;
+    use futures::StreamExt;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn health_response(status: &str) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": status,
+            "cluster_name": "prod"
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_stream_sends_initial_health_then_changes() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/_cluster/health"))
+            .respond_with(health_response("green"))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/_cluster/health"))
+            .respond_with(health_response("yellow"))
+            .mount(&server)
+            .await;
+
+        let configs = vec![ClusterConfig {
+            id: "prod".to_string(),
+            nodes: vec![server.uri()],
+            ..Default::default()
+        }];
+        let manager = Arc::new(
+            ClusterManager::new(configs, Duration::from_secs(60))
+                .await
+                .expect("create manager"),
+        );
+        let initial = manager.check_health("prod").await.expect("initial health");
+        let stream = HealthStream {
+            manager: manager.clone(),
+            cluster_id: "prod".to_string(),
+            subscription: manager.subscribe_health().expect("subscribe"),
+            poll: tokio::time::interval_at(
+                tokio::time::Instant::now() + Duration::from_secs(3600),
+                Duration::from_secs(3600),
+            ),
+            last: None,
+        };
+        let mut events = Box::pin(stream.into_events(initial));
+
+        let first = events.next().await.expect("initial event").expect("event");
+        assert!(format!("{:?}", first).contains("green"));
+
+        // An unchanged refresh is skipped, a changed one is sent
+        manager.refresh_health("prod").await.expect("unchanged");
+        manager.refresh_health("prod").await.expect("changed");
+
+        let next = tokio::time::timeout(Duration::from_secs(1), events.next())
+            .await
+            .expect("change event in time")
+            .expect("change event")
+            .expect("event");
+        assert!(format!("{:?}", next).contains("yellow"));
+        let pending = tokio::time::timeout(Duration::from_millis(100), events.next()).await;
+        assert!(pending.is_err(), "no further event expected");
+    }
+
+    #[tokio::test]
+    async fn test_subscribers_are_capped() {
+        let manager = ClusterManager::with_connections(Vec::new(), Duration::from_secs(30));
+
+        let subscriptions: Vec<_> = (0..crate::cluster::HEALTH_STREAM_MAX_SUBSCRIBERS)
+            .map(|_| manager.subscribe_health().expect("free slot"))
+            .collect();
+        assert!(manager.subscribe_health().is_none());
+
+        // A disconnected subscriber frees its slot
+        drop(subscriptions);
+        assert!(manager.subscribe_health().is_some());
+    }
+}
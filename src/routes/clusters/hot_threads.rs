@@ -0,0 +1,200 @@
+use super::audited::{es_error_reason, require_user, send_raw};
+use super::ClusterState;
+use crate::auth::middleware::AuthenticatedUser;
+use crate::middleware::logging::RequestId;
+use crate::routes::{ApiError, ErrorCode};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, Method},
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::instrument;
+use utoipa::{IntoParams, ToSchema};
+
+/// Upper bound on `threads`, so a request can't dump every thread of a node
+const MAX_HOT_THREADS: u32 = 1000;
+
+/// Kind of thread activity to sample
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HotThreadsType {
+    Cpu,
+    Wait,
+    Block,
+}
+
+impl HotThreadsType {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Cpu => "cpu",
+            Self::Wait => "wait",
+            Self::Block => "block",
+        }
+    }
+}
+
+/// Query parameters passed through to the hot threads API
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct HotThreadsParams {
+    /// Number of hot threads to report per node (default: 3)
+    #[param(example = 5)]
+    pub threads: Option<u32>,
+    /// Sampling interval (default: 500ms)
+    #[param(example = "1s")]
+    pub interval: Option<String>,
+    /// Activity to sample (default: cpu)
+    #[serde(rename = "type")]
+    #[param(rename = "type", inline)]
+    pub kind: Option<HotThreadsType>,
+}
+
+/// Get the hot threads of a node
+///
+/// Proxies `/_nodes/{node_id}/hot_threads`. The API answers with a text
+/// report rather than JSON, so it is returned as-is with a `text/plain`
+/// content type.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/nodes/{node_id}/hot_threads",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ("node_id" = String, Path, description = "Node ID"),
+        HotThreadsParams
+    ),
+    responses(
+        (status = 200, description = "Hot threads report", body = String, content_type = "text/plain"),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError),
+        (status = 502, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext, request_id_ext), fields(cluster_id = %cluster_id, node_id = %node_id))]
+pub async fn get_hot_threads(
+    State(state): State<ClusterState>,
+    Path((cluster_id, node_id)): Path<(String, String)>,
+    Query(params): Query<HotThreadsParams>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+) -> Result<Response, ApiError> {
+    let user = require_user(user_ext)?;
+    let path = hot_threads_path(&node_id, &params)?;
+
+    let (status, body) = send_raw(
+        &state,
+        &cluster_id,
+        &user,
+        request_id_ext,
+        Method::GET,
+        path,
+        None,
+    )
+    .await?;
+
+    if !status.is_success() {
+        // Errors still come back as JSON
+        let reason = serde_json::from_slice::<Value>(&body)
+            .map(|response| es_error_reason(&response))
+            .unwrap_or_else(|_| String::from_utf8_lossy(&body).into_owned());
+        let code = if status == reqwest::StatusCode::NOT_FOUND {
+            ErrorCode::NotFound
+        } else {
+            ErrorCode::UpstreamError
+        };
+        return Err(ApiError::new(
+            code,
+            format!("Failed to get hot threads of node {}: {}", node_id, reason),
+        )
+        .with_cluster(&cluster_id));
+    }
+
+    Ok(([(header::CONTENT_TYPE, "text/plain; charset=utf-8")], body).into_response())
+}
+
+/// Build the hot threads request path, validating the parameters
+fn hot_threads_path(node_id: &str, params: &HotThreadsParams) -> Result<String, ApiError> {
+    let mut query = Vec::new();
+    if let Some(threads) = params.threads {
+        if threads == 0 || threads > MAX_HOT_THREADS {
+            return Err(ApiError::new(
+                ErrorCode::ValidationFailed,
+                format!("threads must be between 1 and {}", MAX_HOT_THREADS),
+            ));
+        }
+        query.push(format!("threads={}", threads));
+    }
+    if let Some(interval) = &params.interval {
+        if !is_time_value(interval) {
+            return Err(ApiError::new(
+                ErrorCode::ValidationFailed,
+                format!(
+                    "Invalid interval '{}'; expected a time value such as 500ms or 1s",
+                    interval
+                ),
+            ));
+        }
+        query.push(format!("interval={}", interval));
+    }
+    if let Some(kind) = params.kind {
+        query.push(format!("type={}", kind.as_str()));
+    }
+
+    let path = format!("/_nodes/{}/hot_threads", urlencoding::encode(node_id));
+    if query.is_empty() {
+        Ok(path)
+    } else {
+        Ok(format!("{}?{}", path, query.join("&")))
+    }
+}
+
+/// Whether `value` is an Elasticsearch time value (e.g. "500ms", "2s")
+fn is_time_value(value: &str) -> bool {
+    let unit_start = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(unit_start);
+    !amount.is_empty() && matches!(unit, "nanos" | "micros" | "ms" | "s" | "m" | "h" | "d")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hot_threads_path_passes_through_params() {
+        assert_eq!(
+            hot_threads_path("node-1", &HotThreadsParams::default()).expect("default path"),
+            "/_nodes/node-1/hot_threads"
+        );
+
+        let params: HotThreadsParams = serde_json::from_value(serde_json::json!({
+            "threads": 5,
+            "interval": "1s",
+            "type": "wait"
+        }))
+        .expect("parse params");
+        assert_eq!(
+            hot_threads_path("node-1", &params).expect("path"),
+            "/_nodes/node-1/hot_threads?threads=5&interval=1s&type=wait"
+        );
+    }
+
+    #[test]
+    fn test_hot_threads_path_rejects_invalid_params() {
+        let params = |threads, interval: &str| HotThreadsParams {
+            threads,
+            interval: Some(interval.to_string()),
+            kind: None,
+        };
+
+        assert!(hot_threads_path("node-1", &params(Some(0), "1s")).is_err());
+        assert!(hot_threads_path("node-1", &params(Some(5000), "1s")).is_err());
+        assert!(hot_threads_path("node-1", &params(None, "1s&threads=9999")).is_err());
+        assert!(hot_threads_path("node-1", &params(None, "ms")).is_err());
+        assert!(hot_threads_path("node-1", &params(None, "250ms")).is_ok());
+    }
+}
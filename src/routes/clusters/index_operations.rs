@@ -0,0 +1,1049 @@
+use super::audited::{require_user, send};
+use super::relocation::validate_concrete_index_name;
+use super::transform::flatten_settings;
+use super::ClusterState;
+use crate::auth::middleware::AuthenticatedUser;
+use crate::cluster::CachedResource;
+use crate::config::Permission;
+use crate::middleware::logging::RequestId;
+use crate::routes::{ApiError, ErrorCode};
+use axum::{
+    extract::{Path, Query, State},
+    http::Method,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use tracing::instrument;
+use utoipa::{IntoParams, ToSchema};
+
+/// Index state-changing operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IndexOperation {
+    Open,
+    Close,
+    Delete,
+    ForceMerge,
+    Flush,
+    Refresh,
+    GetSettings,
+    UpdateSettings,
+}
+
+impl IndexOperation {
+    fn name(self) -> &'static str {
+        match self {
+            IndexOperation::Open => "open",
+            IndexOperation::Close => "close",
+            IndexOperation::Delete => "delete",
+            IndexOperation::ForceMerge => "force-merge",
+            IndexOperation::Flush => "flush",
+            IndexOperation::Refresh => "refresh",
+            IndexOperation::GetSettings => "read settings of",
+            IndexOperation::UpdateSettings => "update settings of",
+        }
+    }
+
+    /// Whether the operation may target a comma-separated list of indices
+    fn accepts_index_list(self) -> bool {
+        matches!(self, IndexOperation::Flush | IndexOperation::Refresh)
+    }
+
+    /// Permission level needed to run the operation
+    ///
+    /// Closing and deleting make data unavailable, so they are admin-only.
+    fn required_permission(self) -> Permission {
+        match self {
+            IndexOperation::GetSettings => Permission::Read,
+            IndexOperation::Open
+            | IndexOperation::ForceMerge
+            | IndexOperation::Flush
+            | IndexOperation::Refresh
+            | IndexOperation::UpdateSettings => Permission::Write,
+            IndexOperation::Close | IndexOperation::Delete => Permission::Admin,
+        }
+    }
+
+    fn method(self) -> Method {
+        match self {
+            IndexOperation::Delete => Method::DELETE,
+            IndexOperation::GetSettings => Method::GET,
+            IndexOperation::UpdateSettings => Method::PUT,
+            _ => Method::POST,
+        }
+    }
+
+    fn path(self, index: &str) -> String {
+        // Encode each name of an index list; the separating commas stay as is
+        let encoded_index = index
+            .split(',')
+            .map(|name| urlencoding::encode(name).into_owned())
+            .collect::<Vec<_>>()
+            .join(",");
+        match self {
+            IndexOperation::Open => format!("/{}/_open", encoded_index),
+            IndexOperation::Close => format!("/{}/_close", encoded_index),
+            IndexOperation::Delete => format!("/{}", encoded_index),
+            IndexOperation::ForceMerge => format!("/{}/_forcemerge", encoded_index),
+            IndexOperation::Flush => format!("/{}/_flush", encoded_index),
+            IndexOperation::Refresh => format!("/{}/_refresh", encoded_index),
+            IndexOperation::GetSettings | IndexOperation::UpdateSettings => {
+                format!("/{}/_settings", encoded_index)
+            }
+        }
+    }
+}
+
+/// Query parameters for a force merge
+#[derive(Debug, Default, Deserialize, IntoParams, ToSchema)]
+pub struct ForceMergeParams {
+    /// Number of segments to merge down to (e.g. 1 for a read-only index)
+    #[param(example = 1)]
+    pub max_num_segments: Option<u32>,
+    /// Only expunge segments containing deleted documents
+    pub only_expunge_deletes: Option<bool>,
+}
+
+impl ForceMergeParams {
+    /// Build the Elasticsearch query string; the merge always runs as a task
+    fn query(&self) -> Result<String, ApiError> {
+        if self.max_num_segments == Some(0) {
+            return Err(ApiError::new(
+                ErrorCode::ValidationFailed,
+                "max_num_segments must be at least 1",
+            ));
+        }
+        if self.max_num_segments.is_some() && self.only_expunge_deletes == Some(true) {
+            return Err(ApiError::new(
+                ErrorCode::ValidationFailed,
+                "max_num_segments and only_expunge_deletes cannot be combined",
+            ));
+        }
+
+        let mut query = "wait_for_completion=false".to_string();
+        if let Some(segments) = self.max_num_segments {
+            query.push_str(&format!("&max_num_segments={}", segments));
+        }
+        if let Some(expunge) = self.only_expunge_deletes {
+            query.push_str(&format!("&only_expunge_deletes={}", expunge));
+        }
+        Ok(query)
+    }
+}
+
+/// Response from the force merge endpoint
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ForceMergeStartResponse {
+    /// ID of the background force merge task (format: node_id:task_number)
+    #[schema(example = "oTUltX4IQMOUUVeiohTt8A:12345")]
+    pub task_id: String,
+}
+
+/// Shard-level outcome of a flush or refresh, as reported by Elasticsearch
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct ShardsAcknowledgement {
+    /// Shard copies the operation ran on
+    pub total: u64,
+    pub successful: u64,
+    pub failed: u64,
+    /// Per-shard failure details, when any shard failed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failures: Vec<Value>,
+}
+
+/// Response from the flush and refresh endpoints
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct ShardsAcknowledgedResponse {
+    #[serde(rename = "_shards")]
+    pub shards: ShardsAcknowledgement,
+}
+
+/// Settings that can only be set when an index is created
+///
+/// Keys are relative to the `index.` namespace. Elasticsearch refuses to
+/// update them on an open index, so they are rejected before forwarding.
+const STATIC_INDEX_SETTINGS: &[&str] = &[
+    "number_of_shards",
+    "number_of_routing_shards",
+    "routing_partition_size",
+    "codec",
+    "mode",
+    "shard.check_on_startup",
+    "soft_deletes.enabled",
+    "load_fixed_bitset_filters_eagerly",
+    "store.type",
+    "uuid",
+    "creation_date",
+    "provided_name",
+];
+
+/// Setting prefixes that are static as a whole (e.g. index sorting)
+const STATIC_INDEX_SETTING_PREFIXES: &[&str] = &["sort.", "version."];
+
+/// Index settings with the commonly tuned values pulled out
+///
+/// The tunables show the effective value, falling back to the Elasticsearch
+/// default when the index does not set it explicitly.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct IndexSettingsResponse {
+    pub index: String,
+    #[serde(rename = "numberOfShards", skip_serializing_if = "Option::is_none")]
+    pub number_of_shards: Option<String>,
+    #[serde(rename = "numberOfReplicas", skip_serializing_if = "Option::is_none")]
+    pub number_of_replicas: Option<String>,
+    #[serde(rename = "autoExpandReplicas", skip_serializing_if = "Option::is_none")]
+    pub auto_expand_replicas: Option<String>,
+    #[serde(rename = "refreshInterval", skip_serializing_if = "Option::is_none")]
+    pub refresh_interval: Option<String>,
+    #[serde(rename = "maxResultWindow", skip_serializing_if = "Option::is_none")]
+    pub max_result_window: Option<String>,
+    /// Whether writes to the index are blocked (`index.blocks.write`)
+    #[serde(rename = "writeBlocked")]
+    pub write_blocked: bool,
+    /// Settings set explicitly on the index, as flat keys
+    /// (e.g. "index.number_of_replicas")
+    #[schema(value_type = Object)]
+    pub settings: BTreeMap<String, Value>,
+}
+
+/// Open a closed index
+#[utoipa::path(
+    post,
+    path = "/clusters/{cluster_id}/indices/{index}/_open",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ("index" = String, Path, description = "Index name")
+    ),
+    responses(
+        (status = 200, description = "Elasticsearch acknowledgement"),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext, request_id_ext), fields(cluster_id = %cluster_id, index = %index))]
+pub async fn open_index(
+    State(state): State<ClusterState>,
+    Path((cluster_id, index)): Path<(String, String)>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+) -> Result<Json<Value>, ApiError> {
+    execute_index_operation(
+        &state,
+        &cluster_id,
+        &index,
+        IndexOperation::Open,
+        None,
+        None,
+        user_ext,
+        request_id_ext,
+    )
+    .await
+}
+
+/// Close an index
+#[utoipa::path(
+    post,
+    path = "/clusters/{cluster_id}/indices/{index}/_close",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ("index" = String, Path, description = "Index name")
+    ),
+    responses(
+        (status = 200, description = "Elasticsearch acknowledgement"),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext, request_id_ext), fields(cluster_id = %cluster_id, index = %index))]
+pub async fn close_index(
+    State(state): State<ClusterState>,
+    Path((cluster_id, index)): Path<(String, String)>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+) -> Result<Json<Value>, ApiError> {
+    execute_index_operation(
+        &state,
+        &cluster_id,
+        &index,
+        IndexOperation::Close,
+        None,
+        None,
+        user_ext,
+        request_id_ext,
+    )
+    .await
+}
+
+/// Delete an index
+#[utoipa::path(
+    delete,
+    path = "/clusters/{cluster_id}/indices/{index}",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ("index" = String, Path, description = "Index name")
+    ),
+    responses(
+        (status = 200, description = "Elasticsearch acknowledgement"),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext, request_id_ext), fields(cluster_id = %cluster_id, index = %index))]
+pub async fn delete_index(
+    State(state): State<ClusterState>,
+    Path((cluster_id, index)): Path<(String, String)>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+) -> Result<Json<Value>, ApiError> {
+    execute_index_operation(
+        &state,
+        &cluster_id,
+        &index,
+        IndexOperation::Delete,
+        None,
+        None,
+        user_ext,
+        request_id_ext,
+    )
+    .await
+}
+
+/// Force merge an index in the background
+///
+/// Starts `_forcemerge` with `wait_for_completion=false` and returns the task
+/// ID to poll with the task progress endpoint. Wildcards and `_all` are
+/// rejected since merging many indices at once can overload the cluster.
+#[utoipa::path(
+    post,
+    path = "/clusters/{cluster_id}/indices/{index}/_forcemerge",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ("index" = String, Path, description = "Index name"),
+        ForceMergeParams
+    ),
+    responses(
+        (status = 200, body = ForceMergeStartResponse),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext, request_id_ext), fields(cluster_id = %cluster_id, index = %index))]
+pub async fn force_merge_index(
+    State(state): State<ClusterState>,
+    Path((cluster_id, index)): Path<(String, String)>,
+    Query(params): Query<ForceMergeParams>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+) -> Result<Json<ForceMergeStartResponse>, ApiError> {
+    let query = params.query()?;
+
+    let Json(response) = execute_index_operation(
+        &state,
+        &cluster_id,
+        &index,
+        IndexOperation::ForceMerge,
+        Some(&query),
+        None,
+        user_ext,
+        request_id_ext,
+    )
+    .await?;
+
+    let task_id = response
+        .get("task")
+        .and_then(|t| t.as_str())
+        .ok_or_else(|| {
+            ApiError::new(
+                ErrorCode::UpstreamError,
+                "Elasticsearch did not return a task ID for the force merge",
+            )
+            .with_cluster(&cluster_id)
+        })?
+        .to_string();
+
+    tracing::info!(
+        cluster_id = %cluster_id,
+        index = %index,
+        task_id = %task_id,
+        "Force merge started"
+    );
+
+    Ok(Json(ForceMergeStartResponse { task_id }))
+}
+
+/// Flush one or more indices
+///
+/// `index` may be a comma-separated list of index names. Returns the
+/// `_shards` summary Elasticsearch reports for the flush.
+#[utoipa::path(
+    post,
+    path = "/clusters/{cluster_id}/indices/{index}/_flush",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ("index" = String, Path, description = "Index name or comma-separated index names")
+    ),
+    responses(
+        (status = 200, body = ShardsAcknowledgedResponse),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext, request_id_ext), fields(cluster_id = %cluster_id, index = %index))]
+pub async fn flush_index(
+    State(state): State<ClusterState>,
+    Path((cluster_id, index)): Path<(String, String)>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+) -> Result<Json<ShardsAcknowledgedResponse>, ApiError> {
+    let Json(response) = execute_index_operation(
+        &state,
+        &cluster_id,
+        &index,
+        IndexOperation::Flush,
+        None,
+        None,
+        user_ext,
+        request_id_ext,
+    )
+    .await?;
+
+    shards_acknowledgement(&cluster_id, IndexOperation::Flush, response)
+}
+
+/// Refresh one or more indices
+///
+/// `index` may be a comma-separated list of index names. Returns the
+/// `_shards` summary Elasticsearch reports for the refresh.
+#[utoipa::path(
+    post,
+    path = "/clusters/{cluster_id}/indices/{index}/_refresh",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ("index" = String, Path, description = "Index name or comma-separated index names")
+    ),
+    responses(
+        (status = 200, body = ShardsAcknowledgedResponse),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext, request_id_ext), fields(cluster_id = %cluster_id, index = %index))]
+pub async fn refresh_index(
+    State(state): State<ClusterState>,
+    Path((cluster_id, index)): Path<(String, String)>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+) -> Result<Json<ShardsAcknowledgedResponse>, ApiError> {
+    let Json(response) = execute_index_operation(
+        &state,
+        &cluster_id,
+        &index,
+        IndexOperation::Refresh,
+        None,
+        None,
+        user_ext,
+        request_id_ext,
+    )
+    .await?;
+
+    shards_acknowledgement(&cluster_id, IndexOperation::Refresh, response)
+}
+
+/// Get the settings of an index
+///
+/// Returns the explicitly set settings as flat keys, with the commonly
+/// tuned values (replicas, refresh interval, ...) pulled out.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/indices/{index}/settings",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ("index" = String, Path, description = "Index name")
+    ),
+    responses(
+        (status = 200, body = IndexSettingsResponse),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext, request_id_ext), fields(cluster_id = %cluster_id, index = %index))]
+pub async fn get_index_settings(
+    State(state): State<ClusterState>,
+    Path((cluster_id, index)): Path<(String, String)>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+) -> Result<Json<IndexSettingsResponse>, ApiError> {
+    let Json(response) = execute_index_operation(
+        &state,
+        &cluster_id,
+        &index,
+        IndexOperation::GetSettings,
+        Some("include_defaults=true"),
+        None,
+        user_ext,
+        request_id_ext,
+    )
+    .await?;
+
+    transform_index_settings(&index, &response)
+        .map(Json)
+        .ok_or_else(|| {
+            ApiError::new(
+                ErrorCode::UpstreamError,
+                format!("Elasticsearch returned no settings for index '{}'", index),
+            )
+            .with_cluster(&cluster_id)
+        })
+}
+
+/// Update the dynamic settings of an index
+///
+/// The body is forwarded to `PUT /{index}/_settings` and may be nested
+/// (`{"index": {"number_of_replicas": 2}}`) or use flat keys. Static
+/// settings such as `number_of_shards` are rejected, since Elasticsearch
+/// only accepts them when the index is created.
+#[utoipa::path(
+    put,
+    path = "/clusters/{cluster_id}/indices/{index}/settings",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ("index" = String, Path, description = "Index name")
+    ),
+    request_body = Object,
+    responses(
+        (status = 200, description = "Elasticsearch acknowledgement"),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext, request_id_ext, settings), fields(cluster_id = %cluster_id, index = %index))]
+pub async fn update_index_settings(
+    State(state): State<ClusterState>,
+    Path((cluster_id, index)): Path<(String, String)>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+    Json(settings): Json<Value>,
+) -> Result<Json<Value>, ApiError> {
+    validate_settings_update(&settings).map_err(|e| e.with_cluster(&cluster_id))?;
+
+    execute_index_operation(
+        &state,
+        &cluster_id,
+        &index,
+        IndexOperation::UpdateSettings,
+        None,
+        Some(settings),
+        user_ext,
+        request_id_ext,
+    )
+    .await
+}
+
+/// Build the settings view from a `GET /{index}/_settings?include_defaults=true` response
+///
+/// The response is keyed by the concrete index name; the first entry is
+/// used so an alias resolving to a single index also works.
+fn transform_index_settings(index: &str, response: &Value) -> Option<IndexSettingsResponse> {
+    let (name, entry) = response.as_object()?.iter().next()?;
+    let settings = flatten_settings(&entry["settings"]);
+    let defaults = flatten_settings(&entry["defaults"]);
+
+    let effective = |key: &str| {
+        let key = format!("index.{}", key);
+        settings
+            .get(&key)
+            .or_else(|| defaults.get(&key))
+            .map(|value| match value {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+    };
+
+    tracing::debug!(index = %index, resolved = %name, settings = settings.len(), "Index settings retrieved");
+
+    Some(IndexSettingsResponse {
+        index: name.clone(),
+        number_of_shards: effective("number_of_shards"),
+        number_of_replicas: effective("number_of_replicas"),
+        auto_expand_replicas: effective("auto_expand_replicas"),
+        refresh_interval: effective("refresh_interval"),
+        max_result_window: effective("max_result_window"),
+        write_blocked: effective("blocks.write").as_deref() == Some("true"),
+        settings,
+    })
+}
+
+/// Check a settings update is a non-empty object without static settings
+fn validate_settings_update(settings: &Value) -> Result<(), ApiError> {
+    if !settings.is_object() {
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            "Index settings must be a JSON object",
+        ));
+    }
+
+    let keys = flatten_settings(settings).into_keys().collect::<Vec<_>>();
+    if keys.is_empty() {
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            "At least one setting must be provided",
+        ));
+    }
+
+    let static_keys: Vec<&str> = keys
+        .iter()
+        // Elasticsearch also accepts the body wrapped in `{"settings": ...}`
+        .map(|key| key.strip_prefix("settings.").unwrap_or(key))
+        .map(|key| key.strip_prefix("index.").unwrap_or(key))
+        .filter(|key| {
+            STATIC_INDEX_SETTINGS.contains(key)
+                || STATIC_INDEX_SETTING_PREFIXES
+                    .iter()
+                    .any(|prefix| key.starts_with(prefix))
+        })
+        .collect();
+    if !static_keys.is_empty() {
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            format!(
+                "Static index settings cannot be changed on an existing index: {}. \
+                 They can only be set when the index is created; use reindex or shrink/split instead.",
+                static_keys.join(", ")
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Extract the `_shards` summary from a flush or refresh response
+fn shards_acknowledgement(
+    cluster_id: &str,
+    operation: IndexOperation,
+    response: Value,
+) -> Result<Json<ShardsAcknowledgedResponse>, ApiError> {
+    serde_json::from_value(response).map(Json).map_err(|e| {
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!(
+                "Elasticsearch returned no shard summary for the {}: {}",
+                operation.name(),
+                e
+            ),
+        )
+        .with_cluster(cluster_id)
+    })
+}
+
+/// Check the required permission, run the operation against Elasticsearch and map the result
+///
+/// `query` is appended to the operation path as the request query string;
+/// `body` is sent as the JSON request body.
+#[allow(clippy::too_many_arguments)]
+async fn execute_index_operation(
+    state: &ClusterState,
+    cluster_id: &str,
+    index: &str,
+    operation: IndexOperation,
+    query: Option<&str>,
+    body: Option<Value>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+) -> Result<Json<Value>, ApiError> {
+    let user = require_user(user_ext).map_err(|e| e.with_cluster(cluster_id))?;
+
+    let required = operation.required_permission();
+    if !state.rbac.has_permission(&user, cluster_id, required) {
+        tracing::warn!(
+            cluster_id = %cluster_id,
+            index = %index,
+            user_id = %user.id,
+            operation = operation.name(),
+            required = ?required,
+            "Index operation denied: insufficient permission"
+        );
+        return Err(ApiError::new(
+            ErrorCode::AccessDenied,
+            format!(
+                "{:?} permission on cluster '{}' is required to {} indices",
+                required,
+                cluster_id,
+                operation.name()
+            ),
+        )
+        .with_cluster(cluster_id));
+    }
+
+    if operation.accepts_index_list() {
+        validate_index_list(index)?;
+    } else {
+        validate_index_name(index)?;
+    }
+
+    tracing::info!(
+        cluster_id = %cluster_id,
+        index = %index,
+        user_id = %user.id,
+        username = %user.username,
+        operation = operation.name(),
+        params = query.unwrap_or_default(),
+        "Index operation requested"
+    );
+
+    let path = match query {
+        Some(query) => format!("{}?{}", operation.path(index), query),
+        None => operation.path(index),
+    };
+    let (status, body) = send(
+        state,
+        cluster_id,
+        &user,
+        request_id_ext,
+        operation.method(),
+        path,
+        body,
+    )
+    .await?;
+
+    if !status.is_success() {
+        let error_msg = body
+            .get("error")
+            .and_then(|e| e.get("reason"))
+            .and_then(|r| r.as_str())
+            .unwrap_or("Unknown error");
+
+        tracing::error!(
+            cluster_id = %cluster_id,
+            index = %index,
+            status = status.as_u16(),
+            error = %error_msg,
+            operation = operation.name(),
+            "Elasticsearch rejected index operation"
+        );
+
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(ApiError::new(
+                ErrorCode::NotFound,
+                format!(
+                    "Index '{}' not found. It may have been deleted already.",
+                    index
+                ),
+            )
+            .with_cluster(cluster_id));
+        }
+
+        return Err(ApiError::new(
+            ErrorCode::UpstreamError,
+            format!(
+                "Elasticsearch rejected the {} request for index '{}': {}",
+                operation.name(),
+                index,
+                error_msg
+            ),
+        )
+        .with_cluster(cluster_id));
+    }
+
+    tracing::info!(
+        cluster_id = %cluster_id,
+        index = %index,
+        user_id = %user.id,
+        username = %user.username,
+        operation = operation.name(),
+        "Index operation completed successfully"
+    );
+
+    // Listings cached before the change would still show the old index state
+    if operation.method() != Method::GET {
+        state
+            .cluster_manager
+            .invalidate_resource(CachedResource::Indices, cluster_id);
+    }
+
+    Ok(Json(body))
+}
+
+/// Reject index names that would target more than one index
+///
+/// Wildcards, comma-separated lists and `_all` are refused so a single call
+/// can never close, delete or force merge more than the named index.
+fn validate_index_name(index: &str) -> Result<(), ApiError> {
+    if index.is_empty() {
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            "Index name is required. Please provide a valid index name.",
+        ));
+    }
+
+    if index == "_all" || index.starts_with('_') {
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            format!(
+                "'{}' is not a concrete index name. Please name a single index.",
+                index
+            ),
+        ));
+    }
+
+    let invalid_chars = ['\\', '/', '*', '?', '"', '<', '>', '|', ' ', ',', '#'];
+    if let Some(invalid_char) = index.chars().find(|c| invalid_chars.contains(c)) {
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            format!(
+                "Index name '{}' contains invalid character '{}'. Wildcards and index lists are not allowed here.",
+                index, invalid_char
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validate a comma-separated list of concrete index names
+fn validate_index_list(indices: &str) -> Result<(), ApiError> {
+    indices
+        .split(',')
+        .try_for_each(validate_concrete_index_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_operation_permissions() {
+        assert_eq!(
+            IndexOperation::Open.required_permission(),
+            Permission::Write
+        );
+        assert_eq!(
+            IndexOperation::Close.required_permission(),
+            Permission::Admin
+        );
+        assert_eq!(
+            IndexOperation::Delete.required_permission(),
+            Permission::Admin
+        );
+        assert_eq!(
+            IndexOperation::ForceMerge.required_permission(),
+            Permission::Write
+        );
+        assert_eq!(
+            IndexOperation::Flush.required_permission(),
+            Permission::Write
+        );
+        assert_eq!(
+            IndexOperation::Refresh.required_permission(),
+            Permission::Write
+        );
+    }
+
+    #[test]
+    fn test_force_merge_query() {
+        let query = |max_num_segments, only_expunge_deletes| {
+            ForceMergeParams {
+                max_num_segments,
+                only_expunge_deletes,
+            }
+            .query()
+        };
+
+        assert_eq!(
+            query(None, None).expect("defaults"),
+            "wait_for_completion=false"
+        );
+        assert_eq!(
+            query(Some(1), None).expect("segments"),
+            "wait_for_completion=false&max_num_segments=1"
+        );
+        assert_eq!(
+            query(None, Some(true)).expect("expunge"),
+            "wait_for_completion=false&only_expunge_deletes=true"
+        );
+        assert!(query(Some(0), None).is_err());
+        assert!(query(Some(1), Some(true)).is_err());
+    }
+
+    #[test]
+    fn test_index_operation_paths() {
+        assert_eq!(IndexOperation::Open.path("logs-1"), "/logs-1/_open");
+        assert_eq!(IndexOperation::Close.path("logs-1"), "/logs-1/_close");
+        assert_eq!(IndexOperation::Delete.path("logs-1"), "/logs-1");
+        assert_eq!(IndexOperation::Delete.method(), Method::DELETE);
+        assert_eq!(IndexOperation::Close.method(), Method::POST);
+        assert_eq!(
+            IndexOperation::ForceMerge.path("logs-1"),
+            "/logs-1/_forcemerge"
+        );
+        assert_eq!(
+            IndexOperation::Flush.path("logs-1,logs-2"),
+            "/logs-1,logs-2/_flush"
+        );
+        assert_eq!(IndexOperation::Refresh.path("logs-1"), "/logs-1/_refresh");
+        assert_eq!(IndexOperation::Refresh.method(), Method::POST);
+    }
+
+    #[test]
+    fn test_validate_index_list() {
+        assert!(validate_index_list("logs-1").is_ok());
+        assert!(validate_index_list("logs-1,metrics-1").is_ok());
+
+        assert!(validate_index_list("").is_err());
+        assert!(validate_index_list("logs-1,").is_err());
+        assert!(validate_index_list("logs-*").is_err());
+        assert!(validate_index_list("Logs-1").is_err());
+    }
+
+    #[test]
+    fn test_shards_acknowledgement() {
+        let response = serde_json::json!({
+            "_shards": {"total": 4, "successful": 2, "failed": 0}
+        });
+        let Json(ack) =
+            shards_acknowledgement("prod", IndexOperation::Flush, response).expect("summary");
+        assert_eq!(
+            ack.shards,
+            ShardsAcknowledgement {
+                total: 4,
+                successful: 2,
+                failed: 0,
+                failures: vec![],
+            }
+        );
+
+        let err = shards_acknowledgement(
+            "prod",
+            IndexOperation::Refresh,
+            serde_json::json!({"acknowledged": true}),
+        )
+        .expect_err("no _shards");
+        assert_eq!(err.code, ErrorCode::UpstreamError);
+    }
+
+    #[test]
+    fn test_settings_operations() {
+        assert_eq!(
+            IndexOperation::GetSettings.required_permission(),
+            Permission::Read
+        );
+        assert_eq!(
+            IndexOperation::UpdateSettings.required_permission(),
+            Permission::Write
+        );
+        assert_eq!(IndexOperation::GetSettings.method(), Method::GET);
+        assert_eq!(IndexOperation::UpdateSettings.method(), Method::PUT);
+        assert_eq!(
+            IndexOperation::UpdateSettings.path("logs-1"),
+            "/logs-1/_settings"
+        );
+    }
+
+    #[test]
+    fn test_transform_index_settings() {
+        let response = serde_json::json!({
+            "logs-1": {
+                "settings": {
+                    "index": {
+                        "number_of_shards": "3",
+                        "number_of_replicas": "2",
+                        "blocks": {"write": "true"},
+                        "provided_name": "logs-1"
+                    }
+                },
+                "defaults": {
+                    "index": {
+                        "number_of_replicas": "1",
+                        "refresh_interval": "1s",
+                        "max_result_window": "10000",
+                        "auto_expand_replicas": "false"
+                    }
+                }
+            }
+        });
+
+        let settings = transform_index_settings("logs-1", &response).expect("settings");
+
+        assert_eq!(settings.index, "logs-1");
+        assert_eq!(settings.number_of_shards.as_deref(), Some("3"));
+        assert_eq!(settings.number_of_replicas.as_deref(), Some("2"));
+        assert_eq!(settings.refresh_interval.as_deref(), Some("1s"));
+        assert_eq!(settings.max_result_window.as_deref(), Some("10000"));
+        assert!(settings.write_blocked);
+        // Only explicitly set settings are listed
+        assert_eq!(settings.settings.len(), 4);
+        assert!(!settings.settings.contains_key("index.refresh_interval"));
+
+        assert!(transform_index_settings("logs-1", &serde_json::json!({})).is_none());
+    }
+
+    #[test]
+    fn test_validate_settings_update() {
+        use serde_json::json;
+
+        assert!(validate_settings_update(&json!({"index": {"number_of_replicas": 2}})).is_ok());
+        assert!(validate_settings_update(&json!({"index.refresh_interval": "30s"})).is_ok());
+        assert!(validate_settings_update(&json!({"number_of_replicas": 0})).is_ok());
+
+        assert!(validate_settings_update(&json!({})).is_err());
+        assert!(validate_settings_update(&json!(["number_of_replicas"])).is_err());
+
+        let err = validate_settings_update(&json!({
+            "index": {"number_of_shards": 5, "number_of_replicas": 1}
+        }))
+        .expect_err("static setting");
+        assert_eq!(err.code, ErrorCode::ValidationFailed);
+        assert!(err.message.contains("number_of_shards"));
+        assert!(!err.message.contains("number_of_replicas"));
+
+        assert!(validate_settings_update(&json!({"index.sort.field": "@timestamp"})).is_err());
+        assert!(validate_settings_update(&json!({"codec": "best_compression"})).is_err());
+
+        // The `settings` wrapper Elasticsearch accepts does not hide static settings
+        let err = validate_settings_update(&json!({
+            "settings": {"index": {"number_of_shards": 5}}
+        }))
+        .expect_err("wrapped static setting");
+        assert!(err.message.contains("number_of_shards"));
+        assert!(validate_settings_update(&json!({"settings": {"number_of_shards": 5}})).is_err());
+        assert!(validate_settings_update(&json!({
+            "settings": {"index": {"number_of_replicas": 2}}
+        }))
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_index_name() {
+        assert!(validate_index_name("logs-2024.01.01").is_ok());
+        assert!(validate_index_name(".kibana_1").is_ok());
+
+        assert!(validate_index_name("").is_err());
+        assert!(validate_index_name("_all").is_err());
+        assert!(validate_index_name("logs-*").is_err());
+        assert!(validate_index_name("a,b").is_err());
+    }
+}
@@ -1,18 +1,25 @@
-use super::{ClusterErrorResponse, ClusterState};
+use super::ClusterState;
 use crate::auth::middleware::AuthenticatedUser;
-use crate::cluster::{manager::ProxyAuditRequest, ProxyRequestError};
+use crate::auth::AuthUser;
+use crate::cluster::manager::read_body_limited;
+use crate::cluster::{manager::ProxyAuditRequest, CachedResource, ProxyRequestError};
 use crate::middleware::logging::RequestId;
+use crate::routes::{ApiError, ErrorCode};
 use axum::{
-    extract::{Path, State},
-    http::Method,
+    body::{Body, Bytes},
+    extract::{rejection::JsonRejection, Path, State},
+    http::{header, HeaderMap, Method, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
+use futures::{Stream, StreamExt};
 use tracing::instrument;
 
 /// Proxy request to Elasticsearch cluster
 ///
-/// Forwards the request to the specified cluster and returns the response
+/// Forwards the request to the specified cluster and returns the response.
+/// Successful responses are streamed to the client as they arrive; error
+/// responses are buffered so their body can be logged.
 ///
 /// # Requirements
 ///
@@ -26,14 +33,15 @@ use tracing::instrument;
     ),
     responses(
         (status = 200, description = "Elasticsearch response"),
-        (status = 400, body = ClusterErrorResponse),
-        (status = 401, body = ClusterErrorResponse),
-        (status = 404, body = ClusterErrorResponse)
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError),
+        (status = 413, body = ApiError)
     ),
     tag = "Clusters"
 )]
 #[axum::debug_handler]
-#[instrument(skip(state, query, body), fields(cluster_id = %cluster_id, http_method = %method))]
+#[instrument(skip(state, query, body), fields(cluster_id = %cluster_id, http_method = %method, route = "/api/clusters/{id}/{*path}"))]
 pub async fn proxy_request(
     State(state): State<ClusterState>,
     Path((cluster_id, path)): Path<(String, String)>,
@@ -41,8 +49,23 @@ pub async fn proxy_request(
     axum::extract::RawQuery(query): axum::extract::RawQuery,
     user_ext: Option<axum::Extension<AuthenticatedUser>>,
     request_id_ext: Option<axum::Extension<RequestId>>,
-    body: Option<Json<serde_json::Value>>,
-) -> Result<Response, ClusterErrorResponse> {
+    body: Result<Option<Json<serde_json::Value>>, JsonRejection>,
+) -> Result<Response, ApiError> {
+    let body = match body {
+        Ok(body) => body.map(|Json(value)| value),
+        Err(rejection) if rejection.status() == StatusCode::PAYLOAD_TOO_LARGE => {
+            return Err(ApiError::new(
+                ErrorCode::PayloadTooLarge,
+                format!(
+                    "Request body is larger than the {} byte limit",
+                    state.max_proxy_body_bytes
+                ),
+            )
+            .with_cluster(&cluster_id));
+        }
+        Err(rejection) => return Ok(rejection.into_response()),
+    };
+
     // Construct full path with query string if present
     // Ensure path starts with / for Elasticsearch API
     let normalized_path = if path.starts_with('/') {
@@ -51,6 +74,14 @@ pub async fn proxy_request(
         format!("/{}", path)
     };
 
+    check_proxy_allowlist(
+        &state,
+        &cluster_id,
+        user_ext.as_ref().map(|u| &u.0 .0),
+        &method,
+        &normalized_path,
+    )?;
+
     let full_path = if let Some(q) = query {
         format!("{}?{}", normalized_path, q)
     } else {
@@ -64,13 +95,20 @@ pub async fn proxy_request(
         "PROXY: Starting Elasticsearch request"
     );
 
+    // Disabled clusters have no client; report that instead of access_denied
+    if let Ok(cluster) = state.cluster_manager.get_cluster(&cluster_id).await {
+        if !cluster.enabled {
+            return Err(ApiError::disabled(&cluster_id));
+        }
+    }
+
     // Enforce RBAC: verify the authenticated user has access to this cluster.
     // In Open mode (no user extension) all access is allowed.
 
     // Use centralized proxy helper which performs client selection, timeouts,
-    // response body read timeout, and emits an audit entry when the request
-    // is actually forwarded to Elasticsearch. The helper returns status,
-    // headers and body so we can construct the Axum response here.
+    // and emits an audit entry when the request is actually forwarded to
+    // Elasticsearch. The helper returns the response with its body unread so
+    // we can stream it to the client here.
     let user_roles: Vec<String> = user_ext
         .as_ref()
         .map(|u| u.0 .0.roles.clone())
@@ -81,85 +119,314 @@ pub async fn proxy_request(
         .map(|r| r.0.as_str().to_string())
         .unwrap_or_default();
 
-    let (status, headers, body_bytes, _matched_role_label) = match state
+    let limit = state.max_proxy_body_bytes;
+    let (response, _matched_role_label, permit) = match state
         .cluster_manager
-        .proxy_request_with_audit(ProxyAuditRequest {
+        .proxy_request_streaming(ProxyAuditRequest {
             cluster_id: cluster_id.clone(),
             method: method.clone(),
             path: full_path.clone(),
-            body: body.map(|j| j.0),
+            body,
             user_id: user_ext.as_ref().map(|u| u.0 .0.id.clone()),
             user_roles: user_roles.clone(),
             request_id: request_id.clone(),
             audit_enabled: state.audit_log,
+            max_response_bytes: Some(limit),
         })
         .await
     {
         Ok(r) => r,
         Err(e) => match e {
+            ProxyRequestError::ResponseTooLarge { limit } => {
+                return Err(ApiError::response_too_large(&cluster_id, limit));
+            }
+            ProxyRequestError::ClusterBusy(busy) => {
+                return Err(ApiError::cluster_busy(&busy));
+            }
+            ProxyRequestError::ClusterNotFound(_) => {
+                return Err(ApiError::cluster_not_found(&cluster_id));
+            }
             ProxyRequestError::AccessDenied => {
                 tracing::warn!(error = %"access_denied", cluster_id = %cluster_id, "Access denied: no matching role client");
-                return Err(ClusterErrorResponse::simple(
-                    "access_denied",
+                return Err(ApiError::new(
+                    ErrorCode::AccessDenied,
                     format!("Access denied to cluster: {}", cluster_id),
-                ));
+                )
+                .with_cluster(&cluster_id));
             }
             ProxyRequestError::ProxyTimeout => {
                 tracing::error!(cluster_id = %cluster_id, "PROXY: request timed out");
-                return Err(ClusterErrorResponse::simple(
-                    "proxy_timeout",
+                return Err(ApiError::new(
+                    ErrorCode::UpstreamTimeout,
                     format!(
                         "Elasticsearch request timed out: {} {} (timeout: 30s)",
                         method, full_path
                     ),
-                ));
+                )
+                .with_cluster(&cluster_id));
             }
             ProxyRequestError::ResponseReadTimeout => {
                 tracing::error!(cluster_id = %cluster_id, "PROXY: response read timed out");
-                return Err(ClusterErrorResponse::simple(
-                    "response_read_timeout",
+                return Err(ApiError::new(
+                    ErrorCode::UpstreamTimeout,
                     "Elasticsearch response read timed out (timeout: 10s)".to_string(),
-                ));
+                )
+                .with_cluster(&cluster_id));
             }
             ProxyRequestError::RequestFailed(reason) => {
                 tracing::error!(cluster_id = %cluster_id, error = %reason, "PROXY: request failed");
-                return Err(ClusterErrorResponse::simple(
-                    "proxy_failed",
+                return Err(ApiError::new(
+                    ErrorCode::ClusterUnreachable,
                     format!("Elasticsearch request failed: {}", reason),
-                ));
+                )
+                .with_cluster(&cluster_id));
             }
             ProxyRequestError::ResponseReadFailed(reason) => {
                 tracing::error!(cluster_id = %cluster_id, error = %reason, "PROXY: response read failed");
-                return Err(ClusterErrorResponse::simple(
-                    "response_read_failed",
+                return Err(ApiError::new(
+                    ErrorCode::UpstreamError,
                     format!("Elasticsearch response read failed: {}", reason),
-                ));
+                )
+                .with_cluster(&cluster_id));
             }
             ProxyRequestError::Other(msg) => {
                 tracing::error!(cluster_id = %cluster_id, error = %msg, "PROXY: unexpected error");
-                return Err(ClusterErrorResponse::simple("proxy_error", msg));
+                return Err(ApiError::new(ErrorCode::UpstreamError, msg).with_cluster(&cluster_id));
             }
         },
     };
 
+    if response
+        .content_length()
+        .is_some_and(|length| length > limit as u64)
+    {
+        return Err(ApiError::response_too_large(&cluster_id, limit));
+    }
+
+    let status = response.status();
+    let headers = response.headers().clone();
+
+    // Writes through the proxy (index create/edit, template changes) would
+    // otherwise leave stale listings until the cache TTL expires
+    if status.is_success() && method != Method::GET && method != Method::HEAD {
+        for resource in [CachedResource::Indices, CachedResource::Templates] {
+            state
+                .cluster_manager
+                .invalidate_resource(resource, &cluster_id);
+        }
+    }
+
     // Build an Axum Response, forwarding the Elasticsearch status + body.
     // We deliberately forward the raw body bytes so that any Elasticsearch
     // response format (JSON, YAML, plain text) is preserved intact.
-    let mut axum_response = (status, body_bytes).into_response();
-
-    // Forward a subset of response headers that are safe/useful for the client.
-    // We do NOT blindly forward all headers to avoid leaking internal details.
-    let resp_headers = axum_response.headers_mut();
-    for (name, value) in &headers {
-        use axum::http::header;
-        // Forward content-type, content-encoding, x-elastic-* headers
-        if name == header::CONTENT_TYPE
-            || name == header::CONTENT_ENCODING
-            || name.as_str().starts_with("x-elastic-")
+    let body = if status.is_client_error() || status.is_server_error() {
+        // Error bodies are small; buffer them so the reason can be logged
+        let body_bytes = match tokio::time::timeout(
+            std::time::Duration::from_secs(10),
+            read_body_limited(response, Some(limit)),
+        )
+        .await
         {
-            resp_headers.insert(name.clone(), value.clone());
+            Ok(Ok(bytes)) => bytes,
+            Ok(Err(ProxyRequestError::ResponseTooLarge { limit })) => {
+                return Err(ApiError::response_too_large(&cluster_id, limit));
+            }
+            Ok(Err(e)) => {
+                tracing::error!(cluster_id = %cluster_id, error = %e, "PROXY: response read failed");
+                return Err(ApiError::new(
+                    ErrorCode::UpstreamError,
+                    format!("Elasticsearch response read failed: {}", e),
+                )
+                .with_cluster(&cluster_id));
+            }
+            Err(_) => {
+                tracing::error!(cluster_id = %cluster_id, "PROXY: response read timed out");
+                return Err(ApiError::new(
+                    ErrorCode::UpstreamTimeout,
+                    "Elasticsearch response read timed out (timeout: 10s)".to_string(),
+                )
+                .with_cluster(&cluster_id));
+            }
+        };
+        tracing::debug!(
+            cluster_id = %cluster_id,
+            status = status.as_u16(),
+            body = %String::from_utf8_lossy(&body_bytes),
+            "PROXY: Elasticsearch returned an error"
+        );
+        Body::from(body_bytes)
+    } else {
+        // Hold the cluster request slot until the body has been streamed
+        Body::from_stream(
+            limit_body_stream(response.bytes_stream(), limit).map(move |chunk| {
+                let _ = &permit;
+                chunk
+            }),
+        )
+    };
+
+    let mut axum_response = (status, body).into_response();
+
+    forward_response_headers(&headers, axum_response.headers_mut());
+
+    Ok(axum_response)
+}
+
+/// Pass body chunks through, failing the stream once more than `limit` bytes
+/// have been sent
+///
+/// The status line is already on its way by then, so the client sees a
+/// truncated response rather than a 413.
+fn limit_body_stream(
+    stream: impl Stream<Item = reqwest::Result<Bytes>>,
+    limit: usize,
+) -> impl Stream<Item = std::io::Result<Bytes>> {
+    let mut sent = 0usize;
+    stream.map(move |chunk| {
+        let chunk = chunk.map_err(std::io::Error::other)?;
+        sent += chunk.len();
+        if sent > limit {
+            return Err(std::io::Error::other(format!(
+                "Elasticsearch response is larger than the {} byte limit",
+                limit
+            )));
         }
+        Ok(chunk)
+    })
+}
+
+/// Enforce the global and per-role proxy allowlists
+///
+/// Without any allowlist configured every request passes, as before.
+fn check_proxy_allowlist(
+    state: &ClusterState,
+    cluster_id: &str,
+    user: Option<&AuthUser>,
+    method: &Method,
+    path: &str,
+) -> Result<(), ApiError> {
+    // The HTTP client resolves dot segments before sending, so match against
+    // the path Elasticsearch will actually see
+    let path = resolve_dot_segments(path);
+
+    let global_allowed = state
+        .proxy_allow
+        .as_ref()
+        .is_none_or(|policy| policy.allows(method.as_str(), &path));
+    let role_allowed = user.is_none_or(|user| {
+        state
+            .rbac
+            .proxy_request_allowed(user, cluster_id, method.as_str(), &path)
+    });
+
+    if global_allowed && role_allowed {
+        return Ok(());
     }
 
-    Ok(axum_response)
+    tracing::warn!(
+        cluster_id = %cluster_id,
+        user_id = user.map(|u| u.id.as_str()).unwrap_or_default(),
+        method = %method,
+        path = %path,
+        "PROXY: request rejected by proxy allowlist"
+    );
+    Err(ApiError::new(
+        ErrorCode::AccessDenied,
+        format!(
+            "{} {} is not allowed through the proxy on cluster '{}'",
+            method, path, cluster_id
+        ),
+    )
+    .with_cluster(cluster_id))
+}
+
+/// Remove `.` and `..` segments from an absolute path
+fn resolve_dot_segments(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/').skip(1) {
+        match segment {
+            "." => {}
+            ".." => {
+                segments.pop();
+            }
+            _ => segments.push(segment),
+        }
+    }
+    format!("/{}", segments.join("/"))
+}
+
+/// Copy the subset of Elasticsearch response headers that are safe/useful for
+/// the client. We do NOT blindly forward all headers to avoid leaking internal
+/// details.
+///
+/// Content-Encoding is deliberately dropped: the HTTP client already decodes
+/// compressed upstream bodies, and the server's compression layer re-encodes
+/// the response for the browser. Forwarding it would mislabel the plain body.
+fn forward_response_headers(upstream: &HeaderMap, target: &mut HeaderMap) {
+    for (name, value) in upstream {
+        if name == header::CONTENT_TYPE || name.as_str().starts_with("x-elastic-") {
+            target.insert(name.clone(), value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn test_resolve_dot_segments() {
+        assert_eq!(resolve_dot_segments("/_cat/indices"), "/_cat/indices");
+        assert_eq!(
+            resolve_dot_segments("/_cat/../_cluster/settings"),
+            "/_cluster/settings"
+        );
+        assert_eq!(resolve_dot_segments("/./_nodes/.."), "/");
+        assert_eq!(resolve_dot_segments("/../../logs"), "/logs");
+    }
+
+    #[tokio::test]
+    async fn test_limit_body_stream_fails_past_limit() {
+        let chunks: Vec<reqwest::Result<Bytes>> = vec![
+            Ok(Bytes::from_static(b"12345")),
+            Ok(Bytes::from_static(b"67890")),
+            Ok(Bytes::from_static(b"abcde")),
+        ];
+
+        let results: Vec<_> = limit_body_stream(futures::stream::iter(chunks), 10)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+        assert!(results[2].is_err());
+    }
+
+    #[test]
+    fn test_forward_response_headers_strips_content_encoding() {
+        let mut upstream = HeaderMap::new();
+        upstream.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        upstream.insert(header::CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+        upstream.insert(
+            "x-elastic-product",
+            HeaderValue::from_static("Elasticsearch"),
+        );
+        upstream.insert(header::SERVER, HeaderValue::from_static("internal"));
+
+        let mut target = HeaderMap::new();
+        forward_response_headers(&upstream, &mut target);
+
+        assert_eq!(target.len(), 2);
+        assert!(target.get(header::CONTENT_ENCODING).is_none());
+        assert!(target.get(header::SERVER).is_none());
+        assert_eq!(
+            target.get("x-elastic-product"),
+            Some(&HeaderValue::from_static("Elasticsearch"))
+        );
+    }
 }
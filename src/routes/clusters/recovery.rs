@@ -0,0 +1,579 @@
+use super::ClusterState;
+use crate::cluster::ClusterConnection;
+use crate::routes::{ApiError, ErrorCode};
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
+    },
+    http::{header, HeaderMap},
+    response::Response,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::instrument;
+use utoipa::{IntoParams, ToSchema};
+
+/// Poll interval while shards are recovering
+const ACTIVE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Poll interval while nothing is recovering (or the cluster fails to answer)
+const IDLE_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Maximum number of recovery streams open at once per cluster
+pub const RECOVERY_STREAM_MAX_SUBSCRIBERS: usize = 32;
+
+/// Recovery stream slots of each cluster, created on first use
+#[derive(Debug, Clone, Default)]
+pub struct RecoveryStreamSlots(Arc<Mutex<HashMap<String, Arc<Semaphore>>>>);
+
+impl RecoveryStreamSlots {
+    /// Take a slot of the cluster, held until the permit is dropped
+    ///
+    /// Returns `None` when [`RECOVERY_STREAM_MAX_SUBSCRIBERS`] streams of the
+    /// cluster are already open.
+    fn acquire(&self, cluster_id: &str) -> Option<OwnedSemaphorePermit> {
+        let slots = self
+            .0
+            .lock()
+            .map(|mut slots| {
+                slots
+                    .entry(cluster_id.to_string())
+                    .or_insert_with(|| Arc::new(Semaphore::new(RECOVERY_STREAM_MAX_SUBSCRIBERS)))
+                    .clone()
+            })
+            .ok()?;
+        slots.try_acquire_owned().ok()
+    }
+}
+
+/// Whether a WebSocket upgrade comes from Secan's own origin or an allowed one
+///
+/// Browsers send the session cookie with cross-site WebSocket upgrades and
+/// CORS does not apply to them, so the `Origin` is checked against the
+/// request's `Host` and `server.cors_allowed_origins`. Requests without an
+/// `Origin` do not come from a browser page and are allowed.
+fn origin_allowed(headers: &HeaderMap, allowed_origins: &[String]) -> bool {
+    let Some(origin) = headers.get(header::ORIGIN) else {
+        return true;
+    };
+    let Ok(origin) = origin.to_str() else {
+        return false;
+    };
+    if allowed_origins
+        .iter()
+        .any(|allowed| allowed.trim_end_matches('/').eq_ignore_ascii_case(origin))
+    {
+        return true;
+    }
+
+    let host = headers
+        .get(header::HOST)
+        .and_then(|host| host.to_str().ok());
+    let origin_authority = url::Url::parse(origin).ok().and_then(|url| {
+        let host = url.host_str()?.to_string();
+        Some(match url.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host,
+        })
+    });
+    matches!((origin_authority, host), (Some(origin), Some(host)) if origin.eq_ignore_ascii_case(host))
+}
+
+/// Progress of one shard recovery
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct RecoveryProgress {
+    pub index: String,
+    pub shard: u32,
+    /// Recovery type (e.g. "PEER", "SNAPSHOT", "EXISTING_STORE")
+    #[serde(rename = "type")]
+    pub recovery_type: String,
+    /// Recovery stage (e.g. "INDEX", "TRANSLOG", "FINALIZE")
+    pub stage: String,
+    /// Percent of the shard recovered (0-100)
+    pub percent: f64,
+    /// Node (or snapshot repository) the shard is recovered from
+    pub source_node: Option<String>,
+    pub target_node: Option<String>,
+    pub bytes_recovered: u64,
+    pub total_bytes: u64,
+}
+
+/// Whether a recovery progress frame reports recoveries
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RecoveryStreamStatus {
+    /// Shards are recovering
+    Active,
+    /// Nothing is recovering
+    Idle,
+    /// Recoveries could not be fetched
+    Error,
+}
+
+/// Frame pushed to recovery WebSocket clients
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct RecoveryFrame {
+    pub status: RecoveryStreamStatus,
+    /// Active recoveries, by index then shard
+    pub recoveries: Vec<RecoveryProgress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Query parameters for the recovery status endpoint
+#[derive(Debug, Default, Deserialize, IntoParams, ToSchema)]
+pub struct RecoveryQueryParams {
+    /// Only report recoveries of indices matching this index expression
+    /// (e.g. "logs-*" or "logs-1,logs-2")
+    #[param(example = "logs-*")]
+    pub index: Option<String>,
+}
+
+impl RecoveryQueryParams {
+    /// The index expression to filter on, if one was given
+    fn index(&self) -> Result<Option<&str>, ApiError> {
+        let Some(index) = self.index.as_deref().map(str::trim) else {
+            return Ok(None);
+        };
+        if index.is_empty() {
+            return Ok(None);
+        }
+        if let Some(invalid) = index
+            .chars()
+            .find(|c| matches!(c, '/' | '\\' | '?' | '#' | ' '))
+        {
+            return Err(ApiError::new(
+                ErrorCode::ValidationFailed,
+                format!(
+                    "Index filter '{}' contains invalid character '{}'",
+                    index, invalid
+                ),
+            ));
+        }
+        Ok(Some(index))
+    }
+}
+
+/// Get shard recoveries in progress
+///
+/// Proxies `/_recovery?active_only=true` and returns one entry per
+/// recovering shard, sorted by index then shard. `index` limits the result
+/// to matching indices.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/recovery",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        RecoveryQueryParams
+    ),
+    responses(
+        (status = 200, body = Vec<RecoveryProgress>),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError),
+        (status = 503, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state), fields(cluster_id = %cluster_id, index = ?params.index))]
+pub async fn get_recovery(
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+    Query(params): Query<RecoveryQueryParams>,
+) -> Result<Json<Vec<RecoveryProgress>>, ApiError> {
+    let index = params.index()?;
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|_| ApiError::cluster_not_found(&cluster_id))?;
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    let response = cluster.active_recoveries(index).await.map_err(|e| {
+        tracing::error!(cluster_id = %cluster_id, error = %e, "Failed to get recoveries");
+        ApiError::upstream(&cluster_id, "get recoveries", &e)
+    })?;
+
+    Ok(Json(transform_recoveries(&response)))
+}
+
+/// Stream shard recovery progress over a WebSocket
+///
+/// Once upgraded, the server polls `/_recovery?active_only=true` every 2
+/// seconds and pushes a [`RecoveryFrame`] as a JSON text message whenever it
+/// changes. When nothing is recovering a single `idle` frame is sent and
+/// polling slows down to every 15 seconds. Cluster access is checked on the
+/// upgrade request like on any other cluster route; polling stops when the
+/// socket closes. Upgrades from other origins than Secan's own or
+/// `server.cors_allowed_origins` get 403, and only a limited number of
+/// streams per cluster may be open at once; further requests get 429.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/ws/recovery",
+    params(("cluster_id" = String, Path, description = "Cluster ID")),
+    responses(
+        (status = 101, description = "Switched to WebSocket; frames are RecoveryFrame JSON"),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError),
+        (status = 404, body = ApiError),
+        (status = 429, description = "Too many open recovery streams", body = ApiError),
+        (status = 503, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, headers, upgrade), fields(cluster_id = %cluster_id))]
+pub async fn recovery_ws(
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+    headers: HeaderMap,
+    upgrade: WebSocketUpgrade,
+) -> Result<Response, ApiError> {
+    if !origin_allowed(&headers, &state.cors_allowed_origins) {
+        tracing::warn!(
+            cluster_id = %cluster_id,
+            origin = ?headers.get(header::ORIGIN),
+            "Recovery stream rejected: origin not allowed"
+        );
+        return Err(ApiError::new(
+            ErrorCode::AccessDenied,
+            "WebSocket connections are not allowed from this origin",
+        )
+        .with_cluster(&cluster_id));
+    }
+
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|_| ApiError::cluster_not_found(&cluster_id))?;
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    let slot = state
+        .recovery_stream_slots
+        .acquire(&cluster_id)
+        .ok_or_else(|| {
+            tracing::warn!(cluster_id = %cluster_id, "Recovery stream rejected: subscriber limit reached");
+            ApiError::new(
+                ErrorCode::RateLimited,
+                "Too many open recovery streams; poll the recovery endpoint instead",
+            )
+            .with_cluster(&cluster_id)
+        })?;
+
+    Ok(upgrade.on_upgrade(move |socket| stream_recovery(socket, cluster, slot)))
+}
+
+/// Push recovery frames until the client goes away, then release the slot
+async fn stream_recovery(
+    mut socket: WebSocket,
+    cluster: Arc<ClusterConnection>,
+    _slot: OwnedSemaphorePermit,
+) {
+    tracing::debug!(cluster_id = %cluster.id, "Recovery stream opened");
+    let mut last: Option<RecoveryFrame> = None;
+
+    'poll: loop {
+        let frame = recovery_frame(&cluster).await;
+        let wait = match frame.status {
+            RecoveryStreamStatus::Active => ACTIVE_POLL_INTERVAL,
+            RecoveryStreamStatus::Idle | RecoveryStreamStatus::Error => IDLE_POLL_INTERVAL,
+        };
+
+        if last.as_ref() != Some(&frame) {
+            let text = match serde_json::to_string(&frame) {
+                Ok(text) => text,
+                Err(e) => {
+                    tracing::error!(cluster_id = %cluster.id, error = %e, "Failed to serialize recovery frame");
+                    break;
+                }
+            };
+            if socket.send(Message::Text(text.into())).await.is_err() {
+                break;
+            }
+            last = Some(frame);
+        }
+
+        // Wait for the next poll, stopping early if the client disconnects
+        let next_poll = tokio::time::sleep(wait);
+        tokio::pin!(next_poll);
+        loop {
+            tokio::select! {
+                _ = &mut next_poll => break,
+                message = socket.recv() => match message {
+                    None | Some(Err(_)) | Some(Ok(Message::Close(_))) => break 'poll,
+                    Some(Ok(_)) => {}
+                },
+            }
+        }
+    }
+
+    tracing::debug!(cluster_id = %cluster.id, "Recovery stream closed");
+}
+
+/// Poll the active recoveries of a cluster into a frame
+async fn recovery_frame(cluster: &ClusterConnection) -> RecoveryFrame {
+    match cluster.active_recoveries(None).await {
+        Ok(response) => {
+            let recoveries = transform_recoveries(&response);
+            RecoveryFrame {
+                status: if recoveries.is_empty() {
+                    RecoveryStreamStatus::Idle
+                } else {
+                    RecoveryStreamStatus::Active
+                },
+                recoveries,
+                error: None,
+            }
+        }
+        Err(e) => {
+            tracing::warn!(cluster_id = %cluster.id, error = %e, "Failed to poll recoveries");
+            RecoveryFrame {
+                status: RecoveryStreamStatus::Error,
+                recoveries: Vec::new(),
+                error: Some(ApiError::upstream(&cluster.id, "get recoveries", &e).message),
+            }
+        }
+    }
+}
+
+/// Transform a `_recovery` response, sorted by index then shard
+pub fn transform_recoveries(response: &Value) -> Vec<RecoveryProgress> {
+    let mut recoveries: Vec<RecoveryProgress> = response
+        .as_object()
+        .into_iter()
+        .flatten()
+        .flat_map(|(index, entry)| {
+            entry["shards"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(move |shard| transform_recovery(index, shard))
+        })
+        .collect();
+    recoveries.sort_by(|a, b| a.index.cmp(&b.index).then(a.shard.cmp(&b.shard)));
+    recoveries
+}
+
+fn transform_recovery(index: &str, shard: &Value) -> RecoveryProgress {
+    let size = &shard["index"]["size"];
+    let files = &shard["index"]["files"];
+    let total_bytes = size["total_in_bytes"].as_u64().unwrap_or(0);
+    let bytes_recovered = size["recovered_in_bytes"].as_u64().unwrap_or(0);
+    let total_files = files["total"].as_u64().unwrap_or(0);
+    let files_recovered = files["recovered"].as_u64().unwrap_or(0);
+
+    // Prefer bytes; shards without data report zero totals and have nothing to copy
+    let percent = if total_bytes > 0 {
+        bytes_recovered as f64 / total_bytes as f64 * 100.0
+    } else if total_files > 0 {
+        files_recovered as f64 / total_files as f64 * 100.0
+    } else {
+        100.0
+    };
+
+    let node_name = |node: &Value| {
+        node["name"]
+            .as_str()
+            .or_else(|| node["repository"].as_str())
+            .map(str::to_string)
+    };
+
+    RecoveryProgress {
+        index: index.to_string(),
+        shard: shard["id"].as_u64().unwrap_or(0) as u32,
+        recovery_type: shard["type"].as_str().unwrap_or("UNKNOWN").to_string(),
+        stage: shard["stage"].as_str().unwrap_or("UNKNOWN").to_string(),
+        percent: (percent.min(100.0) * 10.0).round() / 10.0,
+        source_node: node_name(&shard["source"]),
+        target_node: node_name(&shard["target"]),
+        bytes_recovered,
+        total_bytes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cluster::mock::MockClient;
+    use crate::config::ClusterConfig;
+    use serde_json::json;
+
+    fn recovery_response() -> Value {
+        json!({
+            "logs-2": {
+                "shards": [{
+                    "id": 1,
+                    "type": "PEER",
+                    "stage": "INDEX",
+                    "source": {"id": "a", "name": "node-1"},
+                    "target": {"id": "b", "name": "node-2"},
+                    "index": {
+                        "size": {"total_in_bytes": 2000, "recovered_in_bytes": 500},
+                        "files": {"total": 10, "recovered": 2}
+                    }
+                }]
+            },
+            "logs-1": {
+                "shards": [{
+                    "id": 0,
+                    "type": "SNAPSHOT",
+                    "stage": "TRANSLOG",
+                    "source": {"repository": "backups", "snapshot": "nightly"},
+                    "target": {"id": "b", "name": "node-2"},
+                    "index": {
+                        "size": {"total_in_bytes": 0, "recovered_in_bytes": 0},
+                        "files": {"total": 4, "recovered": 3}
+                    }
+                }, {
+                    "id": 2,
+                    "type": "EXISTING_STORE",
+                    "stage": "FINALIZE",
+                    "source": {},
+                    "target": {"id": "c", "name": "node-3"},
+                    "index": {
+                        "size": {"total_in_bytes": 0, "recovered_in_bytes": 0},
+                        "files": {"total": 0, "recovered": 0}
+                    }
+                }]
+            }
+        })
+    }
+
+    #[test]
+    fn test_transform_recoveries() {
+        let recoveries = transform_recoveries(&recovery_response());
+
+        assert_eq!(recoveries.len(), 3);
+        assert_eq!(
+            recoveries[2],
+            RecoveryProgress {
+                index: "logs-2".to_string(),
+                shard: 1,
+                recovery_type: "PEER".to_string(),
+                stage: "INDEX".to_string(),
+                percent: 25.0,
+                source_node: Some("node-1".to_string()),
+                target_node: Some("node-2".to_string()),
+                bytes_recovered: 500,
+                total_bytes: 2000,
+            }
+        );
+
+        // No bytes to copy: falls back to files
+        assert_eq!(recoveries[0].index, "logs-1");
+        assert_eq!(recoveries[0].percent, 75.0);
+        assert_eq!(recoveries[0].source_node.as_deref(), Some("backups"));
+
+        // Nothing to copy at all
+        assert_eq!(recoveries[1].shard, 2);
+        assert_eq!(recoveries[1].percent, 100.0);
+        assert_eq!(recoveries[1].source_node, None);
+    }
+
+    #[test]
+    fn test_recovery_index_filter() {
+        let params = |index: Option<&str>| RecoveryQueryParams {
+            index: index.map(str::to_string),
+        };
+
+        assert_eq!(params(None).index().expect("no filter"), None);
+        assert_eq!(params(Some(" ")).index().expect("blank filter"), None);
+        assert_eq!(
+            params(Some("logs-*,metrics-1"))
+                .index()
+                .expect("expression"),
+            Some("logs-*,metrics-1")
+        );
+        assert!(params(Some("logs/../_cluster")).index().is_err());
+    }
+
+    #[test]
+    fn test_origin_allowed() {
+        let headers = |origin: Option<&str>| {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                header::HOST,
+                "secan.example.com:8443".parse().expect("host header"),
+            );
+            if let Some(origin) = origin {
+                headers.insert(header::ORIGIN, origin.parse().expect("origin header"));
+            }
+            headers
+        };
+        let allowed = vec!["http://localhost:5173".to_string()];
+
+        assert!(origin_allowed(&headers(None), &allowed));
+        assert!(origin_allowed(
+            &headers(Some("https://secan.example.com:8443")),
+            &allowed
+        ));
+        assert!(origin_allowed(
+            &headers(Some("http://localhost:5173")),
+            &allowed
+        ));
+        assert!(!origin_allowed(
+            &headers(Some("https://evil.example")),
+            &allowed
+        ));
+        assert!(!origin_allowed(
+            &headers(Some("https://secan.example.com")),
+            &allowed
+        ));
+        assert!(!origin_allowed(&headers(Some("null")), &allowed));
+    }
+
+    #[test]
+    fn test_recovery_stream_slots_are_capped_per_cluster() {
+        let slots = RecoveryStreamSlots::default();
+        let mut held: Vec<_> = (0..RECOVERY_STREAM_MAX_SUBSCRIBERS)
+            .map(|_| slots.acquire("prod").expect("free slot"))
+            .collect();
+        assert!(slots.acquire("prod").is_none());
+        // Other clusters have their own slots
+        assert!(slots.acquire("staging").is_some());
+
+        // A closed stream frees its slot
+        held.pop();
+        assert!(slots.acquire("prod").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_recovery_frame_collapses_to_idle() {
+        let config = ClusterConfig {
+            id: "prod".to_string(),
+            ..Default::default()
+        };
+
+        let active = ClusterConnection::with_client(
+            &config,
+            Arc::new(MockClient::new().with_json("_recovery", recovery_response())),
+        );
+        let frame = recovery_frame(&active).await;
+        assert_eq!(frame.status, RecoveryStreamStatus::Active);
+        assert_eq!(frame.recoveries.len(), 3);
+
+        let idle = ClusterConnection::with_client(
+            &config,
+            Arc::new(MockClient::new().with_json("_recovery", json!({}))),
+        );
+        let frame = recovery_frame(&idle).await;
+        assert_eq!(frame.status, RecoveryStreamStatus::Idle);
+        assert!(frame.recoveries.is_empty());
+
+        let failing = ClusterConnection::with_client(&config, Arc::new(MockClient::new()));
+        let frame = recovery_frame(&failing).await;
+        assert_eq!(frame.status, RecoveryStreamStatus::Error);
+        assert!(frame.error.is_some());
+    }
+}
@@ -0,0 +1,346 @@
+use super::audited::{es_error_reason, require_user, send};
+use super::ClusterState;
+use crate::auth::middleware::AuthenticatedUser;
+use crate::middleware::logging::RequestId;
+use crate::routes::{ApiError, ErrorCode};
+use axum::{
+    extract::{Path, State},
+    http::Method,
+    Json,
+};
+use serde::Serialize;
+use serde_json::Value;
+use tracing::instrument;
+use utoipa::ToSchema;
+
+/// Response from start reindex endpoint
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReindexStartResponse {
+    /// ID of the background reindex task (format: node_id:task_number)
+    #[schema(example = "oTUltX4IQMOUUVeiohTt8A:12345")]
+    pub task_id: String,
+}
+
+/// Progress of a background task
+#[derive(Debug, Serialize, ToSchema, PartialEq)]
+pub struct TaskProgressResponse {
+    /// Whether the task has finished
+    pub completed: bool,
+    /// Percentage of documents processed, when the task reports a total
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress_percent: Option<f64>,
+    /// Task description (e.g. source and destination of a reindex)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Start a reindex operation in the background
+///
+/// Forwards the reindex body to Elasticsearch with `wait_for_completion=false`
+/// and returns the task ID to poll with the task progress endpoint.
+#[utoipa::path(
+    post,
+    path = "/clusters/{cluster_id}/_reindex",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID")
+    ),
+    request_body(content = Object, description = "Elasticsearch reindex request body"),
+    responses(
+        (status = 200, body = ReindexStartResponse),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 403, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext, request_id_ext, body), fields(cluster_id = %cluster_id))]
+pub async fn start_reindex(
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+    Json(body): Json<Value>,
+) -> Result<Json<ReindexStartResponse>, ApiError> {
+    let user = require_user(user_ext)?;
+
+    // Reindex writes to the destination index, so it needs write permission
+    if !state.rbac.can_write(&user, &cluster_id) {
+        tracing::warn!(
+            cluster_id = %cluster_id,
+            user_id = %user.id,
+            "Reindex denied: write permission required"
+        );
+        return Err(ApiError::new(
+            ErrorCode::AccessDenied,
+            format!(
+                "Write permission on cluster '{}' is required to start a reindex",
+                cluster_id
+            ),
+        )
+        .with_cluster(&cluster_id));
+    }
+
+    validate_reindex_body(&body)?;
+
+    tracing::info!(
+        cluster_id = %cluster_id,
+        user_id = %user.id,
+        username = %user.username,
+        "Reindex requested"
+    );
+
+    let (status, response) = send(
+        &state,
+        &cluster_id,
+        &user,
+        request_id_ext,
+        Method::POST,
+        "/_reindex?wait_for_completion=false".to_string(),
+        Some(body),
+    )
+    .await?;
+
+    if !status.is_success() {
+        let error_msg = es_error_reason(&response);
+        tracing::error!(
+            cluster_id = %cluster_id,
+            status = status.as_u16(),
+            error = %error_msg,
+            "Elasticsearch rejected reindex request"
+        );
+        return Err(ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("Elasticsearch rejected the reindex request: {}", error_msg),
+        )
+        .with_cluster(&cluster_id));
+    }
+
+    let task_id = response
+        .get("task")
+        .and_then(|t| t.as_str())
+        .ok_or_else(|| {
+            ApiError::new(
+                ErrorCode::UpstreamError,
+                "Elasticsearch did not return a task ID for the reindex",
+            )
+            .with_cluster(&cluster_id)
+        })?
+        .to_string();
+
+    tracing::info!(
+        cluster_id = %cluster_id,
+        task_id = %task_id,
+        user_id = %user.id,
+        username = %user.username,
+        "Reindex started"
+    );
+
+    Ok(Json(ReindexStartResponse { task_id }))
+}
+
+/// Get the progress of a background task
+///
+/// Returns whether the task has completed and, for reindex-style tasks, the
+/// percentage of documents processed so far.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/_tasks/{task_id}",
+    params(
+        ("cluster_id" = String, Path, description = "Cluster ID"),
+        ("task_id" = String, Path, description = "Task ID (format: node_id:task_number)")
+    ),
+    responses(
+        (status = 200, body = TaskProgressResponse),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext, request_id_ext), fields(cluster_id = %cluster_id, task_id = %task_id))]
+pub async fn get_task_progress(
+    State(state): State<ClusterState>,
+    Path((cluster_id, task_id)): Path<(String, String)>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+    request_id_ext: Option<axum::Extension<RequestId>>,
+) -> Result<Json<TaskProgressResponse>, ApiError> {
+    let user = require_user(user_ext)?;
+    validate_task_id(&task_id)?;
+
+    let (status, response) = send(
+        &state,
+        &cluster_id,
+        &user,
+        request_id_ext,
+        Method::GET,
+        format!("/_tasks/{}", task_id),
+        None,
+    )
+    .await?;
+
+    if status == reqwest::StatusCode::NOT_FOUND {
+        return Err(ApiError::new(
+            ErrorCode::NotFound,
+            format!("Task '{}' not found on cluster '{}'", task_id, cluster_id),
+        )
+        .with_cluster(&cluster_id));
+    }
+
+    if !status.is_success() {
+        return Err(ApiError::new(
+            ErrorCode::UpstreamError,
+            format!(
+                "Failed to get task '{}': {}",
+                task_id,
+                es_error_reason(&response)
+            ),
+        )
+        .with_cluster(&cluster_id));
+    }
+
+    Ok(Json(transform_task_progress(&response)))
+}
+
+/// Require a reindex body with `source` and `dest`
+fn validate_reindex_body(body: &Value) -> Result<(), ApiError> {
+    let Some(obj) = body.as_object() else {
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            "Reindex body must be a JSON object",
+        ));
+    };
+
+    for field in ["source", "dest"] {
+        if !obj.contains_key(field) {
+            return Err(ApiError::new(
+                ErrorCode::ValidationFailed,
+                format!("Reindex body is missing required field '{}'", field),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Require a task ID of the form `node_id:task_number`
+fn validate_task_id(task_id: &str) -> Result<(), ApiError> {
+    let valid = task_id.split_once(':').is_some_and(|(node, number)| {
+        !node.is_empty()
+            && node
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            && !number.is_empty()
+            && number.chars().all(|c| c.is_ascii_digit())
+    });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            format!(
+                "Invalid task ID '{}'. Expected format: node_id:task_number",
+                task_id
+            ),
+        ))
+    }
+}
+
+/// Transform a `GET /_tasks/{task_id}` response into task progress
+///
+/// Progress is `(created + updated + deleted) / total` from the task status,
+/// which reindex, update-by-query and delete-by-query tasks report. A
+/// completed task is always at 100%.
+fn transform_task_progress(response: &Value) -> TaskProgressResponse {
+    let completed = response
+        .get("completed")
+        .and_then(|c| c.as_bool())
+        .unwrap_or(false);
+    let task = response.get("task");
+
+    let description = task
+        .and_then(|t| t.get("description"))
+        .and_then(|d| d.as_str())
+        .filter(|d| !d.is_empty())
+        .map(String::from);
+
+    let progress_percent = if completed {
+        Some(100.0)
+    } else {
+        task.and_then(|t| t.get("status")).and_then(|status| {
+            let count = |key: &str| status.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+            let total = count("total");
+            (total > 0).then(|| {
+                let done = count("created") + count("updated") + count("deleted");
+                ((done as f64 / total as f64) * 100.0).min(100.0)
+            })
+        })
+    };
+
+    TaskProgressResponse {
+        completed,
+        progress_percent,
+        description,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_transform_task_progress_running() {
+        let response = json!({
+            "completed": false,
+            "task": {
+                "description": "reindex from [logs-old] to [logs-new]",
+                "status": {"total": 200, "created": 40, "updated": 5, "deleted": 5}
+            }
+        });
+
+        let progress = transform_task_progress(&response);
+        assert!(!progress.completed);
+        assert_eq!(progress.progress_percent, Some(25.0));
+        assert_eq!(
+            progress.description.as_deref(),
+            Some("reindex from [logs-old] to [logs-new]")
+        );
+    }
+
+    #[test]
+    fn test_transform_task_progress_without_total() {
+        let response = json!({
+            "completed": false,
+            "task": {"description": "", "status": {"total": 0}}
+        });
+
+        let progress = transform_task_progress(&response);
+        assert_eq!(progress.progress_percent, None);
+        assert_eq!(progress.description, None);
+
+        let done = transform_task_progress(&json!({"completed": true, "task": {}}));
+        assert_eq!(done.progress_percent, Some(100.0));
+    }
+
+    #[test]
+    fn test_validate_task_id() {
+        assert!(validate_task_id("oTUltX4IQMOUUVeiohTt8A:12345").is_ok());
+        assert!(validate_task_id("node-1:7").is_ok());
+
+        assert!(validate_task_id("12345").is_err());
+        assert!(validate_task_id("node:abc").is_err());
+        assert!(validate_task_id("../_cluster:1").is_err());
+    }
+
+    #[test]
+    fn test_validate_reindex_body() {
+        assert!(validate_reindex_body(&json!({
+            "source": {"index": "a"},
+            "dest": {"index": "b"}
+        }))
+        .is_ok());
+        assert!(validate_reindex_body(&json!({"source": {"index": "a"}})).is_err());
+        assert!(validate_reindex_body(&json!([])).is_err());
+    }
+}
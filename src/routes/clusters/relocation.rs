@@ -1,7 +1,8 @@
-use super::{ClusterErrorResponse, ClusterState};
+use super::ClusterState;
 use crate::auth::middleware::AuthenticatedUser;
 use crate::cluster::{manager::ProxyAuditRequest, ProxyRequestError};
 use crate::middleware::logging::RequestId;
+use crate::routes::{ApiError, ErrorCode};
 use axum::{
     extract::{Path, State},
     http::Method,
@@ -47,9 +48,9 @@ pub struct RelocateShardRequest {
     request_body = RelocateShardRequest,
     responses(
         (status = 200, description = "Shard relocation initiated"),
-        (status = 400, body = ClusterErrorResponse),
-        (status = 401, body = ClusterErrorResponse),
-        (status = 404, body = ClusterErrorResponse)
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError),
+        (status = 404, body = ApiError)
     ),
     tag = "Clusters"
 )]
@@ -60,7 +61,7 @@ pub async fn relocate_shard(
     user_ext: Option<axum::Extension<AuthenticatedUser>>,
     request_id_ext: Option<axum::Extension<RequestId>>,
     Json(req): Json<RelocateShardRequest>,
-) -> Result<Json<Value>, ClusterErrorResponse> {
+) -> Result<Json<Value>, ApiError> {
     tracing::info!(
         cluster_id = %cluster_id,
         index = %req.index,
@@ -76,10 +77,11 @@ pub async fn relocate_shard(
     // In Open mode, the middleware provides a default user
     let user = user_ext.map(|ext| ext.0 .0).ok_or_else(|| {
         tracing::error!("Authentication required but user not found in request");
-        ClusterErrorResponse::simple(
-            "authentication_required",
+        ApiError::new(
+            ErrorCode::AuthenticationRequired,
             "Authentication is required for this operation",
         )
+        .with_cluster(&cluster_id)
     })?;
 
     tracing::debug!(
@@ -103,10 +105,7 @@ pub async fn relocate_shard(
                 error = %e,
                 "Cluster not found"
             );
-            ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Cluster '{}' not found. Please verify the cluster ID and ensure the cluster is configured.", cluster_id),
-            )
+            ApiError::cluster_not_found(&cluster_id)
         })?;
 
     // Build the reroute command
@@ -147,26 +146,38 @@ pub async fn relocate_shard(
             user_roles: user.roles.clone(),
             request_id: request_id.clone(),
             audit_enabled: state.audit_log,
+            max_response_bytes: None,
         })
         .await
     {
         Ok(r) => r,
         Err(e) => {
             match e {
+                ProxyRequestError::ResponseTooLarge { limit } => {
+                    return Err(ApiError::response_too_large(&cluster_id, limit));
+                }
+                ProxyRequestError::ClusterBusy(busy) => {
+                    return Err(ApiError::cluster_busy(&busy));
+                }
+                ProxyRequestError::ClusterNotFound(_) => {
+                    return Err(ApiError::cluster_not_found(&cluster_id));
+                }
                 ProxyRequestError::AccessDenied => {
                     tracing::warn!(error = %"access_denied", cluster_id = %cluster_id, "Access denied: no matching role client");
-                    return Err(ClusterErrorResponse::simple(
-                        "access_denied",
+                    return Err(ApiError::new(
+                        ErrorCode::AccessDenied,
                         "You do not have permissions to perform this operation on the requested cluster",
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ProxyTimeout => {
                     tracing::error!(cluster_id = %cluster_id, "RELOCATE: request timed out");
-                    return Err(ClusterErrorResponse::simple(
-                        "proxy_timeout",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamTimeout,
                         "Elasticsearch request timed out: POST /_cluster/reroute (timeout: 30s)"
                             .to_string(),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::RequestFailed(reason) => {
                     tracing::error!(cluster_id = %cluster_id, error = %reason, "RELOCATE: request failed");
@@ -181,28 +192,33 @@ pub async fn relocate_shard(
                     } else {
                         format!("Failed to relocate shard: {}. Please check cluster logs for more details.", reason)
                     };
-                    return Err(ClusterErrorResponse::simple("relocation_failed", message));
+                    return Err(
+                        ApiError::new(ErrorCode::UpstreamError, message).with_cluster(&cluster_id)
+                    );
                 }
                 ProxyRequestError::ResponseReadTimeout => {
                     tracing::error!(cluster_id = %cluster_id, "Timeout reading reroute response body");
-                    return Err(ClusterErrorResponse::simple(
-                        "response_read_timeout",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamTimeout,
                         "Timeout reading Elasticsearch response body",
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ResponseReadFailed(reason) => {
                     tracing::error!(cluster_id = %cluster_id, error = %reason, "Failed to read reroute response body");
-                    return Err(ClusterErrorResponse::simple(
-                        "response_read_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to read response: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::Other(reason) => {
                     tracing::warn!(error = %reason, "RELOCATE: Unexpected error");
-                    return Err(ClusterErrorResponse::simple(
-                        "relocation_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to relocate shard: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
             }
         }
@@ -215,10 +231,11 @@ pub async fn relocate_shard(
             error = %e,
             "Failed to parse reroute response"
         );
-        ClusterErrorResponse::simple(
-            "response_parse_failed",
+        ApiError::new(
+            ErrorCode::UpstreamError,
             format!("Failed to parse response: {}", e),
         )
+        .with_cluster(&cluster_id)
     })?;
 
     // Check if the request was successful
@@ -264,10 +281,7 @@ pub async fn relocate_shard(
             )
         };
 
-        return Err(ClusterErrorResponse::simple(
-            "elasticsearch_error",
-            user_message,
-        ));
+        return Err(ApiError::new(ErrorCode::UpstreamError, user_message).with_cluster(&cluster_id));
     }
 
     tracing::info!(
@@ -289,50 +303,21 @@ pub async fn relocate_shard(
 /// # Requirements
 ///
 /// Validates: Requirements 6.3, 6.4, 8.1, 8.2, 8.3, 8.4
-fn validate_relocation_request(req: &RelocateShardRequest) -> Result<(), ClusterErrorResponse> {
-    if req.index.is_empty() {
-        tracing::warn!("Validation failed: index name is empty");
-        return Err(ClusterErrorResponse::simple(
-            "validation_failed",
-            "Index name is required. Please provide a valid index name.",
-        ));
-    }
-
-    if req.index.chars().any(|c| c.is_uppercase()) {
-        tracing::warn!(index = %req.index, "Validation failed: index name contains uppercase characters");
-        return Err(ClusterErrorResponse::simple(
-            "validation_failed",
-            format!(
-                "Index name '{}' contains uppercase characters. Elasticsearch index names must be lowercase.",
-                req.index
-            ),
-        ));
-    }
-
-    let invalid_chars = ['\\', '/', '*', '?', '"', '<', '>', '|', ' ', ',', '#'];
-    if let Some(invalid_char) = req.index.chars().find(|c| invalid_chars.contains(c)) {
-        tracing::warn!(index = %req.index, "Validation failed: index name contains invalid characters");
-        return Err(ClusterErrorResponse::simple(
-            "validation_failed",
-            format!(
-                "Index name '{}' contains invalid character '{}'. Index names cannot contain: \\ / * ? \" < > | space , #",
-                req.index, invalid_char
-            ),
-        ));
-    }
+fn validate_relocation_request(req: &RelocateShardRequest) -> Result<(), ApiError> {
+    validate_concrete_index_name(&req.index)?;
 
     if req.from_node.is_empty() {
         tracing::warn!("Validation failed: from_node is empty");
-        return Err(ClusterErrorResponse::simple(
-            "validation_failed",
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
             "Source node ID is required. Please select a source node.",
         ));
     }
 
     if req.to_node.is_empty() {
         tracing::warn!("Validation failed: to_node is empty");
-        return Err(ClusterErrorResponse::simple(
-            "validation_failed",
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
             "Destination node ID is required. Please select a destination node.",
         ));
     }
@@ -343,8 +328,8 @@ fn validate_relocation_request(req: &RelocateShardRequest) -> Result<(), Cluster
             to_node = %req.to_node,
             "Validation failed: source and destination nodes are the same"
         );
-        return Err(ClusterErrorResponse::simple(
-            "validation_failed",
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
             format!(
                 "Source and destination nodes must be different (both are {}). Please select a different destination node.",
                 req.from_node
@@ -363,6 +348,45 @@ fn validate_relocation_request(req: &RelocateShardRequest) -> Result<(), Cluster
     Ok(())
 }
 
+/// Validate a single, concrete Elasticsearch index name
+///
+/// Rejects empty names, uppercase characters and characters Elasticsearch
+/// does not allow in index names, including wildcards and `,`.
+pub(super) fn validate_concrete_index_name(index: &str) -> Result<(), ApiError> {
+    if index.is_empty() {
+        tracing::warn!("Validation failed: index name is empty");
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            "Index name is required. Please provide a valid index name.",
+        ));
+    }
+
+    if index.chars().any(|c| c.is_uppercase()) {
+        tracing::warn!(index = %index, "Validation failed: index name contains uppercase characters");
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            format!(
+                "Index name '{}' contains uppercase characters. Elasticsearch index names must be lowercase.",
+                index
+            ),
+        ));
+    }
+
+    let invalid_chars = ['\\', '/', '*', '?', '"', '<', '>', '|', ' ', ',', '#'];
+    if let Some(invalid_char) = index.chars().find(|c| invalid_chars.contains(c)) {
+        tracing::warn!(index = %index, "Validation failed: index name contains invalid characters");
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            format!(
+                "Index name '{}' contains invalid character '{}'. Index names cannot contain: \\ / * ? \" < > | space , #",
+                index, invalid_char
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -417,7 +441,7 @@ mod tests {
         };
         let err = validate_relocation_request(&req)
             .expect_err("relocation request validation should fail for empty index");
-        assert_eq!(err.error, "validation_failed");
+        assert_eq!(err.code, ErrorCode::ValidationFailed);
         assert!(err.message.contains("Index name is required"));
     }
 
@@ -431,7 +455,7 @@ mod tests {
         };
         let err = validate_relocation_request(&req)
             .expect_err("relocation request validation should fail for uppercase index");
-        assert_eq!(err.error, "validation_failed");
+        assert_eq!(err.code, ErrorCode::ValidationFailed);
         assert!(err.message.contains("lowercase"));
     }
 
@@ -476,7 +500,7 @@ mod tests {
         };
         let err = validate_relocation_request(&req)
             .expect_err("relocation request validation should fail for empty from_node");
-        assert_eq!(err.error, "validation_failed");
+        assert_eq!(err.code, ErrorCode::ValidationFailed);
         assert!(err.message.contains("Source node ID is required"));
     }
 
@@ -490,7 +514,7 @@ mod tests {
         };
         let err = validate_relocation_request(&req)
             .expect_err("relocation request validation should fail for empty to_node");
-        assert_eq!(err.error, "validation_failed");
+        assert_eq!(err.code, ErrorCode::ValidationFailed);
         assert!(err.message.contains("Destination node ID is required"));
     }
 
@@ -505,7 +529,7 @@ mod tests {
         let err = validate_relocation_request(&req).expect_err(
             "relocation request validation should fail when from/to nodes are the same",
         );
-        assert_eq!(err.error, "validation_failed");
+        assert_eq!(err.code, ErrorCode::ValidationFailed);
         assert!(err.message.contains("must be different"));
     }
 
@@ -0,0 +1,228 @@
+use super::ClusterState;
+use crate::auth::middleware::AuthenticatedUser;
+use crate::cluster::ClusterInfo;
+use crate::routes::{ApiError, ErrorCode};
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use utoipa::{IntoParams, ToSchema};
+
+/// Results returned when the request does not set `limit`
+const DEFAULT_SEARCH_LIMIT: usize = 10;
+
+/// Upper bound on `limit`
+const MAX_SEARCH_LIMIT: usize = 100;
+
+/// Query parameters for the cluster search endpoint
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct ClusterSearchParams {
+    /// Text matched against cluster IDs and names
+    #[param(example = "prod")]
+    pub q: String,
+    /// Maximum number of results (default: 10, at most 100)
+    #[param(example = 10)]
+    pub limit: Option<usize>,
+}
+
+/// A cluster matching a search, with its relevance
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ClusterSearchResult {
+    #[serde(flatten)]
+    pub cluster: ClusterInfo,
+    /// Relevance of the match, higher is better (exact 100, prefix 80,
+    /// substring 60, fuzzy below 40)
+    pub score: u32,
+}
+
+/// Search clusters by ID or name
+///
+/// Matches `q` case-insensitively against cluster IDs and names: exact
+/// matches rank first, then prefixes, substrings and finally fuzzy matches
+/// (the query's characters appear in order). Only clusters the user can
+/// access are searched, so the results never reveal other clusters. Ties
+/// keep the cluster list order.
+#[utoipa::path(
+    get,
+    path = "/clusters/search",
+    params(ClusterSearchParams),
+    responses(
+        (status = 200, body = Vec<ClusterSearchResult>),
+        (status = 400, body = ApiError),
+        (status = 401, body = ApiError)
+    ),
+    tag = "Clusters"
+)]
+#[instrument(skip(state, user_ext), fields(q = %params.q))]
+pub async fn search_clusters(
+    State(state): State<ClusterState>,
+    Query(params): Query<ClusterSearchParams>,
+    user_ext: Option<axum::Extension<AuthenticatedUser>>,
+) -> Result<Json<Vec<ClusterSearchResult>>, ApiError> {
+    let query = params.q.trim().to_lowercase();
+    if query.is_empty() {
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            "Search query (q) is required",
+        ));
+    }
+    let limit = params.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+    if limit == 0 || limit > MAX_SEARCH_LIMIT {
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
+            format!("limit must be between 1 and {}", MAX_SEARCH_LIMIT),
+        ));
+    }
+
+    // Filter by RBAC before matching so inaccessible clusters are never scored
+    let mut clusters = state.cluster_manager.list_clusters().await;
+    if let Some(user) = &user_ext {
+        let ids: Vec<String> = clusters.iter().map(|c| c.id.clone()).collect();
+        let accessible = state.rbac.get_accessible_clusters(&user.0 .0, &ids);
+        clusters.retain(|c| accessible.contains(&c.id));
+    }
+
+    let results = rank_clusters(clusters, &query, limit);
+    tracing::debug!(results = results.len(), "Clusters searched");
+    Ok(Json(results))
+}
+
+/// Score clusters against a lowercase query and keep the best `limit`
+fn rank_clusters(
+    clusters: Vec<ClusterInfo>,
+    query: &str,
+    limit: usize,
+) -> Vec<ClusterSearchResult> {
+    let mut results: Vec<ClusterSearchResult> = clusters
+        .into_iter()
+        .filter_map(|cluster| {
+            let id_score = match_score(&cluster.id, query);
+            let name_score = cluster.name.as_deref().and_then(|n| match_score(n, query));
+            let score = id_score.max(name_score)?;
+            Some(ClusterSearchResult { cluster, score })
+        })
+        .collect();
+
+    // Stable sort keeps the cluster list order for equal scores
+    results.sort_by_key(|result| std::cmp::Reverse(result.score));
+    results.truncate(limit);
+    results
+}
+
+/// How well `candidate` matches a lowercase `query`, if at all
+fn match_score(candidate: &str, query: &str) -> Option<u32> {
+    let candidate = candidate.to_lowercase();
+    if candidate == query {
+        return Some(100);
+    }
+    if candidate.starts_with(query) {
+        return Some(80);
+    }
+    if candidate.contains(query) {
+        return Some(60);
+    }
+    fuzzy_score(&candidate, query)
+}
+
+/// Score a subsequence match: every query character appears in order
+///
+/// Tighter matches (fewer skipped characters) score higher, up to 39.
+fn fuzzy_score(candidate: &str, query: &str) -> Option<u32> {
+    let mut chars = candidate.chars();
+    let mut skipped = 0u32;
+    for wanted in query.chars() {
+        loop {
+            let c = chars.next()?;
+            if c == wanted {
+                break;
+            }
+            skipped += 1;
+        }
+    }
+    Some(39u32.saturating_sub(skipped).max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MetricsSource;
+
+    fn cluster(id: &str, name: Option<&str>) -> ClusterInfo {
+        ClusterInfo {
+            id: id.to_string(),
+            name: name.map(str::to_string),
+            nodes: Vec::new(),
+            accessible: true,
+            accessible_reason: None,
+            metrics_source: MetricsSource::default(),
+            enabled: true,
+            es_version: None,
+            es_version_detected: false,
+        }
+    }
+
+    fn ranked_ids(clusters: Vec<ClusterInfo>, query: &str, limit: usize) -> Vec<String> {
+        rank_clusters(clusters, query, limit)
+            .into_iter()
+            .map(|result| result.cluster.id)
+            .collect()
+    }
+
+    #[test]
+    fn test_exact_match_ranks_before_substring() {
+        let clusters = vec![
+            cluster("eu-prod", None),
+            cluster("prod-archive", None),
+            cluster("prod", None),
+            cluster("staging", None),
+        ];
+
+        assert_eq!(
+            ranked_ids(clusters, "prod", 10),
+            vec!["prod", "prod-archive", "eu-prod"]
+        );
+    }
+
+    #[test]
+    fn test_name_matches_and_fuzzy_matches() {
+        let clusters = vec![
+            cluster("c2", Some("Production logs")),
+            cluster("plg-1", None),
+            cluster("c1", Some("Payments")),
+        ];
+
+        // Names are searched too
+        let results = rank_clusters(clusters, "logs", 10);
+        let ids: Vec<&str> = results.iter().map(|r| r.cluster.id.as_str()).collect();
+        assert_eq!(ids, vec!["c2"]);
+
+        // "plg" prefixes "plg-1" but only fuzzily matches "prod-logs"
+        let results = rank_clusters(
+            vec![cluster("prod-logs", None), cluster("plg-1", None)],
+            "plg",
+            10,
+        );
+        assert_eq!(results[0].cluster.id, "plg-1");
+        assert_eq!(results[0].score, 80);
+        assert_eq!(results[1].cluster.id, "prod-logs");
+        assert!(results[1].score < 40);
+    }
+
+    #[test]
+    fn test_results_are_limited() {
+        let clusters = (0..5)
+            .map(|i| cluster(&format!("prod-{}", i), None))
+            .collect();
+
+        assert_eq!(ranked_ids(clusters, "prod", 2), vec!["prod-0", "prod-1"]);
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert_eq!(fuzzy_score("abc", "abc"), Some(39));
+        assert_eq!(fuzzy_score("axbxc", "abc"), Some(37));
+        assert_eq!(fuzzy_score("acb", "abc"), None);
+    }
+}
@@ -11,6 +11,7 @@ use super::ClusterState;
 use crate::auth::middleware::AuthenticatedUser;
 use crate::cluster::manager::ProxyAuditRequest;
 use crate::middleware::logging::RequestId;
+use crate::routes::{ApiError, ErrorCode};
 use axum::http::Method;
 
 /// Task information from Elasticsearch Tasks API
@@ -88,7 +89,39 @@ pub struct CancelTaskResponse {
     pub message: String,
 }
 
-// Note: Task handlers use crate::routes::clusters::ClusterErrorResponse for errors.
+/// A cluster-level change waiting on the master node
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct PendingTaskInfo {
+    /// Order in which the task was queued
+    pub insert_order: i64,
+    /// Task priority (e.g. "URGENT", "HIGH", "NORMAL")
+    #[schema(example = "URGENT")]
+    pub priority: String,
+    /// What queued the task (e.g. "create-index [logs], cause [api]")
+    pub source: String,
+    pub time_in_queue_millis: i64,
+    /// Whether the master is executing the task right now
+    pub executing: bool,
+}
+
+/// Summary of the pending tasks queue
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct PendingTasksSummary {
+    pub count: usize,
+    /// Task that has waited longest, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longest_waiting: Option<PendingTaskInfo>,
+}
+
+/// Response from the pending tasks endpoint
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PendingTasksResponse {
+    /// Pending tasks, longest waiting first
+    pub tasks: Vec<PendingTaskInfo>,
+    pub summary: PendingTasksSummary,
+}
+
+// Note: Task handlers use crate::routes::ApiError for errors.
 // TaskErrorResponse was removed to centralize cluster error handling.
 
 /// Query parameters for listing tasks
@@ -155,6 +188,37 @@ fn transform_tasks_response(tasks_value: &Value) -> Vec<TaskInfo> {
 }
 
 /// Derive unique task types and actions from task list
+/// Transform a `_cluster/pending_tasks` response, longest waiting first
+fn transform_pending_tasks(response: &Value) -> PendingTasksResponse {
+    let mut tasks: Vec<PendingTaskInfo> = response["tasks"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|task| PendingTaskInfo {
+            insert_order: task["insert_order"].as_i64().unwrap_or_default(),
+            priority: task["priority"].as_str().unwrap_or("UNKNOWN").to_string(),
+            source: task["source"].as_str().unwrap_or_default().to_string(),
+            time_in_queue_millis: task["time_in_queue_millis"].as_i64().unwrap_or_default(),
+            executing: task["executing"].as_bool().unwrap_or(false),
+        })
+        .collect();
+
+    // Ties go to the task queued first
+    tasks.sort_by(|a, b| {
+        b.time_in_queue_millis
+            .cmp(&a.time_in_queue_millis)
+            .then(a.insert_order.cmp(&b.insert_order))
+    });
+
+    PendingTasksResponse {
+        summary: PendingTasksSummary {
+            count: tasks.len(),
+            longest_waiting: tasks.first().cloned(),
+        },
+        tasks,
+    }
+}
+
 fn derive_unique_filters(tasks: &[TaskInfo]) -> (Vec<String>, Vec<String>) {
     let mut types = HashSet::new();
     let mut actions = HashSet::new();
@@ -240,9 +304,9 @@ fn apply_filters(
     ),
     responses(
         (status = 200, body = TasksListResponse),
-        (status = 400, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 401, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 404, body = crate::routes::clusters::ClusterErrorResponse)
+        (status = 400, body = crate::routes::ApiError),
+        (status = 401, body = crate::routes::ApiError),
+        (status = 404, body = crate::routes::ApiError)
     ),
     tag = "Clusters"
 )]
@@ -252,7 +316,7 @@ pub async fn fetch_cluster_tasks(
     Query(params): Query<TasksQueryParams>,
     user_ext: Option<axum::Extension<AuthenticatedUser>>,
     request_id_ext: Option<axum::Extension<RequestId>>,
-) -> Result<Json<TasksListResponse>, crate::routes::clusters::ClusterErrorResponse> {
+) -> Result<Json<TasksListResponse>, crate::routes::ApiError> {
     tracing::debug!(
         cluster_id = %cluster_id,
         type_filter = ?params.type_filter,
@@ -267,18 +331,10 @@ pub async fn fetch_cluster_tasks(
         .cluster_manager
         .get_cluster(&cluster_id)
         .await
-        .map_err(|e| {
-            crate::routes::clusters::ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Failed to get cluster: {}", e),
-            )
-        })?;
+        .map_err(|_| ApiError::cluster_not_found(&cluster_id))?;
 
     if !cluster.accessible {
-        return Err(crate::routes::clusters::ClusterErrorResponse::unavailable(
-            &cluster_id,
-            cluster.accessible_reason.clone(),
-        ));
+        return Err(ApiError::inaccessible(&cluster));
     }
 
     // Extract user (if present) for role selection. In Open mode middleware may
@@ -308,6 +364,7 @@ pub async fn fetch_cluster_tasks(
             user_roles: roles.clone(),
             request_id: request_id.clone(),
             audit_enabled: state.audit_log,
+            max_response_bytes: None,
         })
         .await
     {
@@ -316,64 +373,81 @@ pub async fn fetch_cluster_tasks(
             use crate::cluster::ProxyRequestError;
 
             match e {
+                ProxyRequestError::ResponseTooLarge { limit } => {
+                    return Err(ApiError::response_too_large(&cluster_id, limit));
+                }
+                ProxyRequestError::ClusterBusy(busy) => {
+                    return Err(ApiError::cluster_busy(&busy));
+                }
+                ProxyRequestError::ClusterNotFound(_) => {
+                    return Err(ApiError::cluster_not_found(&cluster_id));
+                }
                 ProxyRequestError::AccessDenied => {
                     tracing::warn!(error = %"access_denied", cluster_id = %cluster_id, "Access denied: no matching role client");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "access_denied",
+                    return Err(ApiError::new(
+                        ErrorCode::AccessDenied,
                         "You do not have permissions to perform this operation on the requested cluster".to_string(),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ProxyTimeout => {
                     tracing::error!(cluster_id = %cluster_id, "FETCH TASKS: request timed out");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "proxy_timeout",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamTimeout,
                         "Elasticsearch request timed out: GET /_tasks (timeout: 30s)".to_string(),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::RequestFailed(reason) => {
                     tracing::warn!(error = %reason, "Failed to fetch tasks");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "fetch_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to fetch tasks: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ResponseReadTimeout => {
                     tracing::error!(cluster_id = %cluster_id, "Timeout reading tasks response body");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "response_read_timeout",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamTimeout,
                         "Timeout reading Elasticsearch response body",
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ResponseReadFailed(reason) => {
                     tracing::error!(cluster_id = %cluster_id, error = %reason, "Failed to read tasks response body");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "response_read_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to read response body: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::Other(reason) => {
                     tracing::warn!(error = %reason, "Failed to fetch tasks");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "fetch_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to fetch tasks: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
             }
         }
     };
 
     let text = String::from_utf8(body_vec).map_err(|e| {
-        crate::routes::clusters::ClusterErrorResponse::simple(
-            "parse_error",
+        ApiError::new(
+            ErrorCode::UpstreamError,
             format!("Failed to parse response: {}", e),
         )
+        .with_cluster(&cluster_id)
     })?;
 
     let tasks_json: Value = serde_json::from_str(&text).map_err(|e| {
-        crate::routes::clusters::ClusterErrorResponse::simple(
-            "json_error",
+        ApiError::new(
+            ErrorCode::UpstreamError,
             format!("Failed to parse JSON: {}", e),
         )
+        .with_cluster(&cluster_id)
     })?;
 
     // Transform response
@@ -418,9 +492,9 @@ pub async fn fetch_cluster_tasks(
     ),
     responses(
         (status = 200, body = TaskDetailsResponse),
-        (status = 400, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 401, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 404, body = crate::routes::clusters::ClusterErrorResponse)
+        (status = 400, body = crate::routes::ApiError),
+        (status = 401, body = crate::routes::ApiError),
+        (status = 404, body = crate::routes::ApiError)
     ),
     tag = "Clusters"
 )]
@@ -429,7 +503,7 @@ pub async fn get_task_details(
     Path((cluster_id, task_id)): Path<(String, String)>,
     user_ext: Option<axum::Extension<AuthenticatedUser>>,
     request_id_ext: Option<axum::Extension<RequestId>>,
-) -> Result<Json<TaskDetailsResponse>, crate::routes::clusters::ClusterErrorResponse> {
+) -> Result<Json<TaskDetailsResponse>, crate::routes::ApiError> {
     tracing::debug!(
         cluster_id = %cluster_id,
         task_id = %task_id,
@@ -442,18 +516,10 @@ pub async fn get_task_details(
         .cluster_manager
         .get_cluster(&cluster_id)
         .await
-        .map_err(|e| {
-            crate::routes::clusters::ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Failed to get cluster: {}", e),
-            )
-        })?;
+        .map_err(|_| ApiError::cluster_not_found(&cluster_id))?;
 
     if !cluster.accessible {
-        return Err(crate::routes::clusters::ClusterErrorResponse::unavailable(
-            &cluster_id,
-            cluster.accessible_reason.clone(),
-        ));
+        return Err(ApiError::inaccessible(&cluster));
     }
 
     let user_opt = user_ext.as_ref().map(|u| u.0 .0.clone());
@@ -478,6 +544,7 @@ pub async fn get_task_details(
             user_roles: roles.clone(),
             request_id: request_id.clone(),
             audit_enabled: state.audit_log,
+            max_response_bytes: None,
         })
         .await
     {
@@ -486,67 +553,84 @@ pub async fn get_task_details(
             use crate::cluster::ProxyRequestError;
 
             match e {
+                ProxyRequestError::ResponseTooLarge { limit } => {
+                    return Err(ApiError::response_too_large(&cluster_id, limit));
+                }
+                ProxyRequestError::ClusterBusy(busy) => {
+                    return Err(ApiError::cluster_busy(&busy));
+                }
+                ProxyRequestError::ClusterNotFound(_) => {
+                    return Err(ApiError::cluster_not_found(&cluster_id));
+                }
                 ProxyRequestError::AccessDenied => {
                     tracing::warn!(error = %"access_denied", cluster_id = %cluster_id, "Access denied: no matching role client");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "access_denied",
+                    return Err(ApiError::new(
+                        ErrorCode::AccessDenied,
                         "You do not have permissions to perform this operation on the requested cluster".to_string(),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ProxyTimeout => {
                     tracing::error!(cluster_id = %cluster_id, "GET TASK: request timed out");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "proxy_timeout",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamTimeout,
                         format!(
                             "Elasticsearch request timed out: GET /_tasks/{} (timeout: 30s)",
                             task_id
                         ),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::RequestFailed(reason) => {
                     tracing::warn!(error = %reason, "Failed to fetch task details");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "fetch_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to fetch task details: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ResponseReadTimeout => {
                     tracing::error!(cluster_id = %cluster_id, "Timeout reading task details response body");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "response_read_timeout",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamTimeout,
                         "Timeout reading Elasticsearch response body",
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ResponseReadFailed(reason) => {
                     tracing::error!(cluster_id = %cluster_id, error = %reason, "Failed to read task details response body");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "response_read_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to read response body: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::Other(reason) => {
                     tracing::warn!(error = %reason, "Failed to fetch task details");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "fetch_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to fetch task details: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
             }
         }
     };
 
     let text = String::from_utf8(body_vec).map_err(|e| {
-        crate::routes::clusters::ClusterErrorResponse::simple(
-            "parse_error",
+        ApiError::new(
+            ErrorCode::UpstreamError,
             format!("Failed to parse response: {}", e),
         )
+        .with_cluster(&cluster_id)
     })?;
 
     let task_json: Value = serde_json::from_str(&text).map_err(|e| {
-        crate::routes::clusters::ClusterErrorResponse::simple(
-            "json_error",
+        ApiError::new(
+            ErrorCode::UpstreamError,
             format!("Failed to parse JSON: {}", e),
         )
+        .with_cluster(&cluster_id)
     })?;
 
     // Extract task info and create TaskDetails
@@ -608,10 +692,11 @@ pub async fn get_task_details(
 
     let task_info = serde_json::from_value::<TaskInfo>(task_value.clone()).map_err(|e| {
         tracing::warn!(error = %e, "Failed to deserialize task");
-        crate::routes::clusters::ClusterErrorResponse::simple(
-            "deserialize_error",
+        ApiError::new(
+            ErrorCode::UpstreamError,
             format!("Failed to deserialize task: {}", e),
         )
+        .with_cluster(&cluster_id)
     })?;
 
     let task_details = TaskDetails {
@@ -636,9 +721,9 @@ pub async fn get_task_details(
     ),
     responses(
         (status = 200, body = CancelTaskResponse),
-        (status = 400, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 401, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 404, body = crate::routes::clusters::ClusterErrorResponse)
+        (status = 400, body = crate::routes::ApiError),
+        (status = 401, body = crate::routes::ApiError),
+        (status = 404, body = crate::routes::ApiError)
     ),
     tag = "Clusters"
 )]
@@ -647,7 +732,7 @@ pub async fn cancel_cluster_task(
     Path((cluster_id, task_id)): Path<(String, String)>,
     user_ext: Option<axum::Extension<AuthenticatedUser>>,
     request_id_ext: Option<axum::Extension<RequestId>>,
-) -> Result<Json<CancelTaskResponse>, crate::routes::clusters::ClusterErrorResponse> {
+) -> Result<Json<CancelTaskResponse>, crate::routes::ApiError> {
     tracing::debug!(
         cluster_id = %cluster_id,
         task_id = %task_id,
@@ -660,18 +745,10 @@ pub async fn cancel_cluster_task(
         .cluster_manager
         .get_cluster(&cluster_id)
         .await
-        .map_err(|e| {
-            crate::routes::clusters::ClusterErrorResponse::simple(
-                "cluster_not_found",
-                format!("Failed to get cluster: {}", e),
-            )
-        })?;
+        .map_err(|_| ApiError::cluster_not_found(&cluster_id))?;
 
     if !cluster.accessible {
-        return Err(crate::routes::clusters::ClusterErrorResponse::unavailable(
-            &cluster_id,
-            cluster.accessible_reason.clone(),
-        ));
+        return Err(ApiError::inaccessible(&cluster));
     }
 
     let user_opt = user_ext.as_ref().map(|u| u.0 .0.clone());
@@ -696,6 +773,7 @@ pub async fn cancel_cluster_task(
             user_roles: roles.clone(),
             request_id: request_id.clone(),
             audit_enabled: state.audit_log,
+            max_response_bytes: None,
         })
         .await
     {
@@ -704,60 +782,76 @@ pub async fn cancel_cluster_task(
             use crate::cluster::ProxyRequestError;
 
             match e {
+                ProxyRequestError::ResponseTooLarge { limit } => {
+                    return Err(ApiError::response_too_large(&cluster_id, limit));
+                }
+                ProxyRequestError::ClusterBusy(busy) => {
+                    return Err(ApiError::cluster_busy(&busy));
+                }
+                ProxyRequestError::ClusterNotFound(_) => {
+                    return Err(ApiError::cluster_not_found(&cluster_id));
+                }
                 ProxyRequestError::AccessDenied => {
                     tracing::warn!(error = %"access_denied", cluster_id = %cluster_id, "Access denied: no matching role client");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "access_denied",
+                    return Err(ApiError::new(
+                        ErrorCode::AccessDenied,
                         "You do not have permissions to perform this operation on the requested cluster".to_string(),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ProxyTimeout => {
                     tracing::error!(cluster_id = %cluster_id, "CANCEL TASK: request timed out");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "proxy_timeout",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamTimeout,
                         format!(
                             "Elasticsearch request timed out: POST /_tasks/{}/_cancel (timeout: 30s)",
                             task_id
                         ),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::RequestFailed(reason) => {
                     tracing::warn!(error = %reason, "Failed to cancel task");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "cancel_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to cancel task: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ResponseReadTimeout => {
                     tracing::error!(cluster_id = %cluster_id, "Timeout reading cancel response body");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "response_read_timeout",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamTimeout,
                         "Timeout reading Elasticsearch response body",
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::ResponseReadFailed(reason) => {
                     tracing::error!(cluster_id = %cluster_id, error = %reason, "Failed to read cancel response body");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "response_read_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to read response body: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
                 ProxyRequestError::Other(reason) => {
                     tracing::warn!(error = %reason, "Failed to cancel task");
-                    return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                        "cancel_failed",
+                    return Err(ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to cancel task: {}", reason),
-                    ));
+                    )
+                    .with_cluster(&cluster_id));
                 }
             }
         }
     };
 
     let text = String::from_utf8(body_vec).map_err(|e| {
-        crate::routes::clusters::ClusterErrorResponse::simple(
-            "parse_error",
+        ApiError::new(
+            ErrorCode::UpstreamError,
             format!("Failed to parse response: {}", e),
         )
+        .with_cluster(&cluster_id)
     })?;
 
     if status.is_success() {
@@ -784,17 +878,108 @@ pub async fn cancel_cluster_task(
             "Failed to cancel task"
         );
 
-        Err(crate::routes::clusters::ClusterErrorResponse::simple(
-            "cancel_error",
+        Err(ApiError::new(
+            ErrorCode::UpstreamError,
             format!("Failed to cancel task: {}", error_msg),
-        ))
+        )
+        .with_cluster(&cluster_id))
     }
 }
 
+/// List cluster-level tasks queued on the master
+///
+/// A growing queue or long wait times indicate an overloaded master node.
+#[utoipa::path(
+    get,
+    path = "/clusters/{cluster_id}/pending_tasks",
+    params(("cluster_id" = String, Path, description = "Cluster ID")),
+    responses(
+        (status = 200, body = PendingTasksResponse),
+        (status = 400, body = crate::routes::ApiError),
+        (status = 401, body = crate::routes::ApiError),
+        (status = 404, body = crate::routes::ApiError)
+    ),
+    tag = "Clusters"
+)]
+pub async fn get_pending_tasks(
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+) -> Result<Json<PendingTasksResponse>, crate::routes::ApiError> {
+    crate::routes::clusters::validate_cluster_id(&cluster_id)?;
+
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|_| ApiError::cluster_not_found(&cluster_id))?;
+
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    let response = cluster.pending_tasks().await.map_err(|e| {
+        tracing::error!(cluster_id = %cluster_id, error = %e, "Failed to get pending tasks");
+        ApiError::upstream(&cluster_id, "get pending cluster tasks", &e)
+    })?;
+
+    Ok(Json(transform_pending_tasks(&response)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_transform_pending_tasks() {
+        let response = serde_json::json!({
+            "tasks": [
+                {
+                    "insert_order": 101,
+                    "priority": "URGENT",
+                    "source": "create-index [foo_9], cause [api]",
+                    "executing": true,
+                    "time_in_queue_millis": 86,
+                    "time_in_queue": "86ms"
+                },
+                {
+                    "insert_order": 46,
+                    "priority": "HIGH",
+                    "source": "shard-started ([foo_2][1], node[tMTocMvQQgGCkj7QDHl3OA], [P], s[INITIALIZING]), reason [after recovery from shard_store]",
+                    "executing": false,
+                    "time_in_queue_millis": 842,
+                    "time_in_queue": "842ms"
+                },
+                {
+                    "insert_order": 45,
+                    "priority": "HIGH",
+                    "source": "shard-started ([foo_2][0], node[tMTocMvQQgGCkj7QDHl3OA], [P], s[INITIALIZING]), reason [after recovery from shard_store]",
+                    "executing": false,
+                    "time_in_queue_millis": 858,
+                    "time_in_queue": "858ms"
+                }
+            ]
+        });
+
+        let result = transform_pending_tasks(&response);
+
+        let order: Vec<i64> = result.tasks.iter().map(|t| t.insert_order).collect();
+        assert_eq!(order, vec![45, 46, 101]);
+        assert_eq!(result.summary.count, 3);
+        let longest = result.summary.longest_waiting.expect("longest task");
+        assert_eq!(longest.insert_order, 45);
+        assert_eq!(longest.time_in_queue_millis, 858);
+        assert!(result.tasks[2].executing);
+    }
+
+    #[test]
+    fn test_transform_pending_tasks_empty() {
+        let result = transform_pending_tasks(&serde_json::json!({"tasks": []}));
+
+        assert!(result.tasks.is_empty());
+        assert_eq!(result.summary.count, 0);
+        assert_eq!(result.summary.longest_waiting, None);
+    }
+
     #[test]
     fn test_derive_unique_filters() {
         let tasks = vec![
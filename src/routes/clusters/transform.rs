@@ -26,6 +26,29 @@ fn format_uptime(uptime_millis: u64) -> String {
     }
 }
 
+/// Format a byte count with binary units, up to PiB
+///
+/// # Examples
+/// - 512 -> "512 B"
+/// - 1536 -> "1.5 KiB"
+/// - 1.5 * 2^40 -> "1.5 TiB"
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["KiB", "MiB", "GiB", "TiB", "PiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    // Round before comparing so 1023.96 KiB reads "1.0 MiB", not "1024.0 KiB"
+    while unit + 1 < UNITS.len() && (value * 10.0).round() >= 1024.0 * 10.0 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
 /// Transform cluster stats from SDK response to frontend format
 ///
 /// When Prometheus metrics are available, they take precedence over internal metrics
@@ -228,9 +251,181 @@ pub fn transform_cluster_stats(
         load_average_5m,
         load_average_15m,
         es_version,
+        max_uptime_millis: stats["nodes"]["jvm"]["max_uptime_in_millis"].as_u64(),
+        memory_used_human: None,
+        memory_total_human: None,
+        disk_used_human: None,
+        disk_total_human: None,
+        max_uptime_human: None,
     })
 }
 
+/// How close a node's disk is to the cluster's disk watermarks
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DiskWatermarkState {
+    /// Below the low watermark
+    #[default]
+    Ok,
+    /// Past the low watermark: no new shards are allocated to the node
+    Low,
+    /// Past the high watermark: shards are moved away from the node
+    High,
+    /// Past the flood-stage watermark: indices on the node become read-only
+    Flood,
+}
+
+/// A disk watermark, either as used disk or as free space left
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DiskWatermark {
+    /// Exceeded when more than this percentage of the disk is used
+    UsedPercent(f64),
+    /// Exceeded when less than this many bytes are free
+    FreeBytes(u64),
+}
+
+impl DiskWatermark {
+    /// Parse a watermark setting: a percentage (`85%`), a ratio (`0.85`) or
+    /// a byte size (`50gb`)
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_ascii_lowercase();
+        if let Some(percent) = value.strip_suffix('%') {
+            return percent
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|p| (0.0..=100.0).contains(p))
+                .map(Self::UsedPercent);
+        }
+        if let Ok(ratio) = value.parse::<f64>() {
+            return (0.0..=1.0)
+                .contains(&ratio)
+                .then_some(Self::UsedPercent(ratio * 100.0));
+        }
+
+        let digits = value
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(value.len());
+        let (number, unit) = value.split_at(digits);
+        let multiplier: u64 = match unit.trim() {
+            "b" => 1,
+            "kb" => 1 << 10,
+            "mb" => 1 << 20,
+            "gb" => 1 << 30,
+            "tb" => 1 << 40,
+            "pb" => 1 << 50,
+            _ => return None,
+        };
+        let number: f64 = number.parse().ok()?;
+        Some(Self::FreeBytes((number * multiplier as f64) as u64))
+    }
+
+    /// Whether a disk is past this watermark
+    ///
+    /// `max_headroom` caps the free space a percentage watermark asks for, so
+    /// large disks are not flagged while plenty of space is left.
+    fn exceeded(self, max_headroom: Option<u64>, disk_used: u64, disk_total: u64) -> bool {
+        let free = disk_total.saturating_sub(disk_used);
+        match self {
+            Self::UsedPercent(percent) => {
+                disk_used as f64 * 100.0 / disk_total as f64 > percent
+                    && max_headroom.is_none_or(|headroom| free < headroom)
+            }
+            Self::FreeBytes(bytes) => free < bytes,
+        }
+    }
+}
+
+/// The cluster's disk watermarks (`cluster.routing.allocation.disk.watermark.*`)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DiskWatermarks {
+    pub low: DiskWatermark,
+    pub high: DiskWatermark,
+    pub flood_stage: DiskWatermark,
+    /// Most free space the percentage low watermark asks for (ES 8.5+)
+    pub low_max_headroom: Option<u64>,
+    /// Most free space the percentage high watermark asks for (ES 8.5+)
+    pub high_max_headroom: Option<u64>,
+    /// Most free space the percentage flood-stage watermark asks for (ES 8.5+)
+    pub flood_stage_max_headroom: Option<u64>,
+}
+
+impl Default for DiskWatermarks {
+    /// Elasticsearch's defaults: 85%, 90% and 95%, without headroom caps
+    fn default() -> Self {
+        Self {
+            low: DiskWatermark::UsedPercent(85.0),
+            high: DiskWatermark::UsedPercent(90.0),
+            flood_stage: DiskWatermark::UsedPercent(95.0),
+            low_max_headroom: None,
+            high_max_headroom: None,
+            flood_stage_max_headroom: None,
+        }
+    }
+}
+
+impl DiskWatermarks {
+    /// Resolve the watermarks from a `/_cluster/settings` response
+    ///
+    /// Transient settings win over persistent ones, which win over the
+    /// `defaults` section (present with `include_defaults=true`). Missing or
+    /// unparseable values fall back to the Elasticsearch defaults. The
+    /// `<watermark>.max_headroom` settings (ES 8.5+, reported in `defaults`)
+    /// are resolved the same way; `-1` or a missing value means no cap.
+    pub fn from_cluster_settings(settings: &Value) -> Self {
+        let layers: Vec<_> = ["transient", "persistent", "defaults"]
+            .iter()
+            .map(|layer| flatten_settings(&settings[*layer]))
+            .collect();
+        let resolve = |name: &str| {
+            let key = format!("cluster.routing.allocation.disk.watermark.{}", name);
+            layers
+                .iter()
+                .find_map(|layer| layer.get(&key))
+                .and_then(|value| value.as_str())
+                .and_then(DiskWatermark::parse)
+        };
+        let headroom = |name: &str| match resolve(&format!("{}.max_headroom", name)) {
+            Some(DiskWatermark::FreeBytes(bytes)) => Some(bytes),
+            _ => None,
+        };
+
+        let defaults = Self::default();
+        Self {
+            low: resolve("low").unwrap_or(defaults.low),
+            high: resolve("high").unwrap_or(defaults.high),
+            flood_stage: resolve("flood_stage").unwrap_or(defaults.flood_stage),
+            low_max_headroom: headroom("low"),
+            high_max_headroom: headroom("high"),
+            flood_stage_max_headroom: headroom("flood_stage"),
+        }
+    }
+
+    /// The highest watermark a disk with this usage is past
+    pub fn state(&self, disk_used: u64, disk_total: u64) -> DiskWatermarkState {
+        if disk_total == 0 {
+            DiskWatermarkState::Ok
+        } else if self
+            .flood_stage
+            .exceeded(self.flood_stage_max_headroom, disk_used, disk_total)
+        {
+            DiskWatermarkState::Flood
+        } else if self
+            .high
+            .exceeded(self.high_max_headroom, disk_used, disk_total)
+        {
+            DiskWatermarkState::High
+        } else if self
+            .low
+            .exceeded(self.low_max_headroom, disk_used, disk_total)
+        {
+            DiskWatermarkState::Low
+        } else {
+            DiskWatermarkState::Ok
+        }
+    }
+}
+
 /// Transform nodes info and stats from SDK response to frontend format
 ///
 /// # Parameters
@@ -238,11 +433,13 @@ pub fn transform_cluster_stats(
 /// * `nodes_stats` - Node statistics from /_nodes/stats API  
 /// * `master_node_id` - Optional master node ID from /_cat/master or cluster state
 /// * `prometheus_metrics` - Optional Prometheus metrics (node_name -> {cpu_percent, memory_used})
+/// * `watermarks` - The cluster's disk watermarks, for `disk_watermark_state`
 pub fn transform_nodes(
     nodes_info: &Value,
     nodes_stats: &Value,
     master_node_id: Option<&str>,
     prometheus_metrics: Option<&std::collections::HashMap<String, serde_json::Value>>,
+    watermarks: &DiskWatermarks,
 ) -> Vec<NodeInfoResponse> {
     let mut result = Vec::new();
 
@@ -369,6 +566,7 @@ pub fn transform_nodes(
                 heap_percent,
                 disk_used,
                 disk_total,
+                disk_watermark_state: watermarks.state(disk_used, disk_total),
                 cpu_percent: Some(cpu_percent),
                 ip: node_info["ip"].as_str().map(|s| s.to_string()),
                 version: node_info["version"].as_str().map(|s| s.to_string()),
@@ -833,6 +1031,32 @@ pub struct ClusterStatsResponse {
     pub load_average_15m: Option<f64>,
     #[serde(rename = "esVersion", skip_serializing_if = "Option::is_none")]
     pub es_version: Option<String>,
+    /// Uptime of the longest-running node's JVM
+    #[serde(rename = "maxUptimeMillis", skip_serializing_if = "Option::is_none")]
+    pub max_uptime_millis: Option<u64>,
+    /// Formatted `memoryUsed`, only with `?human=true`
+    #[serde(rename = "memoryUsedHuman", skip_serializing_if = "Option::is_none")]
+    pub memory_used_human: Option<String>,
+    #[serde(rename = "memoryTotalHuman", skip_serializing_if = "Option::is_none")]
+    pub memory_total_human: Option<String>,
+    #[serde(rename = "diskUsedHuman", skip_serializing_if = "Option::is_none")]
+    pub disk_used_human: Option<String>,
+    #[serde(rename = "diskTotalHuman", skip_serializing_if = "Option::is_none")]
+    pub disk_total_human: Option<String>,
+    #[serde(rename = "maxUptimeHuman", skip_serializing_if = "Option::is_none")]
+    pub max_uptime_human: Option<String>,
+}
+
+impl ClusterStatsResponse {
+    /// Fill in the `*Human` fields from their raw counterparts
+    pub fn humanize(mut self) -> Self {
+        self.memory_used_human = self.memory_used.map(format_bytes);
+        self.memory_total_human = self.memory_total.map(format_bytes);
+        self.disk_used_human = self.disk_used.map(format_bytes);
+        self.disk_total_human = self.disk_total.map(format_bytes);
+        self.max_uptime_human = self.max_uptime_millis.map(format_uptime);
+        self
+    }
 }
 
 /// Node info response for frontend
@@ -852,6 +1076,8 @@ pub struct NodeInfoResponse {
     pub disk_used: u64,
     #[serde(rename = "diskTotal")]
     pub disk_total: u64,
+    #[serde(rename = "diskWatermarkState")]
+    pub disk_watermark_state: DiskWatermarkState,
     #[serde(rename = "cpuPercent")]
     pub cpu_percent: Option<u32>,
     pub ip: Option<String>,
@@ -944,6 +1170,52 @@ mod tests {
         assert_eq!(format_uptime(0), "0s");
     }
 
+    #[test]
+    fn test_format_bytes_units() {
+        const KIB: u64 = 1024;
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(KIB), "1.0 KiB");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(KIB * KIB - 1), "1.0 MiB");
+        assert_eq!(format_bytes(KIB.pow(2)), "1.0 MiB");
+        assert_eq!(format_bytes(KIB.pow(3) * 3 / 2), "1.5 GiB");
+        assert_eq!(format_bytes(KIB.pow(4) - KIB.pow(3)), "1023.0 GiB");
+        assert_eq!(format_bytes(KIB.pow(4)), "1.0 TiB");
+        assert_eq!(format_bytes(KIB.pow(5) * 2), "2.0 PiB");
+        // PiB is the largest unit
+        assert_eq!(format_bytes(KIB.pow(6)), "1024.0 PiB");
+    }
+
+    #[test]
+    fn test_cluster_stats_humanize() {
+        let stats = json!({
+            "indices": {"count": 1, "docs": {"count": 1}},
+            "nodes": {
+                "jvm": {
+                    "mem": {"heap_used_in_bytes": 1610612736u64, "heap_max_in_bytes": 4294967296u64},
+                    "max_uptime_in_millis": 183600000u64
+                },
+                "fs": {"total_in_bytes": 2199023255552u64, "available_in_bytes": 1099511627776u64}
+            }
+        });
+        let health = json!({"status": "green"});
+
+        let raw = transform_cluster_stats(&stats, &health, None, None).unwrap();
+        assert_eq!(raw.max_uptime_millis, Some(183600000));
+        assert!(raw.disk_used_human.is_none());
+        let serialized = serde_json::to_value(&raw).unwrap();
+        assert!(serialized.get("diskUsedHuman").is_none());
+
+        let human = raw.humanize();
+        assert_eq!(human.memory_used, Some(1610612736));
+        assert_eq!(human.memory_used_human.as_deref(), Some("1.5 GiB"));
+        assert_eq!(human.memory_total_human.as_deref(), Some("4.0 GiB"));
+        assert_eq!(human.disk_used_human.as_deref(), Some("1.0 TiB"));
+        assert_eq!(human.disk_total_human.as_deref(), Some("2.0 TiB"));
+        assert_eq!(human.max_uptime_human.as_deref(), Some("2d 3h"));
+    }
+
     #[test]
     fn test_transform_cluster_stats() {
         let stats = json!({
@@ -1021,7 +1293,13 @@ mod tests {
             }
         });
 
-        let result = transform_nodes(&nodes_info, &nodes_stats, Some("node1"), None);
+        let result = transform_nodes(
+            &nodes_info,
+            &nodes_stats,
+            Some("node1"),
+            None,
+            &DiskWatermarks::default(),
+        );
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].name, "test-node");
@@ -1078,7 +1356,13 @@ mod tests {
             }
         });
 
-        let result = transform_nodes(&nodes_info, &nodes_stats, Some("other-node"), None);
+        let result = transform_nodes(
+            &nodes_info,
+            &nodes_stats,
+            Some("other-node"),
+            None,
+            &DiskWatermarks::default(),
+        );
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].name, "data-node");
@@ -1126,7 +1410,13 @@ mod tests {
             }
         });
 
-        let result = transform_nodes(&nodes_info, &nodes_stats, None, None);
+        let result = transform_nodes(
+            &nodes_info,
+            &nodes_stats,
+            None,
+            None,
+            &DiskWatermarks::default(),
+        );
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].name, "test-node");
@@ -1181,7 +1471,13 @@ mod tests {
             }
         });
 
-        let result = transform_nodes(&nodes_info, &nodes_stats, None, None);
+        let result = transform_nodes(
+            &nodes_info,
+            &nodes_stats,
+            None,
+            None,
+            &DiskWatermarks::default(),
+        );
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].name, "test-node");
@@ -1231,7 +1527,13 @@ mod tests {
             }
         });
 
-        let result = transform_nodes(&nodes_info, &nodes_stats, None, None);
+        let result = transform_nodes(
+            &nodes_info,
+            &nodes_stats,
+            None,
+            None,
+            &DiskWatermarks::default(),
+        );
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].name, "test-node");
@@ -1423,7 +1725,13 @@ mod tests {
         });
 
         let shards = transform_routing_nodes_to_shards(&state);
-        let nodes = transform_nodes(&nodes_info, &nodes_stats, Some("n1"), None);
+        let nodes = transform_nodes(
+            &nodes_info,
+            &nodes_stats,
+            Some("n1"),
+            None,
+            &DiskWatermarks::default(),
+        );
 
         // Build set of node names returned by transform_nodes
         let node_names: std::collections::HashSet<String> =
@@ -1550,6 +1858,402 @@ mod tests {
         assert_eq!(response.total_pages, 0); // 0 items = 0 pages
         assert_eq!(response.items.len(), 0);
     }
+
+    #[test]
+    fn test_transform_snapshot_repositories() {
+        let repos = json!({
+            "s3-backups": {"type": "s3", "settings": {"bucket": "backups"}},
+            "local": {"type": "fs", "settings": {"location": "/mnt/snapshots"}}
+        });
+
+        let result = transform_snapshot_repositories(&repos);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "local");
+        assert_eq!(result[0].repository_type, "fs");
+        assert_eq!(result[1].name, "s3-backups");
+        assert_eq!(result[1].settings["bucket"], "backups");
+    }
+
+    #[test]
+    fn test_transform_snapshots() {
+        let snapshots = json!({
+            "snapshots": [
+                {
+                    "snapshot": "nightly-1",
+                    "state": "SUCCESS",
+                    "start_time": "2024-01-01T00:00:00.000Z",
+                    "start_time_in_millis": 1704067200000u64,
+                    "end_time": "2024-01-01T00:05:00.000Z",
+                    "end_time_in_millis": 1704067500000u64,
+                    "duration_in_millis": 300000,
+                    "indices": ["logs-1", "logs-2"],
+                    "shards": {"total": 4, "failed": 0, "successful": 4}
+                },
+                {
+                    "snapshot": "nightly-2",
+                    "state": "IN_PROGRESS",
+                    "start_time": "2024-01-02T00:00:00.000Z",
+                    "start_time_in_millis": 1704153600000u64,
+                    "indices": ["logs-1"],
+                    "shards": {"total": 2, "failed": 1, "successful": 1}
+                }
+            ],
+            "total": 2,
+            "remaining": 0
+        });
+
+        let result = transform_snapshots(&snapshots);
+
+        assert_eq!(result.len(), 2);
+        // Newest first
+        assert_eq!(result[0].snapshot, "nightly-2");
+        assert_eq!(result[0].state, "IN_PROGRESS");
+        assert_eq!(result[0].end_time, None);
+        assert_eq!(result[0].indices_count, 1);
+        assert_eq!(result[0].failed_shards, 1);
+        assert_eq!(result[1].snapshot, "nightly-1");
+        assert_eq!(result[1].indices_count, 2);
+        assert_eq!(result[1].total_shards, 4);
+        assert_eq!(result[1].duration_millis, Some(300000));
+    }
+
+    #[test]
+    fn test_transform_snapshots_empty() {
+        assert!(transform_snapshots(&json!({"snapshots": []})).is_empty());
+        assert!(transform_snapshots(&json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_transform_index_ilm() {
+        let explain = json!({
+            "indices": {
+                "logs-000001": {
+                    "index": "logs-000001",
+                    "managed": true,
+                    "policy": "logs",
+                    "age": "2.1d",
+                    "phase": "hot",
+                    "phase_time_millis": 1704067200000u64,
+                    "action": "rollover",
+                    "step": "check-rollover-ready"
+                }
+            }
+        });
+        let policies = json!({
+            "logs": {
+                "policy": {
+                    "phases": {
+                        "hot": {"min_age": "0ms", "actions": {"rollover": {"max_age": "7d"}}},
+                        "delete": {"min_age": "30d", "actions": {"delete": {}}}
+                    }
+                }
+            }
+        });
+
+        let result = transform_index_ilm(&explain, &policies, "logs-000001").unwrap();
+
+        assert!(result.managed);
+        assert_eq!(result.policy.as_deref(), Some("logs"));
+        assert_eq!(result.phase.as_deref(), Some("hot"));
+        assert_eq!(result.age.as_deref(), Some("2.1d"));
+        assert_eq!(result.action.as_deref(), Some("rollover"));
+        // Warm/cold/frozen are not defined, so delete is next
+        assert_eq!(result.next_phase.as_deref(), Some("delete"));
+        assert_eq!(result.next_phase_min_age.as_deref(), Some("30d"));
+        assert_eq!(result.next_action.as_deref(), Some("delete"));
+    }
+
+    #[test]
+    fn test_transform_index_ilm_next_action_follows_execution_order() {
+        let policies = json!({
+            "logs": {
+                "policy": {
+                    "phases": {
+                        "hot": {"actions": {
+                            "rollover": {"max_age": "7d"},
+                            "forcemerge": {"max_num_segments": 1},
+                            "set_priority": {"priority": 100}
+                        }},
+                        "warm": {"min_age": "7d", "actions": {
+                            "shrink": {"number_of_shards": 1},
+                            "allocate": {"number_of_replicas": 1}
+                        }}
+                    }
+                }
+            }
+        });
+        let next_action = |phase: &str, action: &str| {
+            let explain = json!({
+                "indices": {
+                    "logs-000001": {
+                        "index": "logs-000001",
+                        "managed": true,
+                        "policy": "logs",
+                        "phase": phase,
+                        "action": action
+                    }
+                }
+            });
+            transform_index_ilm(&explain, &policies, "logs-000001")
+                .unwrap()
+                .next_action
+        };
+
+        // Remaining actions of the current phase run first, in ES order
+        assert_eq!(next_action("hot", "new").as_deref(), Some("set_priority"));
+        assert_eq!(
+            next_action("hot", "set_priority").as_deref(),
+            Some("rollover")
+        );
+        assert_eq!(
+            next_action("hot", "rollover").as_deref(),
+            Some("forcemerge")
+        );
+        // Then the first action of the next phase, not the alphabetically first
+        assert_eq!(
+            next_action("hot", "forcemerge").as_deref(),
+            Some("allocate")
+        );
+        assert_eq!(next_action("hot", "complete").as_deref(), Some("allocate"));
+        assert_eq!(next_action("warm", "allocate").as_deref(), Some("shrink"));
+        assert_eq!(next_action("warm", "complete"), None);
+        assert_eq!(next_action("hot", "custom_action"), None);
+    }
+
+    #[test]
+    fn test_transform_index_ilm_unmanaged() {
+        let explain = json!({
+            "indices": {
+                "plain": {"index": "plain", "managed": false}
+            }
+        });
+
+        let result = transform_index_ilm(&explain, &json!({}), "plain").unwrap();
+
+        assert!(!result.managed);
+        assert_eq!(result.policy, None);
+        assert_eq!(result.next_phase, None);
+        assert!(transform_index_ilm(&explain, &json!({}), "missing").is_none());
+    }
+
+    #[test]
+    fn test_transform_cluster_settings_layers() {
+        let settings = json!({
+            "persistent": {
+                "cluster": {"routing": {"allocation": {"enable": "primaries"}}},
+                "indices.recovery.max_bytes_per_sec": "100mb"
+            },
+            "transient": {
+                "cluster.routing.allocation.enable": "none"
+            },
+            "defaults": {
+                "cluster": {
+                    "routing": {"allocation": {"enable": "all"}},
+                    "max_shards_per_node": "1000"
+                },
+                "indices": {"recovery": {"max_bytes_per_sec": "40mb"}},
+                "discovery": {"seed_hosts": []}
+            }
+        });
+
+        let result = transform_cluster_settings(&settings);
+        let find = |key: &str| {
+            result
+                .settings
+                .iter()
+                .find(|s| s.key == key)
+                .unwrap()
+                .clone()
+        };
+
+        let enable = find("cluster.routing.allocation.enable");
+        assert_eq!(enable.value, json!("none"));
+        assert_eq!(enable.source, ClusterSettingSource::Transient);
+        assert_eq!(enable.default_value, Some(json!("all")));
+
+        let recovery = find("indices.recovery.max_bytes_per_sec");
+        assert_eq!(recovery.value, json!("100mb"));
+        assert_eq!(recovery.source, ClusterSettingSource::Persistent);
+
+        let shards = find("cluster.max_shards_per_node");
+        assert_eq!(shards.source, ClusterSettingSource::Default);
+        assert_eq!(shards.default_value, None);
+
+        assert_eq!(find("discovery.seed_hosts").value, json!([]));
+
+        let keys: Vec<&str> = result.settings.iter().map(|s| s.key.as_str()).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+        assert_eq!(keys.len(), 4);
+    }
+
+    #[test]
+    fn test_transform_cluster_settings_without_transient() {
+        // Newer versions may omit the transient layer entirely
+        let settings = json!({
+            "persistent": {"cluster.routing.allocation.enable": "primaries"},
+            "defaults": {}
+        });
+
+        let result = transform_cluster_settings(&settings);
+
+        assert_eq!(result.settings.len(), 1);
+        assert_eq!(result.settings[0].source, ClusterSettingSource::Persistent);
+        assert_eq!(result.settings[0].default_value, None);
+    }
+
+    #[test]
+    fn test_disk_watermarks_from_cluster_settings() {
+        let settings = json!({
+            "persistent": {"cluster.routing.allocation.disk.watermark.high": "0.8"},
+            "transient": {"cluster": {"routing": {"allocation": {"disk": {"watermark": {"flood_stage": "10gb"}}}}}},
+            "defaults": {
+                "cluster": {"routing": {"allocation": {"disk": {"watermark": {
+                    "low": "70%",
+                    "high": "90%",
+                    "flood_stage": "95%"
+                }}}}}
+            }
+        });
+
+        let watermarks = DiskWatermarks::from_cluster_settings(&settings);
+        assert_eq!(watermarks.low, DiskWatermark::UsedPercent(70.0));
+        assert_eq!(watermarks.high, DiskWatermark::UsedPercent(80.0));
+        assert_eq!(watermarks.flood_stage, DiskWatermark::FreeBytes(10 << 30));
+
+        // Missing or invalid settings fall back to the Elasticsearch defaults
+        assert_eq!(
+            DiskWatermarks::from_cluster_settings(&json!({
+                "persistent": {"cluster.routing.allocation.disk.watermark.low": "lots"}
+            })),
+            DiskWatermarks::default()
+        );
+    }
+
+    #[test]
+    fn test_node_disk_watermark_state() {
+        let gb = 1u64 << 30;
+        let node = |name: &str, used: u64| {
+            json!({
+                "name": name,
+                "fs": {"total": {"total_in_bytes": 100 * gb, "available_in_bytes": (100 - used) * gb}}
+            })
+        };
+        let nodes_info = json!({
+            "nodes": {
+                "n1": {"name": "ok", "roles": ["data"]},
+                "n2": {"name": "low", "roles": ["data"]},
+                "n3": {"name": "high", "roles": ["data"]},
+                "n4": {"name": "flood", "roles": ["data"]},
+                "n5": {"name": "no-disk", "roles": []}
+            }
+        });
+        let nodes_stats = json!({
+            "nodes": {
+                "n1": node("ok", 85),
+                "n2": node("low", 86),
+                "n3": node("high", 91),
+                "n4": node("flood", 96)
+            }
+        });
+
+        let states = |watermarks: &DiskWatermarks| {
+            let mut states: Vec<(String, DiskWatermarkState)> =
+                transform_nodes(&nodes_info, &nodes_stats, None, None, watermarks)
+                    .into_iter()
+                    .map(|node| (node.name, node.disk_watermark_state))
+                    .collect();
+            states.sort_by(|a, b| a.0.cmp(&b.0));
+            states
+        };
+
+        assert_eq!(
+            states(&DiskWatermarks::default()),
+            vec![
+                ("flood".to_string(), DiskWatermarkState::Flood),
+                ("high".to_string(), DiskWatermarkState::High),
+                ("low".to_string(), DiskWatermarkState::Low),
+                ("no-disk".to_string(), DiskWatermarkState::Ok),
+                ("ok".to_string(), DiskWatermarkState::Ok),
+            ]
+        );
+
+        // Byte watermarks compare against the free space left
+        let watermarks = DiskWatermarks {
+            flood_stage: DiskWatermark::FreeBytes(10 * gb),
+            ..DiskWatermarks::default()
+        };
+        assert_eq!(states(&watermarks)[2].1, DiskWatermarkState::Low);
+        assert_eq!(states(&watermarks)[1].1, DiskWatermarkState::Flood);
+        assert_eq!(
+            serde_json::to_value(DiskWatermarkState::Flood).unwrap(),
+            json!("flood")
+        );
+    }
+
+    #[test]
+    fn test_disk_watermark_max_headroom() {
+        let gb = 1u64 << 30;
+        // The ES 8.5+ defaults, as reported with `include_defaults=true`
+        let watermarks = DiskWatermarks::from_cluster_settings(&json!({
+            "defaults": {"cluster": {"routing": {"allocation": {"disk": {"watermark": {
+                "low": "85%",
+                "low.max_headroom": "200gb",
+                "high": "90%",
+                "high.max_headroom": "150gb",
+                "flood_stage": "95%",
+                "flood_stage.max_headroom": "100gb"
+            }}}}}}
+        }));
+        assert_eq!(watermarks.low_max_headroom, Some(200 * gb));
+        assert_eq!(watermarks.flood_stage_max_headroom, Some(100 * gb));
+
+        // A 10 TB disk at 96% used still has 400 GB free
+        let total = 10_240 * gb;
+        assert_eq!(
+            watermarks.state(total - 400 * gb, total),
+            DiskWatermarkState::Ok
+        );
+        assert_eq!(
+            watermarks.state(total - 120 * gb, total),
+            DiskWatermarkState::High
+        );
+        assert_eq!(
+            watermarks.state(total - 50 * gb, total),
+            DiskWatermarkState::Flood
+        );
+        // Without the caps the same disk is past the flood stage
+        assert_eq!(
+            DiskWatermarks::default().state(total - 400 * gb, total),
+            DiskWatermarkState::Flood
+        );
+
+        // Small disks reach the percentage first
+        assert_eq!(
+            watermarks.state(96 * gb, 100 * gb),
+            DiskWatermarkState::Flood
+        );
+
+        // An explicit `-1` disables the cap
+        let watermarks = DiskWatermarks::from_cluster_settings(&json!({
+            "persistent": {"cluster.routing.allocation.disk.watermark.flood_stage.max_headroom": "-1"},
+            "defaults": {"cluster.routing.allocation.disk.watermark.flood_stage.max_headroom": "100gb"}
+        }));
+        assert_eq!(watermarks.flood_stage_max_headroom, None);
+    }
+
+    #[test]
+    fn test_parse_warning_header() {
+        assert_eq!(
+            parse_warning_header(
+                r#"299 Elasticsearch-8.11.0-abc "[transient settings removal] Updating cluster settings through transientSettings is deprecated. Use persistent settings instead.""#
+            ),
+            "[transient settings removal] Updating cluster settings through transientSettings is deprecated. Use persistent settings instead."
+        );
+        assert_eq!(parse_warning_header(" plain text "), "plain text");
+    }
 }
 
 /// Node detail stats response for frontend
@@ -1579,6 +2283,8 @@ pub struct NodeDetailStatsResponse {
     pub disk_total: u64,
     #[serde(rename = "diskPercent")]
     pub disk_percent: u32,
+    #[serde(rename = "diskWatermarkState")]
+    pub disk_watermark_state: DiskWatermarkState,
     #[serde(rename = "cpuPercent")]
     pub cpu_percent: u32,
     pub ip: Option<String>,
@@ -1718,6 +2424,355 @@ pub fn aggregate_shards_by_node(cat_shards: &Value) -> Vec<NodeShardSummary> {
     result
 }
 
+/// Snapshot repository summary for frontend
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SnapshotRepositoryResponse {
+    pub name: String,
+    /// Repository type (e.g. "fs", "s3", "gcs")
+    #[serde(rename = "type")]
+    pub repository_type: String,
+    /// Raw repository settings as returned by Elasticsearch
+    #[schema(value_type = Object)]
+    pub settings: Value,
+}
+
+/// Snapshot summary for frontend
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SnapshotInfoResponse {
+    pub snapshot: String,
+    /// Snapshot state (SUCCESS, IN_PROGRESS, PARTIAL, FAILED, INCOMPATIBLE)
+    pub state: String,
+    #[serde(rename = "startTime")]
+    pub start_time: Option<String>,
+    #[serde(rename = "startTimeMillis")]
+    pub start_time_millis: Option<u64>,
+    #[serde(rename = "endTime")]
+    pub end_time: Option<String>,
+    #[serde(rename = "endTimeMillis")]
+    pub end_time_millis: Option<u64>,
+    #[serde(rename = "durationMillis")]
+    pub duration_millis: Option<u64>,
+    #[serde(rename = "indicesCount")]
+    pub indices_count: u32,
+    #[serde(rename = "totalShards")]
+    pub total_shards: u32,
+    #[serde(rename = "failedShards")]
+    pub failed_shards: u32,
+}
+
+/// Transform `GET /_snapshot` response into a list of repositories sorted by name
+pub fn transform_snapshot_repositories(repositories: &Value) -> Vec<SnapshotRepositoryResponse> {
+    let mut result: Vec<SnapshotRepositoryResponse> = repositories
+        .as_object()
+        .map(|repos| {
+            repos
+                .iter()
+                .map(|(name, repo)| SnapshotRepositoryResponse {
+                    name: name.clone(),
+                    repository_type: repo["type"].as_str().unwrap_or("unknown").to_string(),
+                    settings: repo.get("settings").cloned().unwrap_or(Value::Null),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    result.sort_by(|a, b| a.name.cmp(&b.name));
+    result
+}
+
+/// Transform `GET /_snapshot/{repo}/_all` response into frontend snapshot summaries
+///
+/// Snapshots are returned newest first (by start time).
+pub fn transform_snapshots(snapshots: &Value) -> Vec<SnapshotInfoResponse> {
+    let mut result: Vec<SnapshotInfoResponse> = snapshots["snapshots"]
+        .as_array()
+        .map(|entries| {
+            entries
+                .iter()
+                .map(|entry| SnapshotInfoResponse {
+                    snapshot: entry["snapshot"].as_str().unwrap_or("").to_string(),
+                    state: entry["state"].as_str().unwrap_or("UNKNOWN").to_string(),
+                    start_time: entry["start_time"].as_str().map(|s| s.to_string()),
+                    start_time_millis: entry["start_time_in_millis"].as_u64(),
+                    end_time: entry["end_time"].as_str().map(|s| s.to_string()),
+                    end_time_millis: entry["end_time_in_millis"].as_u64(),
+                    duration_millis: entry["duration_in_millis"].as_u64(),
+                    indices_count: entry["indices"]
+                        .as_array()
+                        .map(|a| a.len() as u32)
+                        .unwrap_or(0),
+                    total_shards: entry["shards"]["total"].as_u64().unwrap_or(0) as u32,
+                    failed_shards: entry["shards"]["failed"].as_u64().unwrap_or(0) as u32,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    result.sort_by_key(|s| std::cmp::Reverse(s.start_time_millis));
+    result
+}
+
+/// ILM phases in the order Elasticsearch executes them
+const ILM_PHASE_ORDER: [&str; 5] = ["hot", "warm", "cold", "frozen", "delete"];
+
+/// ILM actions of a phase in the order Elasticsearch executes them
+fn ilm_action_order(phase: &str) -> &'static [&'static str] {
+    match phase {
+        "hot" => &[
+            "set_priority",
+            "unfollow",
+            "rollover",
+            "read_only",
+            "downsample",
+            "shrink",
+            "forcemerge",
+            "searchable_snapshot",
+        ],
+        "warm" => &[
+            "set_priority",
+            "unfollow",
+            "read_only",
+            "downsample",
+            "allocate",
+            "migrate",
+            "shrink",
+            "forcemerge",
+        ],
+        "cold" => &[
+            "set_priority",
+            "unfollow",
+            "read_only",
+            "downsample",
+            "searchable_snapshot",
+            "allocate",
+            "migrate",
+            "freeze",
+        ],
+        "frozen" => &["unfollow", "searchable_snapshot"],
+        "delete" => &["wait_for_snapshot", "delete"],
+        _ => &[],
+    }
+}
+
+/// First of `actions` that the phase definition `phase_def` configures
+fn first_configured_action(actions: &[&str], phase_def: &Value) -> Option<String> {
+    actions
+        .iter()
+        .find(|action| phase_def["actions"].get(**action).is_some())
+        .map(|action| action.to_string())
+}
+
+/// Index lifecycle management state for a single index
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct IndexIlmResponse {
+    pub index: String,
+    /// Whether the index is managed by an ILM policy
+    pub managed: bool,
+    pub policy: Option<String>,
+    /// Current phase (hot, warm, cold, frozen, delete)
+    pub phase: Option<String>,
+    /// Time the index entered the current phase (epoch millis)
+    #[serde(rename = "phaseTimeMillis")]
+    pub phase_time_millis: Option<u64>,
+    /// Index age relative to its lifecycle date, as reported by Elasticsearch (e.g. "2.5d")
+    pub age: Option<String>,
+    /// Action currently executing within the phase
+    pub action: Option<String>,
+    /// Step currently executing within the action
+    pub step: Option<String>,
+    /// Step that failed, when the index is in the ERROR step
+    #[serde(rename = "failedStep")]
+    pub failed_step: Option<String>,
+    /// Next phase defined by the policy after the current one
+    #[serde(rename = "nextPhase")]
+    pub next_phase: Option<String>,
+    /// Minimum index age at which the next phase is entered
+    #[serde(rename = "nextPhaseMinAge")]
+    pub next_phase_min_age: Option<String>,
+    /// Next action the policy runs: the next one of the current phase, or
+    /// the first of the next phase once the current phase is done
+    #[serde(rename = "nextAction")]
+    pub next_action: Option<String>,
+}
+
+/// Transform `GET /{index}/_ilm/explain` and `GET /_ilm/policy/{policy}`
+/// responses into the ILM state of `index`
+///
+/// Returns `None` when `index` is not present in the explain response.
+pub fn transform_index_ilm(
+    explain: &Value,
+    policies: &Value,
+    index: &str,
+) -> Option<IndexIlmResponse> {
+    let entry = explain["indices"].get(index)?;
+
+    let policy = entry["policy"].as_str().map(|s| s.to_string());
+    let phase = entry["phase"].as_str().map(|s| s.to_string());
+
+    let mut response = IndexIlmResponse {
+        index: entry["index"].as_str().unwrap_or(index).to_string(),
+        managed: entry["managed"].as_bool().unwrap_or(false),
+        policy: policy.clone(),
+        phase: phase.clone(),
+        phase_time_millis: entry["phase_time_millis"].as_u64(),
+        age: entry["age"].as_str().map(|s| s.to_string()),
+        action: entry["action"].as_str().map(|s| s.to_string()),
+        step: entry["step"].as_str().map(|s| s.to_string()),
+        failed_step: entry["failed_step"].as_str().map(|s| s.to_string()),
+        next_phase: None,
+        next_phase_min_age: None,
+        next_action: None,
+    };
+
+    let (Some(policy), Some(phase)) = (policy, phase) else {
+        return Some(response);
+    };
+
+    let phases = &policies[policy.as_str()]["policy"]["phases"];
+    let current = ILM_PHASE_ORDER.iter().position(|p| *p == phase);
+
+    if let Some(current) = current {
+        let next = ILM_PHASE_ORDER[current + 1..]
+            .iter()
+            .find(|p| phases.get(**p).is_some());
+
+        if let Some(next) = next {
+            let next_def = &phases[*next];
+            response.next_phase = Some(next.to_string());
+            response.next_phase_min_age =
+                Some(next_def["min_age"].as_str().unwrap_or("0ms").to_string());
+        }
+
+        // Actions of the current phase still to run, after the current one.
+        // "new" is reported before the first action and "complete" once the
+        // phase is done; an unknown action leaves the next one undetermined.
+        let order = ilm_action_order(&phase);
+        let remaining = match response.action.as_deref() {
+            None | Some("new") => Some(order),
+            Some("complete") => Some(&order[order.len()..]),
+            Some(action) => order
+                .iter()
+                .position(|a| *a == action)
+                .map(|i| &order[i + 1..]),
+        };
+        response.next_action = remaining.and_then(|remaining| {
+            first_configured_action(remaining, &phases[phase.as_str()]).or_else(|| {
+                next.and_then(|next| {
+                    first_configured_action(ilm_action_order(next), &phases[*next])
+                })
+            })
+        });
+    }
+
+    Some(response)
+}
+
+/// Settings layer an effective cluster setting value comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ClusterSettingSource {
+    Transient,
+    Persistent,
+    Default,
+}
+
+/// Effective value of a single cluster setting
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ClusterSettingResponse {
+    /// Flat setting key (e.g. "cluster.routing.allocation.enable")
+    pub key: String,
+    /// Effective value, as returned by Elasticsearch (usually a string)
+    #[schema(value_type = Object)]
+    pub value: Value,
+    /// Layer the effective value comes from
+    pub source: ClusterSettingSource,
+    /// Default value, when the effective value overrides it
+    #[serde(rename = "defaultValue", skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub default_value: Option<Value>,
+}
+
+/// Effective cluster settings, sorted by key
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ClusterSettingsResponse {
+    pub settings: Vec<ClusterSettingResponse>,
+}
+
+/// Merge the layers of a `GET /_cluster/settings?include_defaults=true` response
+///
+/// Transient settings win over persistent ones, which win over defaults.
+/// Nested and flat (`flat_settings=true`) responses are both accepted.
+pub fn transform_cluster_settings(settings: &Value) -> ClusterSettingsResponse {
+    let defaults = flatten_settings(&settings["defaults"]);
+    let mut effective: std::collections::BTreeMap<String, (Value, ClusterSettingSource)> = defaults
+        .iter()
+        .map(|(key, value)| (key.clone(), (value.clone(), ClusterSettingSource::Default)))
+        .collect();
+
+    for (layer, source) in [
+        ("persistent", ClusterSettingSource::Persistent),
+        ("transient", ClusterSettingSource::Transient),
+    ] {
+        for (key, value) in flatten_settings(&settings[layer]) {
+            effective.insert(key, (value, source));
+        }
+    }
+
+    ClusterSettingsResponse {
+        settings: effective
+            .into_iter()
+            .map(|(key, (value, source))| ClusterSettingResponse {
+                default_value: match source {
+                    ClusterSettingSource::Default => None,
+                    _ => defaults.get(&key).cloned(),
+                },
+                key,
+                value,
+                source,
+            })
+            .collect(),
+    }
+}
+
+/// Flatten a settings object into dotted keys
+///
+/// Arrays and scalars are leaves; empty objects produce no keys.
+pub fn flatten_settings(settings: &Value) -> std::collections::BTreeMap<String, Value> {
+    fn walk(prefix: &str, value: &Value, out: &mut std::collections::BTreeMap<String, Value>) {
+        match value.as_object() {
+            Some(map) => {
+                for (key, child) in map {
+                    let path = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    walk(&path, child, out);
+                }
+            }
+            None if !prefix.is_empty() => {
+                out.insert(prefix.to_string(), value.clone());
+            }
+            None => {}
+        }
+    }
+
+    let mut out = std::collections::BTreeMap::new();
+    walk("", settings, &mut out);
+    out
+}
+
+/// Extract the message from an HTTP `Warning` header value
+///
+/// Elasticsearch sends `299 Elasticsearch-<version> "<message>"`; anything
+/// else is returned as is.
+pub fn parse_warning_header(header: &str) -> String {
+    match (header.find('"'), header.rfind('"')) {
+        (Some(start), Some(end)) if end > start => header[start + 1..end].replace("\\\"", "\""),
+        _ => header.trim().to_string(),
+    }
+}
+
 /// Indexing statistics
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct IndexingStats {
@@ -1974,6 +3029,7 @@ pub fn transform_node_detail_stats(
     node_stats: &Value,
     cluster_state: &Value,
     shards: Option<&Value>,
+    watermarks: &DiskWatermarks,
 ) -> Result<NodeDetailStatsResponse, anyhow::Error> {
     // Get node info and stats
     let node_info = &nodes_info["nodes"][node_id];
@@ -2078,6 +3134,7 @@ pub fn transform_node_detail_stats(
         disk_used,
         disk_total,
         disk_percent,
+        disk_watermark_state: watermarks.state(disk_used, disk_total),
         cpu_percent,
         ip: node_info["ip"].as_str().map(|s| s.to_string()),
         version: node_info["version"].as_str().map(|s| s.to_string()),
@@ -1,9 +1,13 @@
-use axum::{extract::Path, response::IntoResponse, Json};
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
 use serde::{Deserialize, Serialize};
 
-use crate::cluster::client::ElasticsearchClient;
 use crate::routes::cluster_client::ClusterClient;
-use crate::routes::clusters::ClusterErrorResponse;
+use crate::routes::ClusterState;
+use crate::routes::{ApiError, ErrorCode};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ComponentTemplatesResponse {
@@ -39,48 +43,68 @@ pub struct PutComponentTemplateRequest {
 }
 
 pub async fn list_component_templates(
-    ClusterClient { client, .. }: ClusterClient,
-) -> Result<impl IntoResponse, ClusterErrorResponse> {
-    let response = client.get_component_templates().await.map_err(|e| {
-        ClusterErrorResponse::simple("es_request_failed", format!("ES request failed: {}", e))
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|_| ApiError::cluster_not_found(&cluster_id))?;
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
+    }
+
+    let response = cluster.component_templates().await.map_err(|e| {
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("ES request failed: {}", e),
+        )
+        .with_cluster(&cluster_id)
     })?;
 
-    let mut templates = Vec::new();
-    if let Some(components) = response
+    Ok(Json(ComponentTemplatesResponse {
+        component_templates: transform_component_templates(&response),
+    }))
+}
+
+/// Transform a `GET /_component_template` response
+fn transform_component_templates(response: &serde_json::Value) -> Vec<ComponentTemplateSummary> {
+    response
         .get("component_templates")
         .and_then(|v| v.as_array())
-    {
-        for comp in components {
-            let name = comp
+        .into_iter()
+        .flatten()
+        .map(|comp| ComponentTemplateSummary {
+            name: comp
                 .get("name")
                 .and_then(|v| v.as_str())
                 .unwrap_or_default()
-                .to_string();
-            let version = comp
+                .to_string(),
+            version: comp
+                .get("component_template")
+                .unwrap_or(comp)
                 .get("version")
                 .and_then(|v| v.as_i64())
-                .map(|v| v as i32);
-
-            templates.push(ComponentTemplateSummary { name, version });
-        }
-    }
-
-    Ok(Json(ComponentTemplatesResponse {
-        component_templates: templates,
-    }))
+                .map(|v| v as i32),
+        })
+        .collect()
 }
 
 pub async fn get_component_template(
     ClusterClient { client, .. }: ClusterClient,
     Path((_, name)): Path<(String, String)>,
-) -> Result<impl IntoResponse, ClusterErrorResponse> {
+) -> Result<impl IntoResponse, ApiError> {
     let response = client.get_component_template(&name).await.map_err(|e| {
-        ClusterErrorResponse::simple("es_request_failed", format!("ES request failed: {}", e))
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("ES request failed: {}", e),
+        )
     })?;
 
     let detail = serde_json::from_value::<ComponentTemplateDetail>(response).map_err(|e| {
-        ClusterErrorResponse::simple(
-            "parse_error",
+        ApiError::new(
+            ErrorCode::UpstreamError,
             format!("Failed to parse component template response: {}", e),
         )
     })?;
@@ -92,7 +116,7 @@ pub async fn put_component_template(
     ClusterClient { client, .. }: ClusterClient,
     Path((_, name)): Path<(String, String)>,
     Json(body): Json<PutComponentTemplateRequest>,
-) -> Result<impl IntoResponse, ClusterErrorResponse> {
+) -> Result<impl IntoResponse, ApiError> {
     let mut request_body = serde_json::Map::new();
     if let Some(template) = body.template {
         request_body.insert("template".to_string(), template);
@@ -108,11 +132,17 @@ pub async fn put_component_template(
         .put_component_template(&name, serde_json::Value::Object(request_body))
         .await
         .map_err(|e| {
-            ClusterErrorResponse::simple("es_request_failed", format!("ES request failed: {}", e))
+            ApiError::new(
+                ErrorCode::UpstreamError,
+                format!("ES request failed: {}", e),
+            )
         })?;
 
     let detail = serde_json::from_value::<ComponentTemplateDetail>(response).map_err(|e| {
-        ClusterErrorResponse::simple("parse_error", format!("Failed to parse response: {}", e))
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("Failed to parse response: {}", e),
+        )
     })?;
 
     Ok(Json(detail))
@@ -121,10 +151,36 @@ pub async fn put_component_template(
 pub async fn delete_component_template(
     ClusterClient { client, .. }: ClusterClient,
     Path((_, name)): Path<(String, String)>,
-) -> Result<impl IntoResponse, ClusterErrorResponse> {
+) -> Result<impl IntoResponse, ApiError> {
     let response = client.delete_component_template(&name).await.map_err(|e| {
-        ClusterErrorResponse::simple("es_request_failed", format!("ES request failed: {}", e))
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("ES request failed: {}", e),
+        )
     })?;
 
     Ok(Json(response))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_transform_component_templates() {
+        let response = json!({
+            "component_templates": [
+                {"name": "logs-mappings", "component_template": {"version": 2, "template": {}}},
+                {"name": "logs-settings", "component_template": {"template": {}}}
+            ]
+        });
+
+        let templates = transform_component_templates(&response);
+
+        assert_eq!(templates.len(), 2);
+        assert_eq!(templates[0].name, "logs-mappings");
+        assert_eq!(templates[0].version, Some(2));
+        assert_eq!(templates[1].version, None);
+    }
+}
@@ -0,0 +1,349 @@
+//! Structured error responses for API handlers
+//! Every error carries a stable `code` the frontend can branch on; the
+//! HTTP status is derived from the code so handlers cannot disagree on it
+
+use crate::cluster::{ClusterConnection, ClusterRequestError, ConcurrencyLimitError};
+use axum::{
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Stable, machine-readable error kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// The request is malformed or was rejected as invalid
+    ValidationFailed,
+    /// No valid session
+    AuthenticationRequired,
+    /// The user may not perform this operation
+    AccessDenied,
+    /// Secan runs in read-only mode and rejects changes
+    ReadOnlyMode,
+    /// No cluster with the requested id is configured
+    ClusterNotFound,
+    /// An index, task or other resource on the cluster does not exist
+    NotFound,
+    /// The request or the cluster's response is over the size limit
+    PayloadTooLarge,
+    /// Too many concurrent requests; retry later
+    RateLimited,
+    /// Too many requests are in flight to the cluster; retry later
+    ClusterBusy,
+    /// The cluster is configured but cannot be used right now
+    ClusterUnavailable,
+    /// The cluster is disabled in configuration
+    ClusterDisabled,
+    /// Requests are paused after repeated connection failures
+    CircuitOpen,
+    /// The operation is not available for this cluster
+    NotSupported,
+    /// Secan is missing configuration the operation needs
+    ConfigurationError,
+    /// The cluster rejected the credentials Secan is configured with
+    ClusterAuthenticationFailed,
+    /// Secan's credentials for the cluster lack a required privilege
+    ClusterPermissionDenied,
+    /// No node of the cluster could be connected to
+    ClusterUnreachable,
+    /// The cluster failed the request or returned an unusable response
+    UpstreamError,
+    /// The cluster did not answer in time
+    UpstreamTimeout,
+    /// An unexpected failure inside Secan
+    InternalError,
+}
+
+impl ErrorCode {
+    /// HTTP status returned for this kind of error
+    pub fn status(self) -> StatusCode {
+        match self {
+            Self::ValidationFailed | Self::NotSupported => StatusCode::BAD_REQUEST,
+            Self::AuthenticationRequired => StatusCode::UNAUTHORIZED,
+            Self::AccessDenied | Self::ReadOnlyMode => StatusCode::FORBIDDEN,
+            Self::ClusterNotFound | Self::NotFound => StatusCode::NOT_FOUND,
+            Self::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            Self::ClusterUnavailable
+            | Self::ClusterDisabled
+            | Self::CircuitOpen
+            | Self::ClusterBusy => StatusCode::SERVICE_UNAVAILABLE,
+            // Not 401/403: the user's own session and permissions are fine
+            Self::ClusterAuthenticationFailed
+            | Self::ClusterPermissionDenied
+            | Self::ClusterUnreachable
+            | Self::UpstreamError => StatusCode::BAD_GATEWAY,
+            Self::UpstreamTimeout => StatusCode::GATEWAY_TIMEOUT,
+            Self::ConfigurationError | Self::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// Error response body: `{code, message, cluster_id?}`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiError {
+    pub code: ErrorCode,
+    pub message: String,
+    /// Cluster the request was for, when there is one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_id: Option<String>,
+    /// Sent as the `Retry-After` header (seconds), not in the body
+    #[serde(skip)]
+    pub retry_after_secs: Option<u64>,
+}
+
+impl ApiError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            cluster_id: None,
+            retry_after_secs: None,
+        }
+    }
+
+    /// Attach the cluster the request was for
+    pub fn with_cluster(mut self, cluster_id: &str) -> Self {
+        self.cluster_id = Some(cluster_id.to_string());
+        self
+    }
+
+    /// Ask the client to retry after `secs` seconds
+    pub fn with_retry_after(mut self, secs: u64) -> Self {
+        self.retry_after_secs = Some(secs);
+        self
+    }
+
+    /// A request that waited too long for a slot on a busy cluster
+    pub fn cluster_busy(error: &ConcurrencyLimitError) -> Self {
+        Self::new(
+            ErrorCode::ClusterBusy,
+            format!(
+                "Cluster '{}' is busy ({} requests in flight); retry in {}s",
+                error.cluster_id, error.limit, error.retry_after_secs
+            ),
+        )
+        .with_cluster(&error.cluster_id)
+        .with_retry_after(error.retry_after_secs)
+    }
+
+    pub fn cluster_not_found(cluster_id: &str) -> Self {
+        Self::new(
+            ErrorCode::ClusterNotFound,
+            format!("Cluster '{}' not found", cluster_id),
+        )
+        .with_cluster(cluster_id)
+    }
+
+    /// A cluster that is configured but cannot be used, with the reason if known
+    pub fn unavailable(cluster_id: &str, reason: Option<&str>) -> Self {
+        let message = match reason {
+            Some(reason) => format!("Cluster '{}' is inaccessible: {}", cluster_id, reason),
+            None => format!("Cluster '{}' is inaccessible", cluster_id),
+        };
+        Self::new(ErrorCode::ClusterUnavailable, message).with_cluster(cluster_id)
+    }
+
+    pub fn disabled(cluster_id: &str) -> Self {
+        Self::new(
+            ErrorCode::ClusterDisabled,
+            format!("Cluster '{}' is disabled", cluster_id),
+        )
+        .with_cluster(cluster_id)
+    }
+
+    /// A cluster response larger than `limit` bytes
+    pub fn response_too_large(cluster_id: &str, limit: usize) -> Self {
+        Self::new(
+            ErrorCode::PayloadTooLarge,
+            format!(
+                "Elasticsearch response is larger than the {} byte limit",
+                limit
+            ),
+        )
+        .with_cluster(cluster_id)
+    }
+
+    /// A failed call to a cluster, e.g. `upstream(id, "get cluster stats", &e)`
+    ///
+    /// Rejected credentials, missing privileges, unreachable clusters and
+    /// timeouts get their own codes so users know what to look at; other
+    /// failures are `upstream_error` with the error message. A request that
+    /// could not get a slot on a busy cluster is `cluster_busy`.
+    pub fn upstream(cluster_id: &str, operation: &str, error: &anyhow::Error) -> Self {
+        if let Some(busy) = error.downcast_ref::<ConcurrencyLimitError>() {
+            return Self::cluster_busy(busy);
+        }
+        let (code, message) = match ClusterRequestError::classify(error) {
+            Some(ClusterRequestError::AuthenticationFailed) => (
+                ErrorCode::ClusterAuthenticationFailed,
+                format!(
+                    "Failed to {}: cluster '{}' rejected the configured credentials",
+                    operation, cluster_id
+                ),
+            ),
+            Some(ClusterRequestError::PermissionDenied) => (
+                ErrorCode::ClusterPermissionDenied,
+                format!(
+                    "Failed to {}: the credentials configured for cluster '{}' lack the required privileges",
+                    operation, cluster_id
+                ),
+            ),
+            Some(ClusterRequestError::Unreachable(reason)) => (
+                ErrorCode::ClusterUnreachable,
+                format!(
+                    "Failed to {}: cluster '{}' is unreachable: {}",
+                    operation, cluster_id, reason
+                ),
+            ),
+            Some(ClusterRequestError::Timeout) => (
+                ErrorCode::UpstreamTimeout,
+                format!(
+                    "Failed to {}: cluster '{}' did not respond in time",
+                    operation, cluster_id
+                ),
+            ),
+            None => (
+                ErrorCode::UpstreamError,
+                format!("Failed to {}: {}", operation, error),
+            ),
+        };
+        Self::new(code, message).with_cluster(cluster_id)
+    }
+
+    /// Explain why a connection is not accessible
+    ///
+    /// Clusters disabled in configuration get `cluster_disabled`, others
+    /// `cluster_unavailable` with the recorded reason.
+    pub fn inaccessible(cluster: &ClusterConnection) -> Self {
+        if cluster.enabled {
+            Self::unavailable(&cluster.id, cluster.accessible_reason.as_deref())
+        } else {
+            Self::disabled(&cluster.id)
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let retry_after = self.retry_after_secs;
+        let mut response = (self.code.status(), Json(self)).into_response();
+        if let Some(secs) = retry_after {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(secs));
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_error_serialization() {
+        let error = ApiError::cluster_not_found("test");
+
+        let json = serde_json::to_value(&error).expect("serialize ApiError");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "code": "cluster_not_found",
+                "message": "Cluster 'test' not found",
+                "cluster_id": "test"
+            })
+        );
+    }
+
+    #[test]
+    fn test_api_error_without_cluster_omits_cluster_id() {
+        let error = ApiError::new(ErrorCode::ValidationFailed, "Cluster id is empty");
+
+        let json = serde_json::to_value(&error).expect("serialize ApiError");
+        assert!(json.get("cluster_id").is_none());
+        assert_eq!(json["code"], "validation_failed");
+    }
+
+    #[test]
+    fn test_api_error_deserialization() {
+        let json = r#"{"code":"upstream_timeout","message":"Connection timeout"}"#;
+        let error: ApiError = serde_json::from_str(json).expect("deserialize ApiError");
+
+        assert_eq!(error.code, ErrorCode::UpstreamTimeout);
+        assert_eq!(error.message, "Connection timeout");
+        assert_eq!(error.cluster_id, None);
+    }
+
+    #[test]
+    fn test_error_codes_map_to_status() {
+        let cases = [
+            (ErrorCode::ClusterNotFound, StatusCode::NOT_FOUND),
+            (ErrorCode::AccessDenied, StatusCode::FORBIDDEN),
+            (ErrorCode::UpstreamError, StatusCode::BAD_GATEWAY),
+            (ErrorCode::UpstreamTimeout, StatusCode::GATEWAY_TIMEOUT),
+            (
+                ErrorCode::ClusterUnavailable,
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            (ErrorCode::ValidationFailed, StatusCode::BAD_REQUEST),
+            (
+                ErrorCode::ClusterAuthenticationFailed,
+                StatusCode::BAD_GATEWAY,
+            ),
+            (ErrorCode::ClusterUnreachable, StatusCode::BAD_GATEWAY),
+        ];
+        for (code, status) in cases {
+            let response = ApiError::new(code, "x").into_response();
+            assert_eq!(response.status(), status, "{:?}", code);
+        }
+    }
+
+    #[test]
+    fn test_upstream_error_distinguishes_auth_from_network() {
+        let auth = crate::cluster::error::status_error(
+            reqwest::StatusCode::UNAUTHORIZED,
+            "Cluster stats failed with status: 401 Unauthorized".to_string(),
+        );
+        let error = ApiError::upstream("prod", "get cluster stats", &auth);
+        assert_eq!(error.code, ErrorCode::ClusterAuthenticationFailed);
+        assert_eq!(
+            error.message,
+            "Failed to get cluster stats: cluster 'prod' rejected the configured credentials"
+        );
+        assert_eq!(error.cluster_id.as_deref(), Some("prod"));
+
+        let forbidden = crate::cluster::error::status_error(
+            reqwest::StatusCode::FORBIDDEN,
+            "Nodes info failed with status: 403 Forbidden".to_string(),
+        );
+        let error = ApiError::upstream("prod", "get nodes info", &forbidden);
+        assert_eq!(error.code, ErrorCode::ClusterPermissionDenied);
+
+        let other = anyhow::anyhow!("Cluster stats failed with status: 500");
+        let error = ApiError::upstream("prod", "get cluster stats", &other);
+        assert_eq!(error.code, ErrorCode::UpstreamError);
+        assert_eq!(
+            error.message,
+            "Failed to get cluster stats: Cluster stats failed with status: 500"
+        );
+    }
+
+    #[test]
+    fn test_cluster_busy_sets_retry_after() {
+        let busy = anyhow::anyhow!(ConcurrencyLimitError {
+            cluster_id: "prod".to_string(),
+            limit: 20,
+            retry_after_secs: 10,
+        });
+        let error = ApiError::upstream("prod", "get cluster stats", &busy);
+        assert_eq!(error.code, ErrorCode::ClusterBusy);
+
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "10");
+    }
+}
@@ -3,13 +3,13 @@ use axum::{
     Router,
 };
 use serde::{Deserialize, Serialize};
-use std::process::Command;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::instrument;
 use utoipa::ToSchema;
 
 use crate::auth::SessionManager;
+use crate::build_info;
 use crate::cluster::Manager as ClusterManager;
 
 /// Health check response
@@ -19,6 +19,10 @@ pub struct HealthResponse {
     pub status: String,
     #[schema(example = "Server is running")]
     pub message: String,
+    /// Whether Secan rejects cluster changes (`server.read_only`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = false)]
+    pub read_only: Option<bool>,
 }
 
 /// Readiness check response with dependency status
@@ -32,6 +36,23 @@ pub struct ReadinessResponse {
     pub dependencies: Option<DependenciesStatus>,
 }
 
+/// Cluster readiness response for `/health/ready`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ClusterReadinessResponse {
+    #[schema(example = "ready")]
+    pub status: String,
+    #[schema(example = "1 of 2 cluster(s) reachable")]
+    pub message: String,
+    /// Number of enabled clusters that answered a health check
+    #[schema(example = 1)]
+    pub reachable: usize,
+    /// Number of enabled clusters
+    #[schema(example = 2)]
+    pub total: usize,
+    /// Enabled clusters that could not be reached
+    pub unreachable: Vec<ClusterDetail>,
+}
+
 /// Status of external dependencies
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DependenciesStatus {
@@ -82,8 +103,14 @@ pub struct ComponentStatus {
 pub struct VersionResponse {
     #[schema(example = "1.2.28")]
     pub version: String,
-    #[schema(example = "v1.2.28-5-g1234567")]
-    pub git_info: String,
+    /// Short commit hash the binary was built from ("unknown" if not known)
+    #[schema(example = "1a2b3c4")]
+    pub git_sha: String,
+    /// When the binary was built (RFC 3339)
+    #[schema(example = "2026-10-17T08:30:00Z")]
+    pub build_time: String,
+    #[schema(example = "rustc 1.90.0 (1159e78c4 2025-09-14)")]
+    pub rustc_version: String,
 }
 
 /// State for health routes that need access to dependencies
@@ -91,28 +118,35 @@ pub struct VersionResponse {
 pub struct HealthState {
     pub cluster_manager: Arc<ClusterManager>,
     pub session_manager: Arc<SessionManager>,
+    /// Reported by `/health` so the UI can show a read-only banner
+    pub read_only: bool,
 }
 
 /// Create a router for health-related routes with state
 pub fn health_router(
     cluster_manager: Arc<ClusterManager>,
     session_manager: Arc<SessionManager>,
+    read_only: bool,
 ) -> Router {
     let state = HealthState {
         cluster_manager,
         session_manager,
+        read_only,
     };
 
     Router::new()
         .route("/health", get(health_check))
         .route("/ready", get(readiness_check))
+        .route("/health/live", get(liveness_check))
+        .route("/health/ready", get(cluster_readiness_check))
         .route("/api/version", get(get_version))
         .with_state(Arc::new(RwLock::new(state)))
 }
 
 /// Health check endpoint
 ///
-/// Returns HTTP 200 if the server is healthy
+/// Returns HTTP 200 if the server is healthy, and whether it is in
+/// read-only mode
 /// This endpoint does not require authentication
 ///
 /// # Requirements
@@ -126,8 +160,10 @@ pub fn health_router(
         (status = 200, description = "Server is healthy", body = HealthResponse)
     )
 )]
-#[instrument]
-pub async fn health_check() -> (StatusCode, Json<HealthResponse>) {
+#[instrument(skip(state))]
+pub async fn health_check(
+    state: ExtractState<Arc<RwLock<HealthState>>>,
+) -> (StatusCode, Json<HealthResponse>) {
     tracing::debug!("Health check requested");
 
     (
@@ -135,10 +171,104 @@ pub async fn health_check() -> (StatusCode, Json<HealthResponse>) {
         Json(HealthResponse {
             status: "healthy".to_string(),
             message: "Server is running".to_string(),
+            read_only: Some(state.read().await.read_only),
+        }),
+    )
+}
+
+/// Liveness probe endpoint
+///
+/// Returns HTTP 200 as long as the process is up and serving requests.
+/// Dependencies are not checked, so a cluster outage never restarts the pod.
+/// This endpoint does not require authentication
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "Health",
+    responses(
+        (status = 200, description = "Process is alive", body = HealthResponse)
+    )
+)]
+#[instrument]
+pub async fn liveness_check() -> (StatusCode, Json<HealthResponse>) {
+    (
+        StatusCode::OK,
+        Json(HealthResponse {
+            status: "alive".to_string(),
+            message: "Process is running".to_string(),
+            read_only: None,
         }),
     )
 }
 
+/// Readiness probe endpoint
+///
+/// Returns HTTP 200 once the cluster manager is initialized and at least one
+/// enabled cluster is reachable, otherwise 503 listing unreachable clusters.
+/// Cluster health is served from the health cache, and failed checks are
+/// remembered as long as red health, so frequent probes do not each hit (or
+/// wait on) the clusters.
+/// This endpoint does not require authentication
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "Health",
+    responses(
+        (status = 200, description = "At least one cluster is reachable", body = ClusterReadinessResponse),
+        (status = 503, description = "No cluster is reachable", body = ClusterReadinessResponse)
+    )
+)]
+#[instrument(skip(state))]
+pub async fn cluster_readiness_check(
+    state: ExtractState<Arc<RwLock<HealthState>>>,
+) -> impl IntoResponse {
+    let cluster_manager = state.read().await.cluster_manager.clone();
+
+    let clusters: Vec<_> = cluster_manager
+        .list_clusters()
+        .await
+        .into_iter()
+        .filter(|cluster| cluster.enabled)
+        .collect();
+    let ids: Vec<String> = clusters.iter().map(|cluster| cluster.id.clone()).collect();
+    let mut health = cluster_manager.check_health_for_probe(&ids).await;
+
+    let mut unreachable = Vec::new();
+    for cluster in clusters {
+        if let Some(Err(e)) = health.remove(&cluster.id) {
+            unreachable.push(ClusterDetail {
+                id: cluster.id,
+                name: cluster.name,
+                status: "unreachable".to_string(),
+                error: Some(e.to_string()),
+            });
+        }
+    }
+
+    let total = ids.len();
+    let reachable = total - unreachable.len();
+    let is_ready = reachable > 0;
+    if !is_ready {
+        tracing::debug!(total, "Readiness check failed: no cluster reachable");
+    }
+
+    let response = ClusterReadinessResponse {
+        status: if is_ready { "ready" } else { "not_ready" }.to_string(),
+        message: format!("{} of {} cluster(s) reachable", reachable, total),
+        reachable,
+        total,
+        unreachable,
+    };
+
+    let status = if is_ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status, Json(response))
+}
+
 /// Readiness check endpoint
 ///
 /// Returns HTTP 200 if the server is ready to accept requests
@@ -268,7 +398,8 @@ fn check_session_manager_health(_session_manager: &SessionManager) -> ComponentS
 
 /// Version endpoint
 ///
-/// Returns the current version and git information
+/// Returns the version, git commit, build time and compiler of the running
+/// binary, all embedded at build time.
 /// This endpoint does not require authentication
 #[utoipa::path(
     get,
@@ -282,40 +413,15 @@ fn check_session_manager_health(_session_manager: &SessionManager) -> ComponentS
 pub async fn get_version() -> (StatusCode, Json<VersionResponse>) {
     tracing::debug!("Version check requested");
 
-    let version = env!("CARGO_PKG_VERSION").to_string();
-
-    // Try to get git info (tag or branch)
-    let git_info = get_git_info().unwrap_or_else(|_| "unknown".to_string());
-
-    (StatusCode::OK, Json(VersionResponse { version, git_info }))
-}
-
-/// Get git information from the current commit
-/// Tries to get tag first, falls back to branch name
-fn get_git_info() -> Result<String, Box<dyn std::error::Error>> {
-    // Try to get the latest tag that matches v*.*.* pattern
-    let tag_output = Command::new("git")
-        .args(["describe", "--tags", "--match", "v*.*.*", "--exact-match"])
-        .output();
-
-    if let Ok(output) = tag_output {
-        if output.status.success() {
-            let tag = String::from_utf8(output.stdout)?.trim().to_string();
-            return Ok(tag);
-        }
-    }
-
-    // Fall back to branch name
-    let branch_output = Command::new("git")
-        .args(["rev-parse", "--abbrev-ref", "HEAD"])
-        .output()?;
-
-    if branch_output.status.success() {
-        let branch = String::from_utf8(branch_output.stdout)?.trim().to_string();
-        return Ok(branch);
-    }
-
-    Err("Could not determine git info".into())
+    (
+        StatusCode::OK,
+        Json(VersionResponse {
+            version: build_info::VERSION.to_string(),
+            git_sha: build_info::GIT_SHA.to_string(),
+            build_time: build_info::BUILD_TIME.to_string(),
+            rustc_version: build_info::RUSTC_VERSION.to_string(),
+        }),
+    )
 }
 
 #[cfg(test)]
@@ -326,11 +432,32 @@ mod tests {
 
     #[tokio::test]
     async fn test_health_check() {
-        let (status, response) = health_check().await;
+        let cluster_manager = ClusterManager::new(
+            vec![crate::config::ClusterConfig {
+                id: "test".to_string(),
+                nodes: vec!["http://127.0.0.1:9".to_string()],
+                detect_version: false,
+                ..Default::default()
+            }],
+            std::time::Duration::from_secs(30),
+        )
+        .await
+        .expect("create cluster manager");
+        let state = Arc::new(RwLock::new(HealthState {
+            cluster_manager: Arc::new(cluster_manager),
+            session_manager: Arc::new(SessionManager::new(crate::auth::SessionConfig::new(
+                60,
+                TEST_SECRET.to_string(),
+            ))),
+            read_only: true,
+        }));
+
+        let (status, response) = health_check(ExtractState(state)).await;
 
         assert_eq!(status, StatusCode::OK);
         assert_eq!(response.status, "healthy");
         assert_eq!(response.message, "Server is running");
+        assert_eq!(response.read_only, Some(true));
     }
 
     #[tokio::test]
@@ -357,6 +484,7 @@ mod tests {
         let state = HealthState {
             cluster_manager: Arc::new(cluster_manager),
             session_manager: Arc::new(session_manager),
+            read_only: false,
         };
 
         let locked_state = Arc::new(RwLock::new(state));
@@ -372,11 +500,105 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_liveness_check() {
+        let (status, response) = liveness_check().await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response.status, "alive");
+    }
+
+    #[tokio::test]
+    async fn test_cluster_readiness_check() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/_cluster/health"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "green"})),
+            )
+            // Repeated probes are answered from the health cache
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let cluster = |id: &str, node: String| crate::config::ClusterConfig {
+            id: id.to_string(),
+            nodes: vec![node],
+            detect_version: false,
+            ..Default::default()
+        };
+        let cluster_manager = ClusterManager::new(
+            vec![
+                cluster("up", server.uri()),
+                cluster("down", "http://127.0.0.1:9".to_string()),
+            ],
+            std::time::Duration::from_secs(30),
+        )
+        .await
+        .expect("create cluster manager");
+        let state = Arc::new(RwLock::new(HealthState {
+            cluster_manager: Arc::new(cluster_manager),
+            session_manager: Arc::new(SessionManager::new(crate::auth::SessionConfig::new(
+                60,
+                TEST_SECRET.to_string(),
+            ))),
+            read_only: false,
+        }));
+
+        for _ in 0..2 {
+            let response = cluster_readiness_check(ExtractState(state.clone()))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .expect("read body");
+            let body: ClusterReadinessResponse = serde_json::from_slice(&body).expect("json");
+            assert_eq!(body.reachable, 1);
+            assert_eq!(body.total, 2);
+            assert_eq!(body.unreachable.len(), 1);
+            assert_eq!(body.unreachable[0].id, "down");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cluster_readiness_check_without_reachable_clusters() {
+        let cluster_manager = ClusterManager::new(
+            vec![crate::config::ClusterConfig {
+                id: "down".to_string(),
+                nodes: vec!["http://127.0.0.1:9".to_string()],
+                detect_version: false,
+                ..Default::default()
+            }],
+            std::time::Duration::from_secs(30),
+        )
+        .await
+        .expect("create cluster manager");
+        let state = Arc::new(RwLock::new(HealthState {
+            cluster_manager: Arc::new(cluster_manager),
+            session_manager: Arc::new(SessionManager::new(crate::auth::SessionConfig::new(
+                60,
+                TEST_SECRET.to_string(),
+            ))),
+            read_only: false,
+        }));
+
+        let response = cluster_readiness_check(ExtractState(state))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
     #[test]
     fn test_health_response_serialization() {
         let response = HealthResponse {
             status: "healthy".to_string(),
             message: "All systems operational".to_string(),
+            read_only: None,
         };
 
         let json = serde_json::to_string(&response).expect("serialize HealthResponse to JSON");
@@ -400,19 +622,23 @@ mod tests {
 
         assert_eq!(status, StatusCode::OK);
         assert_eq!(response.version, env!("CARGO_PKG_VERSION"));
-        // git_info could be tag, branch, or "unknown" depending on environment
-        assert!(!response.git_info.is_empty());
+        // git_sha is "unknown" when built outside a git checkout
+        assert!(!response.git_sha.is_empty());
+        assert!(!response.build_time.is_empty());
     }
 
     #[test]
     fn test_version_response_serialization() {
         let response = VersionResponse {
             version: "1.0.0".to_string(),
-            git_info: "main".to_string(),
+            git_sha: "1a2b3c4".to_string(),
+            build_time: "2026-10-17T08:30:00Z".to_string(),
+            rustc_version: "rustc 1.90.0".to_string(),
         };
 
         let json = serde_json::to_string(&response).expect("serialize VersionResponse to JSON");
         assert!(json.contains("\"version\":\"1.0.0\""));
-        assert!(json.contains("\"git_info\":\"main\""));
+        assert!(json.contains("\"git_sha\":\"1a2b3c4\""));
+        assert!(json.contains("\"build_time\":\"2026-10-17T08:30:00Z\""));
     }
 }
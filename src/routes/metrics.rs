@@ -6,6 +6,7 @@ use crate::metrics::{InternalMetricsService, MetricsService, PrometheusMetricsSe
 use crate::prometheus::client::{
     Client as PrometheusClient, PrometheusConfig as PrometheusClientConfig,
 };
+use crate::routes::{ApiError, ErrorCode};
 use axum::{
     extract::{Path, Query, State},
     routing::get,
@@ -31,9 +32,9 @@ pub fn metrics_router() -> Router<MetricsState> {
         .route("/nodes/{node_id}", get(get_node_metrics))
 }
 
-// Note: MetricsErrorResponse removed in favor of the shared ClusterErrorResponse
-// located in crate::routes::clusters. Handlers that previously returned
-// MetricsErrorResponse now return ClusterErrorResponse so the frontend sees
+// Note: MetricsErrorResponse removed in favor of the shared ApiError
+// located in crate::routes::error. Handlers that previously returned
+// MetricsErrorResponse now return ApiError so the frontend sees
 // a consistent structured error envelope (including cluster_unavailable).
 
 /// Query parameters for metrics requests
@@ -162,9 +163,9 @@ pub struct PrometheusValidationResponse {
     ),
     responses(
         (status = 200, body = ClusterMetricsHistoryResponse),
-        (status = 400, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 401, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 404, body = crate::routes::clusters::ClusterErrorResponse)
+        (status = 400, body = crate::routes::ApiError),
+        (status = 401, body = crate::routes::ApiError),
+        (status = 404, body = crate::routes::ApiError)
     ),
     tag = "Metrics"
 )]
@@ -174,7 +175,7 @@ pub async fn get_cluster_metrics(
     Path(cluster_id): Path<String>,
     Query(params): Query<MetricsQuery>,
     user_ext: Option<axum::Extension<AuthenticatedUser>>,
-) -> Result<Json<ClusterMetricsHistoryResponse>, crate::routes::clusters::ClusterErrorResponse> {
+) -> Result<Json<ClusterMetricsHistoryResponse>, crate::routes::ApiError> {
     debug!("Getting metrics for cluster: {}", cluster_id);
 
     // Get cluster connection with auth check
@@ -184,10 +185,11 @@ pub async fn get_cluster_metrics(
             .get_cluster_with_auth(&cluster_id, Some(&user.0 .0))
             .await
             .map_err(|_| {
-                crate::routes::clusters::ClusterErrorResponse::simple(
-                    "access_denied",
+                ApiError::new(
+                    ErrorCode::AccessDenied,
                     "You do not have access to this cluster or cluster not found",
                 )
+                .with_cluster(&cluster_id)
             })?
     } else {
         // In open mode, no auth required
@@ -195,30 +197,23 @@ pub async fn get_cluster_metrics(
             .cluster_manager
             .get_cluster(&cluster_id)
             .await
-            .map_err(|_| {
-                crate::routes::clusters::ClusterErrorResponse::simple(
-                    "cluster_not_found",
-                    format!("Cluster '{}' not found", cluster_id),
-                )
-            })?
+            .map_err(|_| ApiError::cluster_not_found(&cluster_id))?
     };
 
     // If the cluster connection exists but is marked inaccessible, return a structured
     // cluster_unavailable response so the frontend can show the reason.
     if !cluster_conn.accessible {
-        return Err(crate::routes::clusters::ClusterErrorResponse::unavailable(
-            &cluster_id,
-            cluster_conn.accessible_reason.clone(),
-        ));
+        return Err(ApiError::inaccessible(&cluster_conn));
     }
 
     // Determine time range
     let time_range = if let (Some(start), Some(end)) = (params.start, params.end) {
         TimeRange::new(start, end).map_err(|e| {
-            crate::routes::clusters::ClusterErrorResponse::simple(
-                "invalid_time_range",
+            ApiError::new(
+                ErrorCode::ValidationFailed,
                 format!("Invalid time range: {}", e),
             )
+            .with_cluster(&cluster_id)
         })?
     } else {
         TimeRange::last_24_hours()
@@ -238,19 +233,21 @@ pub async fn get_cluster_metrics(
                 .await
                 .map_err(|e| {
                     error!("Failed to get internal cluster metrics: {}", e);
-                    crate::routes::clusters::ClusterErrorResponse::simple(
-                        "metrics_error",
+                    ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to retrieve metrics: {}", e),
                     )
+                    .with_cluster(&cluster_id)
                 })?
         }
         crate::config::MetricsSource::Prometheus => {
             // Get Prometheus configuration
             let prometheus_config = cluster_conn.prometheus.as_ref().ok_or_else(|| {
-                crate::routes::clusters::ClusterErrorResponse::simple(
-                    "configuration_error",
+                ApiError::new(
+                    ErrorCode::ConfigurationError,
                     "Prometheus metrics source selected but no Prometheus configuration provided",
                 )
+                .with_cluster(&cluster_id)
             })?;
 
             debug!(
@@ -275,10 +272,11 @@ pub async fn get_cluster_metrics(
             )
             .map_err(|e| {
                 error!("Failed to create Prometheus metrics service: {}", e);
-                crate::routes::clusters::ClusterErrorResponse::simple(
-                    "metrics_error",
+                ApiError::new(
+                    ErrorCode::UpstreamError,
                     format!("Failed to initialize Prometheus metrics: {}", e),
                 )
+                .with_cluster(&cluster_id)
             })?;
 
             // For Prometheus metrics source, ALL data comes from Prometheus ONLY
@@ -288,10 +286,11 @@ pub async fn get_cluster_metrics(
                 .await
                 .map_err(|e| {
                     error!("Failed to get Prometheus cluster metrics: {}", e);
-                    crate::routes::clusters::ClusterErrorResponse::simple(
-                        "metrics_error",
+                    ApiError::new(
+                        ErrorCode::UpstreamError,
                         format!("Failed to retrieve Prometheus metrics: {}", e),
                     )
+                    .with_cluster(&cluster_id)
                 })?
         }
     };
@@ -571,9 +570,9 @@ pub struct NodeMetricsHistoryResponse {
     ),
     responses(
         (status = 200, body = NodeMetricsHistoryResponse),
-        (status = 400, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 401, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 404, body = crate::routes::clusters::ClusterErrorResponse)
+        (status = 400, body = crate::routes::ApiError),
+        (status = 401, body = crate::routes::ApiError),
+        (status = 404, body = crate::routes::ApiError)
     ),
     tag = "Metrics"
 )]
@@ -583,7 +582,7 @@ pub async fn get_node_metrics(
     Path(params): Path<(String, String)>,
     Query(params_query): Query<MetricsQuery>,
     user_ext: Option<axum::Extension<AuthenticatedUser>>,
-) -> Result<Json<NodeMetricsHistoryResponse>, crate::routes::clusters::ClusterErrorResponse> {
+) -> Result<Json<NodeMetricsHistoryResponse>, crate::routes::ApiError> {
     let (cluster_id, node_id) = params;
     debug!(
         "Getting metrics for node {} in cluster {}",
@@ -597,29 +596,22 @@ pub async fn get_node_metrics(
             .get_cluster_with_auth(&cluster_id, Some(&user.0 .0))
             .await
             .map_err(|_| {
-                crate::routes::clusters::ClusterErrorResponse::simple(
-                    "access_denied",
+                ApiError::new(
+                    ErrorCode::AccessDenied,
                     "You do not have access to this cluster or cluster not found",
                 )
+                .with_cluster(&cluster_id)
             })?
     } else {
         state
             .cluster_manager
             .get_cluster(&cluster_id)
             .await
-            .map_err(|_| {
-                crate::routes::clusters::ClusterErrorResponse::simple(
-                    "cluster_not_found",
-                    format!("Cluster '{}' not found", cluster_id),
-                )
-            })?
+            .map_err(|_| ApiError::cluster_not_found(&cluster_id))?
     };
 
     if !cluster_conn.accessible {
-        return Err(crate::routes::clusters::ClusterErrorResponse::unavailable(
-            &cluster_id,
-            cluster_conn.accessible_reason.clone(),
-        ));
+        return Err(ApiError::inaccessible(&cluster_conn));
     }
 
     // Get node name from Elasticsearch (needed for Prometheus queries)
@@ -644,27 +636,30 @@ pub async fn get_node_metrics(
         cluster_conn.metrics_source,
         crate::config::MetricsSource::Prometheus
     ) {
-        return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-            "not_supported",
+        return Err(ApiError::new(
+            ErrorCode::NotSupported,
             "Node-level historical metrics are only available when using Prometheus metrics source",
-        ));
+        )
+        .with_cluster(&cluster_id));
     }
 
     // Get Prometheus configuration
     let prometheus_config = cluster_conn.prometheus.as_ref().ok_or_else(|| {
-        crate::routes::clusters::ClusterErrorResponse::simple(
-            "configuration_error",
+        ApiError::new(
+            ErrorCode::ConfigurationError,
             "Prometheus metrics source selected but no Prometheus configuration provided",
         )
+        .with_cluster(&cluster_id)
     })?;
 
     // Determine time range
     let time_range = if let (Some(start), Some(end)) = (params_query.start, params_query.end) {
         TimeRange::new(start, end).map_err(|e| {
-            crate::routes::clusters::ClusterErrorResponse::simple(
-                "invalid_time_range",
+            ApiError::new(
+                ErrorCode::ValidationFailed,
                 format!("Invalid time range: {}", e),
             )
+            .with_cluster(&cluster_id)
         })?
     } else {
         TimeRange::last_24_hours()
@@ -678,10 +673,11 @@ pub async fn get_node_metrics(
             timeout: std::time::Duration::from_secs(30),
         })
         .map_err(|e| {
-            crate::routes::clusters::ClusterErrorResponse::simple(
-                "configuration_error",
+            ApiError::new(
+                ErrorCode::ConfigurationError,
                 format!("Failed to create Prometheus client: {}", e),
             )
+            .with_cluster(&cluster_id)
         })?;
 
     /// Normalizes a percent value that may be expressed as a fraction (0-1) or percentage (0-100).
@@ -731,17 +727,21 @@ pub async fn get_node_metrics(
 
     // Fetch metrics in parallel
     let heap_used_fut =
-        prom_client.query_range(&heap_query, time_range.start, time_range.end, step);
-    let cpu_fut = prom_client.query_range(&cpu_query, time_range.start, time_range.end, step);
-    let load1_fut = prom_client.query_range(&load1_query, time_range.start, time_range.end, step);
-    let load5_fut = prom_client.query_range(&load5_query, time_range.start, time_range.end, step);
-    let load15_fut = prom_client.query_range(&load15_query, time_range.start, time_range.end, step);
+        prom_client.query_range_series(&heap_query, time_range.start, time_range.end, step);
+    let cpu_fut =
+        prom_client.query_range_series(&cpu_query, time_range.start, time_range.end, step);
+    let load1_fut =
+        prom_client.query_range_series(&load1_query, time_range.start, time_range.end, step);
+    let load5_fut =
+        prom_client.query_range_series(&load5_query, time_range.start, time_range.end, step);
+    let load15_fut =
+        prom_client.query_range_series(&load15_query, time_range.start, time_range.end, step);
     let disk_avail_fut =
-        prom_client.query_range(&disk_avail_query, time_range.start, time_range.end, step);
+        prom_client.query_range_series(&disk_avail_query, time_range.start, time_range.end, step);
     let disk_size_fut =
-        prom_client.query_range(&disk_size_query, time_range.start, time_range.end, step);
+        prom_client.query_range_series(&disk_size_query, time_range.start, time_range.end, step);
     let heap_max_fut =
-        prom_client.query_range(&heap_max_query, time_range.start, time_range.end, step);
+        prom_client.query_range_series(&heap_max_query, time_range.start, time_range.end, step);
 
     let (heap_used, cpu, load1, load5, load15, disk_avail, disk_size, heap_max) = tokio::join!(
         heap_used_fut,
@@ -858,8 +858,8 @@ pub async fn get_node_metrics(
     let prometheus_queries = prometheus_queries
         .as_object()
         .ok_or_else(|| {
-            crate::routes::clusters::ClusterErrorResponse::simple(
-                "internal_error",
+            ApiError::new(
+                ErrorCode::InternalError,
                 "Failed to construct prometheus queries",
             )
         })?
@@ -897,9 +897,9 @@ pub async fn get_node_metrics(
     ),
     responses(
         (status = 200, description = "Cluster metrics history for heatmap"),
-        (status = 400, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 401, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 404, body = crate::routes::clusters::ClusterErrorResponse)
+        (status = 400, body = crate::routes::ApiError),
+        (status = 401, body = crate::routes::ApiError),
+        (status = 404, body = crate::routes::ApiError)
     ),
     tag = "Metrics"
 )]
@@ -909,7 +909,7 @@ pub async fn get_cluster_metrics_history(
     Path(cluster_id): Path<String>,
     Query(params): Query<MetricsQuery>,
     user_ext: Option<axum::Extension<AuthenticatedUser>>,
-) -> Result<Json<serde_json::Value>, crate::routes::clusters::ClusterErrorResponse> {
+) -> Result<Json<serde_json::Value>, crate::routes::ApiError> {
     debug!("Getting metrics history for cluster: {}", cluster_id);
 
     // Get cluster connection with auth check
@@ -919,10 +919,11 @@ pub async fn get_cluster_metrics_history(
             .get_cluster_with_auth(&cluster_id, Some(&user.0 .0))
             .await
             .map_err(|_| {
-                crate::routes::clusters::ClusterErrorResponse::simple(
-                    "access_denied",
+                ApiError::new(
+                    ErrorCode::AccessDenied,
                     "You do not have access to this cluster or cluster not found",
                 )
+                .with_cluster(&cluster_id)
             })?
     } else {
         // In open mode, no auth required
@@ -930,30 +931,23 @@ pub async fn get_cluster_metrics_history(
             .cluster_manager
             .get_cluster(&cluster_id)
             .await
-            .map_err(|_| {
-                crate::routes::clusters::ClusterErrorResponse::simple(
-                    "cluster_not_found",
-                    format!("Cluster '{}' not found", cluster_id),
-                )
-            })?
+            .map_err(|_| ApiError::cluster_not_found(&cluster_id))?
     };
 
     // If the cluster connection exists but is marked inaccessible, return a structured
     // cluster_unavailable response so the frontend can show the reason.
     if !cluster_conn.accessible {
-        return Err(crate::routes::clusters::ClusterErrorResponse::unavailable(
-            &cluster_id,
-            cluster_conn.accessible_reason.clone(),
-        ));
+        return Err(ApiError::inaccessible(&cluster_conn));
     }
 
     // Determine time range (default to 7 days for history)
     let time_range = if let (Some(start), Some(end)) = (params.start, params.end) {
         TimeRange::new(start, end).map_err(|e| {
-            crate::routes::clusters::ClusterErrorResponse::simple(
-                "invalid_time_range",
+            ApiError::new(
+                ErrorCode::ValidationFailed,
                 format!("Invalid time range: {}", e),
             )
+            .with_cluster(&cluster_id)
         })?
     } else {
         TimeRange::last_7_days()
@@ -1056,8 +1050,8 @@ pub async fn get_cluster_metrics_history(
     request_body = PrometheusValidationRequest,
     responses(
         (status = 200, body = PrometheusValidationResponse),
-        (status = 400, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 401, body = crate::routes::clusters::ClusterErrorResponse)
+        (status = 400, body = crate::routes::ApiError),
+        (status = 401, body = crate::routes::ApiError)
     ),
     tag = "Metrics"
 )]
@@ -1066,13 +1060,13 @@ pub async fn validate_prometheus_endpoint(
     State(_state): State<MetricsState>,
     Json(request): Json<PrometheusValidationRequest>,
     user_ext: Option<axum::Extension<AuthenticatedUser>>,
-) -> Result<Json<PrometheusValidationResponse>, crate::routes::clusters::ClusterErrorResponse> {
+) -> Result<Json<PrometheusValidationResponse>, crate::routes::ApiError> {
     debug!("Validating Prometheus endpoint: {}", request.url);
 
     // Check authentication if required
     if user_ext.is_none() {
-        return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-            "unauthorized",
+        return Err(ApiError::new(
+            ErrorCode::AuthenticationRequired,
             "Authentication required for Prometheus validation",
         ));
     }
@@ -1117,8 +1111,8 @@ pub async fn validate_prometheus_endpoint(
         },
         Err(e) => {
             error!("Failed to create Prometheus client: {}", e);
-            Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                "invalid_configuration",
+            Err(ApiError::new(
+                ErrorCode::ValidationFailed,
                 format!("Invalid Prometheus configuration: {}", e),
             ))
         }
@@ -1159,8 +1153,8 @@ mod tests {
         assert!(json.contains("\"job_name\":\"elasticsearch\""));
     }
 
-    // MetricsErrorResponse tests removed; ClusterErrorResponse is used for
-    // metrics validation errors. See routes::clusters tests for ClusterErrorResponse
+    // MetricsErrorResponse tests removed; ApiError is used for
+    // metrics validation errors. See routes::clusters tests for ApiError
     // serialization/deserialization checks.
 
     #[test]
@@ -2,9 +2,11 @@
 pub mod aliases;
 pub mod app_metrics;
 pub mod auth;
+pub mod branding;
 pub mod cluster_client;
 pub mod clusters;
 pub mod component_templates;
+pub mod error;
 pub mod health;
 pub mod metrics;
 pub mod openapi;
@@ -14,7 +16,8 @@ pub mod templates;
 pub mod topology;
 
 pub use auth::{AuthState, ErrorResponse, LoginRequest, LoginResponse, OidcCallbackQuery};
-pub use clusters::{ClusterErrorResponse, ClusterState};
+pub use clusters::ClusterState;
+pub use error::{ApiError, ErrorCode};
 pub use health::HealthResponse;
 pub use metrics::MetricsState;
 pub use openapi::ApiDoc;
@@ -11,6 +11,8 @@ use utoipa::OpenApi;
         // Health routes
         crate::routes::health::health_check,
         crate::routes::health::readiness_check,
+        crate::routes::health::liveness_check,
+        crate::routes::health::cluster_readiness_check,
         crate::routes::health::get_version,
         // Auth routes
         crate::routes::auth::oidc_login,
@@ -19,6 +21,9 @@ use utoipa::OpenApi;
         crate::routes::auth::get_current_user,
         crate::routes::auth::logout,
         crate::routes::auth::get_auth_status,
+        crate::routes::auth::list_sessions,
+        crate::routes::auth::revoke_sessions,
+        crate::routes::branding::get_branding,
         // Cluster routes
         crate::routes::clusters::list_clusters,
         crate::routes::clusters::get_cluster_stats,
@@ -34,12 +39,40 @@ use utoipa::OpenApi;
         crate::routes::clusters::get_shard_stats,
         crate::routes::clusters::get_shards,
         crate::routes::clusters::get_node_shards,
+        crate::routes::clusters::get_all_clusters_health,
+        crate::routes::clusters::get_clusters_summary,
+        crate::routes::clusters::get_cluster_health,
+        crate::routes::clusters::get_snapshot_repositories,
+        crate::routes::clusters::get_snapshots,
+        crate::routes::clusters::get_index_ilm,
+        crate::routes::clusters::index_operations::open_index,
+        crate::routes::clusters::index_operations::close_index,
+        crate::routes::clusters::index_operations::delete_index,
+        crate::routes::clusters::index_operations::force_merge_index,
+        crate::routes::clusters::index_operations::flush_index,
+        crate::routes::clusters::index_operations::refresh_index,
+        crate::routes::clusters::index_operations::get_index_settings,
+        crate::routes::clusters::index_operations::update_index_settings,
         crate::routes::clusters::proxy::proxy_request,
         crate::routes::clusters::relocation::relocate_shard,
+        crate::routes::clusters::allocation::explain_allocation,
+        crate::routes::clusters::cat::cat,
+        crate::routes::clusters::compare::compare_clusters,
+        crate::routes::clusters::search::search_clusters,
+        crate::routes::clusters::health_stream::stream_cluster_health,
+        crate::routes::clusters::deprecations::get_deprecations,
+        crate::routes::clusters::diagnostics::get_diagnostics,
+        crate::routes::clusters::hot_threads::get_hot_threads,
+        crate::routes::clusters::recovery::get_recovery,
+        crate::routes::clusters::recovery::recovery_ws,
         // Task routes
         crate::routes::clusters::tasks::fetch_cluster_tasks,
         crate::routes::clusters::tasks::get_task_details,
         crate::routes::clusters::tasks::cancel_cluster_task,
+        crate::routes::clusters::tasks::get_pending_tasks,
+        crate::routes::clusters::reindex::start_reindex,
+        crate::routes::clusters::flush::synced_flush,
+        crate::routes::clusters::reindex::get_task_progress,
         // Metrics routes
         crate::routes::metrics::get_cluster_metrics,
         crate::routes::metrics::get_node_metrics,
@@ -57,17 +90,34 @@ use utoipa::OpenApi;
             crate::routes::auth::OidcCallbackQuery,
             crate::routes::auth::OidcLoginQuery,
             crate::routes::auth::AuthStatusResponse,
+            crate::auth::ActiveSession,
+            crate::routes::auth::RevokeSessionsRequest,
+            crate::routes::auth::RevokeSessionsResponse,
             // Cluster types
             crate::routes::clusters::ClustersQueryParams,
             crate::routes::clusters::ClusterSettingsUpdateRequest,
             crate::routes::clusters::NodesQueryParams,
             crate::routes::clusters::IndicesQueryParams,
             crate::routes::clusters::ShardsQueryParams,
+            crate::routes::clusters::ClusterStatsQueryParams,
+            crate::routes::clusters::ClusterHealthQueryParams,
+            crate::routes::clusters::ClusterHealthSummary,
+            crate::routes::clusters::ClustersSummary,
+            crate::cluster::CircuitState,
+            crate::cluster::ClusterHealth,
+            crate::cluster::HealthStatus,
             crate::routes::clusters::transform::ClusterStatsResponse,
             crate::routes::clusters::transform::NodeInfoResponse,
+            crate::routes::clusters::transform::DiskWatermarkState,
             crate::routes::clusters::transform::IndexInfoResponse,
             crate::routes::clusters::transform::ShardInfoResponse,
             crate::routes::clusters::transform::NodeDetailStatsResponse,
+            crate::routes::clusters::transform::SnapshotRepositoryResponse,
+            crate::routes::clusters::transform::SnapshotInfoResponse,
+            crate::routes::clusters::transform::IndexIlmResponse,
+            crate::routes::clusters::transform::ClusterSettingsResponse,
+            crate::routes::clusters::transform::ClusterSettingResponse,
+            crate::routes::clusters::transform::ClusterSettingSource,
             // Topology tile types
             crate::routes::topology::generator::TilePayload,
             crate::routes::topology::generator::TileNodeMeta,
@@ -85,16 +135,47 @@ use utoipa::OpenApi;
             crate::routes::clusters::tasks::TaskDetailsResponse,
             crate::routes::clusters::tasks::CancelTaskResponse,
             crate::routes::clusters::tasks::TasksQueryParams,
+            crate::routes::clusters::tasks::PendingTaskInfo,
+            crate::routes::clusters::tasks::PendingTasksSummary,
+            crate::routes::clusters::tasks::PendingTasksResponse,
+            crate::routes::clusters::reindex::ReindexStartResponse,
+            crate::routes::clusters::reindex::TaskProgressResponse,
+            crate::routes::clusters::index_operations::ForceMergeParams,
+            crate::routes::clusters::index_operations::ForceMergeStartResponse,
+            crate::routes::clusters::index_operations::ShardsAcknowledgement,
+            crate::routes::clusters::index_operations::ShardsAcknowledgedResponse,
+            crate::routes::clusters::index_operations::IndexSettingsResponse,
+            crate::routes::clusters::flush::SyncedFlushResponse,
+            crate::routes::clusters::deprecations::DeprecationIssue,
+            crate::routes::clusters::deprecations::DeprecationsResponse,
+            crate::routes::clusters::diagnostics::DiagnosticsBundle,
+            crate::routes::clusters::hot_threads::HotThreadsType,
+            crate::routes::clusters::recovery::RecoveryProgress,
+            crate::routes::clusters::recovery::RecoveryQueryParams,
+            crate::routes::clusters::recovery::RecoveryStreamStatus,
+            crate::routes::clusters::recovery::RecoveryFrame,
+            crate::routes::clusters::allocation::AllocationExplainParams,
+            crate::routes::clusters::allocation::AllocationExplainResponse,
+            crate::routes::clusters::allocation::AllocationDeciderResponse,
+            crate::routes::clusters::compare::CompareTarget,
+            crate::routes::clusters::compare::CompareQueryParams,
+            crate::routes::clusters::compare::CompareDifference,
+            crate::routes::clusters::compare::ClusterComparisonResponse,
+            crate::routes::clusters::search::ClusterSearchParams,
+            crate::routes::clusters::search::ClusterSearchResult,
             // Metrics types
             crate::routes::metrics::MetricsQuery,
             crate::routes::metrics::ClusterMetricsHistoryResponse,
-            crate::routes::clusters::ClusterErrorResponse,
+            crate::routes::ApiError,
+            crate::routes::ErrorCode,
             crate::routes::metrics::PrometheusValidationRequest,
             crate::routes::metrics::PrometheusValidationResponse,
             // Health types
             crate::routes::health::HealthResponse,
             crate::routes::health::ReadinessResponse,
+            crate::routes::health::ClusterReadinessResponse,
             crate::routes::health::VersionResponse,
+            crate::config::BrandingConfig,
         )
     ),
     // Tags
@@ -1,30 +1,23 @@
 use crate::assets::Assets;
+use crate::middleware::base_path::BasePath;
+use crate::middleware::etag::{content_etag, etag_matches};
 use crate::telemetry::config::TelemetryConfig;
 use axum::{
-    body::Body,
-    http::{header, StatusCode, Uri},
-    response::Response,
+    http::{header, HeaderMap, HeaderValue, StatusCode, Uri},
+    response::{IntoResponse, Response},
+    Extension,
 };
 use mime_guess;
 use std::fs;
 use std::path::Path;
 use std::sync::Once;
 
-/// Error type for static asset serving
-#[derive(Debug)]
-enum StaticAssetError {
-    ResponseBuildError,
-}
-
-impl std::fmt::Display for StaticAssetError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            StaticAssetError::ResponseBuildError => write!(f, "Failed to build response"),
-        }
-    }
-}
+/// Precompressed variants served instead of an asset, most preferred first,
+/// as (`Content-Encoding`, file extension)
+const PRECOMPRESSED_VARIANTS: &[(&str, &str)] = &[("br", "br"), ("gzip", "gz")];
 
-impl std::error::Error for StaticAssetError {}
+/// Cache policy of content-hashed assets: their URL changes with their content
+const IMMUTABLE: &str = "public, max-age=31536000, immutable";
 
 /// Serve embedded static assets
 ///
@@ -33,12 +26,25 @@ impl std::error::Error for StaticAssetError {}
 /// - Serving static files (JS, CSS, images, etc.)
 /// - SPA routing fallback (serving index.html for unknown paths)
 /// - Correct MIME type detection
+/// - Rewriting URLs in index.html for the base path (`server.base_path`)
+/// - Precompressed `.br`/`.gz` variants built next to the assets, when the
+///   client accepts them; other assets are left to the compression layer
+/// - Caching: content-hashed files are cached forever, everything else is
+///   revalidated through its ETag
 ///
 /// # Requirements
 ///
 /// Validates: Requirements 1.2
-pub async fn serve_static(uri: Uri) -> Response {
+pub async fn serve_static(
+    uri: Uri,
+    headers: HeaderMap,
+    base_path: Option<Extension<BasePath>>,
+) -> Response {
     let path = uri.path().trim_start_matches('/');
+    let prefix = base_path
+        .as_ref()
+        .map(|Extension(BasePath(prefix))| prefix.as_str())
+        .unwrap_or("");
 
     // Prefer serving files from the frontend/dist directory on disk when
     // available. This allows a local development workflow where the
@@ -62,6 +68,11 @@ pub async fn serve_static(uri: Uri) -> Response {
             tracing::info!("Serving embedded static assets (no frontend/dist found)");
         }
     });
+
+    if let Some(response) = precompressed_response(Path::new(&dev_dir), path, &headers) {
+        return response;
+    }
+
     let candidate = if path.is_empty() {
         Path::new(&dev_dir).join("index.html")
     } else {
@@ -69,69 +80,21 @@ pub async fn serve_static(uri: Uri) -> Response {
     };
     if candidate.exists() && candidate.is_file() {
         if let Ok(bytes) = fs::read(&candidate) {
-            let mime_type = if path.is_empty() {
-                mime_guess::from_path("index.html").first_or_octet_stream()
-            } else {
-                mime_guess::from_path(path).first_or_octet_stream()
-            };
-            let mut builder = Response::builder()
-                .status(StatusCode::OK)
-                .header(header::CONTENT_TYPE, mime_type.as_ref());
-            // If serving index.html from disk, ensure browsers don't cache it.
             if path.is_empty() {
-                builder = builder
-                    .header(
-                        header::CACHE_CONTROL,
-                        "no-cache, no-store, must-revalidate, max-age=0, s-maxage=0",
-                    )
-                    .header(header::PRAGMA, "no-cache")
-                    .header(header::EXPIRES, "0");
-            } else if path.starts_with("assets/") {
-                // Fingerprinted assets under /assets/* are safe to cache long-term
-                builder =
-                    builder.header(header::CACHE_CONTROL, "public, max-age=31536000, immutable");
+                let html = with_base_path(&String::from_utf8_lossy(&bytes), prefix);
+                return asset_response("index.html", html.into_bytes(), None, &headers);
             }
-            return builder
-                .body(Body::from(bytes))
-                .map_err(|_| StaticAssetError::ResponseBuildError)
-                .unwrap_or_else(|_| {
-                    Response::builder()
-                        .status(StatusCode::INTERNAL_SERVER_ERROR)
-                        .body(Body::from("Internal Server Error"))
-                        .unwrap_or_else(|_| panic!("Failed to build error response"))
-                });
+            return asset_response(path, bytes, None, &headers);
         }
     }
 
     // Try to get the requested file
     if let Some(content) = Assets::get(path) {
-        let mime_type = mime_guess::from_path(path).first_or_octet_stream();
-
-        let mut builder = Response::builder()
-            .status(StatusCode::OK)
-            .header(header::CONTENT_TYPE, mime_type.as_ref());
-        // If the requested path is the SPA index, prevent caching
+        let mut data = content.data.into_owned();
         if path == "index.html" || path.is_empty() {
-            builder = builder
-                .header(
-                    header::CACHE_CONTROL,
-                    "no-cache, no-store, must-revalidate, max-age=0, s-maxage=0",
-                )
-                .header(header::PRAGMA, "no-cache")
-                .header(header::EXPIRES, "0");
-        } else if path.starts_with("assets/") {
-            builder = builder.header(header::CACHE_CONTROL, "public, max-age=31536000, immutable");
+            data = with_base_path(&String::from_utf8_lossy(&data), prefix).into_bytes();
         }
-
-        return builder
-            .body(Body::from(content.data))
-            .map_err(|_| StaticAssetError::ResponseBuildError)
-            .unwrap_or_else(|_| {
-                Response::builder()
-                    .status(StatusCode::INTERNAL_SERVER_ERROR)
-                    .body(Body::from("Internal Server Error"))
-                    .unwrap_or_else(|_| panic!("Failed to build error response"))
-            });
+        return asset_response(path, data, None, &headers);
     }
 
     // SPA fallback: serve index.html for unknown paths
@@ -140,35 +103,126 @@ pub async fn serve_static(uri: Uri) -> Response {
         let html = String::from_utf8_lossy(&index.data);
 
         // Inject OTEL configuration if telemetry is enabled
-        let html_with_otel = inject_otel_config(&html);
-
-        return Response::builder()
-            .status(StatusCode::OK)
-            .header(header::CONTENT_TYPE, "text/html")
-            .header(
-                header::CACHE_CONTROL,
-                "no-cache, no-store, must-revalidate, max-age=0, s-maxage=0",
-            )
-            .header(header::PRAGMA, "no-cache")
-            .header(header::EXPIRES, "0")
-            .body(Body::from(html_with_otel))
-            .map_err(|_| StaticAssetError::ResponseBuildError)
-            .unwrap_or_else(|_| {
-                Response::builder()
-                    .status(StatusCode::INTERNAL_SERVER_ERROR)
-                    .body(Body::from("Internal Server Error"))
-                    .unwrap_or_else(|_| panic!("Failed to build error response"))
-            });
+        let html_with_otel = with_base_path(&inject_otel_config(&html, prefix), prefix);
+
+        return asset_response("index.html", html_with_otel.into_bytes(), None, &headers);
     }
 
     // If even index.html is not found, return 404
-    Response::builder()
-        .status(StatusCode::NOT_FOUND)
-        .body(Body::from("Not Found"))
-        .unwrap_or_else(|_| {
-            // Last resort - if we can't even build a 404, return a minimal response
-            Response::new(Body::from("Not Found"))
-        })
+    (StatusCode::NOT_FOUND, "Not Found").into_response()
+}
+
+/// Build the response for an asset at `path`
+///
+/// Content-hashed files get [`IMMUTABLE`]; index.html and other files get
+/// `no-cache` with an ETag over the bytes sent, so revalidating an unchanged
+/// file returns an empty 304.
+fn asset_response(
+    path: &str,
+    data: Vec<u8>,
+    encoding: Option<&'static str>,
+    request_headers: &HeaderMap,
+) -> Response {
+    let is_index = path.is_empty() || path == "index.html";
+    let mime_type = if is_index {
+        mime_guess::mime::TEXT_HTML
+    } else {
+        mime_guess::from_path(path).first_or_octet_stream()
+    };
+    let cache_control = if !is_index && is_content_hashed(path) {
+        IMMUTABLE
+    } else {
+        "no-cache"
+    };
+
+    let mut headers = HeaderMap::new();
+    headers.insert(header::ETAG, content_etag(&data));
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static(cache_control),
+    );
+    if let Some(encoding) = encoding {
+        headers.insert(header::VARY, HeaderValue::from_static("accept-encoding"));
+        headers.insert(header::CONTENT_ENCODING, HeaderValue::from_static(encoding));
+    }
+
+    let not_modified = request_headers
+        .get(header::IF_NONE_MATCH)
+        .is_some_and(|value| etag_matches(value, &headers[header::ETAG]));
+    if not_modified {
+        headers.remove(header::CONTENT_ENCODING);
+        return (StatusCode::NOT_MODIFIED, headers).into_response();
+    }
+
+    if let Ok(content_type) = HeaderValue::from_str(mime_type.as_ref()) {
+        headers.insert(header::CONTENT_TYPE, content_type);
+    }
+    (headers, data).into_response()
+}
+
+/// Whether a file is a build output of the frontend (`assets/index-DiwrgTda.js`)
+///
+/// Vite writes only content-hashed bundles into `assets/` and copies public
+/// files (favicons, logo) to the root, so the directory alone tells them
+/// apart without guessing which part of the name is the hash.
+fn is_content_hashed(path: &str) -> bool {
+    path.strip_prefix("assets/")
+        .is_some_and(|file| !file.is_empty())
+}
+
+/// Serve a precompressed variant of an asset the client accepts, if one exists
+///
+/// Variants come from the same place as the asset itself: the assets
+/// directory on disk when the asset is there, the embedded assets otherwise.
+/// index.html is never served precompressed since it is rewritten per request.
+fn precompressed_response(dev_dir: &Path, path: &str, headers: &HeaderMap) -> Option<Response> {
+    if path.is_empty() || path == "index.html" {
+        return None;
+    }
+
+    let on_disk = dev_dir.join(path).is_file();
+    let (encoding, data) = PRECOMPRESSED_VARIANTS
+        .iter()
+        .filter(|(encoding, _)| accepts_encoding(headers, encoding))
+        .find_map(|(encoding, extension)| {
+            let variant = format!("{}.{}", path, extension);
+            let data = if on_disk {
+                fs::read(dev_dir.join(&variant)).ok()
+            } else {
+                Assets::get(&variant).map(|file| file.data.into_owned())
+            }?;
+            Some((*encoding, data))
+        })?;
+
+    Some(asset_response(path, data, Some(encoding), headers))
+}
+
+/// Whether `Accept-Encoding` allows `encoding` (by name or `*`, with q > 0)
+///
+/// An entry naming `encoding` takes precedence over `*`, so `gzip;q=0, *`
+/// refuses gzip (RFC 9110 section 12.5.3).
+fn accepts_encoding(headers: &HeaderMap, encoding: &str) -> bool {
+    let mut named = None;
+    let mut wildcard = None;
+    for item in headers
+        .get_all(header::ACCEPT_ENCODING)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+    {
+        let mut parts = item.split(';');
+        let name = parts.next().unwrap_or("").trim();
+        let quality = parts
+            .filter_map(|param| param.trim().strip_prefix("q="))
+            .find_map(|q| q.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+        if name.eq_ignore_ascii_case(encoding) {
+            named = Some(quality);
+        } else if name == "*" {
+            wildcard = Some(quality);
+        }
+    }
+    named.or(wildcard).is_some_and(|quality| quality > 0.0)
 }
 
 /// Inject OpenTelemetry configuration into HTML
@@ -178,7 +232,7 @@ pub async fn serve_static(uri: Uri) -> Response {
 /// The frontend sends traces to the backend's /v1/traces proxy endpoint,
 /// which then forwards them to the OTLP collector. This avoids CORS issues
 /// and allows the backend to handle authentication.
-fn inject_otel_config(html: &str) -> String {
+fn inject_otel_config(html: &str, prefix: &str) -> String {
     let telemetry_config = TelemetryConfig::from_env().unwrap_or_else(|_| TelemetryConfig {
         enabled: false,
         service_name: "secan".to_string(), // Match backend default
@@ -193,7 +247,7 @@ fn inject_otel_config(html: &str) -> String {
 
     // Frontend sends traces to backend proxy endpoint, not directly to collector
     // The backend's /v1/traces endpoint proxies to the actual OTLP collector
-    let frontend_endpoint = "/v1/traces";
+    let frontend_endpoint = format!("{}/v1/traces", prefix);
 
     // Build the config injection
     let config_injection = format!(
@@ -223,6 +277,54 @@ fn inject_otel_config(html: &str) -> String {
     }
 }
 
+/// Point the URLs in index.html at the base path
+///
+/// Adds a `<base href>` so relative asset URLs resolve against the base path
+/// on every client-side route, and a `secan-base-path` meta tag the frontend
+/// reads to build API URLs. Root-relative `src` and `href` attributes are
+/// prefixed with the base path.
+fn with_base_path(html: &str, prefix: &str) -> String {
+    let mut html = html.to_string();
+    if !prefix.is_empty() {
+        for attribute in ["src=\"/", "href=\"/"] {
+            html = prefix_attribute(&html, attribute, prefix);
+        }
+    }
+
+    // Right after the opening <head> (or <html> when there is no head)
+    let tag_end = |tag: &str| {
+        html.find(tag)
+            .and_then(|start| html[start..].find('>').map(|end| start + end + 1))
+    };
+    let Some(insert_at) = tag_end("<head").or_else(|| tag_end("<html")) else {
+        return html;
+    };
+    html.insert_str(
+        insert_at,
+        &format!(r#"<base href="{prefix}/"><meta name="secan-base-path" content="{prefix}">"#),
+    );
+    html
+}
+
+/// Insert `prefix` after each `attribute` (e.g. `src="/`), leaving
+/// protocol-relative URLs (`//cdn.example.com`) alone
+fn prefix_attribute(html: &str, attribute: &str, prefix: &str) -> String {
+    let mut result = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(pos) = rest.find(attribute) {
+        // Split before the leading slash of the URL
+        let (before, after) = rest.split_at(pos + attribute.len() - 1);
+        result.push_str(before);
+        if !after[1..].starts_with('/') {
+            result.push_str(prefix);
+        }
+        result.push('/');
+        rest = &after[1..];
+    }
+    result.push_str(rest);
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -232,7 +334,7 @@ mod tests {
     async fn test_serve_index_html() {
         // SAFETY: Static path literals always parse successfully
         let uri: Uri = "/".parse().expect("parse test URI");
-        let response = serve_static(uri).await;
+        let response = serve_static(uri, HeaderMap::new(), None).await;
 
         assert_eq!(response.status(), StatusCode::OK);
 
@@ -249,7 +351,7 @@ mod tests {
     async fn test_serve_static_file() {
         // SAFETY: Static path literals always parse successfully
         let uri: Uri = "/index.html".parse().expect("parse test URI");
-        let response = serve_static(uri).await;
+        let response = serve_static(uri, HeaderMap::new(), None).await;
 
         assert_eq!(response.status(), StatusCode::OK);
 
@@ -267,7 +369,7 @@ mod tests {
         // Should return index.html for SPA routing
         // SAFETY: Static path literals always parse successfully
         let uri: Uri = "/cluster/test-cluster".parse().expect("parse test URI");
-        let response = serve_static(uri).await;
+        let response = serve_static(uri, HeaderMap::new(), None).await;
 
         assert_eq!(response.status(), StatusCode::OK);
 
@@ -279,6 +381,136 @@ mod tests {
         assert_eq!(content_type, "text/html");
     }
 
+    #[test]
+    fn test_with_base_path_rewrites_index_urls() {
+        let html = r#"<html><head><link rel="icon" href="/favicon.svg"><script src="/assets/index.js"></script><script src="//cdn.example.com/x.js"></script></head></html>"#;
+
+        assert_eq!(
+            with_base_path(html, "/secan"),
+            r#"<html><head><base href="/secan/"><meta name="secan-base-path" content="/secan"><link rel="icon" href="/secan/favicon.svg"><script src="/secan/assets/index.js"></script><script src="//cdn.example.com/x.js"></script></head></html>"#
+        );
+
+        // Served from the root only the base tag is added
+        assert_eq!(
+            with_base_path(html, ""),
+            html.replace(
+                "<head>",
+                r#"<head><base href="/"><meta name="secan-base-path" content="">"#
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_js_request_gets_precompressed_variant() {
+        use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+        use std::io::{Read, Write};
+
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let source = "console.log('secan');".repeat(50);
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(source.as_bytes()).expect("gzip asset");
+        let gzipped = encoder.finish().expect("finish gzip");
+        fs::create_dir(dir.path().join("assets")).expect("create assets dir");
+        fs::write(dir.path().join("assets/app.js"), &source).expect("write asset");
+        fs::write(dir.path().join("assets/app.js.gz"), &gzipped).expect("write variant");
+
+        let accept = |value: &'static str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                header::ACCEPT_ENCODING,
+                header::HeaderValue::from_static(value),
+            );
+            headers
+        };
+
+        // Brotli is preferred but there is no .br variant, so gzip is served
+        for value in ["gzip", "br, gzip, deflate", "br;q=1.0, gzip;q=0.8", "*"] {
+            let response = precompressed_response(dir.path(), "assets/app.js", &accept(value))
+                .expect("gzipped variant");
+            assert_eq!(response.headers()[header::CONTENT_ENCODING], "gzip");
+            assert_eq!(response.headers()[header::CONTENT_TYPE], "text/javascript");
+            assert_eq!(response.headers()[header::VARY], "accept-encoding");
+        }
+
+        let response = precompressed_response(dir.path(), "assets/app.js", &accept("gzip"))
+            .expect("gzipped variant");
+        let body = axum::body::to_bytes(response.into_body(), 64 * 1024)
+            .await
+            .expect("read body");
+        let mut decoded = String::new();
+        GzDecoder::new(&body[..])
+            .read_to_string(&mut decoded)
+            .expect("gunzip body");
+        assert_eq!(decoded, source);
+
+        // Raw bytes when gzip is refused or not asked for
+        for value in ["identity", "gzip;q=0", "deflate", "gzip;q=0, *"] {
+            assert!(
+                precompressed_response(dir.path(), "assets/app.js", &accept(value)).is_none(),
+                "{}",
+                value
+            );
+        }
+        assert!(precompressed_response(dir.path(), "assets/app.js", &HeaderMap::new()).is_none());
+    }
+
+    #[test]
+    fn test_content_hashed_file_names() {
+        for path in [
+            "assets/index-DiwrgTda.js",
+            "assets/vendor-C3pF2a1B.css",
+            "assets/logo.5f3a9c21.svg",
+            // Vite hashes are base64url and may contain `-` and `_`
+            "assets/index-D-_a1b2c.js",
+            "assets/index-abc12.js",
+        ] {
+            assert!(is_content_hashed(path), "{}", path);
+        }
+        for path in [
+            "index.html",
+            "favicon-neutral.svg",
+            "logo.svg",
+            "assets/",
+            "robots.txt",
+        ] {
+            assert!(!is_content_hashed(path), "{}", path);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hashed_assets_are_immutable_and_index_revalidates() {
+        let hashed = asset_response(
+            "assets/index-DiwrgTda.js",
+            b"console.log('secan')".to_vec(),
+            None,
+            &HeaderMap::new(),
+        );
+        assert_eq!(hashed.status(), StatusCode::OK);
+        assert_eq!(hashed.headers()[header::CACHE_CONTROL], IMMUTABLE);
+
+        let response =
+            serve_static("/".parse().expect("parse test URI"), HeaderMap::new(), None).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CACHE_CONTROL], "no-cache");
+        let etag = response.headers()[header::ETAG].clone();
+
+        // Revalidating the unchanged index gets an empty 304
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, etag.clone());
+        let response = serve_static("/".parse().expect("parse test URI"), headers, None).await;
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[header::ETAG], etag);
+        let body = axum::body::to_bytes(response.into_body(), 1024)
+            .await
+            .expect("read body");
+        assert!(body.is_empty());
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, HeaderValue::from_static("\"stale\""));
+        let response = serve_static("/".parse().expect("parse test URI"), headers, None).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
     #[tokio::test]
     async fn test_mime_type_detection() {
         // Test that MIME types are correctly detected
@@ -291,7 +523,7 @@ mod tests {
         for (path, expected_mime) in test_cases {
             // SAFETY: Static path literals always parse successfully
             let uri: Uri = format!("/{}", path).parse().expect("parse test URI");
-            let response = serve_static(uri).await;
+            let response = serve_static(uri, HeaderMap::new(), None).await;
 
             if response.status() == StatusCode::OK {
                 let content_type = response
@@ -1,15 +1,15 @@
 use axum::{
-    extract::Path,
+    extract::{Path, State},
     response::IntoResponse,
     routing::{get, post},
     Json,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::cluster::client::ElasticsearchClient;
+use crate::cluster::CachedResource;
 use crate::routes::cluster_client::ClusterClient;
-use crate::routes::clusters::ClusterErrorResponse;
 use crate::routes::ClusterState;
+use crate::routes::{ApiError, ErrorCode};
 
 pub fn router(state: ClusterState) -> axum::Router {
     axum::Router::new()
@@ -44,6 +44,9 @@ pub struct IndexTemplateSummary {
     pub version: Option<i32>,
     #[serde(default)]
     pub composed_of: Option<Vec<String>>,
+    /// Legacy (`/_template`) template rather than a composable one
+    #[serde(default)]
+    pub legacy: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -100,62 +103,146 @@ pub struct OverlappingTemplate {
     pub index_patterns: Vec<String>,
 }
 
+/// List index templates
+///
+/// Returns composable templates; for Elasticsearch 7 clusters (detected or
+/// configured `es_version`) legacy `/_template` templates are merged in and marked `legacy`.
 pub async fn list_templates(
-    ClusterClient { client, .. }: ClusterClient,
-) -> Result<impl IntoResponse, ClusterErrorResponse> {
-    let response = client.get_index_templates().await.map_err(|e| {
-        ClusterErrorResponse::simple("es_request_failed", format!("ES request failed: {}", e))
-    })?;
-
-    let mut templates = Vec::new();
-    if let Some(index_templates) = response.get("index_templates").and_then(|v| v.as_array()) {
-        for tmpl in index_templates {
-            let name = tmpl
-                .get("name")
-                .and_then(|v| v.as_str())
-                .unwrap_or_default()
-                .to_string();
-            let index_patterns = tmpl
-                .get("index_patterns")
-                .and_then(|v| serde_json::from_value(v.clone()).ok());
-            let priority = tmpl
-                .get("priority")
-                .and_then(|v| v.as_i64())
-                .map(|v| v as i32);
-            let version = tmpl
-                .get("version")
-                .and_then(|v| v.as_i64())
-                .map(|v| v as i32);
-            let composed_of = tmpl
-                .get("composed_of")
-                .and_then(|v| serde_json::from_value(v.clone()).ok());
-
-            templates.push(IndexTemplateSummary {
-                name,
-                index_patterns,
-                priority,
-                version,
-                composed_of,
-            });
-        }
+    State(state): State<ClusterState>,
+    Path(cluster_id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let cluster = state
+        .cluster_manager
+        .get_cluster(&cluster_id)
+        .await
+        .map_err(|_| ApiError::cluster_not_found(&cluster_id))?;
+    if !cluster.accessible {
+        return Err(ApiError::inaccessible(&cluster));
     }
 
+    let manager = &state.cluster_manager;
+    let composable = manager
+        .cached_resource(CachedResource::Templates, &cluster_id, "composable", || {
+            cluster.index_templates()
+        })
+        .await;
+    let mut templates = if cluster.has_legacy_templates() {
+        let legacy = manager
+            .cached_resource(CachedResource::Templates, &cluster_id, "legacy", || {
+                cluster.legacy_index_templates()
+            })
+            .await
+            .map_err(|e| {
+                ApiError::new(
+                    ErrorCode::UpstreamError,
+                    format!("ES request failed: {}", e),
+                )
+                .with_cluster(&cluster_id)
+            })?;
+        // Composable templates only exist from 7.8 on
+        let mut templates = match composable {
+            Ok(response) => transform_index_templates(&response),
+            Err(e) => {
+                tracing::debug!(
+                    cluster_id = %cluster_id,
+                    error = %e,
+                    "Composable index templates unavailable, listing legacy templates only"
+                );
+                Vec::new()
+            }
+        };
+        templates.extend(transform_legacy_index_templates(&legacy));
+        templates
+    } else {
+        let response = composable.map_err(|e| {
+            ApiError::new(
+                ErrorCode::UpstreamError,
+                format!("ES request failed: {}", e),
+            )
+            .with_cluster(&cluster_id)
+        })?;
+        transform_index_templates(&response)
+    };
+    templates.sort_by(|a, b| a.name.cmp(&b.name).then(a.legacy.cmp(&b.legacy)));
+
     Ok(Json(IndexTemplateResponse {
         index_templates: templates,
     }))
 }
 
+/// Transform a `GET /_index_template` response
+fn transform_index_templates(response: &serde_json::Value) -> Vec<IndexTemplateSummary> {
+    response
+        .get("index_templates")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .map(|tmpl| {
+            let body = tmpl.get("index_template").unwrap_or(tmpl);
+            IndexTemplateSummary {
+                name: tmpl
+                    .get("name")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                index_patterns: body
+                    .get("index_patterns")
+                    .and_then(|v| serde_json::from_value(v.clone()).ok()),
+                priority: body
+                    .get("priority")
+                    .and_then(|v| v.as_i64())
+                    .map(|v| v as i32),
+                version: body
+                    .get("version")
+                    .and_then(|v| v.as_i64())
+                    .map(|v| v as i32),
+                composed_of: body
+                    .get("composed_of")
+                    .and_then(|v| serde_json::from_value(v.clone()).ok()),
+                legacy: false,
+            }
+        })
+        .collect()
+}
+
+/// Transform a `GET /_template` response (legacy templates keyed by name)
+///
+/// Legacy templates have no priority; their `order` is reported instead.
+fn transform_legacy_index_templates(response: &serde_json::Value) -> Vec<IndexTemplateSummary> {
+    response
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(name, tmpl)| IndexTemplateSummary {
+            name: name.clone(),
+            index_patterns: tmpl
+                .get("index_patterns")
+                .and_then(|v| serde_json::from_value(v.clone()).ok()),
+            priority: tmpl.get("order").and_then(|v| v.as_i64()).map(|v| v as i32),
+            version: tmpl
+                .get("version")
+                .and_then(|v| v.as_i64())
+                .map(|v| v as i32),
+            composed_of: None,
+            legacy: true,
+        })
+        .collect()
+}
+
 pub async fn get_template(
     ClusterClient { client, .. }: ClusterClient,
     Path((_, name)): Path<(String, String)>,
-) -> Result<impl IntoResponse, ClusterErrorResponse> {
+) -> Result<impl IntoResponse, ApiError> {
     let response = client.get_index_template(&name).await.map_err(|e| {
-        ClusterErrorResponse::simple("es_request_failed", format!("ES request failed: {}", e))
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("ES request failed: {}", e),
+        )
     })?;
 
     let detail = serde_json::from_value::<IndexTemplateDetail>(response).map_err(|e| {
-        ClusterErrorResponse::simple(
-            "parse_error",
+        ApiError::new(
+            ErrorCode::UpstreamError,
             format!("Failed to parse template response: {}", e),
         )
     })?;
@@ -164,10 +251,11 @@ pub async fn get_template(
 }
 
 pub async fn put_template(
-    ClusterClient { client, .. }: ClusterClient,
+    State(state): State<ClusterState>,
+    ClusterClient { client, cluster_id }: ClusterClient,
     Path((_, name)): Path<(String, String)>,
     Json(body): Json<PutIndexTemplateRequest>,
-) -> Result<impl IntoResponse, ClusterErrorResponse> {
+) -> Result<impl IntoResponse, ApiError> {
     let mut request_body = serde_json::Map::new();
     if let Some(patterns) = body.index_patterns {
         request_body.insert("index_patterns".to_string(), serde_json::json!(patterns));
@@ -192,23 +280,39 @@ pub async fn put_template(
         .put_index_template(&name, serde_json::Value::Object(request_body))
         .await
         .map_err(|e| {
-            ClusterErrorResponse::simple("es_request_failed", format!("ES request failed: {}", e))
+            ApiError::new(
+                ErrorCode::UpstreamError,
+                format!("ES request failed: {}", e),
+            )
         })?;
+    state
+        .cluster_manager
+        .invalidate_resource(CachedResource::Templates, &cluster_id);
 
     let detail = serde_json::from_value::<IndexTemplateDetail>(response).map_err(|e| {
-        ClusterErrorResponse::simple("parse_error", format!("Failed to parse response: {}", e))
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("Failed to parse response: {}", e),
+        )
     })?;
 
     Ok(Json(detail))
 }
 
 pub async fn delete_template(
-    ClusterClient { client, .. }: ClusterClient,
+    State(state): State<ClusterState>,
+    ClusterClient { client, cluster_id }: ClusterClient,
     Path((_, name)): Path<(String, String)>,
-) -> Result<impl IntoResponse, ClusterErrorResponse> {
+) -> Result<impl IntoResponse, ApiError> {
     let response = client.delete_index_template(&name).await.map_err(|e| {
-        ClusterErrorResponse::simple("es_request_failed", format!("ES request failed: {}", e))
+        ApiError::new(
+            ErrorCode::UpstreamError,
+            format!("ES request failed: {}", e),
+        )
     })?;
+    state
+        .cluster_manager
+        .invalidate_resource(CachedResource::Templates, &cluster_id);
 
     Ok(Json(response))
 }
@@ -216,7 +320,7 @@ pub async fn delete_template(
 pub async fn simulate_template(
     ClusterClient { client, .. }: ClusterClient,
     Json(body): Json<SimulateRequest>,
-) -> Result<impl IntoResponse, ClusterErrorResponse> {
+) -> Result<impl IntoResponse, ApiError> {
     let mut request_body = serde_json::Map::new();
     if let Some(patterns) = body.index_patterns {
         request_body.insert("index_patterns".to_string(), serde_json::json!(patterns));
@@ -229,7 +333,10 @@ pub async fn simulate_template(
         .simulate_index_template(serde_json::Value::Object(request_body))
         .await
         .map_err(|e| {
-            ClusterErrorResponse::simple("es_request_failed", format!("ES request failed: {}", e))
+            ApiError::new(
+                ErrorCode::UpstreamError,
+                format!("ES request failed: {}", e),
+            )
         })?;
 
     let template = response
@@ -265,3 +372,63 @@ pub async fn simulate_template(
         overlapping,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_transform_index_templates() {
+        let response = json!({
+            "index_templates": [{
+                "name": "logs",
+                "index_template": {
+                    "index_patterns": ["logs-*"],
+                    "priority": 200,
+                    "version": 3,
+                    "composed_of": ["logs-mappings", "logs-settings"]
+                }
+            }]
+        });
+
+        let templates = transform_index_templates(&response);
+
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].name, "logs");
+        assert_eq!(
+            templates[0].index_patterns.as_deref(),
+            Some(&["logs-*".to_string()][..])
+        );
+        assert_eq!(templates[0].priority, Some(200));
+        assert_eq!(templates[0].version, Some(3));
+        assert_eq!(
+            templates[0].composed_of.as_deref().map(|c| c.len()),
+            Some(2)
+        );
+        assert!(!templates[0].legacy);
+    }
+
+    #[test]
+    fn test_transform_legacy_index_templates() {
+        let response = json!({
+            "old-metrics": {
+                "order": 5,
+                "version": 1,
+                "index_patterns": ["metrics-*"],
+                "settings": {},
+                "mappings": {},
+                "aliases": {}
+            }
+        });
+
+        let templates = transform_legacy_index_templates(&response);
+
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].name, "old-metrics");
+        assert_eq!(templates[0].priority, Some(5));
+        assert_eq!(templates[0].composed_of, None);
+        assert!(templates[0].legacy);
+        assert!(transform_legacy_index_templates(&json!({})).is_empty());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::routes::clusters::transform::ShardInfoResponse;
+use crate::routes::{ApiError, ErrorCode};
 use axum::{
     extract::{Path, State},
     response::IntoResponse,
@@ -45,8 +46,8 @@ pub struct TileBatchResponse {
     request_body = TileBatchRequest,
     responses(
         (status = 200, body = TileBatchResponse),
-        (status = 400, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 401, body = crate::routes::clusters::ClusterErrorResponse)
+        (status = 400, body = crate::routes::ApiError),
+        (status = 401, body = crate::routes::ApiError)
     ),
     tag = "Clusters"
 )]
@@ -65,7 +66,7 @@ pub async fn post_tiles(
         axum::http::HeaderMap,
         Json<TileBatchResponse>,
     ),
-    crate::routes::clusters::ClusterErrorResponse,
+    crate::routes::ApiError,
 > {
     // Enforce cluster access for the requesting user (Open mode allows all access)
 
@@ -85,14 +86,15 @@ pub async fn post_tiles(
             max = max_tiles,
             "Too many tiles requested in a single call"
         );
-        return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-            "too_many_tiles",
+        return Err(ApiError::new(
+            ErrorCode::ValidationFailed,
             format!(
                 "Request contains {} tiles which exceeds the allowed maximum of {}",
                 requests.len(),
                 max_tiles
             ),
-        ));
+        )
+        .with_cluster(&cluster_id));
     }
 
     // Attempt to fetch cluster nodes and shards from ClusterManager via ClusterState
@@ -105,10 +107,7 @@ pub async fn post_tiles(
             // Short-circuit if cluster is marked inaccessible so callers don't
             // attempt live network calls through ClusterConnection.
             if !cluster_conn.accessible {
-                return Err(crate::routes::clusters::ClusterErrorResponse::unavailable(
-                    &cluster_id,
-                    cluster_conn.accessible_reason.clone(),
-                ));
+                return Err(ApiError::inaccessible(&cluster_conn));
             }
             // Fetch nodes_info and nodes_stats via cluster connection
             let nodes_info_opt = cluster_conn.nodes_info().await.ok();
@@ -119,11 +118,15 @@ pub async fn post_tiles(
                 let master_node = cluster_conn.cat_master().await.ok();
                 let master_node_ref = master_node.as_deref();
 
+                let watermarks =
+                    crate::routes::clusters::disk_watermarks(&state.cluster_manager, &cluster_conn)
+                        .await;
                 let transformed = crate::routes::clusters::transform::transform_nodes(
                     &nodes_info,
                     &nodes_stats,
                     master_node_ref,
                     None,
+                    &watermarks,
                 );
 
                 // Get routing nodes for shards
@@ -241,17 +244,19 @@ pub async fn post_tiles(
                         }
                         Ok(Err(e)) => {
                             tracing::error!(error = %e, "Semaphore closed unexpectedly");
-                            return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                                "semaphore_error",
+                            return Err(ApiError::new(
+                                ErrorCode::InternalError,
                                 format!("Failed to acquire generation permit: {}", e),
-                            ));
+                            )
+                            .with_cluster(&cluster_id));
                         }
                         Err(_) => {
                             tracing::warn!("Timed out waiting for generation permit");
-                            return Err(crate::routes::clusters::ClusterErrorResponse::simple(
-                                "generation_concurrency_limited",
+                            return Err(ApiError::new(
+                                ErrorCode::RateLimited,
                                 "Server is currently handling maximum concurrent tile generations; try again later",
-                            ));
+                            )
+                            .with_cluster(&cluster_id));
                         }
                     }
                 }
@@ -1,4 +1,5 @@
 use crate::routes::clusters::transform::ShardInfoResponse;
+use crate::routes::ApiError;
 use axum::{
     extract::{Path, Query, State},
     Json,
@@ -318,8 +319,8 @@ pub fn aggregate_sankey_data(
     ),
     responses(
         (status = 200, body = SankeyDataResponse),
-        (status = 400, body = crate::routes::clusters::ClusterErrorResponse),
-        (status = 401, body = crate::routes::clusters::ClusterErrorResponse),
+        (status = 400, body = crate::routes::ApiError),
+        (status = 401, body = crate::routes::ApiError),
     ),
     tag = "Clusters"
 )]
@@ -327,7 +328,7 @@ pub async fn get_sankey(
     State(state): State<crate::routes::ClusterState>,
     Path(cluster_id): Path<String>,
     Query(params): Query<SankeyQueryParams>,
-) -> Result<Json<SankeyDataResponse>, crate::routes::clusters::ClusterErrorResponse> {
+) -> Result<Json<SankeyDataResponse>, crate::routes::ApiError> {
     tracing::debug!(
         cluster = %cluster_id,
         sort_by = ?params.sort_by,
@@ -342,10 +343,7 @@ pub async fn get_sankey(
             // If cluster is inaccessible, return an explicit unavailable error so
             // the frontend can show why the cluster is not usable.
             if !cluster_conn.accessible {
-                return Err(crate::routes::clusters::ClusterErrorResponse::unavailable(
-                    &cluster_id,
-                    cluster_conn.accessible_reason.clone(),
-                ));
+                return Err(ApiError::inaccessible(&cluster_conn));
             }
 
             // Use _cat/shards which returns human-readable node names in the
@@ -1,19 +1,22 @@
 use crate::auth::SessionManager;
 use crate::cluster::Manager as ClusterManager;
-use crate::config::Config;
+use crate::config::{Config, LocalUser};
 use crate::telemetry::axum_middleware::OtelTraceLayer;
 use crate::telemetry::config::TelemetryConfig;
 use anyhow::Context;
 use axum::{
-    http::Method,
+    extract::{ConnectInfo, DefaultBodyLimit},
     middleware,
-    routing::{get, post, put},
-    Router,
+    routing::{delete, get, post, put},
+    Extension, Router,
 };
+use std::net::{Ipv4Addr, SocketAddr};
+use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{RwLock, Semaphore};
-use tower_http::{compression::CompressionLayer, cors::CorsLayer};
+use tower_http::compression::CompressionLayer;
 
 /// Server structure containing application state and configuration
 #[derive(Clone)]
@@ -28,6 +31,13 @@ pub struct Server {
     pub oidc_provider: Option<Arc<crate::auth::OidcAuthProvider>>,
     /// LDAP provider (initialized if LDAP mode is configured)
     pub ldap_provider: Option<Arc<crate::auth::LdapAuthProvider>>,
+    /// Local user provider (initialized if local users mode is configured,
+    /// users swapped on config reload)
+    pub local_provider: Option<Arc<crate::auth::LocalAuthProvider>>,
+    /// Rate limiter shared by local user logins and API token authentication
+    pub rate_limiter: crate::auth::RateLimiter,
+    /// RBAC manager shared by all routes and middleware (swapped on config reload)
+    pub rbac: crate::auth::RbacManager,
 }
 
 impl Server {
@@ -95,12 +105,32 @@ impl Server {
             None
         };
 
+        let rate_limiter = crate::auth::RateLimiter::new(crate::auth::RateLimitConfig::default());
+
+        // Initialize local user provider if local users mode is configured
+        let local_provider = match (&config.auth.mode, &config.auth.local_users) {
+            (crate::config::AuthMode::LocalUsers, Some(local_users)) => {
+                session_manager.set_local_credentials(local_users);
+                Some(Arc::new(crate::auth::LocalAuthProvider::with_rate_limiter(
+                    local_users.clone(),
+                    session_manager.clone(),
+                    rate_limiter.clone(),
+                )))
+            }
+            _ => None,
+        };
+
+        let rbac = crate::auth::RbacManager::new(config.auth.roles.clone());
+
         Ok(Self {
             config: Arc::new(config),
             cluster_manager: Arc::new(cluster_manager),
             session_manager: Arc::new(session_manager),
             oidc_provider,
             ldap_provider,
+            local_provider,
+            rate_limiter,
+            rbac,
         })
     }
 
@@ -114,34 +144,27 @@ impl Server {
     ///
     /// Validates: Requirements 1.2, 30.2, 30.7, 31.8
     pub fn router(&self) -> Router {
-        // Prepare optional local provider holder so we can attach it to the
-        // routes-level AuthState after construction.
-        let mut local_provider_option: Option<Arc<crate::auth::LocalAuthProvider>> = None;
-
         // Create auth state for middleware (independent of routes state)
-        let auth_middleware_state = Arc::new(crate::auth::AuthState::new(
+        let mut auth_middleware_state = crate::auth::AuthState::new(
             self.session_manager.clone(),
             self.config.auth.mode.clone(),
-        ));
-
-        // If local users mode, construct LocalAuthProvider with optional rate limiter
-        if self.config.auth.mode == crate::config::AuthMode::LocalUsers {
-            if let Some(local_users) = &self.config.auth.local_users {
-                let session_manager_clone = self.session_manager.clone();
-                let rate_limiter =
-                    crate::auth::RateLimiter::new(crate::auth::RateLimitConfig::default());
-                let provider = crate::auth::LocalAuthProvider::with_rate_limiter(
-                    local_users.clone(),
-                    (*session_manager_clone).clone(),
-                    rate_limiter,
-                );
-                // Attach to routes state by saving into the local option so it
-                // can be used when constructing the AuthState below.
-                local_provider_option = Some(Arc::new(provider));
-            }
+        );
+        if self.config.auth.mode != crate::config::AuthMode::Open
+            && !self.config.auth.api_tokens.is_empty()
+        {
+            auth_middleware_state = auth_middleware_state.with_api_tokens(Arc::new(
+                crate::auth::ApiTokenAuthenticator::new(
+                    self.config.auth.api_tokens.clone(),
+                    self.rate_limiter.clone(),
+                ),
+            ));
+        }
+        if let Some(oidc_provider) = &self.oidc_provider {
+            auth_middleware_state = auth_middleware_state.with_oidc_provider(oidc_provider.clone());
         }
+        let auth_middleware_state = Arc::new(auth_middleware_state);
 
-        let rbac = crate::auth::RbacManager::new(self.config.auth.roles.clone());
+        let rbac = self.rbac.clone();
 
         // Create auth state for authentication routes (attach local provider if any)
         let auth_routes_state = crate::routes::AuthState {
@@ -149,7 +172,7 @@ impl Server {
             ldap_provider: self.ldap_provider.clone(),
             session_manager: self.session_manager.clone(),
             config: self.config.clone(),
-            local_provider: local_provider_option,
+            local_provider: self.local_provider.clone(),
             cluster_manager: self.cluster_manager.clone(),
             rbac: rbac.clone(),
         };
@@ -212,6 +235,9 @@ impl Server {
         let topology_limit = self.config.topology_max_concurrent_generations.unwrap_or(4);
         let topology_generation_semaphore = Arc::new(Semaphore::new(topology_limit));
 
+        // Weak ETags let dashboards poll stats without re-downloading unchanged payloads
+        let etag_layer = axum::middleware::from_fn(crate::middleware::etag::etag_middleware);
+
         let cluster_state = crate::routes::ClusterState {
             cluster_manager: self.cluster_manager.clone(),
             details_cache: details_cache.clone(),
@@ -229,6 +255,10 @@ impl Server {
             // Audit logging enabled flag propagated from top-level config
             audit_log: self.config.audit_log,
             rbac: rbac.clone(),
+            proxy_allow: self.config.proxy_allow.clone(),
+            max_proxy_body_bytes: self.config.server.max_proxy_body_bytes,
+            cors_allowed_origins: self.config.server.cors_allowed_origins.clone(),
+            recovery_stream_slots: Default::default(),
         };
 
         // Create metrics state for metrics routes
@@ -240,6 +270,7 @@ impl Server {
         let health_state = crate::routes::health::HealthState {
             cluster_manager: self.cluster_manager.clone(),
             session_manager: self.session_manager.clone(),
+            read_only: self.config.server.read_only,
         };
 
         // Build the router with all routes
@@ -248,8 +279,21 @@ impl Server {
             // These must come before .with_state() since they use different state
             .route("/health", get(crate::routes::health::health_check))
             .route("/ready", get(crate::routes::health::readiness_check))
+            .route("/health/live", get(crate::routes::health::liveness_check))
+            .route(
+                "/health/ready",
+                get(crate::routes::health::cluster_readiness_check),
+            )
             .route("/api/version", get(crate::routes::health::get_version))
-            .with_state(Arc::new(RwLock::new(health_state)));
+            .with_state(Arc::new(RwLock::new(health_state)))
+            // Login screen branding (no auth required)
+            .route(
+                "/api/branding",
+                get(crate::routes::branding::get_branding)
+                    .with_state(Arc::new(self.config.branding.clone())),
+            )
+            // Prometheus scrape endpoint for secan's own metrics (no auth required)
+            .merge(crate::routes::app_metrics::app_metrics_router());
 
         // Add OTLP proxy endpoint only if telemetry is enabled
         if let Some(proxy_state) = otel_proxy_state {
@@ -259,11 +303,19 @@ impl Server {
             );
         }
 
-        app
+        let app = app
             // Authentication routes
             .route("/api/auth/login", post(crate::routes::auth::login))
             .route("/api/auth/logout", post(crate::routes::auth::logout))
             .route("/api/auth/me", get(crate::routes::auth::get_current_user))
+            .route(
+                "/api/auth/sessions",
+                get(crate::routes::auth::list_sessions),
+            )
+            .route(
+                "/api/auth/sessions/revoke",
+                post(crate::routes::auth::revoke_sessions),
+            )
             .route(
                 "/api/auth/status",
                 get(crate::routes::auth::get_auth_status),
@@ -283,7 +335,7 @@ impl Server {
             // Typed SDK routes for cluster data
             .route(
                 "/api/clusters/{id}/stats",
-                get(crate::routes::clusters::get_cluster_stats),
+                get(crate::routes::clusters::get_cluster_stats).layer(etag_layer.clone()),
             )
             .route(
                 "/api/clusters/{id}/details",
@@ -299,7 +351,7 @@ impl Server {
             )
             .route(
                 "/api/clusters/{id}/nodes",
-                get(crate::routes::clusters::get_nodes),
+                get(crate::routes::clusters::get_nodes).layer(etag_layer.clone()),
             )
             // Topology tile generation (per-cluster)
             .route(
@@ -313,11 +365,49 @@ impl Server {
             )
             .route(
                 "/api/clusters/{id}/nodes/{nodeId}/stats",
-                get(crate::routes::clusters::get_node_stats),
+                get(crate::routes::clusters::get_node_stats).layer(etag_layer.clone()),
+            )
+            .route(
+                "/api/clusters/{id}/nodes/{nodeId}/hot_threads",
+                get(crate::routes::clusters::hot_threads::get_hot_threads),
             )
             .route(
                 "/api/clusters/{id}/indices",
-                get(crate::routes::clusters::get_indices),
+                get(crate::routes::clusters::get_indices).layer(etag_layer.clone()),
+            )
+            .route(
+                "/api/clusters/{id}/indices/{index}/ilm",
+                get(crate::routes::clusters::get_index_ilm),
+            )
+            // Index state-changing operations (require write permission)
+            .route(
+                "/api/clusters/{id}/indices/{index}",
+                delete(crate::routes::clusters::index_operations::delete_index),
+            )
+            .route(
+                "/api/clusters/{id}/indices/{index}/_open",
+                post(crate::routes::clusters::index_operations::open_index),
+            )
+            .route(
+                "/api/clusters/{id}/indices/{index}/_close",
+                post(crate::routes::clusters::index_operations::close_index),
+            )
+            .route(
+                "/api/clusters/{id}/indices/{index}/_forcemerge",
+                post(crate::routes::clusters::index_operations::force_merge_index),
+            )
+            .route(
+                "/api/clusters/{id}/indices/{index}/_flush",
+                post(crate::routes::clusters::index_operations::flush_index),
+            )
+            .route(
+                "/api/clusters/{id}/indices/{index}/_refresh",
+                post(crate::routes::clusters::index_operations::refresh_index),
+            )
+            .route(
+                "/api/clusters/{id}/indices/{index}/settings",
+                get(crate::routes::clusters::index_operations::get_index_settings)
+                    .put(crate::routes::clusters::index_operations::update_index_settings),
             )
             .route(
                 "/api/clusters/{id}/shards",
@@ -337,6 +427,65 @@ impl Server {
                 "/api/clusters/{id}/nodes/shard-summary",
                 get(crate::routes::clusters::get_nodes_shard_summary),
             )
+            .route(
+                "/api/clusters/health",
+                get(crate::routes::clusters::get_all_clusters_health),
+            )
+            .route(
+                "/api/summary",
+                get(crate::routes::clusters::get_clusters_summary),
+            )
+            .route(
+                "/api/clusters/search",
+                get(crate::routes::clusters::search::search_clusters),
+            )
+            .route(
+                "/api/clusters/compare",
+                get(crate::routes::clusters::compare::compare_clusters),
+            )
+            .route(
+                "/api/clusters/{id}/health",
+                get(crate::routes::clusters::get_cluster_health),
+            )
+            .route(
+                "/api/clusters/{id}/health/stream",
+                get(crate::routes::clusters::health_stream::stream_cluster_health),
+            )
+            .route(
+                "/api/clusters/{id}/deprecations",
+                get(crate::routes::clusters::deprecations::get_deprecations),
+            )
+            .route(
+                "/api/clusters/{id}/diagnostics",
+                get(crate::routes::clusters::diagnostics::get_diagnostics),
+            )
+            .route(
+                "/api/clusters/{id}/recovery",
+                get(crate::routes::clusters::recovery::get_recovery),
+            )
+            .route(
+                "/api/clusters/{id}/ws/recovery",
+                get(crate::routes::clusters::recovery::recovery_ws),
+            )
+            // Snapshot endpoints
+            .route(
+                "/api/clusters/{id}/snapshots/repositories",
+                get(crate::routes::clusters::get_snapshot_repositories),
+            )
+            .route(
+                "/api/clusters/{id}/snapshots/{repository}",
+                get(crate::routes::clusters::get_snapshots),
+            )
+            // Shard allocation explain endpoint
+            .route(
+                "/api/clusters/{id}/allocation/explain",
+                get(crate::routes::clusters::allocation::explain_allocation),
+            )
+            // Typed _cat endpoint
+            .route(
+                "/api/clusters/{id}/cat/{target}",
+                get(crate::routes::clusters::cat::cat),
+            )
             // Shard relocation endpoint
             .route(
                 "/api/clusters/{id}/shards/relocate",
@@ -355,6 +504,23 @@ impl Server {
                 "/api/clusters/{id}/tasks/{task_id}/_cancel",
                 post(crate::routes::clusters::tasks::cancel_cluster_task),
             )
+            .route(
+                "/api/clusters/{id}/pending_tasks",
+                get(crate::routes::clusters::tasks::get_pending_tasks),
+            )
+            .route(
+                "/api/clusters/{id}/_flush/synced",
+                post(crate::routes::clusters::flush::synced_flush),
+            )
+            // Reindex and task progress endpoints
+            .route(
+                "/api/clusters/{id}/_reindex",
+                post(crate::routes::clusters::reindex::start_reindex),
+            )
+            .route(
+                "/api/clusters/{id}/_tasks/{task_id}",
+                get(crate::routes::clusters::reindex::get_task_progress),
+            )
             // Index templates endpoints
             .route(
                 "/api/clusters/{id}/index-templates",
@@ -402,7 +568,10 @@ impl Server {
                 get(crate::routes::clusters::proxy_request)
                     .post(crate::routes::clusters::proxy_request)
                     .put(crate::routes::clusters::proxy_request)
-                    .delete(crate::routes::clusters::proxy_request),
+                    .delete(crate::routes::clusters::proxy_request)
+                    .layer(DefaultBodyLimit::max(
+                        self.config.server.max_proxy_body_bytes,
+                    )),
             )
             .with_state(cluster_state)
             // Static assets - must be last to act as fallback
@@ -411,69 +580,157 @@ impl Server {
             .layer(middleware::from_fn_with_state(
                 Arc::new(crate::middleware::permissions::PermissionState::new(
                     self.config.auth.mode.clone(),
-                    crate::auth::RbacManager::new(self.config.auth.roles.clone()),
+                    rbac.clone(),
                 )),
                 crate::middleware::permissions::permission_middleware,
             ))
+            // Reject cluster changes in read-only mode, before any role is considered
+            .layer(middleware::from_fn_with_state(
+                self.config.server.read_only,
+                crate::middleware::read_only::read_only_middleware,
+            ))
+            // Apply request rate limits (after auth, so requests count per user)
+            .layer(middleware::from_fn_with_state(
+                Arc::new(crate::middleware::rate_limit::RateLimitState::new(
+                    self.config.server.rate_limits.clone(),
+                )),
+                crate::middleware::rate_limit::rate_limit_middleware,
+            ))
             // Add auth middleware (handles authentication in all modes)
             .layer(middleware::from_fn_with_state(
                 auth_middleware_state.clone(),
                 crate::auth::auth_middleware,
             ))
+            // Resolve the client IP (through trusted proxies) before authentication
+            .layer(middleware::from_fn_with_state(
+                Arc::new(crate::middleware::client_ip::ClientIpState::new(
+                    self.config.server.trusted_proxies.clone(),
+                )),
+                crate::middleware::client_ip::client_ip_middleware,
+            ))
             // Add security headers middleware (CSP, HSTS, X-Frame-Options, etc.)
-            .layer(middleware::from_fn(
+            .layer(middleware::from_fn_with_state(
+                crate::middleware::security::content_security_policy(
+                    self.config.branding.logo_origin().as_deref(),
+                ),
                 crate::middleware::security::security_headers_middleware,
             ))
             // Add logging middleware (logs all requests with request IDs)
             .layer(middleware::from_fn(
                 crate::middleware::logging::logging_middleware,
             ))
+            // Add metrics middleware (counts requests by route and status)
+            .layer(middleware::from_fn(
+                crate::middleware::metrics::metrics_middleware,
+            ))
             // Add OpenTelemetry tracing layer (creates OTel spans for requests)
             .layer(OtelTraceLayer)
-            // Add CORS middleware with proper configuration
-            // Security: If allowed_origins is configured, restrict to those origins only
-            // This prevents external API access when using embedded frontend
-            .layer({
-                let mut cors = CorsLayer::new()
-                    .allow_methods([
-                        Method::GET,
-                        Method::POST,
-                        Method::PUT,
-                        Method::DELETE,
-                        Method::HEAD,
-                        Method::OPTIONS,
-                    ])
-                    .allow_headers([
-                        axum::http::header::CONTENT_TYPE,
-                        axum::http::header::AUTHORIZATION,
-                        axum::http::header::ACCEPT,
-                    ]);
-
-                if self.config.server.allowed_origins.is_empty() {
-                    tracing::warn!(
-                        "CORS: no allowed_origins configured — accepting any origin. \
-                        Set server.allowed_origins in config for production deployments."
-                    );
-                    cors = cors.allow_origin(tower_http::cors::Any);
-                } else {
-                    tracing::info!(
-                        origins = ?self.config.server.allowed_origins,
-                        "CORS restricted to configured origins"
-                    );
-                    // Convert string origins to HeaderValue
-                    let origins: Vec<axum::http::HeaderValue> = self
-                        .config
-                        .server
-                        .allowed_origins
-                        .iter()
-                        .filter_map(|s| s.parse().ok())
-                        .collect();
-                    cors = cors.allow_origin(origins);
-                }
-                cors
-            })
-            // Add compression middleware
-            .layer(CompressionLayer::new())
+            // Add CORS middleware for the configured origins. Without any
+            // configured origins no CORS headers are sent (same-origin only).
+            .layer(tower::util::option_layer(
+                crate::middleware::cors::cors_layer(&self.config.server.cors_allowed_origins),
+            ));
+
+        // Serve everything under the base path, if configured. Nesting strips
+        // the prefix, so routes and middleware keep matching on `/api/...`
+        // and only absolute URLs sent to the browser add it back.
+        let prefix = self.config.server.path_prefix();
+        let app = if prefix.is_empty() {
+            app
+        } else {
+            Router::new().nest(
+                prefix,
+                app.layer(Extension(crate::middleware::base_path::BasePath(
+                    prefix.to_string(),
+                ))),
+            )
+        };
+
+        // Add compression middleware (gzip/brotli, negotiated via Accept-Encoding)
+        if self.config.server.compression {
+            app.layer(CompressionLayer::new().gzip(true).br(true))
+        } else {
+            app
+        }
+    }
+
+    /// Reload configuration from disk and apply it without restarting
+    ///
+    /// Re-runs `Config::load_with_warnings` (including validation) and swaps the
+    /// cluster set, RBAC roles and local users. Sessions of local users whose
+    /// password hash changed or who were removed are revoked; other sessions
+    /// are untouched. If loading or validation fails, the running
+    /// configuration is kept and the error is logged.
+    ///
+    /// Other settings (server address, auth mode, providers, cache sizes) still
+    /// require a restart.
+    pub async fn reload_config(&self) {
+        let (config, warnings) = match Config::load_with_warnings() {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                tracing::error!(error = %e, "Config reload failed, keeping current configuration");
+                return;
+            }
+        };
+
+        if config.auth.mode != self.config.auth.mode {
+            tracing::warn!(
+                current = ?self.config.auth.mode,
+                new = ?config.auth.mode,
+                "Auth mode change requires a restart and was not applied"
+            );
+        }
+
+        if let Err(e) = self
+            .cluster_manager
+            .reload_clusters(config.clusters.clone(), Some(warnings))
+            .await
+        {
+            tracing::error!(error = %e, "Config reload failed, keeping current configuration");
+            return;
+        }
+
+        self.rbac.reload(config.auth.roles.clone());
+        // Users of a mode that is not applied yet must not replace the running ones
+        if config.auth.mode == self.config.auth.mode {
+            self.reload_local_users(config.auth.local_users.clone().unwrap_or_default())
+                .await;
+        }
+
+        tracing::info!(
+            cluster_count = config.clusters.len(),
+            role_count = config.auth.roles.len(),
+            "Configuration reloaded"
+        );
+    }
+
+    /// Swap the local users and revoke the sessions of users whose password
+    /// hash changed or who were removed, so old credentials can't ride an
+    /// existing session
+    ///
+    /// Tokens are bound to the password hash they were issued for, so this
+    /// also rejects tokens this instance never tracked. Returns the number of
+    /// tracked sessions revoked.
+    pub async fn reload_local_users(&self, users: Vec<LocalUser>) -> usize {
+        let Some(local_provider) = &self.local_provider else {
+            return 0;
+        };
+
+        self.session_manager.set_local_credentials(&users);
+
+        let mut revoked = 0;
+        for username in local_provider.reload(users) {
+            let session_ids = self.session_manager.revoke_user_sessions(&username).await;
+            if !session_ids.is_empty() {
+                tracing::info!(
+                    username = %username,
+                    sessions = session_ids.len(),
+                    "Revoked sessions of local user whose password changed or who was removed"
+                );
+            }
+            revoked += session_ids.len();
+        }
+        revoked
     }
 
     /// Start the server and listen for incoming connections
@@ -495,6 +752,10 @@ impl Server {
     ///
     /// Validates: Requirements 1.2, 30.1, 30.8
     pub async fn run(self) -> anyhow::Result<()> {
+        if let Some(socket_path) = self.config.server.unix_socket.clone() {
+            return self.run_unix(&socket_path).await;
+        }
+
         let addr = format!("{}:{}", self.config.server.host, self.config.server.port);
         let listener = tokio::net::TcpListener::bind(&addr).await?;
 
@@ -513,34 +774,115 @@ impl Server {
         tracing::info!(address = %addr, "Server listening");
 
         let app = self.router();
+        self.spawn_reload_handler();
 
-        // Setup graceful shutdown signal handler
-        let shutdown_signal = async {
-            let mut sigterm =
-                tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
-                    .expect("Failed to setup SIGTERM handler");
-            let mut sigint =
-                tokio::signal::unix::signal(tokio::signal::unix::SignalKind::interrupt())
-                    .expect("Failed to setup SIGINT handler");
-
-            tokio::select! {
-                _ = sigterm.recv() => {
-                    tracing::info!("Received SIGTERM, starting graceful shutdown");
-                },
-                _ = sigint.recv() => {
-                    tracing::info!("Received SIGINT, starting graceful shutdown");
-                },
-            }
-        };
+        // Peer address is used to rate limit API token authentication per client
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+        )
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
+
+        tracing::info!("Server shut down gracefully");
+
+        Ok(())
+    }
+
+    /// Serve the application on a Unix domain socket
+    ///
+    /// Connections over the socket come from a local reverse proxy, so they
+    /// are reported to the middleware as coming from loopback. Add
+    /// `127.0.0.1` to `trusted_proxies` to take the client IP from the
+    /// proxy's forwarding headers.
+    async fn run_unix(self, socket_path: &Path) -> anyhow::Result<()> {
+        let listener = bind_unix_socket(socket_path, self.config.server.unix_socket_mode)?;
+        tracing::info!(socket = %socket_path.display(), "Server listening");
+
+        let app = self.router().layer(Extension(ConnectInfo(SocketAddr::from((
+            Ipv4Addr::LOCALHOST,
+            0,
+        )))));
+        self.spawn_reload_handler();
 
         axum::serve(listener, app)
-            .with_graceful_shutdown(shutdown_signal)
+            .with_graceful_shutdown(shutdown_signal())
             .await?;
 
+        if let Err(e) = std::fs::remove_file(socket_path) {
+            tracing::warn!(socket = %socket_path.display(), error = %e, "Failed to remove socket file");
+        }
         tracing::info!("Server shut down gracefully");
 
         Ok(())
     }
+
+    /// Reload clusters and RBAC roles on SIGHUP
+    fn spawn_reload_handler(&self) {
+        let reload_server = self.clone();
+        tokio::spawn(async move {
+            let mut sighup = match tokio::signal::unix::signal(
+                tokio::signal::unix::SignalKind::hangup(),
+            ) {
+                Ok(signal) => signal,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to setup SIGHUP handler, config reload disabled");
+                    return;
+                }
+            };
+
+            while sighup.recv().await.is_some() {
+                tracing::info!("Received SIGHUP, reloading configuration");
+                reload_server.reload_config().await;
+            }
+        });
+    }
+}
+
+/// Resolve once SIGTERM or SIGINT is received
+async fn shutdown_signal() {
+    let mut sigterm = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+        .expect("Failed to setup SIGTERM handler");
+    let mut sigint = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::interrupt())
+        .expect("Failed to setup SIGINT handler");
+
+    tokio::select! {
+        _ = sigterm.recv() => {
+            tracing::info!("Received SIGTERM, starting graceful shutdown");
+        },
+        _ = sigint.recv() => {
+            tracing::info!("Received SIGINT, starting graceful shutdown");
+        },
+    }
+}
+
+/// Bind a Unix domain socket and apply `mode` to the socket file
+///
+/// A socket file left behind by a previous run is removed first. A socket
+/// another process is still listening on, or a path that is not a socket,
+/// is an error.
+fn bind_unix_socket(path: &Path, mode: u32) -> anyhow::Result<tokio::net::UnixListener> {
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => {
+            if std::os::unix::net::UnixStream::connect(path).is_ok() {
+                anyhow::bail!("Socket {} is already in use", path.display());
+            }
+            tracing::debug!(socket = %path.display(), "Removing stale socket file");
+            std::fs::remove_file(path)
+                .with_context(|| format!("Failed to remove stale socket {}", path.display()))?;
+        }
+        Ok(_) => anyhow::bail!("{} exists and is not a socket", path.display()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => {
+            return Err(e).with_context(|| format!("Failed to inspect {}", path.display()));
+        }
+    }
+
+    let listener = tokio::net::UnixListener::bind(path)
+        .with_context(|| format!("Failed to bind socket {}", path.display()))?;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode))
+        .with_context(|| format!("Failed to set permissions on {}", path.display()))?;
+    Ok(listener)
 }
 
 #[cfg(test)]
@@ -557,15 +899,29 @@ mod tests {
                 host: "127.0.0.1".to_string(),
                 port: 27182,
                 tls: None,
-                allowed_origins: vec![],
+                cors_allowed_origins: vec![],
+                compression: true,
+                trusted_proxies: vec![],
+                rate_limits: vec![],
+                unix_socket: None,
+                unix_socket_mode: 0o660,
+                fail_on_unreachable_cluster: false,
+                max_proxy_body_bytes: 1024,
+                es_query_timeout_seconds: 10,
+                cert_expiry_warning_days: 30,
+                read_only: false,
+                base_path: None,
             },
             auth: AuthConfig {
                 mode: AuthMode::Open,
                 session_timeout_minutes: 60,
+                session_idle_timeout_minutes: None,
+                session_cookie: Default::default(),
                 local_users: None,
                 oidc: None,
                 ldap: None,
                 roles: vec![],
+                api_tokens: vec![],
             },
             clusters: vec![],
             cache: crate::config::CacheConfig::default(),
@@ -573,6 +929,12 @@ mod tests {
             topology_max_concurrent_generations: None,
             topology_generation_acquire_timeout_seconds: None,
             audit_log: false,
+            proxy_allow: None,
+            notifications: crate::config::NotificationsConfig::default(),
+            logging: crate::config::LoggingConfig::default(),
+            tracing: crate::config::TracingConfig::default(),
+            default_cluster: None,
+            branding: Default::default(),
         }
     }
 
@@ -606,6 +968,77 @@ mod tests {
         assert_eq!(server.config.server.port, 27182);
     }
 
+    #[tokio::test]
+    async fn test_reload_revokes_sessions_of_users_with_changed_password() {
+        let user = |username: &str, password: &str| LocalUser {
+            username: username.to_string(),
+            password_hash: crate::auth::hash_password_with_cost(password, 4)
+                .expect("hash password"),
+            groups: vec![],
+        };
+        let alice = user("alice", "old-password");
+        let bob = user("bob", "password");
+
+        let mut config = create_test_config();
+        config.auth.mode = AuthMode::LocalUsers;
+        config.auth.local_users = Some(vec![alice, bob.clone()]);
+        let cluster_manager = ClusterManager::new(
+            vec![ClusterConfig {
+                id: "test".to_string(),
+                nodes: vec!["http://localhost:9200".to_string()],
+                ..Default::default()
+            }],
+            std::time::Duration::from_secs(30),
+        )
+        .await
+        .expect("create cluster manager");
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let server = Server::new(config, cluster_manager, session_manager)
+            .await
+            .expect("create server");
+
+        let local_provider = server.local_provider.clone().expect("local provider");
+        let login = |username: &'static str, password: &'static str| {
+            let local_provider = local_provider.clone();
+            async move {
+                local_provider
+                    .authenticate(username, password)
+                    .await
+                    .expect("authenticate")
+                    .expect("valid credentials")
+            }
+        };
+        let alice_token = login("alice", "old-password").await;
+        let bob_token = login("bob", "password").await;
+
+        // Rotate alice's password hash; bob is unchanged
+        let revoked = server
+            .reload_local_users(vec![user("alice", "new-password"), bob])
+            .await;
+
+        assert_eq!(revoked, 1);
+        let is_valid = |token: String| {
+            let session_manager = server.session_manager.clone();
+            async move {
+                session_manager
+                    .validate_session(&token)
+                    .await
+                    .expect("validate session")
+                    .is_some()
+            }
+        };
+        assert!(!is_valid(alice_token).await);
+        assert!(is_valid(bob_token).await);
+
+        // The new password works, the old one no longer does
+        assert!(local_provider
+            .authenticate("alice", "old-password")
+            .await
+            .expect("authenticate")
+            .is_none());
+        login("alice", "new-password").await;
+    }
+
     #[tokio::test]
     async fn test_router_creation() {
         let config = create_test_config();
@@ -633,4 +1066,941 @@ mod tests {
         // Router creation should succeed
         // We can't easily test the routes without starting the server
     }
+
+    #[tokio::test]
+    async fn test_proxy_rejects_request_body_over_limit() {
+        use axum::body::Body;
+        use axum::http::{header, Request, StatusCode};
+        use tower::ServiceExt;
+
+        let config = create_test_config();
+        let cluster_config = ClusterConfig {
+            id: "test".to_string(),
+            nodes: vec!["http://127.0.0.1:9".to_string()],
+            ..Default::default()
+        };
+        let cluster_manager =
+            ClusterManager::new(vec![cluster_config], std::time::Duration::from_secs(30))
+                .await
+                .expect("create cluster manager");
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let server = Server::new(config, cluster_manager, session_manager)
+            .await
+            .expect("create server");
+
+        let body = serde_json::json!({ "padding": "x".repeat(2048) }).to_string();
+        let request = Request::builder()
+            .method("POST")
+            .uri("/api/clusters/test/_search")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body))
+            .expect("build request");
+        let response = server
+            .router()
+            .oneshot(request)
+            .await
+            .expect("send request");
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        let bytes = axum::body::to_bytes(response.into_body(), 64 * 1024)
+            .await
+            .expect("read body");
+        let error: serde_json::Value = serde_json::from_slice(&bytes).expect("parse body");
+        assert_eq!(error["code"], "payload_too_large");
+    }
+
+    #[tokio::test]
+    async fn test_proxy_streams_response_with_headers() {
+        use axum::body::Body;
+        use axum::http::{header, Request, StatusCode};
+        use tower::ServiceExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let es = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/_cat/health"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-elastic-product", "Elasticsearch")
+                    .set_body_raw("green", "text/plain"),
+            )
+            .mount(&es)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/_cat/indices"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("x".repeat(2048)))
+            .mount(&es)
+            .await;
+
+        let cluster_config = ClusterConfig {
+            id: "test".to_string(),
+            nodes: vec![es.uri()],
+            ..Default::default()
+        };
+        let cluster_manager =
+            ClusterManager::new(vec![cluster_config], std::time::Duration::from_secs(30))
+                .await
+                .expect("create cluster manager");
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let server = Server::new(create_test_config(), cluster_manager, session_manager)
+            .await
+            .expect("create server");
+
+        let request = Request::builder()
+            .uri("/api/clusters/test/_cat/health")
+            .body(Body::empty())
+            .expect("build request");
+        let response = server
+            .router()
+            .oneshot(request)
+            .await
+            .expect("send request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response
+                .headers()
+                .get(header::CONTENT_TYPE)
+                .map(|v| v.as_bytes()),
+            Some(&b"text/plain"[..])
+        );
+        assert!(response.headers().get("x-elastic-product").is_some());
+        let bytes = axum::body::to_bytes(response.into_body(), 64 * 1024)
+            .await
+            .expect("read body");
+        assert_eq!(&bytes[..], b"green");
+
+        // Responses declaring a length over the limit are refused up front
+        let request = Request::builder()
+            .uri("/api/clusters/test/_cat/indices")
+            .body(Body::empty())
+            .expect("build request");
+        let response = server
+            .router()
+            .oneshot(request)
+            .await
+            .expect("send request");
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_proxy_decodes_gzipped_response() {
+        use axum::body::Body;
+        use axum::http::{header, Request, StatusCode};
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+        use tower::ServiceExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(br#"{"count":42}"#)
+            .expect("compress body");
+        let es = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/logs/_count"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-encoding", "gzip")
+                    .set_body_raw(encoder.finish().expect("finish gzip"), "application/json"),
+            )
+            .mount(&es)
+            .await;
+
+        let cluster_config = ClusterConfig {
+            id: "test".to_string(),
+            nodes: vec![es.uri()],
+            ..Default::default()
+        };
+        let cluster_manager =
+            ClusterManager::new(vec![cluster_config], std::time::Duration::from_secs(30))
+                .await
+                .expect("create cluster manager");
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let server = Server::new(create_test_config(), cluster_manager, session_manager)
+            .await
+            .expect("create server");
+
+        let request = Request::builder()
+            .uri("/api/clusters/test/logs/_count")
+            .body(Body::empty())
+            .expect("build request");
+        let response = server
+            .router()
+            .oneshot(request)
+            .await
+            .expect("send request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+        let bytes = axum::body::to_bytes(response.into_body(), 64 * 1024)
+            .await
+            .expect("read body");
+        assert_eq!(&bytes[..], br#"{"count":42}"#);
+    }
+
+    #[tokio::test]
+    async fn test_cluster_stats_route_transforms_mock_responses() {
+        use crate::cluster::mock::MockClient;
+        use crate::cluster::ClusterConnection;
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let client = MockClient::new()
+            .with_json(
+                "_cluster/stats",
+                serde_json::json!({
+                    "cluster_name": "fixture",
+                    "indices": {"count": 3, "docs": {"count": 1200}},
+                    "nodes": {
+                        "jvm": {"mem": {"heap_used_in_bytes": 512, "heap_max_in_bytes": 1024}},
+                        "fs": {"total_in_bytes": 1000, "available_in_bytes": 250}
+                    }
+                }),
+            )
+            .with_json(
+                "_cluster/health",
+                serde_json::json!({
+                    "cluster_name": "fixture",
+                    "status": "yellow",
+                    "number_of_nodes": 2,
+                    "number_of_data_nodes": 2,
+                    "active_primary_shards": 3,
+                    "active_shards": 5,
+                    "unassigned_shards": 1
+                }),
+            )
+            .with_json(
+                "_nodes/stats",
+                serde_json::json!({"nodes": {
+                    "a": {"os": {"cpu": {"percent": 20}}},
+                    "b": {"os": {"cpu": {"percent": 40}}}
+                }}),
+            )
+            .with_json("/", serde_json::json!({"version": {"number": "8.15.0"}}));
+        let cluster_config = ClusterConfig::new("mock".to_string(), vec!["http://mock".into()]);
+        let cluster_manager = ClusterManager::with_connections(
+            vec![ClusterConnection::with_client(
+                &cluster_config,
+                Arc::new(client),
+            )],
+            std::time::Duration::from_secs(30),
+        );
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let server = Server::new(create_test_config(), cluster_manager, session_manager)
+            .await
+            .expect("create server");
+
+        let request = Request::builder()
+            .uri("/api/clusters/mock/stats")
+            .body(Body::empty())
+            .expect("build request");
+        let response = server
+            .router()
+            .oneshot(request)
+            .await
+            .expect("send request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), 64 * 1024)
+            .await
+            .expect("read body");
+        let stats: serde_json::Value = serde_json::from_slice(&bytes).expect("json body");
+        assert_eq!(
+            stats,
+            serde_json::json!({
+                "health": "yellow",
+                "clusterName": "fixture",
+                "numberOfNodes": 2,
+                "numberOfDataNodes": 2,
+                "numberOfIndices": 3,
+                "numberOfDocuments": 1200,
+                "activePrimaryShards": 3,
+                "activeShards": 5,
+                "relocatingShards": 0,
+                "initializingShards": 0,
+                "unassignedShards": 1,
+                "memoryUsed": 512,
+                "memoryTotal": 1024,
+                "diskUsed": 750,
+                "diskTotal": 1000,
+                "cpuPercent": 30,
+                "esVersion": "v8.15.0"
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_diagnostics_route_bundles_sections_and_reports_failures() {
+        use crate::cluster::mock::MockClient;
+        use crate::cluster::ClusterConnection;
+        use axum::body::Body;
+        use axum::http::{header, Request, StatusCode};
+        use tower::ServiceExt;
+
+        // Only some sections answer; the rest end up in `errors`
+        let client = MockClient::new()
+            .with_json("_cluster/health", serde_json::json!({"status": "green"}))
+            .with_json(
+                "_nodes",
+                serde_json::json!({"nodes": {"a": {"name": "node-a"}}}),
+            )
+            .with_json("_cluster/pending_tasks", serde_json::json!({"tasks": []}));
+        let cluster_config = ClusterConfig::new("mock".to_string(), vec!["http://mock".into()]);
+        let cluster_manager = ClusterManager::with_connections(
+            vec![ClusterConnection::with_client(
+                &cluster_config,
+                Arc::new(client),
+            )],
+            std::time::Duration::from_secs(30),
+        );
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let server = Server::new(create_test_config(), cluster_manager, session_manager)
+            .await
+            .expect("create server");
+
+        let request = Request::builder()
+            .uri("/api/clusters/mock/diagnostics")
+            .body(Body::empty())
+            .expect("build request");
+        let response = server
+            .router()
+            .oneshot(request)
+            .await
+            .expect("send request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let disposition = response
+            .headers()
+            .get(header::CONTENT_DISPOSITION)
+            .and_then(|v| v.to_str().ok())
+            .expect("content disposition")
+            .to_string();
+        assert!(
+            disposition.starts_with("attachment; filename=\"diag-mock-")
+                && disposition.ends_with(".json\""),
+            "{}",
+            disposition
+        );
+
+        let bytes = axum::body::to_bytes(response.into_body(), 64 * 1024)
+            .await
+            .expect("read body");
+        let bundle: serde_json::Value = serde_json::from_slice(&bytes).expect("json body");
+        assert_eq!(bundle["cluster_id"], "mock");
+        assert!(bundle["timestamp"].is_string());
+        assert_eq!(bundle["health"]["status"], "green");
+        assert_eq!(bundle["nodes_info"]["nodes"]["a"]["name"], "node-a");
+        assert_eq!(bundle["pending_tasks"]["tasks"], serde_json::json!([]));
+        assert!(bundle["cluster_stats"].is_null());
+        let failed: Vec<&str> = bundle["errors"]
+            .as_object()
+            .expect("errors object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(failed, vec!["cluster_stats", "nodes_stats", "settings"]);
+    }
+
+    #[tokio::test]
+    async fn test_summary_route_aggregates_clusters() {
+        use crate::cluster::mock::MockClient;
+        use crate::cluster::ClusterConnection;
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let connection = |id: &str, client: MockClient| {
+            let config = ClusterConfig::new(id.to_string(), vec!["http://mock".into()]);
+            ClusterConnection::with_client(&config, Arc::new(client))
+        };
+        let green = MockClient::new()
+            .with_json(
+                "_cluster/health",
+                serde_json::json!({"status": "green", "number_of_nodes": 3}),
+            )
+            .with_json(
+                "_cat/indices",
+                serde_json::json!([
+                    {"index": "logs", "docs.count": "100"},
+                    {"index": "metrics", "docs.count": "50"}
+                ]),
+            );
+        let yellow = MockClient::new()
+            .with_json(
+                "_cluster/health",
+                serde_json::json!({"status": "yellow", "number_of_nodes": 1}),
+            )
+            .with_json(
+                "_cat/indices",
+                serde_json::json!([{"index": "orders", "docs.count": "7"}]),
+            );
+        // No health response registered: the cluster is unreachable
+        let down = MockClient::new();
+
+        let cluster_manager = ClusterManager::with_connections(
+            vec![
+                connection("green", green),
+                connection("yellow", yellow),
+                connection("down", down),
+            ],
+            std::time::Duration::from_secs(30),
+        );
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let server = Server::new(create_test_config(), cluster_manager, session_manager)
+            .await
+            .expect("create server");
+
+        let request = Request::builder()
+            .uri("/api/summary")
+            .body(Body::empty())
+            .expect("build request");
+        let response = server
+            .router()
+            .oneshot(request)
+            .await
+            .expect("send request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), 64 * 1024)
+            .await
+            .expect("read body");
+        let summary: serde_json::Value = serde_json::from_slice(&bytes).expect("json body");
+        assert_eq!(
+            summary,
+            serde_json::json!({
+                "clusters": 3,
+                "green": 1,
+                "yellow": 1,
+                "red": 0,
+                "unreachable": 1,
+                "status": "red",
+                "nodes": 4,
+                "indices": 3,
+                "documents": 157
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_indices_route_passes_index_pattern_to_elasticsearch() {
+        use crate::cluster::mock::MockClient;
+        use crate::cluster::ClusterConnection;
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let index = |name: &str| {
+            serde_json::json!({
+                "health": "green", "status": "open", "index": name, "pri": "1",
+                "rep": "0", "docs.count": "10", "store.size": "100"
+            })
+        };
+        let client = Arc::new(
+            MockClient::new()
+                .with_json(
+                    "_cat/indices/logs-*,-.*",
+                    serde_json::json!([index("logs-1"), index("logs-2")]),
+                )
+                .with_json(
+                    "_cat/indices/metrics-*",
+                    serde_json::json!([index("metrics-1"), index(".metrics-internal")]),
+                ),
+        );
+        let mut cluster_config = ClusterConfig::new("mock".to_string(), vec!["http://mock".into()]);
+        cluster_config.default_index_pattern = Some("logs-*".to_string());
+        let cluster_manager = ClusterManager::with_connections(
+            vec![ClusterConnection::with_client(
+                &cluster_config,
+                client.clone(),
+            )],
+            std::time::Duration::from_secs(30),
+        );
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let router = Server::new(create_test_config(), cluster_manager, session_manager)
+            .await
+            .expect("create server")
+            .router();
+
+        let mut names = Vec::new();
+        for uri in [
+            "/api/clusters/mock/indices",
+            "/api/clusters/mock/indices?pattern=metrics-*&exclude=&show_special=true",
+        ] {
+            let request = Request::builder()
+                .uri(uri)
+                .body(Body::empty())
+                .expect("build request");
+            let response = router.clone().oneshot(request).await.expect("send request");
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = axum::body::to_bytes(response.into_body(), 64 * 1024)
+                .await
+                .expect("read body");
+            let page: serde_json::Value = serde_json::from_slice(&bytes).expect("json body");
+            let page_names: Vec<String> = page["items"]
+                .as_array()
+                .expect("items")
+                .iter()
+                .map(|i| i["name"].as_str().expect("name").to_string())
+                .collect();
+            names.push(page_names);
+        }
+
+        assert_eq!(names[0], vec!["logs-1", "logs-2"]);
+        assert_eq!(names[1], vec!["metrics-1", ".metrics-internal"]);
+        assert!(client
+            .requests()
+            .iter()
+            .all(|r| r.starts_with("GET _cat/indices/")));
+    }
+
+    #[tokio::test]
+    async fn test_proxy_write_invalidates_cached_indices() {
+        use crate::cluster::mock::MockClient;
+        use crate::cluster::ClusterConnection;
+        use axum::body::Body;
+        use axum::http::{Method, Request, StatusCode};
+        use tower::ServiceExt;
+
+        let client = Arc::new(
+            MockClient::new()
+                .with_json(
+                    "_cat/indices",
+                    serde_json::json!([{"index": "logs", "docs.count": "1"}]),
+                )
+                .with_response(
+                    Method::PUT,
+                    "logs-new",
+                    StatusCode::OK,
+                    serde_json::json!({"acknowledged": true}),
+                ),
+        );
+        let cluster_config = ClusterConfig::new("mock".to_string(), vec!["http://mock".into()]);
+        let cluster_manager = ClusterManager::with_connections(
+            vec![ClusterConnection::with_client(
+                &cluster_config,
+                client.clone(),
+            )],
+            std::time::Duration::from_secs(30),
+        );
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let router = Server::new(create_test_config(), cluster_manager, session_manager)
+            .await
+            .expect("create server")
+            .router();
+        let send = |method: Method, uri: &str| {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .body(Body::empty())
+                .expect("build request");
+            router.clone().oneshot(request)
+        };
+        let listings = || {
+            client
+                .requests()
+                .iter()
+                .filter(|r| r.starts_with("GET _cat/indices"))
+                .count()
+        };
+
+        for _ in 0..2 {
+            let response = send(Method::GET, "/api/clusters/mock/indices?show_special=true")
+                .await
+                .expect("send request");
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        assert_eq!(listings(), 1, "second listing is served from the cache");
+
+        let response = send(Method::PUT, "/api/clusters/mock/logs-new")
+            .await
+            .expect("send request");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = send(Method::GET, "/api/clusters/mock/indices?show_special=true")
+            .await
+            .expect("send request");
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(listings(), 2, "listing is fetched again after the write");
+    }
+
+    #[tokio::test]
+    async fn test_disk_watermarks_are_cached_with_node_info() {
+        use crate::cluster::mock::MockClient;
+        use crate::cluster::ClusterConnection;
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let client = Arc::new(
+            MockClient::new()
+                .with_json(
+                    "_nodes",
+                    serde_json::json!({"nodes": {"n1": {"name": "node-1", "roles": ["data"]}}}),
+                )
+                .with_json(
+                    "_nodes/stats",
+                    serde_json::json!({"nodes": {"n1": {"fs": {"total": {
+                        "total_in_bytes": 100,
+                        "available_in_bytes": 20
+                    }}}}}),
+                )
+                .with_json(
+                    "_cluster/settings",
+                    serde_json::json!({"persistent": {
+                        "cluster.routing.allocation.disk.watermark.low": "75%"
+                    }}),
+                ),
+        );
+        let cluster_config = ClusterConfig::new("mock".to_string(), vec!["http://mock".into()]);
+        let cluster_manager = ClusterManager::with_connections(
+            vec![ClusterConnection::with_client(
+                &cluster_config,
+                client.clone(),
+            )],
+            std::time::Duration::from_secs(30),
+        );
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let router = Server::new(create_test_config(), cluster_manager, session_manager)
+            .await
+            .expect("create server")
+            .router();
+
+        for _ in 0..2 {
+            let request = Request::builder()
+                .uri("/api/clusters/mock/nodes")
+                .body(Body::empty())
+                .expect("build request");
+            let response = router.clone().oneshot(request).await.expect("send request");
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .expect("read body");
+            let body: serde_json::Value = serde_json::from_slice(&body).expect("parse body");
+            assert_eq!(body["items"][0]["diskWatermarkState"], "low");
+        }
+
+        let settings_reads = client
+            .requests()
+            .iter()
+            .filter(|r| r.starts_with("GET _cluster/settings"))
+            .count();
+        assert_eq!(settings_reads, 1, "second listing reuses the watermarks");
+    }
+
+    #[tokio::test]
+    async fn test_nodes_role_filter() {
+        use crate::cluster::mock::MockClient;
+        use crate::cluster::ClusterConnection;
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let client = Arc::new(
+            MockClient::new()
+                .with_json(
+                    "_nodes",
+                    serde_json::json!({"nodes": {
+                        "m1": {"name": "master-1", "roles": ["master"]},
+                        "d1": {"name": "data-1", "roles": ["data", "ingest"]},
+                        "h1": {"name": "hot-1", "roles": ["data_hot", "data_content"]},
+                        "ml1": {"name": "ml-1", "roles": ["ml", "remote_cluster_client"]},
+                        "c1": {"name": "coord-1", "roles": []}
+                    }}),
+                )
+                .with_json("_nodes/stats", serde_json::json!({"nodes": {}})),
+        );
+        let cluster_config = ClusterConfig::new("mock".to_string(), vec!["http://mock".into()]);
+        let cluster_manager = ClusterManager::with_connections(
+            vec![ClusterConnection::with_client(&cluster_config, client)],
+            std::time::Duration::from_secs(30),
+        );
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let router = Server::new(create_test_config(), cluster_manager, session_manager)
+            .await
+            .expect("create server")
+            .router();
+        let get = |query: &str| {
+            let request = Request::builder()
+                .uri(format!("/api/clusters/mock/nodes?{}", query))
+                .body(Body::empty())
+                .expect("build request");
+            router.clone().oneshot(request)
+        };
+
+        for (query, expected) in [
+            ("role=master", vec!["master-1"]),
+            ("role=data", vec!["data-1", "hot-1"]),
+            ("role=coordinating", vec!["coord-1"]),
+            ("role=ingest,%20ML", vec!["data-1", "ml-1"]),
+            (
+                "role=",
+                vec!["coord-1", "data-1", "hot-1", "master-1", "ml-1"],
+            ),
+            ("roles=data", vec!["data-1"]),
+            ("roles=data,data_hot&role=ingest", vec!["data-1"]),
+        ] {
+            let response = get(query).await.expect("send request");
+            assert_eq!(response.status(), StatusCode::OK, "{}", query);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .expect("read body");
+            let body: serde_json::Value = serde_json::from_slice(&body).expect("parse body");
+            let mut names: Vec<&str> = body["items"]
+                .as_array()
+                .expect("items")
+                .iter()
+                .filter_map(|node| node["name"].as_str())
+                .collect();
+            names.sort();
+            assert_eq!(names, expected, "{}", query);
+        }
+
+        let response = get("role=data,voting_only").await.expect("send request");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_branding_is_served_without_login() {
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let mut config = create_test_config();
+        config.auth.mode = AuthMode::LocalUsers;
+        config.auth.local_users = Some(vec![LocalUser {
+            username: "alice".to_string(),
+            password_hash: crate::auth::hash_password_with_cost("password", 4)
+                .expect("hash password"),
+            groups: vec![],
+        }]);
+        config.branding.title = Some("Search Platform".to_string());
+        config.branding.primary_color = Some("#1971c2".to_string());
+        config.branding.logo_url = Some("https://static.example.com/brand/logo.svg".to_string());
+        let cluster_manager = ClusterManager::new(
+            vec![ClusterConfig {
+                id: "test".to_string(),
+                nodes: vec!["http://localhost:9200".to_string()],
+                ..Default::default()
+            }],
+            std::time::Duration::from_secs(30),
+        )
+        .await
+        .expect("create cluster manager");
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let router = Server::new(config, cluster_manager, session_manager)
+            .await
+            .expect("create server")
+            .router();
+        let get = |uri: &str| {
+            Request::builder()
+                .uri(uri)
+                .body(Body::empty())
+                .expect("build request")
+        };
+
+        let response = router
+            .clone()
+            .oneshot(get("/api/branding"))
+            .await
+            .expect("send request");
+        assert_eq!(response.status(), StatusCode::OK);
+        // The login screen can load the logo from its origin
+        let csp = response
+            .headers()
+            .get(axum::http::header::CONTENT_SECURITY_POLICY)
+            .and_then(|v| v.to_str().ok())
+            .expect("CSP header")
+            .to_string();
+        assert!(
+            csp.contains("img-src 'self' data: https://static.example.com;"),
+            "{}",
+            csp
+        );
+        let bytes = axum::body::to_bytes(response.into_body(), 64 * 1024)
+            .await
+            .expect("read body");
+        let branding: serde_json::Value = serde_json::from_slice(&bytes).expect("json body");
+        assert_eq!(
+            branding,
+            serde_json::json!({
+                "title": "Search Platform",
+                "logo_url": "https://static.example.com/brand/logo.svg",
+                "primary_color": "#1971c2"
+            })
+        );
+
+        // Everything else still needs a session
+        let response = router
+            .oneshot(get("/api/clusters"))
+            .await
+            .expect("send request");
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_routes_are_nested_under_base_path() {
+        use crate::cluster::mock::MockClient;
+        use crate::cluster::ClusterConnection;
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let mut config = create_test_config();
+        config.server.base_path = Some("/secan/".to_string());
+        let cluster_config = ClusterConfig::new("mock".to_string(), vec!["http://mock".into()]);
+        let cluster_manager = ClusterManager::with_connections(
+            vec![ClusterConnection::with_client(
+                &cluster_config,
+                Arc::new(MockClient::new()),
+            )],
+            std::time::Duration::from_secs(30),
+        );
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let router = Server::new(config, cluster_manager, session_manager)
+            .await
+            .expect("create server")
+            .router();
+        let get = |uri: &str| {
+            Request::builder()
+                .uri(uri)
+                .body(Body::empty())
+                .expect("build request")
+        };
+
+        let response = router
+            .clone()
+            .oneshot(get("/secan/api/clusters"))
+            .await
+            .expect("send request");
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), 64 * 1024)
+            .await
+            .expect("read body");
+        let clusters: serde_json::Value = serde_json::from_slice(&bytes).expect("json body");
+        assert!(clusters["items"].is_array(), "{}", clusters);
+
+        for uri in ["/api/clusters", "/health", "/secanx/api/clusters"] {
+            let response = router
+                .clone()
+                .oneshot(get(uri))
+                .await
+                .expect("send request");
+            assert_eq!(response.status(), StatusCode::NOT_FOUND, "{}", uri);
+        }
+
+        // Client-side routes get index.html pointing at the base path
+        let response = router
+            .clone()
+            .oneshot(get("/secan/clusters/mock"))
+            .await
+            .expect("send request");
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), 64 * 1024)
+            .await
+            .expect("read body");
+        assert!(String::from_utf8_lossy(&bytes).contains(r#"<base href="/secan/">"#));
+    }
+
+    #[tokio::test]
+    async fn test_proxy_forwards_request_id_as_opaque_id() {
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let es = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/_cat/health"))
+            .and(header("x-opaque-id", "trace-abc-123"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("green"))
+            .expect(1)
+            .mount(&es)
+            .await;
+
+        let cluster_config = ClusterConfig {
+            id: "test".to_string(),
+            nodes: vec![es.uri()],
+            ..Default::default()
+        };
+        let cluster_manager =
+            ClusterManager::new(vec![cluster_config], std::time::Duration::from_secs(30))
+                .await
+                .expect("create cluster manager");
+        let session_manager = SessionManager::new(SessionConfig::new(60, TEST_SECRET.to_string()));
+        let server = Server::new(create_test_config(), cluster_manager, session_manager)
+            .await
+            .expect("create server");
+
+        let request = Request::builder()
+            .uri("/api/clusters/test/_cat/health")
+            .header("x-request-id", "trace-abc-123")
+            .body(Body::empty())
+            .expect("build request");
+        let response = server
+            .router()
+            .oneshot(request)
+            .await
+            .expect("send request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get("x-request-id").map(|v| v.as_bytes()),
+            Some(&b"trace-abc-123"[..])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_unix_socket_replaces_stale_socket() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let path = dir.path().join("secan.sock");
+
+        // A socket file left behind by a process that is gone
+        drop(std::os::unix::net::UnixListener::bind(&path).expect("bind stale socket"));
+        assert!(path.exists());
+
+        let listener = bind_unix_socket(&path, 0o600).expect("bind over stale socket");
+        let mode = std::fs::metadata(&path)
+            .expect("metadata")
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        // The socket is now in use and cannot be taken over
+        assert!(bind_unix_socket(&path, 0o600).is_err());
+
+        let app = Router::new().route("/health/live", get(|| async { "ok" }));
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let mut stream = tokio::net::UnixStream::connect(&path)
+            .await
+            .expect("connect");
+        stream
+            .write_all(b"GET /health/live HTTP/1.1\r\nHost: secan\r\nConnection: close\r\n\r\n")
+            .await
+            .expect("write request");
+        let mut response = String::new();
+        stream
+            .read_to_string(&mut response)
+            .await
+            .expect("read response");
+        assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+    }
+
+    #[test]
+    fn test_bind_unix_socket_refuses_regular_file() {
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let path = dir.path().join("secan.sock");
+        std::fs::write(&path, "not a socket").expect("write file");
+
+        let err = bind_unix_socket(&path, 0o660).expect_err("regular file");
+        assert!(err.to_string().contains("not a socket"), "{}", err);
+        assert!(path.exists());
+    }
 }
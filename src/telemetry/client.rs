@@ -3,7 +3,7 @@
 //! This module provides tracing for Elasticsearch client operations
 //! using the tracing crate with OpenTelemetry integration.
 
-use crate::cluster::client::{Client, ElasticsearchClient};
+use crate::cluster::client::ElasticsearchClient;
 use anyhow::Result;
 use async_trait::async_trait;
 use reqwest::{Method, Response};
@@ -20,17 +20,19 @@ pub trait InstrumentedElasticsearchClient: ElasticsearchClient {
     ///
     /// Creates a child span for the ES operation with appropriate attributes.
     /// The span will automatically be parented to the current tracing span.
+    /// `opaque_id` is forwarded as the `X-Opaque-Id` header.
     async fn instrumented_request(
         &self,
         method: Method,
         path: &str,
         body: Option<Value>,
         cluster_id: &str,
+        opaque_id: Option<&str>,
     ) -> Result<Response>;
 }
 
 #[async_trait]
-impl InstrumentedElasticsearchClient for Client {
+impl<T: ElasticsearchClient + ?Sized> InstrumentedElasticsearchClient for T {
     #[instrument(
         skip(self, body),
         fields(
@@ -46,12 +48,15 @@ impl InstrumentedElasticsearchClient for Client {
         path: &str,
         body: Option<Value>,
         cluster_id: &str,
+        opaque_id: Option<&str>,
     ) -> Result<Response> {
         let _operation = extract_operation_name(path);
         let full_url = format!("{}{}", self.base_url(), path);
 
         // Execute the request
-        let result = self.request(method, path, body.clone()).await;
+        let result = self
+            .request_with_opaque_id(method, path, body.clone(), opaque_id)
+            .await;
 
         // Record response attributes or errors using tracing
         match &result {
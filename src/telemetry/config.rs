@@ -180,6 +180,18 @@ impl TelemetryConfig {
         })
     }
 
+    /// Export to `endpoint` from the `tracing.otlp_endpoint` config, if set
+    ///
+    /// Setting the endpoint enables telemetry; without it the environment
+    /// configuration is left as is.
+    pub fn with_otlp_endpoint(mut self, endpoint: Option<&str>) -> Self {
+        if let Some(endpoint) = endpoint {
+            self.enabled = true;
+            self.otlp_endpoint = endpoint.to_string();
+        }
+        self
+    }
+
     /// Get the default OTLP endpoint based on protocol
     pub fn default_endpoint(&self) -> &str {
         &self.otlp_endpoint
@@ -281,6 +293,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_with_otlp_endpoint_enables_export() {
+        let config = TelemetryConfig::from_env().expect("telemetry config from env");
+        let endpoint = config.otlp_endpoint.clone();
+        let enabled = config.enabled;
+
+        let unchanged = config.clone().with_otlp_endpoint(None);
+        assert_eq!(unchanged.enabled, enabled);
+        assert_eq!(unchanged.otlp_endpoint, endpoint);
+
+        let configured = config.with_otlp_endpoint(Some("http://collector:4318/v1/traces"));
+        assert!(configured.enabled);
+        assert_eq!(configured.otlp_endpoint, "http://collector:4318/v1/traces");
+    }
+
     #[test]
     fn test_telemetry_config_from_env_disabled() {
         // Save original value
@@ -30,6 +30,7 @@ pub mod middleware;
 
 pub use config::{BatchConfig, OtlpProtocol, SamplerConfig, TelemetryConfig};
 
+use crate::config::{LoggingConfig, TracingConfig};
 use anyhow::{Context, Result};
 use opentelemetry::global;
 use tracing_subscriber::layer::SubscriberExt;
@@ -83,7 +84,18 @@ impl Drop for TelemetryGuard {
 /// }
 /// ```
 pub fn init_telemetry() -> Option<TelemetryGuard> {
-    match init_telemetry_inner() {
+    init_telemetry_with_config(&LoggingConfig::default(), &TracingConfig::default())
+}
+
+/// Initialize telemetry from the `logging` and `tracing` config sections
+///
+/// Spans are exported when `tracing.otlp_endpoint` is set, in addition to
+/// the `OTEL_*` environment variables.
+pub fn init_telemetry_with_config(
+    logging: &LoggingConfig,
+    tracing: &TracingConfig,
+) -> Option<TelemetryGuard> {
+    match init_telemetry_inner(logging, tracing) {
         Ok(guard) => guard,
         Err(e) => {
             // Use eprintln! since tracing subscriber may not be initialized yet
@@ -93,8 +105,13 @@ pub fn init_telemetry() -> Option<TelemetryGuard> {
     }
 }
 
-fn init_telemetry_inner() -> Result<Option<TelemetryGuard>> {
-    let config = TelemetryConfig::from_env().context("Failed to parse telemetry configuration")?;
+fn init_telemetry_inner(
+    logging: &LoggingConfig,
+    tracing: &TracingConfig,
+) -> Result<Option<TelemetryGuard>> {
+    let config = TelemetryConfig::from_env()
+        .context("Failed to parse telemetry configuration")?
+        .with_otlp_endpoint(tracing.otlp_endpoint.as_deref());
 
     if !config.enabled {
         return Ok(None);
@@ -106,7 +123,7 @@ fn init_telemetry_inner() -> Result<Option<TelemetryGuard>> {
         .unwrap_or(false);
 
     if use_console {
-        init_tracing_subscriber_only()?;
+        init_tracing_subscriber_only(logging)?;
         return Ok(Some(TelemetryGuard { provider: None }));
     }
 
@@ -121,19 +138,13 @@ fn init_telemetry_inner() -> Result<Option<TelemetryGuard>> {
     // This automatically uses the global tracer provider
     let otel_layer = tracing_opentelemetry::layer();
 
-    // Initialize the tracing subscriber with the OTel layer and JSON formatting
+    // Initialize the tracing subscriber with the OTel layer and configured log formatting
     let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
 
     tracing_subscriber::registry()
         .with(env_filter)
         .with(otel_layer)
-        .with(
-            tracing_subscriber::fmt::layer()
-                .json()
-                .with_target(true)
-                .with_thread_ids(true)
-                .with_line_number(true),
-        )
+        .with(crate::logging::fmt_layer(logging)?)
         .init();
 
     tracing::debug!(
@@ -149,19 +160,8 @@ fn init_telemetry_inner() -> Result<Option<TelemetryGuard>> {
 }
 
 /// Initialize tracing subscriber without OpenTelemetry (console mode)
-fn init_tracing_subscriber_only() -> Result<()> {
-    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-
-    tracing_subscriber::registry()
-        .with(env_filter)
-        .with(
-            tracing_subscriber::fmt::layer()
-                .json()
-                .with_target(true)
-                .with_thread_ids(true)
-                .with_line_number(true),
-        )
-        .init();
+fn init_tracing_subscriber_only(logging: &LoggingConfig) -> Result<()> {
+    crate::logging::init(logging)?;
 
     tracing::debug!("Tracing initialized (console mode)");
     Ok(())
@@ -296,3 +296,115 @@ clusters:
         }
     }
 }
+
+#[test]
+#[serial]
+fn test_load_from_path_reads_given_file() {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let config_path = temp_dir.path().join("secan-staging.yaml");
+
+    let yaml_content = r#"
+auth:
+  mode: open
+
+clusters:
+  - id: "staging"
+    name: "Staging"
+    nodes:
+      - "http://localhost:9200"
+"#;
+
+    fs::write(&config_path, yaml_content).expect("write config file");
+
+    let (config, warnings) = Config::load_from_path(&config_path).expect("load config");
+
+    assert!(warnings.is_empty());
+    assert_eq!(config.clusters.len(), 1);
+    assert_eq!(config.clusters[0].id, "staging");
+}
+
+#[test]
+#[serial]
+fn test_load_from_path_reports_validation_error() {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let config_path = temp_dir.path().join("config.yaml");
+
+    let yaml_content = r#"
+server:
+  port: 0
+
+auth:
+  mode: open
+
+clusters:
+  - id: "prod"
+    name: "Production"
+    nodes:
+      - "http://localhost:9200"
+"#;
+
+    fs::write(&config_path, yaml_content).expect("write config file");
+
+    let err = Config::load_from_path(&config_path).expect_err("port 0 is invalid");
+    assert!(
+        format!("{:#}", err).contains("port"),
+        "Error should mention the port: {:#}",
+        err
+    );
+}
+
+#[test]
+fn test_load_from_path_missing_file() {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let missing = temp_dir.path().join("missing.yaml");
+
+    let err = Config::load_from_path(&missing).expect_err("missing file");
+    assert!(err.to_string().contains("not found"));
+}
+
+#[test]
+#[serial]
+fn test_load_from_path_merges_includes() {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    fs::create_dir(temp_dir.path().join("shared")).expect("create shared dir");
+
+    fs::write(
+        temp_dir.path().join("shared/clusters.yaml"),
+        r#"
+server:
+  port: 9000
+
+clusters:
+  - id: "prod"
+    nodes:
+      - "http://localhost:9200"
+  - id: "staging"
+    nodes:
+      - "http://localhost:9201"
+"#,
+    )
+    .expect("write include");
+
+    let config_path = temp_dir.path().join("config.yaml");
+    fs::write(
+        &config_path,
+        r#"
+includes:
+  - shared/clusters.yaml
+
+server:
+  port: 9100
+
+auth:
+  mode: open
+"#,
+    )
+    .expect("write config file");
+
+    let (config, _) = Config::load_from_path(&config_path).expect("load config");
+
+    assert_eq!(config.clusters.len(), 2);
+    assert_eq!(config.clusters[1].id, "staging");
+    // The including file overrides values from its includes
+    assert_eq!(config.server.port, 9100);
+}
@@ -36,6 +36,7 @@ async fn test_health_check_returns_ok() {
     let state = secan::routes::health::HealthState {
         cluster_manager: Arc::new(cluster_manager),
         session_manager: Arc::new(session_manager),
+        read_only: false,
     };
 
     let locked_state = Arc::new(RwLock::new(state));
@@ -73,6 +74,7 @@ async fn test_readiness_check_includes_dependencies() {
     let state = secan::routes::health::HealthState {
         cluster_manager: Arc::new(cluster_manager),
         session_manager: Arc::new(session_manager),
+        read_only: false,
     };
 
     let locked_state = Arc::new(RwLock::new(state));
@@ -93,6 +95,7 @@ fn test_health_response_serialization() {
     let response = secan::routes::health::HealthResponse {
         status: "healthy".to_string(),
         message: "Server is running".to_string(),
+        read_only: None,
     };
 
     let json = serde_json::to_string(&response).expect("serialize health response");
@@ -174,5 +177,8 @@ async fn test_version_endpoint() {
     let (status, response) = secan::routes::health::get_version().await;
 
     assert_eq!(status, axum::http::StatusCode::OK);
-    assert!(!response.version.is_empty());
+    assert_eq!(response.version, env!("CARGO_PKG_VERSION"));
+    assert!(!response.git_sha.is_empty());
+    assert!(!response.build_time.is_empty());
+    assert!(response.rustc_version.starts_with("rustc"));
 }
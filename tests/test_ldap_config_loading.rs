@@ -10,6 +10,8 @@ fn test_ldap_config_validation_at_startup() {
         auth: AuthConfig {
             mode: AuthMode::Ldap,
             session_timeout_minutes: 60,
+            session_idle_timeout_minutes: None,
+            session_cookie: Default::default(),
             local_users: None,
             oidc: None,
             ldap: Some(LdapConfig {
@@ -33,6 +35,7 @@ fn test_ldap_config_validation_at_startup() {
                 display_name_attribute: "cn".to_string(),
             }),
             roles: Vec::new(),
+            api_tokens: Vec::new(),
         },
         clusters: vec![secan::config::ClusterConfig::new(
             "test".to_string(),
@@ -40,6 +43,12 @@ fn test_ldap_config_validation_at_startup() {
         )],
         cache: secan::config::CacheConfig::default(),
         audit_log: false,
+        proxy_allow: None,
+        notifications: secan::config::NotificationsConfig::default(),
+        logging: secan::config::LoggingConfig::default(),
+        tracing: secan::config::TracingConfig::default(),
+        default_cluster: None,
+        branding: Default::default(),
         topology_max_tiles_per_request: None,
         topology_max_concurrent_generations: None,
         topology_generation_acquire_timeout_seconds: None,
@@ -57,6 +66,8 @@ fn test_ldap_config_validation_fails_with_invalid_url() {
         auth: AuthConfig {
             mode: AuthMode::Ldap,
             session_timeout_minutes: 60,
+            session_idle_timeout_minutes: None,
+            session_cookie: Default::default(),
             local_users: None,
             oidc: None,
             ldap: Some(LdapConfig {
@@ -80,6 +91,7 @@ fn test_ldap_config_validation_fails_with_invalid_url() {
                 display_name_attribute: "cn".to_string(),
             }),
             roles: Vec::new(),
+            api_tokens: Vec::new(),
         },
         clusters: vec![secan::config::ClusterConfig::new(
             "test".to_string(),
@@ -87,6 +99,12 @@ fn test_ldap_config_validation_fails_with_invalid_url() {
         )],
         cache: secan::config::CacheConfig::default(),
         audit_log: false,
+        proxy_allow: None,
+        notifications: secan::config::NotificationsConfig::default(),
+        logging: secan::config::LoggingConfig::default(),
+        tracing: secan::config::TracingConfig::default(),
+        default_cluster: None,
+        branding: Default::default(),
         topology_max_tiles_per_request: None,
         topology_max_concurrent_generations: None,
         topology_generation_acquire_timeout_seconds: None,
@@ -109,10 +127,13 @@ fn test_ldap_config_validation_fails_without_config() {
         auth: AuthConfig {
             mode: AuthMode::Ldap,
             session_timeout_minutes: 60,
+            session_idle_timeout_minutes: None,
+            session_cookie: Default::default(),
             local_users: None,
             oidc: None,
             ldap: None, // No LDAP config
             roles: Vec::new(),
+            api_tokens: Vec::new(),
         },
         clusters: vec![secan::config::ClusterConfig::new(
             "test".to_string(),
@@ -120,6 +141,12 @@ fn test_ldap_config_validation_fails_without_config() {
         )],
         cache: secan::config::CacheConfig::default(),
         audit_log: false,
+        proxy_allow: None,
+        notifications: secan::config::NotificationsConfig::default(),
+        logging: secan::config::LoggingConfig::default(),
+        tracing: secan::config::TracingConfig::default(),
+        default_cluster: None,
+        branding: Default::default(),
         topology_max_tiles_per_request: None,
         topology_max_concurrent_generations: None,
         topology_generation_acquire_timeout_seconds: None,
@@ -142,6 +169,8 @@ fn test_ldap_config_validation_fails_with_empty_bind_dn() {
         auth: AuthConfig {
             mode: AuthMode::Ldap,
             session_timeout_minutes: 60,
+            session_idle_timeout_minutes: None,
+            session_cookie: Default::default(),
             local_users: None,
             oidc: None,
             ldap: Some(LdapConfig {
@@ -165,6 +194,7 @@ fn test_ldap_config_validation_fails_with_empty_bind_dn() {
                 display_name_attribute: "cn".to_string(),
             }),
             roles: Vec::new(),
+            api_tokens: Vec::new(),
         },
         clusters: vec![secan::config::ClusterConfig::new(
             "test".to_string(),
@@ -172,6 +202,12 @@ fn test_ldap_config_validation_fails_with_empty_bind_dn() {
         )],
         cache: secan::config::CacheConfig::default(),
         audit_log: false,
+        proxy_allow: None,
+        notifications: secan::config::NotificationsConfig::default(),
+        logging: secan::config::LoggingConfig::default(),
+        tracing: secan::config::TracingConfig::default(),
+        default_cluster: None,
+        branding: Default::default(),
         topology_max_tiles_per_request: None,
         topology_max_concurrent_generations: None,
         topology_generation_acquire_timeout_seconds: None,
@@ -194,6 +230,8 @@ fn test_ldap_config_validation_fails_with_zero_timeout() {
         auth: AuthConfig {
             mode: AuthMode::Ldap,
             session_timeout_minutes: 60,
+            session_idle_timeout_minutes: None,
+            session_cookie: Default::default(),
             local_users: None,
             oidc: None,
             ldap: Some(LdapConfig {
@@ -217,6 +255,7 @@ fn test_ldap_config_validation_fails_with_zero_timeout() {
                 display_name_attribute: "cn".to_string(),
             }),
             roles: Vec::new(),
+            api_tokens: Vec::new(),
         },
         clusters: vec![secan::config::ClusterConfig::new(
             "test".to_string(),
@@ -224,6 +263,12 @@ fn test_ldap_config_validation_fails_with_zero_timeout() {
         )],
         cache: secan::config::CacheConfig::default(),
         audit_log: false,
+        proxy_allow: None,
+        notifications: secan::config::NotificationsConfig::default(),
+        logging: secan::config::LoggingConfig::default(),
+        tracing: secan::config::TracingConfig::default(),
+        default_cluster: None,
+        branding: Default::default(),
         topology_max_tiles_per_request: None,
         topology_max_concurrent_generations: None,
         topology_generation_acquire_timeout_seconds: None,
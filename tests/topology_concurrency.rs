@@ -50,6 +50,10 @@ async fn concurrent_generation_is_limited_and_cached_paths_bypass() {
         topology_generation_semaphore: topology_generation_semaphore.clone(),
         topology_generation_acquire_timeout_seconds: 1, // short timeout for test
         audit_log: false,
+        proxy_allow: None,
+        max_proxy_body_bytes: 1024 * 1024,
+        cors_allowed_origins: vec![],
+        recovery_stream_slots: Default::default(),
         rbac: secan::auth::RbacManager::new(vec![]),
     };
 
@@ -91,7 +95,7 @@ async fn concurrent_generation_is_limited_and_cached_paths_bypass() {
                 .expect("read response body");
             let v: serde_json::Value =
                 serde_json::from_slice(&body_bytes).expect("parse error body JSON");
-            assert_eq!(v["error"], "generation_concurrency_limited");
+            assert_eq!(v["code"], "rate_limited");
         }
         Ok((status, ..)) => panic!("expected error but got success: {}", status),
     }